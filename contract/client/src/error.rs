@@ -0,0 +1,30 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ClientError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("MissingEventAttribute: Event {} has no attribute {}", event, key)]
+    MissingEventAttribute { event: String, key: String },
+
+    #[error(
+        "InvalidEventAttribute: Attribute {} of event {} has an invalid value: {}",
+        key,
+        event,
+        reason
+    )]
+    InvalidEventAttribute {
+        event: String,
+        key: String,
+        reason: String,
+    },
+}
+
+// Extracts the code of a contract error from the message of a failed transaction, which wraps the contract error message
+pub fn contract_error_code(message: &str) -> Option<u32> {
+    let (_, rest) = message.split_once("[E")?;
+    let (code, _) = rest.split_once(']')?;
+    code.parse().ok()
+}
@@ -0,0 +1,261 @@
+// Parsers of the custom events emitted by the bridge contract
+use cosmwasm_std::{Coin, Event, Uint128};
+use serde::de::DeserializeOwned;
+use std::str::FromStr;
+
+use coreumbridge_xrpl::{
+    evidence::Evidence,
+    state::{TokenState, TokenStateTransitionReason},
+};
+
+use crate::error::ClientError;
+
+// Prefix added by wasmd to the type of the events emitted by contracts
+pub const WASM_EVENT_PREFIX: &str = "wasm-";
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum BridgeEvent {
+    EvidenceSaved(EvidenceSaved),
+    OperationSigningProgress(OperationSigningProgress),
+    PendingOperationsRebased(PendingOperationsRebased),
+    RelayerInfractionRecorded(RelayerInfractionRecorded),
+    XRPLTokenRegistrationQueued(XRPLTokenRegistration),
+    XRPLTokenRegistrationActivated(XRPLTokenRegistration),
+    TokenStateTransition(TokenStateTransition),
+    EmergencyWithdrawalScheduled(EmergencyWithdrawalScheduled),
+    EmergencyWithdrawalExecuted(EmergencyWithdrawalExecuted),
+    EmergencyWithdrawalCancelled,
+    FeesCollected(FeesCollected),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EvidenceSaved {
+    pub relayer: String,
+    pub evidence_hash: String,
+    // Invalid transaction results are saved without a transaction hash
+    pub tx_hash: Option<String>,
+    pub evidence: Evidence,
+    pub threshold_reached: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct OperationSigningProgress {
+    pub operation_id: u64,
+    pub operation_version: u64,
+    pub signature_count: u32,
+    pub quorum: u32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingOperationsRebased {
+    pub xrpl_base_fee: u64,
+    pub operation_ids: Vec<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RelayerInfractionRecorded {
+    pub relayer: String,
+    pub tx_hash: String,
+    pub evidence: Evidence,
+    pub conflicting_evidence: Evidence,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct XRPLTokenRegistration {
+    pub issuer: String,
+    pub currency: String,
+    pub denom: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenStateTransition {
+    pub denom: String,
+    // None when the token was just registered
+    pub from: Option<TokenState>,
+    pub to: TokenState,
+    pub reason: TokenStateTransitionReason,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EmergencyWithdrawalScheduled {
+    pub recipient: String,
+    pub amounts: Vec<Coin>,
+    pub executable_at: u64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EmergencyWithdrawalExecuted {
+    pub recipient: String,
+    pub amounts: Vec<Coin>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeesCollected {
+    pub denom: String,
+    pub bridging_fee: Uint128,
+    pub priority_fee: Uint128,
+    pub truncation_fee: Uint128,
+}
+
+// Parses all the events of a transaction that were emitted by the bridge contract, skipping any other event
+pub fn parse_events(events: &[Event]) -> Result<Vec<BridgeEvent>, ClientError> {
+    let mut bridge_events = vec![];
+    for event in events {
+        if let Some(bridge_event) = parse_event(event)? {
+            bridge_events.push(bridge_event);
+        }
+    }
+
+    Ok(bridge_events)
+}
+
+// Returns None if the event is not one of the events of the bridge contract
+// Events can be provided with or without the prefix that wasmd adds to their type
+pub fn parse_event(event: &Event) -> Result<Option<BridgeEvent>, ClientError> {
+    let ty = event
+        .ty
+        .strip_prefix(WASM_EVENT_PREFIX)
+        .unwrap_or(&event.ty);
+
+    let bridge_event = match ty {
+        "evidence_saved" => BridgeEvent::EvidenceSaved(EvidenceSaved {
+            relayer: attribute(event, "relayer")?.to_owned(),
+            evidence_hash: attribute(event, "evidence_hash")?.to_owned(),
+            tx_hash: optional_attribute(event, "tx_hash").map(str::to_owned),
+            evidence: json_attribute(event, "evidence")?,
+            threshold_reached: parsed_attribute(event, "threshold_reached")?,
+        }),
+        "operation_signing_progress" => {
+            BridgeEvent::OperationSigningProgress(OperationSigningProgress {
+                operation_id: parsed_attribute(event, "operation_id")?,
+                operation_version: parsed_attribute(event, "operation_version")?,
+                signature_count: parsed_attribute(event, "signature_count")?,
+                quorum: parsed_attribute(event, "quorum")?,
+            })
+        }
+        "pending_operations_rebased" => {
+            let operation_ids = attribute(event, "operation_ids")?;
+            BridgeEvent::PendingOperationsRebased(PendingOperationsRebased {
+                xrpl_base_fee: parsed_attribute(event, "xrpl_base_fee")?,
+                operation_ids: operation_ids
+                    .split(',')
+                    .filter(|operation_id| !operation_id.is_empty())
+                    .map(|operation_id| parse_value(event, "operation_ids", operation_id))
+                    .collect::<Result<Vec<u64>, ClientError>>()?,
+            })
+        }
+        "relayer_infraction_recorded" => {
+            BridgeEvent::RelayerInfractionRecorded(RelayerInfractionRecorded {
+                relayer: attribute(event, "relayer")?.to_owned(),
+                tx_hash: attribute(event, "tx_hash")?.to_owned(),
+                evidence: json_attribute(event, "evidence")?,
+                conflicting_evidence: json_attribute(event, "conflicting_evidence")?,
+            })
+        }
+        "xrpl_token_registration_queued" => {
+            BridgeEvent::XRPLTokenRegistrationQueued(xrpl_token_registration(event)?)
+        }
+        "xrpl_token_registration_activated" => {
+            BridgeEvent::XRPLTokenRegistrationActivated(xrpl_token_registration(event)?)
+        }
+        "token_state_transition" => {
+            let from = attribute(event, "from")?;
+            BridgeEvent::TokenStateTransition(TokenStateTransition {
+                denom: attribute(event, "denom")?.to_owned(),
+                from: match from {
+                    "none" => None,
+                    state => Some(enum_value(event, "from", state)?),
+                },
+                to: enum_value(event, "to", attribute(event, "to")?)?,
+                reason: enum_value(event, "reason", attribute(event, "reason")?)?,
+            })
+        }
+        "emergency_withdrawal_scheduled" => {
+            BridgeEvent::EmergencyWithdrawalScheduled(EmergencyWithdrawalScheduled {
+                recipient: attribute(event, "recipient")?.to_owned(),
+                amounts: json_attribute(event, "amounts")?,
+                executable_at: parsed_attribute(event, "executable_at")?,
+            })
+        }
+        "emergency_withdrawal_executed" => {
+            BridgeEvent::EmergencyWithdrawalExecuted(EmergencyWithdrawalExecuted {
+                recipient: attribute(event, "recipient")?.to_owned(),
+                amounts: json_attribute(event, "amounts")?,
+            })
+        }
+        "emergency_withdrawal_cancelled" => BridgeEvent::EmergencyWithdrawalCancelled,
+        "fees_collected" => BridgeEvent::FeesCollected(FeesCollected {
+            denom: attribute(event, "denom")?.to_owned(),
+            bridging_fee: parsed_attribute(event, "bridging_fee")?,
+            priority_fee: parsed_attribute(event, "priority_fee")?,
+            truncation_fee: parsed_attribute(event, "truncation_fee")?,
+        }),
+        _ => return Ok(None),
+    };
+
+    Ok(Some(bridge_event))
+}
+
+fn xrpl_token_registration(event: &Event) -> Result<XRPLTokenRegistration, ClientError> {
+    Ok(XRPLTokenRegistration {
+        issuer: attribute(event, "issuer")?.to_owned(),
+        currency: attribute(event, "currency")?.to_owned(),
+        denom: attribute(event, "denom")?.to_owned(),
+    })
+}
+
+fn attribute<'a>(event: &'a Event, key: &str) -> Result<&'a str, ClientError> {
+    optional_attribute(event, key).ok_or_else(|| ClientError::MissingEventAttribute {
+        event: event.ty.clone(),
+        key: key.to_owned(),
+    })
+}
+
+fn optional_attribute<'a>(event: &'a Event, key: &str) -> Option<&'a str> {
+    event
+        .attributes
+        .iter()
+        .find(|attribute| attribute.key == key)
+        .map(|attribute| attribute.value.as_str())
+}
+
+fn parsed_attribute<T>(event: &Event, key: &str) -> Result<T, ClientError>
+where
+    T: FromStr,
+    T::Err: ToString,
+{
+    parse_value(event, key, attribute(event, key)?)
+}
+
+fn parse_value<T>(event: &Event, key: &str, value: &str) -> Result<T, ClientError>
+where
+    T: FromStr,
+    T::Err: ToString,
+{
+    value
+        .parse()
+        .map_err(|e: T::Err| invalid_attribute(event, key, e.to_string()))
+}
+
+fn json_attribute<T: DeserializeOwned>(event: &Event, key: &str) -> Result<T, ClientError> {
+    serde_json::from_str(attribute(event, key)?)
+        .map_err(|e| invalid_attribute(event, key, e.to_string()))
+}
+
+// Enums are emitted with the same snake case names they have in JSON
+fn enum_value<T: DeserializeOwned>(
+    event: &Event,
+    key: &str,
+    value: &str,
+) -> Result<T, ClientError> {
+    serde_json::from_value(serde_json::Value::String(value.to_owned()))
+        .map_err(|e| invalid_attribute(event, key, e.to_string()))
+}
+
+fn invalid_attribute(event: &Event, key: &str, reason: String) -> ClientError {
+    ClientError::InvalidEventAttribute {
+        event: event.ty.clone(),
+        key: key.to_owned(),
+        reason,
+    }
+}
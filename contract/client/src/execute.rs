@@ -0,0 +1,913 @@
+// Builders of all the messages that can be executed on the bridge contract, with the funds that must be attached to them
+use cosmwasm_std::{
+    to_json_binary, to_json_string, Addr, Coin, CosmosMsg, Decimal, StdResult, Uint128, WasmMsg,
+};
+use cw_ownable::Action;
+
+use coreumbridge_xrpl::{
+    evidence::Evidence,
+    light_client::{LightClientConfig, XRPLTransactionProof},
+    msg::{ExecuteMsg, TokenPriceUpdate},
+    relayer::Relayer,
+    state::{BridgeState, HaltReason, RoundingMode, TokenState, USDBridgingFee, XRPLRecipientRule},
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExecuteRequest {
+    pub msg: ExecuteMsg,
+    pub funds: Vec<Coin>,
+}
+
+impl ExecuteRequest {
+    pub const fn new(msg: ExecuteMsg) -> Self {
+        Self { msg, funds: vec![] }
+    }
+
+    // Funds sent with the message, e.g. the amount to bridge or the issue fee when registering tokens
+    pub fn with_funds(mut self, funds: Vec<Coin>) -> Self {
+        self.funds = funds;
+        self
+    }
+
+    pub fn to_json(&self) -> StdResult<String> {
+        to_json_string(&self.msg)
+    }
+
+    pub fn to_wasm_msg(&self, contract_addr: impl Into<String>) -> StdResult<WasmMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr: contract_addr.into(),
+            msg: to_json_binary(&self.msg)?,
+            funds: self.funds.clone(),
+        })
+    }
+
+    pub fn to_cosmos_msg(&self, contract_addr: impl Into<String>) -> StdResult<CosmosMsg> {
+        Ok(self.to_wasm_msg(contract_addr)?.into())
+    }
+}
+
+// Transfer, accept or renounce the ownership of the contract
+pub fn update_ownership(action: Action) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateOwnership(action))
+}
+
+// Registers a Coreum token so that it can be bridged to XRPL. IBC vouchers (ibc/<HASH>) can also be registered
+// Only the owner can do this
+pub fn register_coreum_token(
+    denom: impl Into<String>,
+    decimals: u32,
+    sending_precision: i32,
+    max_holding_amount: Uint128,
+    bridging_fee: Uint128,
+    xrpl_currency: Option<String>,
+    rounding_mode: Option<RoundingMode>,
+) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::RegisterCoreumToken {
+        denom: denom.into(),
+        decimals,
+        sending_precision,
+        max_holding_amount,
+        bridging_fee,
+        xrpl_currency,
+        rounding_mode,
+    })
+}
+
+// Registers an XRPL originated token so that it can be bridge to Coreum
+// Only the owner can do this
+#[allow(clippy::too_many_arguments)]
+pub fn register_xrpl_token(
+    issuer: impl Into<String>,
+    currency: impl Into<String>,
+    sending_precision: i32,
+    max_holding_amount: Uint128,
+    bridging_fee: Uint128,
+    symbol: Option<String>,
+    description: Option<String>,
+    uri: Option<String>,
+    queue_if_no_tickets: Option<bool>,
+    rounding_mode: Option<RoundingMode>,
+) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::RegisterXRPLToken {
+        issuer: issuer.into(),
+        currency: currency.into(),
+        sending_precision,
+        max_holding_amount,
+        bridging_fee,
+        symbol,
+        description,
+        uri,
+        queue_if_no_tickets,
+        rounding_mode,
+    })
+}
+
+// Perform a ticket recovery in case the bridge has run out of tickets due to rejected ticket allocation operations on XRPL
+// Only the owner can do this
+pub fn recover_tickets(account_sequence: u64, number_of_tickets: Option<u32>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::RecoverTickets {
+        account_sequence,
+        number_of_tickets,
+    })
+}
+
+// Redo an XRPL Token Registration that failed due to a rejected Trust Set operation on XRPL
+// Only the owner can do this
+pub fn recover_xrpl_token_registration(
+    issuer: impl Into<String>,
+    currency: impl Into<String>,
+) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::RecoverXRPLTokenRegistration {
+        issuer: issuer.into(),
+        currency: currency.into(),
+    })
+}
+
+// Save a signature for a specific Pending Operation
+// Only relayers can do this
+pub fn save_signature(
+    operation_id: u64,
+    operation_version: u64,
+    signature: impl Into<String>,
+) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::SaveSignature {
+        operation_id,
+        operation_version,
+        signature: signature.into(),
+    })
+}
+
+// Record the XRPL transaction submitted for a pending operation that reached the quorum of signatures
+// Only relayers can do this
+pub fn save_transaction_submission(
+    operation_id: u64,
+    operation_version: u64,
+    tx_hash: impl Into<String>,
+    tx_blob: Option<String>,
+) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::SaveTransactionSubmission {
+        operation_id,
+        operation_version,
+        tx_hash: tx_hash.into(),
+        tx_blob,
+    })
+}
+
+// Provide an evidence for a specific operation that was executed on XRPL, optionally with the close time of the XRPL ledger
+// Only relayers can do this
+pub fn save_evidence(
+    evidence: Evidence,
+    signature: Option<String>,
+    close_time: Option<u64>,
+) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::SaveEvidence {
+        evidence,
+        signature,
+        close_time,
+    })
+}
+
+// Send a Token from Coreum to XRPL
+// Prioritized transfers must also send the priority fee, either included in the coin sent or as a separate coin
+// Retrying a transfer with the same idempotency key returns the funds and the operation created the first time
+// Anyone can do this
+pub fn send_to_xrpl(
+    recipient: impl Into<String>,
+    deliver_amount: Option<Uint128>,
+    exact_amount: Option<bool>,
+    split_amount: Option<bool>,
+    priority: Option<bool>,
+    idempotency_key: Option<String>,
+) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::SendToXRPL {
+        recipient: recipient.into(),
+        deliver_amount,
+        exact_amount,
+        split_amount,
+        priority,
+        idempotency_key,
+    })
+}
+
+// Lock the funds of a transfer to XRPL without disclosing its details. Required for amounts above the commit reveal threshold of the token
+// The commitment is the hex encoded SHA256 of the JSON encoded TransferCommitmentPreimage of the transfer
+// Anyone can do this
+pub fn commit_send_to_xrpl(commitment: impl Into<String>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::CommitSendToXRPL {
+        commitment: commitment.into(),
+    })
+}
+
+// Reveal the details of a committed transfer to XRPL and send it. Only possible once the commit reveal delay has passed
+// and before the commitment expires
+// Only the sender of the commitment can do this
+pub fn reveal_send_to_xrpl(
+    commitment: impl Into<String>,
+    recipient: impl Into<String>,
+    deliver_amount: Option<Uint128>,
+    exact_amount: Option<bool>,
+    salt: impl Into<String>,
+) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::RevealSendToXRPL {
+        commitment: commitment.into(),
+        recipient: recipient.into(),
+        deliver_amount,
+        exact_amount,
+        salt: salt.into(),
+    })
+}
+
+// Cancel a committed transfer to XRPL, its funds become a pending refund of the sender of the commitment
+// The sender of the commitment can do this at any time, anyone else only once the commitment expired
+pub fn cancel_send_to_xrpl_commitment(
+    sender: impl Into<String>,
+    commitment: impl Into<String>,
+) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::CancelSendToXRPLCommitment {
+        sender: sender.into(),
+        commitment: commitment.into(),
+    })
+}
+
+// Register a transfer to XRPL of amount (in the denom of the funds sent) that is sent every interval (in seconds), starting now
+// The funds sent are escrowed in the contract to pay for the executions
+// Anyone can do this
+pub fn create_recurring_transfer(
+    recipient: impl Into<String>,
+    amount: Uint128,
+    interval: u64,
+) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::CreateRecurringTransfer {
+        recipient: recipient.into(),
+        amount,
+        interval,
+    })
+}
+
+// Add the funds sent to the escrowed balance of a recurring transfer
+// Only the sender of the recurring transfer can do this
+pub fn fund_recurring_transfer(id: u64) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::FundRecurringTransfer { id })
+}
+
+// Remove a recurring transfer and return its escrowed balance
+// Only the sender of the recurring transfer can do this
+pub fn cancel_recurring_transfer(id: u64) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::CancelRecurringTransfer { id })
+}
+
+// Send the next transfer to XRPL of a recurring transfer that is due
+// Anyone can do this
+pub fn execute_recurring_transfer(id: u64) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::ExecuteRecurringTransfer { id })
+}
+
+// Set the minimum amount of a token from which transfers to XRPL must be committed before being revealed, or remove it if no threshold is provided
+// Only the owner can do this
+pub fn update_commit_reveal_threshold(
+    denom: impl Into<String>,
+    threshold: Option<Uint128>,
+) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateCommitRevealThreshold {
+        denom: denom.into(),
+        threshold,
+    })
+}
+
+// Update the blocks that must pass before a committed transfer can be revealed and the blocks after which it expires
+// Only the owner can do this
+pub fn update_commit_reveal_config(
+    commit_reveal_delay: Option<u64>,
+    commit_reveal_expiration: Option<u64>,
+) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateCommitRevealConfig {
+        commit_reveal_delay,
+        commit_reveal_expiration,
+    })
+}
+
+// Update the configuration of an XRPL originated token
+// Only the owner can do this
+pub fn update_xrpl_token(
+    issuer: impl Into<String>,
+    currency: impl Into<String>,
+    state: Option<TokenState>,
+    sending_precision: Option<i32>,
+    bridging_fee: Option<Uint128>,
+    max_holding_amount: Option<Uint128>,
+    rounding_mode: Option<RoundingMode>,
+) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateXRPLToken {
+        issuer: issuer.into(),
+        currency: currency.into(),
+        state,
+        sending_precision,
+        bridging_fee,
+        max_holding_amount,
+        rounding_mode,
+    })
+}
+
+// Update the metadata (description and URI) of the Coreum FT issued for an XRPL originated token
+// Only the owner can do this
+pub fn update_xrpl_token_metadata(
+    issuer: impl Into<String>,
+    currency: impl Into<String>,
+    description: Option<String>,
+    uri: Option<String>,
+) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateXRPLTokenMetadata {
+        issuer: issuer.into(),
+        currency: currency.into(),
+        description,
+        uri,
+    })
+}
+
+// Update the configuration of a Coreum originated token
+pub fn update_coreum_token(
+    denom: impl Into<String>,
+    state: Option<TokenState>,
+    sending_precision: Option<i32>,
+    bridging_fee: Option<Uint128>,
+    max_holding_amount: Option<Uint128>,
+    rounding_mode: Option<RoundingMode>,
+) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateCoreumToken {
+        denom: denom.into(),
+        state,
+        sending_precision,
+        bridging_fee,
+        max_holding_amount,
+        rounding_mode,
+    })
+}
+
+// Updates the XRPL base fee in config. When this operation is completed, all signatures on current pending operations will be deleted
+// and we will increase the version of all current pending operations.
+// Only the owner can do this
+pub fn update_xrpl_base_fee(xrpl_base_fee: u64) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateXRPLBaseFee { xrpl_base_fee })
+}
+
+// Claim refund. User who can claim amounts due to failed transactions can do it with this message
+// If owner is provided, the claim is executed on behalf of that address and the funds are sent to it
+// Anyone can do this
+pub fn claim_refund(pending_refund_id: impl Into<String>, owner: Option<String>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::ClaimRefund {
+        pending_refund_id: pending_refund_id.into(),
+        owner,
+    })
+}
+
+// Claim up to limit pending refunds of the sender at once, sending one aggregated amount per denom
+// Held back transfers that are still denied by the compliance contract are skipped
+// Anyone can do this
+pub fn claim_all_refunds(limit: Option<u32>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::ClaimAllRefunds { limit })
+}
+
+// Any relayer can claim fees at any point in time. They need to provide what they want to claim
+// Only relayers can do this
+pub fn claim_relayer_fees(amounts: Vec<Coin>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::ClaimRelayerFees { amounts })
+}
+
+// Elect the denom in which all the fees claimed by the sender are received, or opt out if no denom is provided
+// Only relayers can do this
+pub fn update_fee_consolidation_denom(denom: Option<String>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateFeeConsolidationDenom { denom })
+}
+
+// Halt the bridge. This will prevent certain new operations to be created
+// A reason can be provided so that it's clear why the bridge was halted
+// Only the owner or a relayer can do this
+pub fn halt_bridge(reason: Option<HaltReason>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::HaltBridge { reason })
+}
+
+// Resume a bridge in halted (with no pending key rotations) or maintenance state
+// Only the owner can do this
+pub fn resume_bridge() -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::ResumeBridge {})
+}
+
+// Put an active bridge in maintenance. New transfers to XRPL will be rejected but operations in flight can still be completed
+// Only the owner can do this
+pub fn start_maintenance() -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::StartMaintenance {})
+}
+
+// Request the bridge to be halted. When enough relayers (halt request threshold) request it, the bridge is halted
+// This way relayers can halt the bridge if something is wrong even if the owner is not available
+// Only relayers can do this
+pub fn request_halt() -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::RequestHalt {})
+}
+
+// Update the amount of relayers that need to request a halt for the bridge to be halted
+// Only the owner can do this
+pub fn update_halt_request_threshold(halt_request_threshold: u32) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateHaltRequestThreshold {
+        halt_request_threshold,
+    })
+}
+
+// Request a token to be frozen because of anomalous activity (e.g. mass issuance by its issuer on XRPL)
+// When enough relayers (token freeze request threshold) request it, the token can't be transferred in any direction
+// until the freeze expires, unless the owner confirms it
+// Only relayers can do this
+pub fn request_token_freeze(denom: impl Into<String>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::RequestTokenFreeze {
+        denom: denom.into(),
+    })
+}
+
+// Confirm a token freeze requested by relayers so that it doesn't expire
+// Only the owner can do this
+pub fn confirm_token_freeze(denom: impl Into<String>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::ConfirmTokenFreeze {
+        denom: denom.into(),
+    })
+}
+
+// Lift the freeze of a token and clear its pending freeze requests
+// Only the owner can do this
+pub fn unfreeze_token(denom: impl Into<String>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UnfreezeToken {
+        denom: denom.into(),
+    })
+}
+
+// Globally freeze a token issued by the bridge on Coreum, so that no holder can transfer it
+// Only the owner can do this
+pub fn globally_freeze_token(denom: impl Into<String>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::GloballyFreezeToken {
+        denom: denom.into(),
+    })
+}
+
+// Lift the global freeze of a token issued by the bridge on Coreum
+// Only the owner can do this
+pub fn globally_unfreeze_token(denom: impl Into<String>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::GloballyUnfreezeToken {
+        denom: denom.into(),
+    })
+}
+
+// Globally freeze up to limit tokens issued by the bridge, continuing after start_after_key
+// Only the owner can do this
+pub fn globally_freeze_all_tokens(
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::GloballyFreezeAllTokens {
+        start_after_key,
+        limit,
+    })
+}
+
+// Lift the global freeze of up to limit tokens issued by the bridge, continuing after start_after_key
+// Only the owner can do this
+pub fn globally_unfreeze_all_tokens(
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::GloballyUnfreezeAllTokens {
+        start_after_key,
+        limit,
+    })
+}
+
+// Update the amount of relayers that need to request a token freeze and how long unconfirmed freezes last
+// Only the owner can do this
+pub fn update_token_freeze_config(
+    token_freeze_request_threshold: Option<u32>,
+    token_freeze_duration: Option<u64>,
+) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateTokenFreezeConfig {
+        token_freeze_request_threshold,
+        token_freeze_duration,
+    })
+}
+
+// Set whether the tokens of an issuer are disabled when relayers report that it enabled a global freeze or clawback on XRPL
+// Only the owner can do this
+pub fn update_auto_disable_on_issuer_change(auto_disable_on_issuer_change: bool) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateAutoDisableOnIssuerChange {
+        auto_disable_on_issuer_change,
+    })
+}
+
+// Set after how many consecutive transfers to XRPL rejected on XRPL a token is disabled, 0 never disables it
+// Only the owner can do this
+pub fn update_token_failure_threshold(token_failure_threshold: u32) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateTokenFailureThreshold {
+        token_failure_threshold,
+    })
+}
+
+// Update the maximum amount of resolved operations kept in the operation archive, 0 disables the archive
+// Only the owner can do this
+pub fn update_operation_archive_size(operation_archive_size: u32) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateOperationArchiveSize {
+        operation_archive_size,
+    })
+}
+
+// Remove the oldest archived operations that exceed the operation archive size
+// Only the owner can do this
+pub fn prune_operation_archive(limit: Option<u32>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::PruneOperationArchive { limit })
+}
+
+// Update the seconds after which evidences that didn't reach the threshold are considered stale
+// Only the owner can do this
+pub fn update_evidence_ttl(evidence_ttl: u64) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateEvidenceTTL { evidence_ttl })
+}
+
+// Set the contract that screens transfers, or remove it if no contract is provided
+// Transfers to XRPL it denies are refused and transfers from XRPL it denies are held back until it allows them
+// Only the owner can do this
+pub fn update_compliance_contract(compliance_contract: Option<String>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateComplianceContract {
+        compliance_contract,
+    })
+}
+
+// Set the contract that converts the fees claimed by relayers to their consolidation denom, or remove it if no contract is provided
+// Only the owner can do this
+pub fn update_fee_converter_contract(fee_converter_contract: Option<String>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateFeeConverterContract {
+        fee_converter_contract,
+    })
+}
+
+// Remove evidences that didn't reach the threshold within the evidence TTL
+// Anyone can do this
+pub fn prune_stale_evidences(limit: Option<u32>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::PruneStaleEvidences { limit })
+}
+
+// Perform up to limit housekeeping tasks: prune stale evidences, refund expired transfer commitments, lift expired token freezes
+// and allocate tickets if needed. If there was anything to do, the sender is paid the crank reward
+// Anyone can do this
+pub fn crank(limit: Option<u32>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::Crank { limit })
+}
+
+// Update the amount paid from the relayer reimbursement pool to whoever triggers housekeeping that had work to do
+// Only the owner can do this
+pub fn update_crank_reward(crank_reward: Uint128) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateCrankReward { crank_reward })
+}
+
+// Update the fee charged for placing transfers to XRPL in the priority lane. None disables the priority lane
+// Only the owner can do this
+pub fn update_priority_fee(priority_fee: Option<Coin>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdatePriorityFee { priority_fee })
+}
+
+// Publish the current price of a registered token on the XRPL DEX/AMM, in XRP per unit of the token
+// Quotes of at least evidence threshold relayers are aggregated and expire after the DEX quote TTL
+// Only relayers can do this
+pub fn publish_xrpl_dex_quote(denom: impl Into<String>, price: Decimal) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::PublishXRPLDexQuote {
+        denom: denom.into(),
+        price,
+    })
+}
+
+// Update the seconds during which published DEX quotes are taken into account
+// Only the owner can do this
+pub fn update_xrpl_dex_quote_ttl(xrpl_dex_quote_ttl: u64) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateXRPLDexQuoteTTL { xrpl_dex_quote_ttl })
+}
+
+// Set the address that pushes the USD prices of tokens, or remove it if no address is provided
+// Only the owner can do this
+pub fn update_price_oracle(price_oracle: Option<String>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdatePriceOracle { price_oracle })
+}
+
+// Set the bridging fee of a token in USD, or go back to its fixed bridging fee if no USD fee is provided
+// The fixed bridging fee is still used while the price oracle doesn't have a recent price for the token
+// Only the owner can do this
+pub fn update_usd_bridging_fee(
+    denom: impl Into<String>,
+    usd_bridging_fee: Option<USDBridgingFee>,
+) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateUSDBridgingFee {
+        denom: denom.into(),
+        usd_bridging_fee,
+    })
+}
+
+// Push the USD price of one whole token for one or more registered tokens
+// Only the price oracle can do this
+pub fn update_token_prices(prices: Vec<TokenPriceUpdate>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateTokenPrices { prices })
+}
+
+// Apply a supply drift reported by relayers to the amount tracked as bridged for the XRPL token
+// Only the owner can do this
+pub fn reconcile_xrpl_token_supply(tx_hash: impl Into<String>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::ReconcileXRPLTokenSupply {
+        tx_hash: tx_hash.into(),
+    })
+}
+
+// Deliver a deposit from XRPL that didn't have a valid Coreum recipient to the recipient it was meant for
+// Only the owner can do this
+pub fn assign_invalid_deposit(
+    tx_hash: impl Into<String>,
+    recipient: impl Into<String>,
+) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::AssignInvalidDeposit {
+        tx_hash: tx_hash.into(),
+        recipient: recipient.into(),
+    })
+}
+
+// Attest the current XRP balance (in drops) of the bridge XRPL account, used to check that it can afford its reserve
+// Only relayers can do this
+pub fn attest_bridge_xrpl_balance(balance: Uint128) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::AttestBridgeXRPLBalance { balance })
+}
+
+// Update the XRP (in drops) that XRPL requires the bridge account to hold, in total and for each object it owns
+// Only the owner can do this
+pub fn update_xrpl_reserves(
+    xrpl_base_reserve: Uint128,
+    xrpl_owner_reserve: Uint128,
+) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateXRPLReserves {
+        xrpl_base_reserve,
+        xrpl_owner_reserve,
+    })
+}
+
+// Exclude a relayer that didn't save evidences or signatures within the relayer inactivity period from fee splits and the evidence threshold
+// The relayer is active again as soon as it saves an evidence or a signature
+// Only the owner can do this
+pub fn mark_relayer_inactive(relayer_address: Addr) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::MarkRelayerInactive { relayer_address })
+}
+
+// Update the seconds without activity after which a relayer can be marked as inactive
+// Only the owner can do this
+pub fn update_relayer_inactivity_period(relayer_inactivity_period: u64) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateRelayerInactivityPeriod {
+        relayer_inactivity_period,
+    })
+}
+
+// Report a relayer that provided two different evidences for the same XRPL transaction, both still waiting for the threshold
+// The relayer is removed from both evidences and can't provide evidences for the transaction anymore
+// Anyone can do this
+pub fn report_conflicting_evidences(
+    relayer_address: Addr,
+    evidence: Evidence,
+    conflicting_evidence: Evidence,
+) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::ReportConflictingEvidences {
+        relayer_address,
+        evidence,
+        conflicting_evidence,
+    })
+}
+
+// Cancels a pending operation whose evidence wasn't saved before its deadline, refunding the user if it was a transfer
+// Anyone can do this
+pub fn expire_operation(operation_id: u64) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::ExpireOperation { operation_id })
+}
+
+// Cancel a pending transfer to XRPL while the bridge is halted, if no signatures were collected for it yet
+// Only the sender of the transfer can do this
+pub fn cancel_halted_transfer(operation_id: u64) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::CancelHaltedTransfer { operation_id })
+}
+
+// Update the seconds new pending operations wait for their evidence before they can be expired
+// Only the owner can do this
+pub fn update_operation_evidence_period(operation_evidence_period: u64) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateOperationEvidencePeriod {
+        operation_evidence_period,
+    })
+}
+
+// Increases the version of all pending operations at once and deletes their signatures, so that relayers sign them again
+// with the XRPL base fee provided (the current one if not provided) and the current relayer set, e.g. after a key rotation
+// The bridge must be halted
+// Only the owner can do this
+pub fn rebase_pending_operations(xrpl_base_fee: Option<u64>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::RebasePendingOperations { xrpl_base_fee })
+}
+
+// Confirm that the relayer observed the current state of the bridge, e.g. after a halt or a resume
+// Acknowledgements are cleared every time the bridge state changes
+// Only relayers can do this
+pub fn acknowledge_bridge_state(bridge_state: BridgeState) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::AcknowledgeBridgeState { bridge_state })
+}
+
+// Update the maximum amount of XRPL originated tokens that can be registered
+// Only the owner can do this
+pub fn update_max_registered_xrpl_tokens(max_registered_xrpl_tokens: u32) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateMaxRegisteredXRPLTokens {
+        max_registered_xrpl_tokens,
+    })
+}
+
+// Set the amount a relayer is reimbursed (from the reimbursement pool) each time its evidence triggers a state transition
+// Only the owner can do this
+pub fn update_relayer_reimbursement(amount_per_operation: Coin) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateRelayerReimbursement {
+        amount_per_operation,
+    })
+}
+
+// Add the funds sent to the pool used to reimburse relayers
+// Only the owner can do this
+pub fn fund_reimbursement_pool() -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::FundReimbursementPool {})
+}
+
+// Send funds that are not owed to anyone (e.g. sent to the contract by mistake) to a recovery address
+// Only the amount above what the bridge is accountable for can be rescued
+// Only the owner can do this
+// This is a high risk action, so it must be executed twice with the same message, the first execution only proposes it
+pub fn rescue_funds(
+    denom: impl Into<String>,
+    amount: Uint128,
+    recipient: impl Into<String>,
+) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::RescueFunds {
+        denom: denom.into(),
+        amount,
+        recipient: recipient.into(),
+    })
+}
+
+// Send the part of the fee division remainder of a token that is above the threshold to a treasury address
+// Only the owner can do this
+pub fn sweep_fee_remainder(
+    denom: impl Into<String>,
+    threshold: Uint128,
+    recipient: impl Into<String>,
+) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::SweepFeeRemainder {
+        denom: denom.into(),
+        threshold,
+        recipient: recipient.into(),
+    })
+}
+
+// Send a transfer that couldn't be delivered on Coreum (e.g. the recipient is not whitelisted for the token) back to XRPL
+// Only the recipient of the undelivered transfer can do this
+pub fn return_undelivered_transfer(
+    pending_refund_id: impl Into<String>,
+    recipient: impl Into<String>,
+) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::ReturnUndeliveredTransfer {
+        pending_refund_id: pending_refund_id.into(),
+        recipient: recipient.into(),
+    })
+}
+
+// Trigger a rotate keys operation, removing and/or adding relayers, and specifying a new threshold
+// Only the owner can do this
+// This is a high risk action, so it must be executed twice with the same message, the first execution only proposes it
+pub fn rotate_keys(new_relayers: Vec<Relayer>, new_evidence_threshold: u32) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::RotateKeys {
+        new_relayers,
+        new_evidence_threshold,
+    })
+}
+
+// Add a single address to the prohibited addresses list. Adding an address that is already prohibited does nothing
+// Only the owner can do this
+pub fn add_prohibited_xrpl_recipient(recipient: impl Into<String>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::AddProhibitedXRPLRecipient {
+        recipient: recipient.into(),
+    })
+}
+
+// Remove a single address from the prohibited addresses list. The XRPL address of the bridge can't be removed
+// Only the owner can do this
+pub fn remove_prohibited_xrpl_recipient(recipient: impl Into<String>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::RemoveProhibitedXRPLRecipient {
+        recipient: recipient.into(),
+    })
+}
+
+// Add or replace a rule that prohibits all the XRPL recipients matching it
+// Only the owner can do this
+pub fn set_xrpl_recipient_rule(name: impl Into<String>, rule: XRPLRecipientRule) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::SetXRPLRecipientRule {
+        name: name.into(),
+        rule,
+    })
+}
+
+// Only the owner can do this
+pub fn remove_xrpl_recipient_rule(name: impl Into<String>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::RemoveXRPLRecipientRule { name: name.into() })
+}
+
+// Cancels a pending operation, considering it as invalid
+// This will almost NEVER be used, unless there is some expected operation that causes an error on relayers
+// Only owner can do this
+pub fn cancel_pending_operation(operation_id: u64) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::CancelPendingOperation { operation_id })
+}
+
+// Cancels several pending operations at once during the recovery from an incident, considering them as invalid
+// Only owner can do this
+pub fn cancel_pending_operations(operation_ids: Vec<u64>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::CancelPendingOperations { operation_ids })
+}
+
+// Enables the light client verification mode for transfers from XRPL, or disables it if no config is provided
+// Only the owner can do this
+pub fn update_light_client_config(light_client: Option<LightClientConfig>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateLightClientConfig { light_client })
+}
+
+// Provide a proof that an XRPL transaction was included in a validated ledger. When the light client verification mode
+// is enabled, transfers from XRPL must be proven before relayers can provide evidences for them
+// Only relayers can do this
+pub fn save_xrpl_transaction_proof(proof: XRPLTransactionProof) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::SaveXRPLTransactionProof { proof })
+}
+
+// Register the key that a relayer must use to sign its evidences, or remove it if no key is provided
+// Only the owner can do this
+pub fn update_evidence_signing_key(
+    relayer_coreum_address: Addr,
+    pub_key: Option<String>,
+) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateEvidenceSigningKey {
+        relayer_coreum_address,
+        pub_key,
+    })
+}
+
+// Register an address that can only save evidences and signatures on behalf of the sender, or revoke it if no delegate is provided
+// Only relayers can do this
+pub fn update_evidence_delegate(delegate: Option<String>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateEvidenceDelegate { delegate })
+}
+
+// Declare the source the sender reads XRPL data from, or remove it if no source is provided
+// Only relayers can do this
+pub fn update_evidence_source(source: Option<String>) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateEvidenceSource { source })
+}
+
+// Set how many distinct sources the relayers confirming the result of an operation must have declared, 0 disables the rule
+// Only the owner can do this
+pub fn update_min_distinct_evidence_sources(min_distinct_evidence_sources: u32) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateMinDistinctEvidenceSources {
+        min_distinct_evidence_sources,
+    })
+}
+
+// Set the maximum amount of relayers, which can't be lower than the current amount of relayers or higher than the amount
+// of signers XRPL allows in a signer list
+// Only the owner can do this
+pub fn update_max_relayers(max_relayers: u32) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateMaxRelayers { max_relayers })
+}
+
+// Publish the configuration of the sender, replacing the one it published before
+// Only relayers can do this
+pub fn update_relayer_attestation(
+    xrpl_node_fingerprint: Option<String>,
+    software_version: Option<String>,
+    contact: Option<String>,
+) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateRelayerAttestation {
+        xrpl_node_fingerprint,
+        software_version,
+        contact,
+    })
+}
+
+// Executes the emergency withdrawal scheduled by governance once its delay passed
+// Anyone can do this
+pub fn execute_emergency_withdrawal() -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::ExecuteEmergencyWithdrawal {})
+}
+
+// Updates the XRPL base fee of a single pending operation (e.g. if it's stuck because the fee is too low).
+// Signatures of this operation will be deleted and its version increased, other pending operations are not affected.
+// Only the owner can do this
+pub fn update_operation_xrpl_base_fee(operation_id: u64, xrpl_base_fee: u64) -> ExecuteRequest {
+    ExecuteRequest::new(ExecuteMsg::UpdateOperationXRPLBaseFee {
+        operation_id,
+        xrpl_base_fee,
+    })
+}
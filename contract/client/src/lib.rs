@@ -0,0 +1,11 @@
+// Client of the bridge contract for relayers, bots and indexers written in Rust, without any of the contract entry points
+pub mod error;
+pub mod events;
+pub mod execute;
+pub mod query;
+pub mod sudo;
+#[cfg(test)]
+mod tests;
+
+// Message, response and state types of the contract, so that they don't have to be redefined
+pub use coreumbridge_xrpl::{evidence, light_client, msg, operation, relayer, state};
@@ -0,0 +1,611 @@
+// Builders of all the queries of the bridge contract, typed with the response the contract returns for them
+use std::marker::PhantomData;
+
+use cosmwasm_std::{
+    from_json, to_json_binary, to_json_string, Addr, StdResult, Uint128, WasmQuery,
+};
+use cw_ownable::Ownership;
+use serde::de::DeserializeOwned;
+
+use coreumbridge_xrpl::{
+    msg::{
+        AmountConversionResponse, ArchivedOperationsResponse, AvailableTicketsResponse,
+        BlockedClaimsResponse, BridgeActivitySnapshotResponse, BridgeStateAcknowledgementsResponse,
+        BridgeStateResponse, CommitRevealThresholdResponse, CoreumTokensResponse,
+        DeferredInboundTransfersResponse, DustAccountingResponse, EmergencyWithdrawalResponse,
+        EvidenceDelegatesResponse, EvidenceSigningKeysResponse, EvidenceSourcesResponse,
+        FeeAccountingResponse, FeeRemaindersResponse, FeesCollectedResponse, HaltRequestsResponse,
+        InboundTransfersResponse, InvalidDepositsResponse, OperationEvidenceDeadlineResponse,
+        OperationSigningPayloadResponse, OperationSubmissionResponse,
+        PendingHighRiskActionsResponse, PendingOperationsResponse, PendingRefundTotalsResponse,
+        PendingRefundsResponse, PrioritizedPendingOperationsResponse, ProcessedTxsResponse,
+        ProhibitedXRPLAddressesResponse, ProvenXRPLTransactionResponse, QueryMsg,
+        RecurringTransfersResponse, RegisteredXRPLTokensResponse, RelayerAttestationsResponse,
+        RelayerFeeClaimsResponse, RelayerInfractionsResponse, RelayerReimbursementResponse,
+        RelayersActivityResponse, SolvencyResponse, StaleEvidencesResponse,
+        StaleOperationsResponse, StorageLayoutResponse, TokenFreezeResponse,
+        TokenStateHistoryResponse, TransactionEvidence, TransactionEvidencesResponse,
+        TransferChunksResponse, TransferCommitmentsResponse, USDBridgingFeeResponse,
+        UndeliveredTransfersResponse, XRPLAddressResponse, XRPLCurrencyForDenomResponse,
+        XRPLDexQuoteResponse, XRPLIssuerConfigResponse, XRPLRecipientFlagsResponse,
+        XRPLRecipientRulesResponse, XRPLReserveResponse, XRPLSupplyDriftsResponse,
+        XRPLTokenSuppliesResponse, XRPLTokensResponse,
+    },
+    state::{Config, TokenMetadata, TransferReceipt},
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryRequest<R> {
+    pub msg: QueryMsg,
+    response: PhantomData<fn() -> R>,
+}
+
+impl<R: DeserializeOwned> QueryRequest<R> {
+    pub const fn new(msg: QueryMsg) -> Self {
+        Self {
+            msg,
+            response: PhantomData,
+        }
+    }
+
+    pub fn to_json(&self) -> StdResult<String> {
+        to_json_string(&self.msg)
+    }
+
+    pub fn to_wasm_query(&self, contract_addr: impl Into<String>) -> StdResult<WasmQuery> {
+        Ok(WasmQuery::Smart {
+            contract_addr: contract_addr.into(),
+            msg: to_json_binary(&self.msg)?,
+        })
+    }
+
+    // Decodes the raw JSON returned by the contract for this query
+    pub fn parse_response(&self, data: &[u8]) -> StdResult<R> {
+        from_json(data)
+    }
+}
+
+pub fn ownership() -> QueryRequest<Ownership<String>> {
+    QueryRequest::new(QueryMsg::Ownership {})
+}
+
+pub fn config() -> QueryRequest<Config> {
+    QueryRequest::new(QueryMsg::Config {})
+}
+
+pub fn xrpl_tokens(
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> QueryRequest<XRPLTokensResponse> {
+    QueryRequest::new(QueryMsg::XRPLTokens {
+        start_after_key,
+        limit,
+    })
+}
+
+// Returns the supply drifts reported by relayers that weren't reconciled yet
+pub fn xrpl_supply_drifts(
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> QueryRequest<XRPLSupplyDriftsResponse> {
+    QueryRequest::new(QueryMsg::XRPLSupplyDrifts {
+        start_after_key,
+        limit,
+    })
+}
+
+// Returns, for each XRPL originated token, the amount minted on Coreum by the bridge that wasn't burned yet
+pub fn xrpl_token_supplies(
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> QueryRequest<XRPLTokenSuppliesResponse> {
+    QueryRequest::new(QueryMsg::XRPLTokenSupplies {
+        start_after_key,
+        limit,
+    })
+}
+
+pub fn coreum_tokens(
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> QueryRequest<CoreumTokensResponse> {
+    QueryRequest::new(QueryMsg::CoreumTokens {
+        start_after_key,
+        limit,
+    })
+}
+
+pub fn pending_operations(
+    start_after_key: Option<u64>,
+    limit: Option<u32>,
+) -> QueryRequest<PendingOperationsResponse> {
+    QueryRequest::new(QueryMsg::PendingOperations {
+        start_after_key,
+        limit,
+    })
+}
+
+// Returns the pending operations in the order relayers are expected to sign them: the priority lane first
+// start_after_key is the ticket/sequence of the last operation returned
+pub fn prioritized_pending_operations(
+    start_after_key: Option<u64>,
+    limit: Option<u32>,
+) -> QueryRequest<PrioritizedPendingOperationsResponse> {
+    QueryRequest::new(QueryMsg::PrioritizedPendingOperations {
+        start_after_key,
+        limit,
+    })
+}
+
+// Returns the bytes each current relayer must sign for the current version of a pending operation
+pub fn operation_signing_payload(
+    operation_id: u64,
+) -> QueryRequest<OperationSigningPayloadResponse> {
+    QueryRequest::new(QueryMsg::OperationSigningPayload { operation_id })
+}
+
+pub fn available_tickets() -> QueryRequest<AvailableTicketsResponse> {
+    QueryRequest::new(QueryMsg::AvailableTickets {})
+}
+
+pub fn fees_collected(relayer_address: Addr) -> QueryRequest<FeesCollectedResponse> {
+    QueryRequest::new(QueryMsg::FeesCollected { relayer_address })
+}
+
+// Returns the truncation dust collected for a token, its current fee division remainder and how much of it was swept
+pub fn dust_accounting(denom: impl Into<String>) -> QueryRequest<DustAccountingResponse> {
+    QueryRequest::new(QueryMsg::DustAccounting {
+        denom: denom.into(),
+    })
+}
+
+// Returns the fees collected for a token, with the bridging fees, priority fees and truncated amounts reported separately
+pub fn fee_accounting(denom: impl Into<String>) -> QueryRequest<FeeAccountingResponse> {
+    QueryRequest::new(QueryMsg::FeeAccounting {
+        denom: denom.into(),
+    })
+}
+
+// Returns the fee remainders of all denoms, kept until the next fee collection of the same denom
+pub fn fee_remainders(
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> QueryRequest<FeeRemaindersResponse> {
+    QueryRequest::new(QueryMsg::FeeRemainders {
+        start_after_key,
+        limit,
+    })
+}
+
+pub fn pending_refunds(
+    address: Addr,
+    start_after_key: Option<(Addr, String)>,
+    limit: Option<u32>,
+) -> QueryRequest<PendingRefundsResponse> {
+    QueryRequest::new(QueryMsg::PendingRefunds {
+        address,
+        start_after_key,
+        limit,
+    })
+}
+
+// Returns the sum of the pending refunds per denom, for a single address if provided or for all addresses otherwise
+pub fn pending_refund_totals(address: Option<Addr>) -> QueryRequest<PendingRefundTotalsResponse> {
+    QueryRequest::new(QueryMsg::PendingRefundTotals { address })
+}
+
+// Returns the deposits from XRPL without a valid Coreum recipient that weren't resolved yet
+pub fn invalid_deposits(
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> QueryRequest<InvalidDepositsResponse> {
+    QueryRequest::new(QueryMsg::InvalidDeposits {
+        start_after_key,
+        limit,
+    })
+}
+
+// Returns the reserve the bridge XRPL account must hold for the objects it owns and whether it needs to be topped up
+pub fn xrpl_reserve() -> QueryRequest<XRPLReserveResponse> {
+    QueryRequest::new(QueryMsg::XRPLReserve {})
+}
+
+// Returns the last activity of each relayer and whether it's marked as inactive
+pub fn relayers_activity() -> QueryRequest<RelayersActivityResponse> {
+    QueryRequest::new(QueryMsg::RelayersActivity {})
+}
+
+// Returns the block time after which a pending operation can be expired
+pub fn operation_evidence_deadline(
+    operation_id: u64,
+) -> QueryRequest<OperationEvidenceDeadlineResponse> {
+    QueryRequest::new(QueryMsg::OperationEvidenceDeadline { operation_id })
+}
+
+// Returns the XRPL transaction recorded as submitted for a pending operation, if any
+pub fn operation_submission(operation_id: u64) -> QueryRequest<OperationSubmissionResponse> {
+    QueryRequest::new(QueryMsg::OperationSubmission { operation_id })
+}
+
+// Returns the high risk actions proposed by the owner that are waiting to be confirmed
+pub fn pending_high_risk_actions() -> QueryRequest<PendingHighRiskActionsResponse> {
+    QueryRequest::new(QueryMsg::PendingHighRiskActions {})
+}
+
+// Returns the pending refunds whose claims failed because their token is frozen
+pub fn blocked_claims(
+    start_after_key: Option<(Addr, String)>,
+    limit: Option<u32>,
+) -> QueryRequest<BlockedClaimsResponse> {
+    QueryRequest::new(QueryMsg::BlockedClaims {
+        start_after_key,
+        limit,
+    })
+}
+
+// Returns the transfers from XRPL that are waiting for their token to be enabled or unfrozen
+pub fn deferred_inbound_transfers(
+    start_after_key: Option<(String, String)>,
+    limit: Option<u32>,
+) -> QueryRequest<DeferredInboundTransfersResponse> {
+    QueryRequest::new(QueryMsg::DeferredInboundTransfers {
+        start_after_key,
+        limit,
+    })
+}
+
+// Returns the pending operations that are too old or missing signatures for a quorum for too long, all of them if no threshold is provided
+pub fn stale_operations(
+    min_age: Option<u64>,
+    missing_quorum_for: Option<u64>,
+    start_after_key: Option<u64>,
+    limit: Option<u32>,
+) -> QueryRequest<StaleOperationsResponse> {
+    QueryRequest::new(QueryMsg::StaleOperations {
+        min_age,
+        missing_quorum_for,
+        start_after_key,
+        limit,
+    })
+}
+
+// Returns the inbound transfers that couldn't be delivered and are waiting to be claimed or returned to XRPL
+pub fn undelivered_transfers(
+    start_after_key: Option<(Addr, String)>,
+    limit: Option<u32>,
+) -> QueryRequest<UndeliveredTransfersResponse> {
+    QueryRequest::new(QueryMsg::UndeliveredTransfers {
+        start_after_key,
+        limit,
+    })
+}
+
+// Returns the activity of the bridge during an epoch, the current one if not provided
+pub fn bridge_activity_snapshot(
+    epoch: Option<u64>,
+) -> QueryRequest<BridgeActivitySnapshotResponse> {
+    QueryRequest::new(QueryMsg::BridgeActivitySnapshot { epoch })
+}
+
+// Returns the most recent fee claims of a relayer, from oldest to newest
+pub fn relayer_fee_claims(
+    relayer_address: Addr,
+    start_after_key: Option<u64>,
+    limit: Option<u32>,
+) -> QueryRequest<RelayerFeeClaimsResponse> {
+    QueryRequest::new(QueryMsg::RelayerFeeClaims {
+        relayer_address,
+        start_after_key,
+        limit,
+    })
+}
+
+// Returns the conflicting evidences a relayer was reported for, sorted by transaction hash
+pub fn relayer_infractions(
+    relayer_address: Addr,
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> QueryRequest<RelayerInfractionsResponse> {
+    QueryRequest::new(QueryMsg::RelayerInfractions {
+        relayer_address,
+        start_after_key,
+        limit,
+    })
+}
+
+// Returns the most recent transfers from XRPL delivered to a recipient, from oldest to newest
+pub fn inbound_transfers(
+    recipient: Addr,
+    start_after_key: Option<u64>,
+    limit: Option<u32>,
+) -> QueryRequest<InboundTransfersResponse> {
+    QueryRequest::new(QueryMsg::InboundTransfers {
+        recipient,
+        start_after_key,
+        limit,
+    })
+}
+
+// Returns the most recently resolved operations with their results, from oldest to newest
+pub fn archived_operations(
+    start_after_key: Option<u64>,
+    limit: Option<u32>,
+) -> QueryRequest<ArchivedOperationsResponse> {
+    QueryRequest::new(QueryMsg::ArchivedOperations {
+        start_after_key,
+        limit,
+    })
+}
+
+pub fn bridge_state() -> QueryRequest<BridgeStateResponse> {
+    QueryRequest::new(QueryMsg::BridgeState {})
+}
+
+pub fn transaction_evidence(hash: impl Into<String>) -> QueryRequest<TransactionEvidence> {
+    QueryRequest::new(QueryMsg::TransactionEvidence { hash: hash.into() })
+}
+
+pub fn transaction_evidences(
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> QueryRequest<TransactionEvidencesResponse> {
+    QueryRequest::new(QueryMsg::TransactionEvidences {
+        start_after_key,
+        limit,
+    })
+}
+
+// Returns the evidences that didn't reach the threshold within the evidence TTL and can be pruned
+pub fn stale_evidences(
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> QueryRequest<StaleEvidencesResponse> {
+    QueryRequest::new(QueryMsg::StaleEvidences {
+        start_after_key,
+        limit,
+    })
+}
+
+pub fn processed_tx(hash: impl Into<String>) -> QueryRequest<bool> {
+    QueryRequest::new(QueryMsg::ProcessedTx { hash: hash.into() })
+}
+
+pub fn processed_txs(
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> QueryRequest<ProcessedTxsResponse> {
+    QueryRequest::new(QueryMsg::ProcessedTxs {
+        start_after_key,
+        limit,
+    })
+}
+
+pub fn prohibited_xrpl_addresses(
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> QueryRequest<ProhibitedXRPLAddressesResponse> {
+    QueryRequest::new(QueryMsg::ProhibitedXRPLAddresses {
+        start_after_key,
+        limit,
+    })
+}
+
+pub fn xrpl_recipient_rules(
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> QueryRequest<XRPLRecipientRulesResponse> {
+    QueryRequest::new(QueryMsg::XRPLRecipientRules {
+        start_after_key,
+        limit,
+    })
+}
+
+pub fn proven_xrpl_transaction(
+    tx_hash: impl Into<String>,
+) -> QueryRequest<ProvenXRPLTransactionResponse> {
+    QueryRequest::new(QueryMsg::ProvenXRPLTransaction {
+        tx_hash: tx_hash.into(),
+    })
+}
+
+pub fn evidence_signing_keys() -> QueryRequest<EvidenceSigningKeysResponse> {
+    QueryRequest::new(QueryMsg::EvidenceSigningKeys {})
+}
+
+pub fn evidence_delegates() -> QueryRequest<EvidenceDelegatesResponse> {
+    QueryRequest::new(QueryMsg::EvidenceDelegates {})
+}
+
+pub fn evidence_sources() -> QueryRequest<EvidenceSourcesResponse> {
+    QueryRequest::new(QueryMsg::EvidenceSources {})
+}
+
+// Returns the configuration attestations published by the relayers
+pub fn relayer_attestations() -> QueryRequest<RelayerAttestationsResponse> {
+    QueryRequest::new(QueryMsg::RelayerAttestations {})
+}
+
+// Returns the emergency withdrawal scheduled by governance, if any
+pub fn emergency_withdrawal() -> QueryRequest<EmergencyWithdrawalResponse> {
+    QueryRequest::new(QueryMsg::EmergencyWithdrawal {})
+}
+
+pub fn recurring_transfers(
+    start_after_key: Option<u64>,
+    limit: Option<u32>,
+) -> QueryRequest<RecurringTransfersResponse> {
+    QueryRequest::new(QueryMsg::RecurringTransfers {
+        start_after_key,
+        limit,
+    })
+}
+
+// Returns the minimum amount of a token from which transfers to XRPL must be committed before being revealed
+pub fn commit_reveal_threshold(
+    denom: impl Into<String>,
+) -> QueryRequest<CommitRevealThresholdResponse> {
+    QueryRequest::new(QueryMsg::CommitRevealThreshold {
+        denom: denom.into(),
+    })
+}
+
+// Returns the transfers to XRPL committed by a sender that were not revealed or cancelled yet
+pub fn transfer_commitments(
+    sender: Addr,
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> QueryRequest<TransferCommitmentsResponse> {
+    QueryRequest::new(QueryMsg::TransferCommitments {
+        sender,
+        start_after_key,
+        limit,
+    })
+}
+
+// Validates an XRPL address (classic or X-address) and returns its decoded components
+pub fn xrpl_address(address: impl Into<String>) -> QueryRequest<XRPLAddressResponse> {
+    QueryRequest::new(QueryMsg::XRPLAddress {
+        address: address.into(),
+    })
+}
+
+// Returns the relayers that requested the bridge to be halted and the amount of requests needed to halt it
+pub fn halt_requests() -> QueryRequest<HaltRequestsResponse> {
+    QueryRequest::new(QueryMsg::HaltRequests {})
+}
+
+// Returns the relayers that acknowledged the current bridge state and the ones that didn't yet
+pub fn bridge_state_acknowledgements() -> QueryRequest<BridgeStateAcknowledgementsResponse> {
+    QueryRequest::new(QueryMsg::BridgeStateAcknowledgements {})
+}
+
+// Returns the freeze of a token (if any) and the relayers that requested it to be frozen
+pub fn token_freeze(denom: impl Into<String>) -> QueryRequest<TokenFreezeResponse> {
+    QueryRequest::new(QueryMsg::TokenFreeze {
+        denom: denom.into(),
+    })
+}
+
+// Returns the account settings of an issuer of XRPL tokens, as last reported by relayers
+pub fn xrpl_issuer_config(issuer: impl Into<String>) -> QueryRequest<XRPLIssuerConfigResponse> {
+    QueryRequest::new(QueryMsg::XRPLIssuerConfig {
+        issuer: issuer.into(),
+    })
+}
+
+// Returns the USD bridging fee of a token, its last price and the bridging fee that would be charged right now
+pub fn usd_bridging_fee(denom: impl Into<String>) -> QueryRequest<USDBridgingFeeResponse> {
+    QueryRequest::new(QueryMsg::USDBridgingFee {
+        denom: denom.into(),
+    })
+}
+
+// Returns the median of the XRPL DEX/AMM quotes of a token that relayers published within the DEX quote TTL
+pub fn xrpl_dex_quote(denom: impl Into<String>) -> QueryRequest<XRPLDexQuoteResponse> {
+    QueryRequest::new(QueryMsg::XRPLDexQuote {
+        denom: denom.into(),
+    })
+}
+
+// Returns the account settings of an XRPL recipient that make transfers to it fail, as last reported by relayers
+pub fn xrpl_recipient_flags(
+    account: impl Into<String>,
+) -> QueryRequest<XRPLRecipientFlagsResponse> {
+    QueryRequest::new(QueryMsg::XRPLRecipientFlags {
+        account: account.into(),
+    })
+}
+
+// Calculates the amount that will be sent to XRPL when bridging a Coreum amount of a registered token
+pub fn coreum_to_xrpl_amount(
+    denom: impl Into<String>,
+    amount: Uint128,
+) -> QueryRequest<AmountConversionResponse> {
+    QueryRequest::new(QueryMsg::CoreumToXRPLAmount {
+        denom: denom.into(),
+        amount,
+    })
+}
+
+// Calculates the amount that will be received on Coreum when bridging an XRPL amount of a registered token
+pub fn xrpl_to_coreum_amount(
+    issuer: impl Into<String>,
+    currency: impl Into<String>,
+    amount: Uint128,
+) -> QueryRequest<AmountConversionResponse> {
+    QueryRequest::new(QueryMsg::XRPLToCoreumAmount {
+        issuer: issuer.into(),
+        currency: currency.into(),
+        amount,
+    })
+}
+
+// Compares, for every registered token, the amounts the contract owes with the amounts it is actually holding
+pub fn solvency(
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> QueryRequest<SolvencyResponse> {
+    QueryRequest::new(QueryMsg::Solvency {
+        start_after_key,
+        limit,
+    })
+}
+
+// Returns the XRPL currency that will be generated for a Coreum token when it's registered without a custom currency
+pub fn xrpl_currency_for_denom(
+    denom: impl Into<String>,
+) -> QueryRequest<XRPLCurrencyForDenomResponse> {
+    QueryRequest::new(QueryMsg::XRPLCurrencyForDenom {
+        denom: denom.into(),
+    })
+}
+
+// Returns the metadata of the Coreum FT issued for an XRPL originated token
+pub fn token_metadata(denom: impl Into<String>) -> QueryRequest<TokenMetadata> {
+    QueryRequest::new(QueryMsg::TokenMetadata {
+        denom: denom.into(),
+    })
+}
+
+// Returns the most recent state transitions of a token, which can be found by its denom or, for XRPL originated tokens, by its issuer and currency
+pub fn token_state_history(
+    denom: Option<String>,
+    issuer: Option<String>,
+    currency: Option<String>,
+) -> QueryRequest<TokenStateHistoryResponse> {
+    QueryRequest::new(QueryMsg::TokenStateHistory {
+        denom,
+        issuer,
+        currency,
+    })
+}
+
+// Returns the amount of XRPL originated tokens registered and the maximum amount that can be registered
+pub fn registered_xrpl_tokens() -> QueryRequest<RegisteredXRPLTokensResponse> {
+    QueryRequest::new(QueryMsg::RegisteredXRPLTokens {})
+}
+
+pub fn relayer_reimbursement() -> QueryRequest<RelayerReimbursementResponse> {
+    QueryRequest::new(QueryMsg::RelayerReimbursement {})
+}
+
+// Returns the lifecycle of a transfer, found by its receipt id (operation id for transfers to XRPL) or its XRPL transaction hash
+pub fn transfer_receipt(id_or_hash: impl Into<String>) -> QueryRequest<TransferReceipt> {
+    QueryRequest::new(QueryMsg::TransferReceipt {
+        id_or_hash: id_or_hash.into(),
+    })
+}
+
+// Returns the operation ids of the chunks a transfer to XRPL was split into, empty if it wasn't split
+pub fn transfer_chunks(
+    parent_transfer_id: impl Into<String>,
+) -> QueryRequest<TransferChunksResponse> {
+    QueryRequest::new(QueryMsg::TransferChunks {
+        parent_transfer_id: parent_transfer_id.into(),
+    })
+}
+
+// Returns how the contract state is stored, so that indexers reading it with raw queries can detect layout changes
+pub fn storage_layout() -> QueryRequest<StorageLayoutResponse> {
+    QueryRequest::new(QueryMsg::StorageLayout {})
+}
@@ -0,0 +1,40 @@
+// Builders of the messages that governance can send to the bridge contract with a sudo proposal
+use cosmwasm_std::{to_json_binary, to_json_string, Binary, Coin, StdResult};
+
+use coreumbridge_xrpl::msg::SudoMsg;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SudoRequest {
+    pub msg: SudoMsg,
+}
+
+impl SudoRequest {
+    pub const fn new(msg: SudoMsg) -> Self {
+        Self { msg }
+    }
+
+    pub fn to_json(&self) -> StdResult<String> {
+        to_json_string(&self.msg)
+    }
+
+    // Message included in the sudo proposal
+    pub fn to_binary(&self) -> StdResult<Binary> {
+        to_json_binary(&self.msg)
+    }
+}
+
+// Schedules the transfer of the provided contract balances to the recipient, executable once the emergency withdrawal delay passed
+pub fn schedule_emergency_withdrawal(
+    recipient: impl Into<String>,
+    amounts: Vec<Coin>,
+) -> SudoRequest {
+    SudoRequest::new(SudoMsg::ScheduleEmergencyWithdrawal {
+        recipient: recipient.into(),
+        amounts,
+    })
+}
+
+// Cancels the scheduled emergency withdrawal
+pub fn cancel_emergency_withdrawal() -> SudoRequest {
+    SudoRequest::new(SudoMsg::CancelEmergencyWithdrawal {})
+}
@@ -0,0 +1,232 @@
+use cosmwasm_std::{coins, Addr, Event, Uint128, WasmMsg};
+
+use coreumbridge_xrpl::{
+    evidence::{Evidence, TransactionResult},
+    msg::{ExecuteMsg, PendingOperationsResponse, SudoMsg},
+    state::{TokenState, TokenStateTransitionReason},
+};
+
+use crate::{
+    error::{contract_error_code, ClientError},
+    events::{
+        parse_event, parse_events, BridgeEvent, EmergencyWithdrawalExecuted,
+        EmergencyWithdrawalScheduled, EvidenceSaved, FeesCollected, PendingOperationsRebased,
+        TokenStateTransition,
+    },
+    execute, query, sudo,
+};
+
+#[test]
+fn execute_request_builds_wasm_msg() {
+    let request = execute::send_to_xrpl(
+        "rrrrrrrrrrrrrrrrrrrrBZbvji",
+        None,
+        Some(true),
+        None,
+        None,
+        None,
+    )
+    .with_funds(coins(100, "ucore"));
+
+    assert_eq!(
+        request.msg,
+        ExecuteMsg::SendToXRPL {
+            recipient: "rrrrrrrrrrrrrrrrrrrrBZbvji".to_owned(),
+            deliver_amount: None,
+            exact_amount: Some(true),
+            split_amount: None,
+            priority: None,
+            idempotency_key: None,
+        }
+    );
+    assert_eq!(
+        request.to_json().unwrap(),
+        r#"{"send_to_xrpl":{"recipient":"rrrrrrrrrrrrrrrrrrrrBZbvji","deliver_amount":null,"exact_amount":true,"split_amount":null,"priority":null,"idempotency_key":null}}"#
+    );
+
+    match request.to_wasm_msg("contract").unwrap() {
+        WasmMsg::Execute {
+            contract_addr,
+            funds,
+            ..
+        } => {
+            assert_eq!(contract_addr, "contract");
+            assert_eq!(funds, coins(100, "ucore"));
+        }
+        msg => panic!("unexpected message {msg:?}"),
+    }
+}
+
+#[test]
+fn sudo_request_builds_governance_msg() {
+    let request = sudo::schedule_emergency_withdrawal("recovery", coins(1000, "ucore"));
+
+    assert_eq!(
+        request.msg,
+        SudoMsg::ScheduleEmergencyWithdrawal {
+            recipient: "recovery".to_owned(),
+            amounts: coins(1000, "ucore"),
+        }
+    );
+    assert_eq!(
+        request.to_json().unwrap(),
+        r#"{"schedule_emergency_withdrawal":{"recipient":"recovery","amounts":[{"denom":"ucore","amount":"1000"}]}}"#
+    );
+    assert_eq!(
+        sudo::cancel_emergency_withdrawal().to_json().unwrap(),
+        r#"{"cancel_emergency_withdrawal":{}}"#
+    );
+}
+
+#[test]
+fn query_request_parses_typed_response() {
+    let request = query::pending_operations(None, Some(10));
+    assert_eq!(
+        request.to_json().unwrap(),
+        r#"{"pending_operations":{"start_after_key":null,"limit":10}}"#
+    );
+
+    let response: PendingOperationsResponse = request
+        .parse_response(br#"{"last_key":null,"operations":[]}"#)
+        .unwrap();
+    assert_eq!(
+        response,
+        PendingOperationsResponse {
+            last_key: None,
+            operations: vec![],
+        }
+    );
+
+    let processed = query::processed_tx("HASH").parse_response(b"true").unwrap();
+    assert!(processed);
+}
+
+#[test]
+fn parse_bridge_events() {
+    let evidence = Evidence::XRPLToCoreumTransfer {
+        tx_hash: "HASH".to_owned(),
+        issuer: "issuer".to_owned(),
+        currency: "currency".to_owned(),
+        amount: Uint128::new(100),
+        recipient: Addr::unchecked("recipient"),
+    };
+
+    let events = vec![
+        Event::new("wasm-evidence_saved")
+            .add_attribute("relayer", "relayer")
+            .add_attribute("evidence_hash", evidence.get_hash())
+            .add_attribute("tx_hash", evidence.get_tx_hash())
+            .add_attribute("evidence", evidence.to_json())
+            .add_attribute("threshold_reached", "true"),
+        Event::new("transfer").add_attribute("amount", "100ucore"),
+        Event::new("wasm-pending_operations_rebased")
+            .add_attribute("xrpl_base_fee", "20")
+            .add_attribute("operations_count", "0")
+            .add_attribute("operation_ids", ""),
+        Event::new("token_state_transition")
+            .add_attribute("denom", "denom")
+            .add_attribute("from", "none")
+            .add_attribute("to", TokenState::Processing.as_str())
+            .add_attribute("reason", TokenStateTransitionReason::Registration.as_str()),
+        Event::new("wasm-emergency_withdrawal_scheduled")
+            .add_attribute("recipient", "recovery")
+            .add_attribute("amounts", r#"[{"denom":"ucore","amount":"1000"}]"#)
+            .add_attribute("executable_at", "604800"),
+        Event::new("wasm-emergency_withdrawal_executed")
+            .add_attribute("recipient", "recovery")
+            .add_attribute("amounts", r#"[{"denom":"ucore","amount":"1000"}]"#),
+        Event::new("wasm-emergency_withdrawal_cancelled"),
+        Event::new("wasm-fees_collected")
+            .add_attribute("denom", "ucore")
+            .add_attribute("bridging_fee", "10")
+            .add_attribute("priority_fee", "0")
+            .add_attribute("truncation_fee", "3"),
+    ];
+
+    assert_eq!(
+        parse_events(&events).unwrap(),
+        vec![
+            BridgeEvent::EvidenceSaved(EvidenceSaved {
+                relayer: "relayer".to_owned(),
+                evidence_hash: evidence.get_hash(),
+                tx_hash: Some("HASH".to_owned()),
+                evidence,
+                threshold_reached: true,
+            }),
+            BridgeEvent::PendingOperationsRebased(PendingOperationsRebased {
+                xrpl_base_fee: 20,
+                operation_ids: vec![],
+            }),
+            BridgeEvent::TokenStateTransition(TokenStateTransition {
+                denom: "denom".to_owned(),
+                from: None,
+                to: TokenState::Processing,
+                reason: TokenStateTransitionReason::Registration,
+            }),
+            BridgeEvent::EmergencyWithdrawalScheduled(EmergencyWithdrawalScheduled {
+                recipient: "recovery".to_owned(),
+                amounts: coins(1000, "ucore"),
+                executable_at: 604800,
+            }),
+            BridgeEvent::EmergencyWithdrawalExecuted(EmergencyWithdrawalExecuted {
+                recipient: "recovery".to_owned(),
+                amounts: coins(1000, "ucore"),
+            }),
+            BridgeEvent::EmergencyWithdrawalCancelled,
+            BridgeEvent::FeesCollected(FeesCollected {
+                denom: "ucore".to_owned(),
+                bridging_fee: Uint128::new(10),
+                priority_fee: Uint128::zero(),
+                truncation_fee: Uint128::new(3),
+            }),
+        ]
+    );
+
+    // Invalid transaction results are emitted without a transaction hash
+    let invalid_evidence = Evidence::XRPLTransactionResult {
+        tx_hash: None,
+        account_sequence: Some(1),
+        ticket_sequence: None,
+        transaction_result: TransactionResult::Invalid,
+        operation_result: None,
+    };
+    assert_eq!(
+        parse_event(
+            &Event::new("wasm-evidence_saved")
+                .add_attribute("relayer", "relayer")
+                .add_attribute("evidence_hash", invalid_evidence.get_hash())
+                .add_attribute("evidence", invalid_evidence.to_json())
+                .add_attribute("threshold_reached", "false"),
+        )
+        .unwrap(),
+        Some(BridgeEvent::EvidenceSaved(EvidenceSaved {
+            relayer: "relayer".to_owned(),
+            evidence_hash: invalid_evidence.get_hash(),
+            tx_hash: None,
+            evidence: invalid_evidence,
+            threshold_reached: false,
+        }))
+    );
+
+    let error = parse_event(
+        &Event::new("wasm-operation_signing_progress")
+            .add_attribute("operation_id", "1")
+            .add_attribute("operation_version", "one"),
+    )
+    .unwrap_err();
+    assert!(matches!(
+        error,
+        ClientError::InvalidEventAttribute { key, .. } if key == "operation_version"
+    ));
+}
+
+#[test]
+fn extract_contract_error_code() {
+    assert_eq!(
+        contract_error_code(
+            "failed to execute message; message index: 0: [E0051] BridgeHalted: The bridge is currently halted and this operation is not authorized: execute wasm contract failed"
+        ),
+        Some(51)
+    );
+    assert_eq!(contract_error_code("out of gas"), None);
+}
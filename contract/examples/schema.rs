@@ -1,5 +1,9 @@
-use coreumbridge_xrpl::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
-use cosmwasm_schema::write_api;
+use std::env::current_dir;
+
+use coreumbridge_xrpl::msg::{
+    ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, SudoMsg, TransferCommitmentPreimage,
+};
+use cosmwasm_schema::{export_schema, schema_for, write_api};
 
 //run cargo schema to generate
 fn main() {
@@ -7,5 +11,13 @@ fn main() {
         instantiate: InstantiateMsg,
         execute: ExecuteMsg,
         query: QueryMsg,
+        migrate: MigrateMsg,
+        sudo: SudoMsg,
     }
+
+    // Types that are not part of any message but that clients need, e.g. to build the commitment of a transfer
+    let mut out_dir = current_dir().unwrap();
+    out_dir.push("schema");
+    out_dir.push("raw");
+    export_schema(&schema_for!(TransferCommitmentPreimage), &out_dir);
 }
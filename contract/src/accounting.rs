@@ -0,0 +1,125 @@
+use cosmwasm_std::{OverflowError, Storage, Uint128};
+
+use crate::{
+    error::ContractError,
+    state::{TokenAccounting, TOKEN_ACCOUNTING},
+};
+
+impl TokenAccounting {
+    // Amount the contract must be holding to cover everything it owes for this token
+    pub fn liabilities(&self) -> Result<Uint128, ContractError> {
+        Ok(self
+            .locked
+            .checked_add(self.refund_reserved)?
+            .checked_add(self.fee_reserved)?)
+    }
+}
+
+pub fn load_token_accounting(
+    storage: &dyn Storage,
+    denom: &str,
+) -> Result<TokenAccounting, ContractError> {
+    Ok(TOKEN_ACCOUNTING
+        .may_load(storage, denom.to_owned())?
+        .unwrap_or_default())
+}
+
+// Amounts are never allowed to go below zero, if they do it means that the contract tried to release funds it doesn't account for
+fn update_token_accounting(
+    storage: &mut dyn Storage,
+    denom: &str,
+    update: impl FnOnce(&mut TokenAccounting) -> Result<(), OverflowError>,
+) -> Result<(), ContractError> {
+    let mut accounting = load_token_accounting(storage, denom)?;
+    update(&mut accounting)?;
+    TOKEN_ACCOUNTING.save(storage, denom.to_owned(), &accounting)?;
+    Ok(())
+}
+
+pub fn lock_amount(
+    storage: &mut dyn Storage,
+    denom: &str,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    update_token_accounting(storage, denom, |accounting| {
+        accounting.locked = accounting.locked.checked_add(amount)?;
+        Ok(())
+    })
+}
+
+pub fn unlock_amount(
+    storage: &mut dyn Storage,
+    denom: &str,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    update_token_accounting(storage, denom, |accounting| {
+        accounting.locked = accounting.locked.checked_sub(amount)?;
+        Ok(())
+    })
+}
+
+pub fn mint_amount(
+    storage: &mut dyn Storage,
+    denom: &str,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    update_token_accounting(storage, denom, |accounting| {
+        accounting.minted = accounting.minted.checked_add(amount)?;
+        Ok(())
+    })
+}
+
+pub fn burn_amount(
+    storage: &mut dyn Storage,
+    denom: &str,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    update_token_accounting(storage, denom, |accounting| {
+        accounting.minted = accounting.minted.checked_sub(amount)?;
+        Ok(())
+    })
+}
+
+pub fn reserve_refund(
+    storage: &mut dyn Storage,
+    denom: &str,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    update_token_accounting(storage, denom, |accounting| {
+        accounting.refund_reserved = accounting.refund_reserved.checked_add(amount)?;
+        Ok(())
+    })
+}
+
+pub fn release_refund(
+    storage: &mut dyn Storage,
+    denom: &str,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    update_token_accounting(storage, denom, |accounting| {
+        accounting.refund_reserved = accounting.refund_reserved.checked_sub(amount)?;
+        Ok(())
+    })
+}
+
+pub fn reserve_fee(
+    storage: &mut dyn Storage,
+    denom: &str,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    update_token_accounting(storage, denom, |accounting| {
+        accounting.fee_reserved = accounting.fee_reserved.checked_add(amount)?;
+        Ok(())
+    })
+}
+
+pub fn release_fee(
+    storage: &mut dyn Storage,
+    denom: &str,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    update_token_accounting(storage, denom, |accounting| {
+        accounting.fee_reserved = accounting.fee_reserved.checked_sub(amount)?;
+        Ok(())
+    })
+}
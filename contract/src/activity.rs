@@ -0,0 +1,113 @@
+use cosmwasm_std::{coin, Coin, Decimal, Storage, Uint128};
+
+use crate::{
+    error::ContractError,
+    evidence::TransactionResult,
+    state::{BridgeActivity, TransferDirection, BRIDGE_ACTIVITY},
+};
+
+// Activity is counted in weekly epochs, starting at the unix epoch
+pub const ACTIVITY_EPOCH_DURATION: u64 = 604800;
+// Epochs kept, older ones are removed
+pub const MAX_ACTIVITY_EPOCHS: u64 = 52;
+
+pub fn activity_epoch(timestamp: u64) -> u64 {
+    timestamp / ACTIVITY_EPOCH_DURATION
+}
+
+fn update_bridge_activity(
+    storage: &mut dyn Storage,
+    timestamp: u64,
+    update: impl FnOnce(&mut BridgeActivity) -> Result<(), ContractError>,
+) -> Result<(), ContractError> {
+    let epoch = activity_epoch(timestamp);
+    let mut activity = BRIDGE_ACTIVITY
+        .may_load(storage, epoch)?
+        .unwrap_or_default();
+    update(&mut activity)?;
+    BRIDGE_ACTIVITY.save(storage, epoch, &activity)?;
+
+    // Epochs only move forward so removing the one that just went out of the window is enough to stay within the limit
+    if epoch >= MAX_ACTIVITY_EPOCHS {
+        BRIDGE_ACTIVITY.remove(storage, epoch - MAX_ACTIVITY_EPOCHS);
+    }
+
+    Ok(())
+}
+
+// Adds the coin to the amounts, which have one coin per denom
+fn add_coin(amounts: &mut Vec<Coin>, coin: &Coin) -> Result<(), ContractError> {
+    if coin.amount.is_zero() {
+        return Ok(());
+    }
+
+    match amounts.iter_mut().find(|c| c.denom == coin.denom) {
+        Some(amount) => amount.amount = amount.amount.checked_add(coin.amount)?,
+        None => amounts.push(coin.clone()),
+    }
+
+    Ok(())
+}
+
+// Records a transfer with the amount that was bridged, before fees, and the fee charged for it
+pub fn record_transfer_activity(
+    storage: &mut dyn Storage,
+    timestamp: u64,
+    direction: TransferDirection,
+    amount: &Coin,
+    fee_charged: Uint128,
+) -> Result<(), ContractError> {
+    update_bridge_activity(storage, timestamp, |activity| {
+        match direction {
+            TransferDirection::CoreumToXRPL => {
+                activity.coreum_to_xrpl_transfers += 1;
+                add_coin(&mut activity.coreum_to_xrpl_volume, amount)?;
+            }
+            TransferDirection::XRPLToCoreum => {
+                activity.xrpl_to_coreum_transfers += 1;
+                add_coin(&mut activity.xrpl_to_coreum_volume, amount)?;
+            }
+        }
+        add_coin(
+            &mut activity.fees_collected,
+            &coin(fee_charged.u128(), amount.denom.clone()),
+        )
+    })
+}
+
+pub fn record_refund_activity(
+    storage: &mut dyn Storage,
+    timestamp: u64,
+    refund: &Coin,
+) -> Result<(), ContractError> {
+    update_bridge_activity(storage, timestamp, |activity| {
+        add_coin(&mut activity.refunds, refund)
+    })
+}
+
+pub fn record_operation_activity(
+    storage: &mut dyn Storage,
+    timestamp: u64,
+    transaction_result: &TransactionResult,
+) -> Result<(), ContractError> {
+    update_bridge_activity(storage, timestamp, |activity| {
+        match transaction_result {
+            TransactionResult::Accepted => activity.operations_accepted += 1,
+            TransactionResult::Rejected | TransactionResult::Invalid => {
+                activity.operations_failed += 1
+            }
+        }
+        Ok(())
+    })
+}
+
+impl BridgeActivity {
+    // Share of the operations resolved in the epoch that failed, zero if there were none
+    pub fn operation_failure_rate(&self) -> Decimal {
+        let operations = self.operations_accepted + self.operations_failed;
+        if operations == 0 {
+            return Decimal::zero();
+        }
+        Decimal::from_ratio(self.operations_failed, operations)
+    }
+}
@@ -1,9 +1,26 @@
-use crate::{error::ContractError, state::PROHIBITED_XRPL_ADDRESSES};
+use crate::{
+    error::ContractError,
+    state::{
+        XRPLNetwork, XRPLRecipientRule, PROHIBITED_XRPL_ADDRESSES, XRPL_CONFIG,
+        XRPL_RECIPIENT_RULES, XRPL_TOKENS,
+    },
+};
 use bs58::Alphabet;
-use cosmwasm_std::Storage;
+use cosmwasm_std::{Order, Storage};
 use sha2::{Digest, Sha256};
 
+// X-addresses encode a classic address together with an optional destination tag. Mainnet X-addresses start with X and testnet ones with T
+// For more info, see https://xrpaddress.info
+const X_ADDRESS_MAINNET_PREFIX: [u8; 2] = [0x05, 0x44];
+const X_ADDRESS_TESTNET_PREFIX: [u8; 2] = [0x04, 0x93];
+
 pub fn validate_xrpl_address_format(address: &str) -> Result<(), ContractError> {
+    decode_xrpl_address(address)?;
+    Ok(())
+}
+
+// Decodes a classic XRPL address and returns the 20-byte account ID it represents
+pub fn decode_xrpl_address(address: &str) -> Result<Vec<u8>, ContractError> {
     // We need to use the base58 dictionary for ripple which is rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz
     // To understand this alphabet, see https://xrpl.org/base58-encodings.html#ripple-base58-alphabet
     // In short, the alphabet represents the bytes values in the address. r = 0, p = 1, s = 2, etc.
@@ -38,7 +55,94 @@ pub fn validate_xrpl_address_format(address: &str) -> Result<(), ContractError>
         });
     }
 
-    Ok(())
+    Ok(data[1..21].to_vec())
+}
+
+// Encodes a 20-byte account ID into its classic XRPL address representation
+pub fn encode_xrpl_address(account_id: &[u8]) -> String {
+    let mut data = vec![0u8];
+    data.extend_from_slice(account_id);
+    let checksum = checksum(&data);
+    data.extend_from_slice(&checksum[..4]);
+
+    bs58::encode(data)
+        .with_alphabet(Alphabet::RIPPLE)
+        .into_string()
+}
+
+pub fn is_x_address(address: &str) -> bool {
+    address.starts_with('X') || address.starts_with('T')
+}
+
+// Decodes an X-address of the provided network and returns the classic address and the destination tag (if any) it contains
+pub fn decode_xrpl_x_address(
+    address: &str,
+    xrpl_network: &XRPLNetwork,
+) -> Result<(String, Option<u32>), ContractError> {
+    let invalid_address = || ContractError::InvalidXRPLAddress {
+        address: address.to_string(),
+    };
+
+    let data = bs58::decode(address)
+        .with_alphabet(Alphabet::RIPPLE)
+        .into_vec()
+        .map_err(|_| invalid_address())?;
+
+    // A decoded X-address is 35 bytes long: 2 bytes for the network prefix, 20 bytes for the account ID, 1 byte for the flag that indicates if there is a tag,
+    // 8 bytes for the tag (little endian, only 32 bits are used currently) and a 4-byte checksum at the end. The total is thus 2 + 20 + 1 + 8 + 4 = 35 bytes.
+    if data.len() != 35
+        || (data[..2] != X_ADDRESS_MAINNET_PREFIX && data[..2] != X_ADDRESS_TESTNET_PREFIX)
+    {
+        return Err(invalid_address());
+    }
+
+    // The checksum is calculated the same way as for classic addresses
+    if checksum(&data[..31])[..4] != data[31..] {
+        return Err(invalid_address());
+    }
+
+    // A valid X-address of another network would be paid to the same classic address on this network
+    let network_prefix = match xrpl_network {
+        XRPLNetwork::Mainnet => X_ADDRESS_MAINNET_PREFIX,
+        XRPLNetwork::Testnet => X_ADDRESS_TESTNET_PREFIX,
+    };
+    if data[..2] != network_prefix {
+        return Err(ContractError::XAddressNetworkMismatch {
+            address: address.to_string(),
+        });
+    }
+
+    // The upper 32 bits of the tag are reserved and must be 0
+    if data[27..31].iter().any(|b| *b != 0) {
+        return Err(invalid_address());
+    }
+
+    let destination_tag = match data[22] {
+        0 => {
+            // If there is no tag, the tag bytes must be 0
+            if data[23..27].iter().any(|b| *b != 0) {
+                return Err(invalid_address());
+            }
+            None
+        }
+        1 => Some(u32::from_le_bytes([data[23], data[24], data[25], data[26]])),
+        _ => return Err(invalid_address()),
+    };
+
+    Ok((encode_xrpl_address(&data[2..22]), destination_tag))
+}
+
+// Recipients can be provided as classic addresses or as X-addresses. In both cases we return the classic address and the destination tag (if any)
+pub fn decode_xrpl_recipient(
+    storage: &dyn Storage,
+    address: &str,
+) -> Result<(String, Option<u32>), ContractError> {
+    if is_x_address(address) {
+        return decode_xrpl_x_address(address, &XRPL_CONFIG.load(storage)?.xrpl_network);
+    }
+
+    validate_xrpl_address_format(address)?;
+    Ok((address.to_string(), None))
 }
 
 pub fn checksum(data: &[u8]) -> Vec<u8> {
@@ -61,3 +165,31 @@ pub fn validate_xrpl_address(storage: &dyn Storage, address: String) -> Result<(
     validate_xrpl_address_is_not_prohibited(storage, address)?;
     Ok(())
 }
+
+// Checks that the recipient of a transfer, with the destination tag it's sent with, doesn't match any of the recipient rules
+pub fn validate_xrpl_recipient_rules(
+    storage: &dyn Storage,
+    recipient: &str,
+    destination_tag: Option<u32>,
+) -> Result<(), ContractError> {
+    for rule in XRPL_RECIPIENT_RULES.range(storage, None, None, Order::Ascending) {
+        let (name, rule) = rule?;
+        let matches = match rule {
+            XRPLRecipientRule::RequireDestinationTag { xrpl_addresses } => {
+                destination_tag.is_none() && xrpl_addresses.iter().any(|a| a == recipient)
+            }
+            XRPLRecipientRule::ProhibitTokenIssuers {} => XRPL_TOKENS
+                .idx
+                .issuer
+                .prefix(recipient.to_string())
+                .keys(storage, None, None, Order::Ascending)
+                .next()
+                .is_some(),
+        };
+
+        if matches {
+            return Err(ContractError::XRPLRecipientRuleViolated { name });
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Coin, QuerierWrapper, Storage};
+
+use crate::{
+    error::ContractError,
+    state::{TransferDirection, OPERATIONS_CONFIG},
+};
+
+// Query the bridge sends to the compliance contract before accepting a transfer
+#[cw_serde]
+pub enum ComplianceQueryMsg {
+    CheckTransfer {
+        direction: TransferDirection,
+        // Sender of the transfer. XRPL senders are not part of the evidences so it's only provided for transfers to XRPL
+        sender: Option<String>,
+        recipient: String,
+        coin: Coin,
+    },
+}
+
+#[cw_serde]
+pub struct ComplianceCheckResponse {
+    pub allowed: bool,
+    pub reason: Option<String>,
+}
+
+// Returns the reason why the compliance contract denied the transfer, if it did
+// If the compliance contract can't be queried the transfer is denied
+pub fn check_transfer_compliance(
+    storage: &dyn Storage,
+    querier: &QuerierWrapper,
+    direction: TransferDirection,
+    sender: Option<String>,
+    recipient: String,
+    coin: Coin,
+) -> Result<Option<String>, ContractError> {
+    let operations_config = OPERATIONS_CONFIG.load(storage)?;
+    let compliance_contract = match operations_config.compliance_contract {
+        Some(compliance_contract) => compliance_contract,
+        None => return Ok(None),
+    };
+
+    let response: Result<ComplianceCheckResponse, _> = querier.query_wasm_smart(
+        compliance_contract,
+        &ComplianceQueryMsg::CheckTransfer {
+            direction,
+            sender,
+            recipient,
+            coin,
+        },
+    );
+
+    match response {
+        Ok(response) if response.allowed => Ok(None),
+        Ok(response) => Ok(Some(response.reason.unwrap_or_default())),
+        Err(error) => Ok(Some(error.to_string())),
+    }
+}
@@ -1,50 +1,134 @@
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, VecDeque};
 
 use crate::{
-    address::{validate_xrpl_address, validate_xrpl_address_format},
+    accounting::{
+        burn_amount, load_token_accounting, lock_amount, mint_amount, release_refund, reserve_fee,
+        reserve_refund, unlock_amount,
+    },
+    activity::{activity_epoch, record_transfer_activity, ACTIVITY_EPOCH_DURATION},
+    address::{
+        decode_xrpl_recipient, is_x_address, validate_xrpl_address, validate_xrpl_address_format,
+        validate_xrpl_recipient_rules,
+    },
+    compliance::check_transfer_compliance,
     error::ContractError,
     evidence::{
-        handle_evidence, hash_bytes, Evidence, OperationResult::TicketsAllocation,
-        TransactionResult,
+        handle_conflicting_evidences, handle_evidence, hash_bytes, stale_evidences,
+        validate_evidence_close_time, validate_evidence_signing_key, verify_evidence_signature,
+        Evidence, OperationResult::TicketsAllocation, TransactionResult,
+    },
+    fees::{
+        amount_after_bridge_fees, effective_bridging_fee, handle_fee_collection,
+        handle_priority_fee_collection, record_relayer_fee_claim, reimburse_relayer,
+        split_priority_fee, split_truncation_remainder, substract_relayer_fees,
+        sweep_fee_remainder, take_crank_reward, FeeConverterExecuteMsg,
+    },
+    light_client::{
+        compute_transaction_id, validate_light_client_config, verify_evidence_against_proof,
+        verify_xrpl_transaction_proof, LightClientConfig, XRPLTransactionProof,
     },
-    fees::{amount_after_bridge_fees, handle_fee_collection, substract_relayer_fees},
     msg::{
-        AvailableTicketsResponse, BridgeStateResponse, CoreumTokensResponse, ExecuteMsg,
-        FeesCollectedResponse, InstantiateMsg, PendingOperationsResponse, PendingRefund,
-        PendingRefundsResponse, ProcessedTxsResponse, ProhibitedXRPLAddressesResponse, QueryMsg,
-        TransactionEvidence, TransactionEvidencesResponse, XRPLTokensResponse,
+        AmountConversionResponse, ArchivedOperationsResponse, AvailableTicketsResponse,
+        BlockedClaimInfo, BlockedClaimsResponse, BridgeActivitySnapshotResponse,
+        BridgeStateAcknowledgement, BridgeStateAcknowledgementsResponse, BridgeStateResponse,
+        CommitRevealThresholdResponse, CoreumTokensResponse, DeferredInboundTransferInfo,
+        DeferredInboundTransfersResponse, DustAccountingResponse, EmergencyWithdrawalResponse,
+        EvidenceDelegate, EvidenceDelegatesResponse, EvidenceSigningKey,
+        EvidenceSigningKeysResponse, EvidenceSource, EvidenceSourcesResponse, ExecuteMsg,
+        FeeAccountingResponse, FeeRemaindersResponse, FeesCollectedResponse, HaltRequestsResponse,
+        InboundTransfersResponse, InstantiateMsg, InvalidDepositsResponse,
+        OperationEvidenceDeadlineResponse, OperationSigningPayloadResponse,
+        OperationSubmissionResponse, PendingHighRiskActionInfo, PendingHighRiskActionsResponse,
+        PendingOperationsResponse, PendingRefund, PendingRefundTotalsResponse,
+        PendingRefundsResponse, PrioritizedOperation, PrioritizedPendingOperationsResponse,
+        ProcessedTxsResponse, ProhibitedXRPLAddressesResponse, ProvenXRPLTransactionResponse,
+        QueryMsg, RecurringTransfersResponse, RegisteredXRPLTokensResponse, RelayerActivityInfo,
+        RelayerAttestationInfo, RelayerAttestationsResponse, RelayerFeeClaimsResponse,
+        RelayerInfractionsResponse, RelayerReimbursementResponse, RelayerSigningPayload,
+        RelayersActivityResponse, SolvencyResponse, StaleEvidence, StaleEvidencesResponse,
+        StaleOperation, StaleOperationsResponse, StorageLayoutEntry, StorageLayoutResponse,
+        SudoMsg, TokenFreezeResponse, TokenPriceUpdate, TokenSolvency, TokenStateHistoryResponse,
+        TransactionEvidence, TransactionEvidencesResponse, TransferChunksResponse,
+        TransferCommitmentInfo, TransferCommitmentPreimage, TransferCommitmentsResponse,
+        USDBridgingFeeResponse, UndeliveredTransfer, UndeliveredTransfersResponse,
+        XRPLAddressResponse, XRPLCurrencyForDenomResponse, XRPLDexQuoteResponse,
+        XRPLIssuerConfigResponse, XRPLRecipientFlagsResponse, XRPLRecipientRuleInfo,
+        XRPLRecipientRulesResponse, XRPLReserveResponse, XRPLSupplyDriftsResponse,
+        XRPLTokenSuppliesResponse, XRPLTokenSupply, XRPLTokensResponse,
     },
     operation::{
-        check_operation_exists, create_pending_operation, handle_operation, remove_pending_refund,
+        check_operation_exists, create_pending_operation, handle_operation,
+        prune_operation_archive, record_blocked_claim, remove_pending_refund, store_pending_refund,
         Operation, OperationType,
     },
-    relayer::{is_relayer, validate_relayers, Relayer},
+    receipt::{
+        create_coreum_to_xrpl_transfer_receipt, create_xrpl_to_coreum_transfer_receipt,
+        load_transfer_receipt, record_inbound_transfer, record_undelivered_transfer,
+    },
+    relayer::{
+        evidence_relayer, init_relayers_activity, is_evidence_delegate, is_relayer,
+        operation_signers_config, record_relayer_activity, record_relayer_workload,
+        reset_operation_relayer_set, validate_relayers, Relayer,
+    },
+    reserve::{
+        attested_bridge_xrpl_balance, bridge_xrpl_owner_count, bridged_xrp, check_xrpl_reserve,
+        required_xrpl_reserve, validate_xrpl_base_fee,
+    },
     signatures::add_signature,
+    signing_payload::{
+        operation_signing_payload, serialize_operation_transaction,
+        verify_operation_transaction_hash,
+    },
     state::{
-        BridgeState, Config, ContractActions, CoreumToken, TokenState, UserType, XRPLToken,
-        AVAILABLE_TICKETS, CONFIG, COREUM_TOKENS, FEES_COLLECTED, PENDING_OPERATIONS,
-        PENDING_REFUNDS, PENDING_ROTATE_KEYS, PENDING_TICKET_UPDATE, PROCESSED_TXS,
-        PROHIBITED_XRPL_ADDRESSES, TX_EVIDENCES, USED_TICKETS_COUNTER, XRPL_TOKENS,
+        BlockedClaim, BridgeState, Config, ContractActions, CoreumToken, DeferredInboundTransfer,
+        EmergencyWithdrawal, HaltInfo, HaltReason, HaltReasonCode, InvalidDeposit,
+        OperationSubmission, PendingDelivery, PendingHighRiskAction, RecurringTransfer,
+        RelayerAttestation, RelayerReimbursement, RoundingMode, TokenFreeze, TokenMetadata,
+        TokenPrice, TokenState, TokenStateTransitionReason, TransferCommitment, TransferDirection,
+        TransferReceipt, USDBridgingFee, UserType, XRPLBalanceAttestation, XRPLConfig,
+        XRPLDexQuote, XRPLIssuerConfig, XRPLRecipientFlags, XRPLRecipientRule, XRPLSupplyDrift,
+        XRPLToken, AVAILABLE_TICKETS, BLOCKED_CLAIMS, BRIDGE_ACTIVITY,
+        BRIDGE_STATE_ACKNOWLEDGEMENTS, BRIDGE_STATE_CONFIG, COMMIT_REVEAL_THRESHOLDS, CONFIG,
+        COREUM_TOKENS, DEFERRED_INBOUND_TRANSFERS, DUST_ACCOUNTING, EMERGENCY_WITHDRAWAL,
+        EVIDENCE_DELEGATES, EVIDENCE_SIGNING_KEYS, EVIDENCE_SOURCES, FEES_COLLECTED, FEES_CONFIG,
+        FEE_ACCOUNTING, FEE_REMAINDERS, HALT_REQUESTS, INBOUND_TRANSFERS, INVALID_DEPOSITS,
+        OPERATIONS_CONFIG, OPERATION_ARCHIVE, OPERATION_CREATIONS, OPERATION_EVIDENCE_DEADLINES,
+        OPERATION_SUBMISSIONS, PENDING_DELIVERY, PENDING_HIGH_RISK_ACTIONS, PENDING_OPERATIONS,
+        PENDING_REFUNDS, PENDING_ROTATE_KEYS, PENDING_TICKET_UPDATE, PRIORITY_OPERATIONS,
+        PROCESSED_TXS, PROHIBITED_XRPL_ADDRESSES, PROVEN_XRPL_TRANSACTIONS,
+        QUEUED_XRPL_TOKEN_REGISTRATIONS, RECURRING_TRANSFERS, RECURRING_TRANSFERS_COUNTER,
+        RELAYERS_CONFIG, RELAYER_ACTIVITY, RELAYER_ATTESTATIONS, RELAYER_FEE_CLAIMS,
+        RELAYER_FEE_CONSOLIDATION_DENOMS, RELAYER_INFRACTIONS, RELAYER_REIMBURSEMENT,
+        RELAYER_WORKLOADS, SEND_TO_XRPL_IDEMPOTENCY_KEYS, STORAGE_LAYOUT, TOKEN_ACCOUNTING,
+        TOKEN_FREEZES, TOKEN_FREEZE_REQUESTS, TOKEN_METADATA, TOKEN_PRICES, TOKEN_STATE_HISTORY,
+        TRANSFER_CHUNKS, TRANSFER_COMMITMENTS, TX_EVIDENCES, UNDELIVERED_TRANSFERS,
+        USD_BRIDGING_FEES, USED_TICKETS_COUNTER, XRPL_BALANCE_ATTESTATIONS, XRPL_CONFIG,
+        XRPL_DEX_QUOTES, XRPL_ISSUER_CONFIGS, XRPL_RECIPIENT_FLAGS, XRPL_RECIPIENT_RULES,
+        XRPL_SUPPLY_DRIFTS, XRPL_TOKENS,
     },
-    tickets::{allocate_ticket, register_used_ticket},
+    tickets::{allocate_ticket, allocate_tickets_if_needed, register_used_ticket},
     token::{
-        build_xrpl_token_key, is_token_xrp, set_token_bridging_fee, set_token_max_holding_amount,
-        set_token_sending_precision, set_token_state,
+        assert_token_not_frozen, build_xrpl_token_key, checked_decimals_factor,
+        convert_amount_decimals, count_registered_xrpl_tokens, handle_issuer_config_change,
+        is_token_frozen, is_token_registered, is_token_xrp, record_token_state_transition,
+        set_token_bridging_fee, set_token_max_holding_amount, set_token_rounding_mode,
+        set_token_sending_precision, set_token_state, xrpl_tokens_of_issuer,
     },
 };
 
 use coreum_wasm_sdk::{
-    assetft::{self, Msg::Issue, ParamsResponse, Query, IBC, MINTING},
+    assetft::{self, Msg::Issue, ParamsResponse, Query, FREEZING, IBC, MINTING},
     core::{CoreumMsg, CoreumQueries, CoreumResult},
 };
 use cosmwasm_std::{
-    coin, coins, entry_point, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Deps,
-    DepsMut, Empty, Env, MessageInfo, Order, Response, StdResult, Storage, Uint128,
+    coin, coins, entry_point, to_json_binary, to_json_string, to_json_vec, Addr, BankMsg, Binary,
+    BlockInfo, Coin, CosmosMsg, Decimal, Deps, DepsMut, Empty, Env, Event, MessageInfo, Order,
+    Reply, Response, StdError, StdResult, Storage, SubMsg, SubMsgResult, Uint128, WasmMsg,
 };
 use cw2::set_contract_version;
 use cw_ownable::{get_ownership, initialize_owner, is_owner, Action};
 use cw_storage_plus::Bound;
-use cw_utils::one_coin;
+use cw_utils::{must_pay, one_coin};
 
 // version info for migration info
 const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
@@ -61,7 +145,41 @@ const MAX_SENDING_PRECISION: i32 = 15;
 pub const MAX_COREUM_TOKEN_DECIMALS: u32 = 100;
 
 pub const MAX_TICKETS: u32 = 250;
-pub const MAX_RELAYERS: usize = 32;
+// Maximum amount of signers XRPL allows in a signer list, which bounds the amount of relayers
+pub const XRPL_MAX_SIGNERS: u32 = 32;
+// Maximum amount of relayers when the bridge is instantiated, it can be updated by the owner up to XRPL_MAX_SIGNERS
+pub const DEFAULT_MAX_RELAYERS: u32 = XRPL_MAX_SIGNERS;
+// Maximum amount of pending operations the owner can cancel at once
+pub const MAX_OPERATIONS_PER_CANCELLATION: usize = 50;
+// Seconds during which a high risk action proposed by the owner can be confirmed
+pub const HIGH_RISK_ACTION_CONFIRMATION_WINDOW: u64 = 3600;
+// Maximum amount of XRPL originated tokens that can be registered when the bridge is instantiated, it can be updated by the owner
+pub const DEFAULT_MAX_REGISTERED_XRPL_TOKENS: u32 = 100;
+// Seconds a token frozen by relayers stays frozen if the owner doesn't confirm the freeze, it can be updated by the owner
+pub const DEFAULT_TOKEN_FREEZE_DURATION: u64 = 86400;
+// Maximum amount of resolved operations kept in the operation archive when the bridge is instantiated, it can be updated by the owner
+pub const DEFAULT_OPERATION_ARCHIVE_SIZE: u32 = 1000;
+// Seconds after which evidences that didn't reach the threshold are considered stale when the bridge is instantiated, it can be updated by the owner
+pub const DEFAULT_EVIDENCE_TTL: u64 = 604800;
+// Blocks that must pass before a committed transfer to XRPL can be revealed when the bridge is instantiated, it can be updated by the owner
+pub const DEFAULT_COMMIT_REVEAL_DELAY: u64 = 10;
+// Blocks after which a committed transfer to XRPL expires when the bridge is instantiated, it can be updated by the owner
+pub const DEFAULT_COMMIT_REVEAL_EXPIRATION: u64 = 86400;
+// Seconds during which DEX quotes published by relayers are valid when the bridge is instantiated, it can be updated by the owner
+pub const DEFAULT_XRPL_DEX_QUOTE_TTL: u64 = 300;
+// XRP (in drops) that XRPL requires every account to hold when the bridge is instantiated, it can be updated by the owner
+pub const DEFAULT_XRPL_BASE_RESERVE: u128 = 1_000_000;
+// XRP (in drops) that XRPL requires an account to hold for each object it owns when the bridge is instantiated, it can be updated by the owner
+pub const DEFAULT_XRPL_OWNER_RESERVE: u128 = 200_000;
+// Seconds without activity after which a relayer can be marked as inactive when the bridge is instantiated, it can be updated by the owner
+pub const DEFAULT_RELAYER_INACTIVITY_PERIOD: u64 = 2592000;
+// Seconds pending operations wait for their evidence before anyone can expire them when the bridge is instantiated, it can be updated by the owner
+pub const DEFAULT_OPERATION_EVIDENCE_PERIOD: u64 = 604800;
+// Seconds that must pass between governance scheduling an emergency withdrawal and its execution
+pub const EMERGENCY_WITHDRAWAL_DELAY: u64 = 604800;
+
+// Reply id used for deliveries of Coreum originated tokens, which can fail if the token is frozen
+const DELIVERY_REPLY_ID: u64 = 1;
 
 // Information for the XRP token
 const XRP_SYMBOL: &str = "XRP";
@@ -89,6 +207,20 @@ pub const XRPL_MAX_TRUNCATED_AMOUNT_LENGTH: usize = 16;
 pub const MIN_DENOM_LENGTH: usize = 3;
 pub const MAX_DENOM_LENGTH: usize = 128;
 pub const DENOM_SPECIAL_CHARACTERS: [char; 5] = ['/', ':', '.', '_', '-'];
+// IBC vouchers are represented as ibc/<HASH> where the hash is the hex encoded SHA256 of the denom trace
+pub const IBC_DENOM_PREFIX: &str = "ibc/";
+pub const IBC_DENOM_HASH_LENGTH: usize = 64;
+
+pub const MAX_HALT_MESSAGE_LENGTH: usize = 256;
+pub const MAX_IDEMPOTENCY_KEY_LENGTH: usize = 64;
+pub const MAX_EVIDENCE_SOURCE_LENGTH: usize = 128;
+pub const MAX_RELAYER_ATTESTATION_FIELD_LENGTH: usize = 256;
+// Maximum length of the hex encoded XRPL transactions relayers record as submitted, enough for the signatures of XRPL_MAX_SIGNERS relayers
+pub const MAX_TRANSACTION_BLOB_LENGTH: usize = 20000;
+
+// Maximum lengths Coreum allows for the description and URI of an FT
+pub const MAX_TOKEN_DESCRIPTION_LENGTH: usize = 200;
+pub const MAX_TOKEN_URI_LENGTH: usize = 256;
 
 pub const INITIAL_PROHIBITED_XRPL_ADDRESSES: [&str; 5] = [
     "rrrrrrrrrrrrrrrrrrrrrhoLvTp", // ACCOUNT_ZERO: An address that is the XRP Ledger's base58 encoding of the value 0. In peer-to-peer communications, rippled uses this address as the issuer for XRP.
@@ -122,6 +254,7 @@ pub fn instantiate(
         deps.as_ref().into_empty(),
         &msg.relayers,
         msg.evidence_threshold,
+        DEFAULT_MAX_RELAYERS,
     )?;
 
     // The multisig address on XRPL must be valid
@@ -143,6 +276,8 @@ pub fn instantiate(
     PENDING_TICKET_UPDATE.save(deps.storage, &false)?;
     PENDING_ROTATE_KEYS.save(deps.storage, &false)?;
     AVAILABLE_TICKETS.save(deps.storage, &VecDeque::new())?;
+    QUEUED_XRPL_TOKEN_REGISTRATIONS.save(deps.storage, &VecDeque::new())?;
+    HALT_REQUESTS.save(deps.storage, &vec![])?;
 
     let config = Config {
         relayers: msg.relayers,
@@ -152,9 +287,35 @@ pub fn instantiate(
         bridge_xrpl_address: msg.bridge_xrpl_address,
         bridge_state: BridgeState::Active,
         xrpl_base_fee: msg.xrpl_base_fee,
+        light_client: None,
+        halt_info: None,
+        halt_request_threshold: msg.evidence_threshold,
+        max_registered_xrpl_tokens: DEFAULT_MAX_REGISTERED_XRPL_TOKENS,
+        token_freeze_request_threshold: msg.evidence_threshold,
+        token_freeze_duration: DEFAULT_TOKEN_FREEZE_DURATION,
+        auto_disable_on_issuer_change: false,
+        operation_archive_size: DEFAULT_OPERATION_ARCHIVE_SIZE,
+        evidence_ttl: DEFAULT_EVIDENCE_TTL,
+        compliance_contract: None,
+        commit_reveal_delay: DEFAULT_COMMIT_REVEAL_DELAY,
+        commit_reveal_expiration: DEFAULT_COMMIT_REVEAL_EXPIRATION,
+        crank_reward: Uint128::zero(),
+        xrpl_dex_quote_ttl: DEFAULT_XRPL_DEX_QUOTE_TTL,
+        price_oracle: None,
+        xrpl_base_reserve: Uint128::new(DEFAULT_XRPL_BASE_RESERVE),
+        xrpl_owner_reserve: Uint128::new(DEFAULT_XRPL_OWNER_RESERVE),
+        relayer_inactivity_period: DEFAULT_RELAYER_INACTIVITY_PERIOD,
+        operation_evidence_period: DEFAULT_OPERATION_EVIDENCE_PERIOD,
+        token_failure_threshold: 0,
+        priority_fee: None,
+        fee_converter_contract: None,
+        min_distinct_evidence_sources: 0,
+        max_relayers: DEFAULT_MAX_RELAYERS,
+        xrpl_network: msg.xrpl_network,
     };
 
     CONFIG.save(deps.storage, &config)?;
+    init_relayers_activity(deps.storage, &config.relayers, env.block.time.seconds())?;
 
     // We will issue the XRP token during instantiation. We don't need to register it
     let xrp_issue_msg = CosmosMsg::from(CoreumMsg::AssetFT(Issue {
@@ -163,7 +324,7 @@ pub fn instantiate(
         precision: XRP_DECIMALS,
         initial_amount: Uint128::zero(),
         description: None,
-        features: Some(vec![MINTING, IBC]),
+        features: Some(vec![MINTING, IBC, FREEZING]),
         burn_rate: "0.0".to_string(),
         send_commission_rate: "0.0".to_string(),
         uri: None,
@@ -176,16 +337,34 @@ pub fn instantiate(
     let token = XRPLToken {
         issuer: XRP_ISSUER.to_string(),
         currency: XRP_CURRENCY.to_string(),
-        coreum_denom: xrp_coreum_denom,
+        coreum_denom: xrp_coreum_denom.clone(),
         sending_precision: XRP_DEFAULT_SENDING_PRECISION,
         max_holding_amount: Uint128::new(XRP_DEFAULT_MAX_HOLDING_AMOUNT),
         // The XRP token is enabled from the start because it doesn't need approval to be received on the XRPL side
         state: TokenState::Enabled,
         bridging_fee: XRP_DEFAULT_FEE,
+        rounding_mode: RoundingMode::TruncateToFee,
     };
 
     let key = build_xrpl_token_key(XRP_ISSUER, XRP_CURRENCY);
     XRPL_TOKENS.save(deps.storage, key, &token)?;
+    let xrp_state_event = record_token_state_transition(
+        deps.storage,
+        &xrp_coreum_denom,
+        None,
+        TokenState::Enabled,
+        TokenStateTransitionReason::Registration,
+        env.block.time.seconds(),
+    )?;
+    TOKEN_METADATA.save(
+        deps.storage,
+        xrp_coreum_denom,
+        &TokenMetadata {
+            symbol: XRP_SYMBOL.to_string(),
+            description: None,
+            uri: None,
+        },
+    )?;
 
     Ok(Response::new()
         .add_attribute("action", ContractActions::Instantiation.as_str())
@@ -193,7 +372,8 @@ pub fn instantiate(
         .add_attribute("contract_version", CONTRACT_VERSION)
         .add_attribute("owner", msg.owner)
         .add_attribute("sender", info.sender)
-        .add_message(xrp_issue_msg))
+        .add_message(xrp_issue_msg)
+        .add_event(xrp_state_event))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -203,6 +383,10 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> CoreumResult<ContractError> {
+    if let Some(response) = propose_high_risk_action(deps.storage, &env, &info.sender, &msg)? {
+        return Ok(response);
+    }
+
     match msg {
         ExecuteMsg::UpdateOwnership(action) => {
             update_ownership(deps.into_empty(), env, info, action)
@@ -213,15 +397,19 @@ pub fn execute(
             sending_precision,
             max_holding_amount,
             bridging_fee,
+            xrpl_currency,
+            rounding_mode,
         } => register_coreum_token(
             deps.into_empty(),
-            env,
+            env.block.time.seconds(),
             info.sender,
             denom,
             decimals,
             sending_precision,
             max_holding_amount,
             bridging_fee,
+            xrpl_currency,
+            rounding_mode.unwrap_or_default(),
         ),
         ExecuteMsg::RegisterXRPLToken {
             issuer,
@@ -229,6 +417,11 @@ pub fn execute(
             sending_precision,
             max_holding_amount,
             bridging_fee,
+            symbol,
+            description,
+            uri,
+            queue_if_no_tickets,
+            rounding_mode,
         } => register_xrpl_token(
             deps,
             env,
@@ -238,16 +431,30 @@ pub fn execute(
             sending_precision,
             max_holding_amount,
             bridging_fee,
+            symbol,
+            description,
+            uri,
+            queue_if_no_tickets.unwrap_or(false),
+            rounding_mode.unwrap_or_default(),
+        ),
+        ExecuteMsg::SaveEvidence {
+            evidence,
+            signature,
+            close_time,
+        } => save_evidence(
+            deps.into_empty(),
+            env,
+            info.sender,
+            evidence,
+            signature,
+            close_time,
         ),
-        ExecuteMsg::SaveEvidence { evidence } => {
-            save_evidence(deps.into_empty(), env, info.sender, evidence)
-        }
         ExecuteMsg::RecoverTickets {
             account_sequence,
             number_of_tickets,
         } => recover_tickets(
             deps.into_empty(),
-            env.block.time.seconds(),
+            &env.block,
             info.sender,
             account_sequence,
             number_of_tickets,
@@ -255,7 +462,7 @@ pub fn execute(
         ExecuteMsg::RecoverXRPLTokenRegistration { issuer, currency } => {
             recover_xrpl_token_registration(
                 deps.into_empty(),
-                env.block.time.seconds(),
+                &env.block,
                 info.sender,
                 issuer,
                 currency,
@@ -267,15 +474,92 @@ pub fn execute(
             signature,
         } => save_signature(
             deps.into_empty(),
+            env,
             info.sender,
             operation_id,
             operation_version,
             &signature,
         ),
+        ExecuteMsg::SaveTransactionSubmission {
+            operation_id,
+            operation_version,
+            tx_hash,
+            tx_blob,
+        } => save_transaction_submission(
+            deps.into_empty(),
+            env,
+            info.sender,
+            operation_id,
+            operation_version,
+            tx_hash,
+            tx_blob,
+        ),
         ExecuteMsg::SendToXRPL {
             recipient,
             deliver_amount,
-        } => send_to_xrpl(deps.into_empty(), env, info, recipient, deliver_amount),
+            exact_amount,
+            split_amount,
+            priority,
+            idempotency_key,
+        } => send_to_xrpl(
+            deps.into_empty(),
+            env,
+            info,
+            recipient,
+            deliver_amount,
+            exact_amount.unwrap_or(false),
+            split_amount.unwrap_or(false),
+            priority.unwrap_or(false),
+            idempotency_key,
+        ),
+        ExecuteMsg::CommitSendToXRPL { commitment } => {
+            commit_send_to_xrpl(deps.into_empty(), env, info, commitment)
+        }
+        ExecuteMsg::RevealSendToXRPL {
+            commitment,
+            recipient,
+            deliver_amount,
+            exact_amount,
+            salt,
+        } => reveal_send_to_xrpl(
+            deps.into_empty(),
+            env,
+            info.sender,
+            commitment,
+            recipient,
+            deliver_amount,
+            exact_amount.unwrap_or(false),
+            salt,
+        ),
+        ExecuteMsg::CancelSendToXRPLCommitment { sender, commitment } => {
+            cancel_send_to_xrpl_commitment(deps.into_empty(), env, info.sender, sender, commitment)
+        }
+        ExecuteMsg::CreateRecurringTransfer {
+            recipient,
+            amount,
+            interval,
+        } => create_recurring_transfer(deps.into_empty(), env, info, recipient, amount, interval),
+        ExecuteMsg::FundRecurringTransfer { id } => {
+            fund_recurring_transfer(deps.into_empty(), info, id)
+        }
+        ExecuteMsg::CancelRecurringTransfer { id } => {
+            cancel_recurring_transfer(deps.into_empty(), info.sender, id)
+        }
+        ExecuteMsg::ExecuteRecurringTransfer { id } => {
+            execute_recurring_transfer(deps.into_empty(), env, info.sender, id)
+        }
+        ExecuteMsg::UpdateCommitRevealThreshold { denom, threshold } => {
+            update_commit_reveal_threshold(deps.into_empty(), info.sender, denom, threshold)
+        }
+        ExecuteMsg::UpdateCommitRevealConfig {
+            commit_reveal_delay,
+            commit_reveal_expiration,
+        } => update_commit_reveal_config(
+            deps.into_empty(),
+            info.sender,
+            commit_reveal_delay,
+            commit_reveal_expiration,
+        ),
         ExecuteMsg::UpdateXRPLToken {
             issuer,
             currency,
@@ -283,8 +567,10 @@ pub fn execute(
             sending_precision,
             bridging_fee,
             max_holding_amount,
+            rounding_mode,
         } => update_xrpl_token(
             deps.into_empty(),
+            env.block.time.seconds(),
             info.sender,
             issuer,
             currency,
@@ -292,6 +578,20 @@ pub fn execute(
             sending_precision,
             bridging_fee,
             max_holding_amount,
+            rounding_mode,
+        ),
+        ExecuteMsg::UpdateXRPLTokenMetadata {
+            issuer,
+            currency,
+            description,
+            uri,
+        } => update_xrpl_token_metadata(
+            deps.into_empty(),
+            info.sender,
+            issuer,
+            currency,
+            description,
+            uri,
         ),
         ExecuteMsg::UpdateCoreumToken {
             denom,
@@ -299,6 +599,7 @@ pub fn execute(
             sending_precision,
             bridging_fee,
             max_holding_amount,
+            rounding_mode,
         } => update_coreum_token(
             deps.into_empty(),
             env,
@@ -308,18 +609,233 @@ pub fn execute(
             sending_precision,
             bridging_fee,
             max_holding_amount,
+            rounding_mode,
         ),
         ExecuteMsg::UpdateXRPLBaseFee { xrpl_base_fee } => {
             update_xrpl_base_fee(deps.into_empty(), info.sender, xrpl_base_fee)
         }
-        ExecuteMsg::ClaimRefund { pending_refund_id } => {
-            claim_pending_refund(deps.into_empty(), info.sender, pending_refund_id)
+        ExecuteMsg::RebasePendingOperations { xrpl_base_fee } => {
+            rebase_pending_operations(deps.into_empty(), info.sender, xrpl_base_fee)
+        }
+        ExecuteMsg::ClaimRefund {
+            pending_refund_id,
+            owner,
+        } => claim_pending_refund(
+            deps.into_empty(),
+            &env.block,
+            info.sender,
+            pending_refund_id,
+            owner,
+        ),
+        ExecuteMsg::ClaimAllRefunds { limit } => {
+            claim_all_pending_refunds(deps.into_empty(), &env.block, info.sender, limit)
         }
+        ExecuteMsg::ReturnUndeliveredTransfer {
+            pending_refund_id,
+            recipient,
+        } => return_undelivered_transfer(
+            deps.into_empty(),
+            env,
+            info.sender,
+            pending_refund_id,
+            recipient,
+        ),
         ExecuteMsg::ClaimRelayerFees { amounts } => {
-            claim_relayer_fees(deps.into_empty(), info.sender, amounts)
+            claim_relayer_fees(deps.into_empty(), env, info.sender, amounts)
+        }
+        ExecuteMsg::UpdateFeeConsolidationDenom { denom } => {
+            update_fee_consolidation_denom(deps.into_empty(), info.sender, denom)
+        }
+        ExecuteMsg::UpdateRelayerReimbursement {
+            amount_per_operation,
+        } => update_relayer_reimbursement(deps.into_empty(), info.sender, amount_per_operation),
+        ExecuteMsg::FundReimbursementPool {} => fund_reimbursement_pool(deps.into_empty(), info),
+        ExecuteMsg::HaltBridge { reason } => {
+            halt_bridge(deps.into_empty(), env, info.sender, reason)
         }
-        ExecuteMsg::HaltBridge {} => halt_bridge(deps.into_empty(), info.sender),
         ExecuteMsg::ResumeBridge {} => resume_bridge(deps.into_empty(), info.sender),
+        ExecuteMsg::StartMaintenance {} => start_maintenance(deps.into_empty(), info.sender),
+        ExecuteMsg::RequestHalt {} => request_halt(deps.into_empty(), env, info.sender),
+        ExecuteMsg::AcknowledgeBridgeState { bridge_state } => {
+            acknowledge_bridge_state(deps.into_empty(), env, info.sender, bridge_state)
+        }
+        ExecuteMsg::UpdateHaltRequestThreshold {
+            halt_request_threshold,
+        } => update_halt_request_threshold(deps.into_empty(), info.sender, halt_request_threshold),
+        ExecuteMsg::RequestTokenFreeze { denom } => {
+            request_token_freeze(deps.into_empty(), env, info.sender, denom)
+        }
+        ExecuteMsg::ConfirmTokenFreeze { denom } => {
+            confirm_token_freeze(deps.into_empty(), env, info.sender, denom)
+        }
+        ExecuteMsg::UnfreezeToken { denom } => {
+            unfreeze_token(deps.into_empty(), info.sender, denom)
+        }
+        ExecuteMsg::GloballyFreezeToken { denom } => {
+            update_token_global_freeze(deps.into_empty(), info.sender, denom, true)
+        }
+        ExecuteMsg::GloballyUnfreezeToken { denom } => {
+            update_token_global_freeze(deps.into_empty(), info.sender, denom, false)
+        }
+        ExecuteMsg::GloballyFreezeAllTokens {
+            start_after_key,
+            limit,
+        } => update_all_tokens_global_freeze(
+            deps.into_empty(),
+            info.sender,
+            start_after_key,
+            limit,
+            true,
+        ),
+        ExecuteMsg::GloballyUnfreezeAllTokens {
+            start_after_key,
+            limit,
+        } => update_all_tokens_global_freeze(
+            deps.into_empty(),
+            info.sender,
+            start_after_key,
+            limit,
+            false,
+        ),
+        ExecuteMsg::UpdateTokenFreezeConfig {
+            token_freeze_request_threshold,
+            token_freeze_duration,
+        } => update_token_freeze_config(
+            deps.into_empty(),
+            info.sender,
+            token_freeze_request_threshold,
+            token_freeze_duration,
+        ),
+        ExecuteMsg::UpdateAutoDisableOnIssuerChange {
+            auto_disable_on_issuer_change,
+        } => update_auto_disable_on_issuer_change(
+            deps.into_empty(),
+            info.sender,
+            auto_disable_on_issuer_change,
+        ),
+        ExecuteMsg::UpdateTokenFailureThreshold {
+            token_failure_threshold,
+        } => {
+            update_token_failure_threshold(deps.into_empty(), info.sender, token_failure_threshold)
+        }
+        ExecuteMsg::UpdatePriorityFee { priority_fee } => {
+            update_priority_fee(deps.into_empty(), info.sender, priority_fee)
+        }
+        ExecuteMsg::UpdateEvidenceTTL { evidence_ttl } => {
+            update_evidence_ttl(deps.into_empty(), info.sender, evidence_ttl)
+        }
+        ExecuteMsg::UpdateComplianceContract {
+            compliance_contract,
+        } => update_compliance_contract(deps.into_empty(), info.sender, compliance_contract),
+        ExecuteMsg::UpdateFeeConverterContract {
+            fee_converter_contract,
+        } => update_fee_converter_contract(deps.into_empty(), info.sender, fee_converter_contract),
+        ExecuteMsg::PruneStaleEvidences { limit } => {
+            prune_stale_evidences(deps.into_empty(), env, info.sender, limit)
+        }
+        ExecuteMsg::Crank { limit } => crank(deps.into_empty(), env, info.sender, limit),
+        ExecuteMsg::UpdateCrankReward { crank_reward } => {
+            update_crank_reward(deps.into_empty(), info.sender, crank_reward)
+        }
+        ExecuteMsg::PublishXRPLDexQuote { denom, price } => {
+            publish_xrpl_dex_quote(deps.into_empty(), env, info.sender, denom, price)
+        }
+        ExecuteMsg::UpdateXRPLDexQuoteTTL { xrpl_dex_quote_ttl } => {
+            update_xrpl_dex_quote_ttl(deps.into_empty(), info.sender, xrpl_dex_quote_ttl)
+        }
+        ExecuteMsg::UpdatePriceOracle { price_oracle } => {
+            update_price_oracle(deps.into_empty(), info.sender, price_oracle)
+        }
+        ExecuteMsg::UpdateUSDBridgingFee {
+            denom,
+            usd_bridging_fee,
+        } => update_usd_bridging_fee(deps.into_empty(), info.sender, denom, usd_bridging_fee),
+        ExecuteMsg::UpdateTokenPrices { prices } => {
+            update_token_prices(deps.into_empty(), env, info.sender, prices)
+        }
+        ExecuteMsg::ReconcileXRPLTokenSupply { tx_hash } => {
+            reconcile_xrpl_token_supply(deps.into_empty(), info.sender, tx_hash)
+        }
+        ExecuteMsg::AssignInvalidDeposit { tx_hash, recipient } => {
+            assign_invalid_deposit(deps.into_empty(), env, info.sender, tx_hash, recipient)
+        }
+        ExecuteMsg::AttestBridgeXRPLBalance { balance } => {
+            attest_bridge_xrpl_balance(deps.into_empty(), env, info.sender, balance)
+        }
+        ExecuteMsg::UpdateXRPLReserves {
+            xrpl_base_reserve,
+            xrpl_owner_reserve,
+        } => update_xrpl_reserves(
+            deps.into_empty(),
+            info.sender,
+            xrpl_base_reserve,
+            xrpl_owner_reserve,
+        ),
+        ExecuteMsg::MarkRelayerInactive { relayer_address } => {
+            mark_relayer_inactive(deps.into_empty(), env, info.sender, relayer_address)
+        }
+        ExecuteMsg::UpdateRelayerInactivityPeriod {
+            relayer_inactivity_period,
+        } => update_relayer_inactivity_period(
+            deps.into_empty(),
+            info.sender,
+            relayer_inactivity_period,
+        ),
+        ExecuteMsg::ReportConflictingEvidences {
+            relayer_address,
+            evidence,
+            conflicting_evidence,
+        } => report_conflicting_evidences(
+            deps.into_empty(),
+            env,
+            info.sender,
+            relayer_address,
+            evidence,
+            conflicting_evidence,
+        ),
+        ExecuteMsg::ExpireOperation { operation_id } => {
+            expire_operation(deps.into_empty(), &env.block, info.sender, operation_id)
+        }
+        ExecuteMsg::CancelHaltedTransfer { operation_id } => {
+            cancel_halted_transfer(deps.into_empty(), &env.block, info.sender, operation_id)
+        }
+        ExecuteMsg::UpdateOperationEvidencePeriod {
+            operation_evidence_period,
+        } => update_operation_evidence_period(
+            deps.into_empty(),
+            info.sender,
+            operation_evidence_period,
+        ),
+        ExecuteMsg::UpdateOperationArchiveSize {
+            operation_archive_size,
+        } => update_operation_archive_size(deps.into_empty(), info.sender, operation_archive_size),
+        ExecuteMsg::PruneOperationArchive { limit } => {
+            prune_archived_operations(deps.into_empty(), info.sender, limit)
+        }
+        ExecuteMsg::UpdateMaxRegisteredXRPLTokens {
+            max_registered_xrpl_tokens,
+        } => update_max_registered_xrpl_tokens(
+            deps.into_empty(),
+            info.sender,
+            max_registered_xrpl_tokens,
+        ),
+        ExecuteMsg::RescueFunds {
+            denom,
+            amount,
+            recipient,
+        } => rescue_funds(
+            deps.into_empty(),
+            env,
+            info.sender,
+            denom,
+            amount,
+            recipient,
+        ),
+        ExecuteMsg::SweepFeeRemainder {
+            denom,
+            threshold,
+            recipient,
+        } => sweep_fee_remainders(deps.into_empty(), info.sender, denom, threshold, recipient),
         ExecuteMsg::RotateKeys {
             new_relayers,
             new_evidence_threshold,
@@ -330,15 +846,132 @@ pub fn execute(
             new_relayers,
             new_evidence_threshold,
         ),
-        ExecuteMsg::UpdateProhibitedXRPLAddresses {
-            prohibited_xrpl_addresses,
-        } => update_prohibited_xrpl_addresses(
+        ExecuteMsg::AddProhibitedXRPLRecipient { recipient } => {
+            add_prohibited_xrpl_recipient(deps.into_empty(), info.sender, recipient)
+        }
+        ExecuteMsg::RemoveProhibitedXRPLRecipient { recipient } => {
+            remove_prohibited_xrpl_recipient(deps.into_empty(), info.sender, recipient)
+        }
+        ExecuteMsg::SetXRPLRecipientRule { name, rule } => {
+            set_xrpl_recipient_rule(deps.into_empty(), info.sender, name, rule)
+        }
+        ExecuteMsg::RemoveXRPLRecipientRule { name } => {
+            remove_xrpl_recipient_rule(deps.into_empty(), info.sender, name)
+        }
+        ExecuteMsg::CancelPendingOperation { operation_id } => {
+            cancel_pending_operation(deps.into_empty(), &env.block, info.sender, operation_id)
+        }
+        ExecuteMsg::CancelPendingOperations { operation_ids } => {
+            cancel_pending_operations(deps.into_empty(), &env.block, info.sender, operation_ids)
+        }
+        ExecuteMsg::UpdateLightClientConfig { light_client } => {
+            update_light_client_config(deps.into_empty(), info.sender, light_client)
+        }
+        ExecuteMsg::SaveXRPLTransactionProof { proof } => {
+            save_xrpl_transaction_proof(deps.into_empty(), info.sender, proof)
+        }
+        ExecuteMsg::UpdateEvidenceSigningKey {
+            relayer_coreum_address,
+            pub_key,
+        } => update_evidence_signing_key(
+            deps.into_empty(),
+            info.sender,
+            relayer_coreum_address,
+            pub_key,
+        ),
+        ExecuteMsg::UpdateEvidenceDelegate { delegate } => {
+            update_evidence_delegate(deps.into_empty(), info.sender, delegate)
+        }
+        ExecuteMsg::UpdateEvidenceSource { source } => {
+            update_evidence_source(deps.into_empty(), info.sender, source)
+        }
+        ExecuteMsg::UpdateMinDistinctEvidenceSources {
+            min_distinct_evidence_sources,
+        } => update_min_distinct_evidence_sources(
             deps.into_empty(),
             info.sender,
-            prohibited_xrpl_addresses,
+            min_distinct_evidence_sources,
         ),
-        ExecuteMsg::CancelPendingOperation { operation_id } => {
-            cancel_pending_operation(deps.into_empty(), info.sender, operation_id)
+        ExecuteMsg::UpdateMaxRelayers { max_relayers } => {
+            update_max_relayers(deps.into_empty(), info.sender, max_relayers)
+        }
+        ExecuteMsg::UpdateRelayerAttestation {
+            xrpl_node_fingerprint,
+            software_version,
+            contact,
+        } => update_relayer_attestation(
+            deps.into_empty(),
+            env,
+            info.sender,
+            xrpl_node_fingerprint,
+            software_version,
+            contact,
+        ),
+        ExecuteMsg::ExecuteEmergencyWithdrawal {} => {
+            execute_emergency_withdrawal(deps.into_empty(), env, info.sender)
+        }
+        ExecuteMsg::UpdateOperationXRPLBaseFee {
+            operation_id,
+            xrpl_base_fee,
+        } => update_operation_xrpl_base_fee(
+            deps.into_empty(),
+            info.sender,
+            operation_id,
+            xrpl_base_fee,
+        ),
+    }
+}
+
+// Irreversible actions must be executed twice with the same message, in different blocks and within the confirmation window,
+// so that a single mistaken transaction can't cause any damage. The first execution only records the proposal of the action
+// Returns the response of the proposal if the message is not confirming a previous one
+fn propose_high_risk_action(
+    storage: &mut dyn Storage,
+    env: &Env,
+    sender: &Addr,
+    msg: &ExecuteMsg,
+) -> Result<Option<Response<CoreumMsg>>, ContractError> {
+    let action = match msg {
+        ExecuteMsg::RotateKeys { .. } => ContractActions::RotateKeys,
+        ExecuteMsg::RescueFunds { .. } => ContractActions::RescueFunds,
+        _ => return Ok(None),
+    };
+    check_authorization(storage, sender, &action)?;
+
+    let msg_hash = hash_bytes(to_json_vec(msg)?);
+    let timestamp = env.block.time.seconds();
+    let key = action.as_str().to_owned();
+    match PENDING_HIGH_RISK_ACTIONS.may_load(storage, key.clone())? {
+        Some(pending_action)
+            if pending_action.msg_hash.eq(&msg_hash)
+                && pending_action.proposed_at_height < env.block.height
+                && pending_action.expires_at >= timestamp =>
+        {
+            PENDING_HIGH_RISK_ACTIONS.remove(storage, key);
+            Ok(None)
+        }
+        // A different message or an expired proposal is proposed again, replacing the previous proposal
+        _ => {
+            let expires_at = timestamp.saturating_add(HIGH_RISK_ACTION_CONFIRMATION_WINDOW);
+            PENDING_HIGH_RISK_ACTIONS.save(
+                storage,
+                key,
+                &PendingHighRiskAction {
+                    msg_hash: msg_hash.clone(),
+                    proposed_by: sender.clone(),
+                    proposed_at_height: env.block.height,
+                    expires_at,
+                },
+            )?;
+
+            Ok(Some(
+                Response::new()
+                    .add_attribute("action", action.as_str())
+                    .add_attribute("sender", sender)
+                    .add_attribute("confirmation_required", true.to_string())
+                    .add_attribute("msg_hash", msg_hash)
+                    .add_attribute("expires_at", expires_at.to_string()),
+            ))
         }
     }
 }
@@ -358,16 +991,18 @@ fn update_ownership(
 #[allow(clippy::too_many_arguments)]
 fn register_coreum_token(
     deps: DepsMut,
-    env: Env,
+    timestamp: u64,
     sender: Addr,
     denom: String,
     decimals: u32,
     sending_precision: i32,
     max_holding_amount: Uint128,
     bridging_fee: Uint128,
+    xrpl_currency: Option<String>,
+    rounding_mode: RoundingMode,
 ) -> CoreumResult<ContractError> {
     check_authorization(deps.storage, &sender, &ContractActions::RegisterCoreumToken)?;
-    assert_bridge_active(deps.as_ref())?;
+    assert_bridge_not_halted(deps.as_ref())?;
 
     validate_coreum_token_decimals(decimals)?;
     validate_sending_precision(sending_precision, decimals)?;
@@ -378,30 +1013,38 @@ fn register_coreum_token(
 
     validate_coreum_denom(&denom)?;
 
-    // We generate a currency creating a Sha256 hash of the denom, the decimals and the current time so that if it fails we can try again
-    let to_hash = format!("{}{}{}", denom, decimals, env.block.time.seconds()).into_bytes();
-    let hex_string = hash_bytes(to_hash)
-        .get(0..10)
-        .unwrap()
-        .to_string()
-        .to_lowercase();
+    let xrpl_currency = match xrpl_currency {
+        // The registrant can choose the currency so that the token is recognizable on XRPL
+        Some(xrpl_currency) => {
+            validate_xrpl_currency(&xrpl_currency)?;
 
-    // Format will be the hex representation in XRPL of the string coreum<hash> in uppercase
-    let xrpl_currency =
-        convert_currency_to_xrpl_hexadecimal(format!("{COREUM_CURRENCY_PREFIX}{hex_string}"));
+            if COREUM_TOKENS
+                .idx
+                .xrpl_currency
+                .item(deps.storage, xrpl_currency.clone())?
+                .is_some()
+            {
+                return Err(ContractError::XRPLCurrencyAlreadyInUse {});
+            }
 
-    // Validate XRPL currency just in case we got an invalid XRPL currency (starting with 0x00)
-    validate_xrpl_currency(&xrpl_currency)?;
+            xrpl_currency
+        }
+        None => {
+            let xrpl_currency = generate_xrpl_currency(&denom)?;
+
+            // We check that the this currency is not used already (we got the same hash)
+            if COREUM_TOKENS
+                .idx
+                .xrpl_currency
+                .item(deps.storage, xrpl_currency.clone())?
+                .is_some()
+            {
+                return Err(ContractError::RegistrationFailure {});
+            }
 
-    // We check that the this currency is not used already (we got the same hash)
-    if COREUM_TOKENS
-        .idx
-        .xrpl_currency
-        .item(deps.storage, xrpl_currency.clone())?
-        .is_some()
-    {
-        return Err(ContractError::RegistrationFailure {});
-    }
+            xrpl_currency
+        }
+    };
 
     let token = CoreumToken {
         denom: denom.clone(),
@@ -412,17 +1055,30 @@ fn register_coreum_token(
         // All registered Coreum originated tokens will start as enabled because they don't need a TrustSet operation to be bridged because issuer for such tokens is bridge address
         state: TokenState::Enabled,
         bridging_fee,
+        rounding_mode: rounding_mode.clone(),
     };
     COREUM_TOKENS.save(deps.storage, denom.clone(), &token)?;
 
+    let state_event = record_token_state_transition(
+        deps.storage,
+        &denom,
+        None,
+        TokenState::Enabled,
+        TokenStateTransitionReason::Registration,
+        timestamp,
+    )?;
+
     Ok(Response::new()
+        .add_event(state_event)
         .add_attribute("action", ContractActions::RegisterCoreumToken.as_str())
         .add_attribute("sender", sender)
         .add_attribute("denom", denom)
         .add_attribute("decimals", decimals.to_string())
-        .add_attribute("xrpl_currency_for_denom", xrpl_currency))
+        .add_attribute("xrpl_currency_for_denom", xrpl_currency)
+        .add_attribute("rounding_mode", rounding_mode.as_str()))
 }
 
+#[allow(clippy::too_many_arguments)]
 #[allow(clippy::too_many_arguments)]
 fn register_xrpl_token(
     deps: DepsMut<CoreumQueries>,
@@ -433,6 +1089,11 @@ fn register_xrpl_token(
     sending_precision: i32,
     max_holding_amount: Uint128,
     bridging_fee: Uint128,
+    symbol: Option<String>,
+    description: Option<String>,
+    uri: Option<String>,
+    queue_if_no_tickets: bool,
+    rounding_mode: RoundingMode,
 ) -> CoreumResult<ContractError> {
     check_authorization(
         deps.as_ref().storage,
@@ -445,6 +1106,11 @@ fn register_xrpl_token(
 
     validate_sending_precision(sending_precision, XRPL_TOKENS_DECIMALS)?;
 
+    if let Some(symbol) = &symbol {
+        validate_token_symbol(symbol)?;
+    }
+    validate_token_metadata(&description, &uri)?;
+
     // We want to check that exactly the issue fee was sent, not more.
     check_issue_fee(&deps, &info)?;
     let key = build_xrpl_token_key(&issuer, &currency);
@@ -453,6 +1119,13 @@ fn register_xrpl_token(
         return Err(ContractError::XRPLTokenAlreadyRegistered { issuer, currency });
     }
 
+    // Each registered token needs a trust line (and its reserve) on XRPL so we limit how many can be registered
+    let xrpl_config = XRPL_CONFIG.load(deps.storage)?;
+    if count_registered_xrpl_tokens(deps.storage) >= xrpl_config.max_registered_xrpl_tokens {
+        return Err(ContractError::MaxRegisteredXRPLTokensReached {});
+    }
+    check_xrpl_reserve(deps.storage, env.block.time.seconds(), 1, 1)?;
+
     // We generate a denom creating a Sha256 hash of the issuer, currency and current time
     let to_hash = format!("{}{}{}", issuer, currency, env.block.time.seconds()).into_bytes();
 
@@ -463,19 +1136,25 @@ fn register_xrpl_token(
         .to_string()
         .to_lowercase();
 
-    // Symbol and subunit we will use for the issued token in Coreum
+    // Subunit we will use for the issued token in Coreum, which is also the symbol if none is provided
     let symbol_and_subunit = format!("{XRPL_DENOM_PREFIX}{hex_string}");
 
+    let metadata = TokenMetadata {
+        symbol: symbol.unwrap_or_else(|| symbol_and_subunit.to_uppercase()),
+        description,
+        uri,
+    };
+
     let issue_msg = CosmosMsg::from(CoreumMsg::AssetFT(Issue {
-        symbol: symbol_and_subunit.to_uppercase(),
+        symbol: metadata.symbol.clone(),
         subunit: symbol_and_subunit.clone(),
         precision: XRPL_TOKENS_DECIMALS,
         initial_amount: Uint128::zero(),
-        description: None,
-        features: Some(vec![MINTING, IBC]),
+        description: metadata.description.clone(),
+        features: Some(vec![MINTING, IBC, FREEZING]),
         burn_rate: "0.0".to_string(),
         send_commission_rate: "0.0".to_string(),
-        uri: None,
+        uri: metadata.uri.clone(),
         uri_hash: None,
     }));
 
@@ -496,60 +1175,152 @@ fn register_xrpl_token(
         // Registered tokens will start in processing until TrustSet operation is accepted/rejected
         state: TokenState::Processing,
         bridging_fee,
+        rounding_mode,
     };
 
-    XRPL_TOKENS.save(deps.storage, key, &token)?;
-
-    // Create the pending operation to approve the token
-    let config = CONFIG.load(deps.storage)?;
-    let ticket = allocate_ticket(deps.storage)?;
+    XRPL_TOKENS.save(deps.storage, key.clone(), &token)?;
+    TOKEN_METADATA.save(deps.storage, denom.clone(), &metadata)?;
 
-    // We create the TrustSet operation. If this operation is accepted, the token will be enabled, if not, it will be in Inactive state
-    // waiting for owner to recover this operation
-    create_pending_operation(
+    let state_event = record_token_state_transition(
         deps.storage,
-        env.block.time.seconds(),
-        Some(ticket),
+        &denom,
         None,
-        OperationType::TrustSet {
-            issuer: issuer.clone(),
-            currency: currency.clone(),
-            trust_set_limit_amount: config.trust_set_limit_amount,
-        },
+        TokenState::Processing,
+        TokenStateTransitionReason::Registration,
+        env.block.time.seconds(),
     )?;
 
-    Ok(Response::new()
+    let mut response = Response::new()
         .add_message(issue_msg)
-        .add_attribute("action", ContractActions::RegisterXRPLToken.as_str())
-        .add_attribute("sender", info.sender)
-        .add_attribute("issuer", issuer)
-        .add_attribute("currency", currency)
-        .add_attribute("denom", denom))
-}
+        .add_event(state_event);
 
-fn save_evidence(
-    deps: DepsMut,
+    // Create the pending operation to approve the token
+    match allocate_ticket(deps.storage) {
+        Ok(ticket) => {
+            // We create the TrustSet operation. If this operation is accepted, the token will be enabled, if not, it will be in Inactive state
+            // waiting for owner to recover this operation
+            create_pending_operation(
+                deps.storage,
+                &env.block,
+                Some(ticket),
+                None,
+                OperationType::TrustSet {
+                    issuer: issuer.clone(),
+                    currency: currency.clone(),
+                    trust_set_limit_amount: xrpl_config.trust_set_limit_amount,
+                },
+            )?;
+        }
+        // The token stays in Processing state until tickets are allocated and its TrustSet operation is created
+        Err(ContractError::NoAvailableTickets {} | ContractError::LastTicketReserved {})
+            if queue_if_no_tickets =>
+        {
+            QUEUED_XRPL_TOKEN_REGISTRATIONS.update(deps.storage, |mut queued| -> StdResult<_> {
+                queued.push_back(key);
+                Ok(queued)
+            })?;
+
+            response = response.add_event(
+                Event::new("xrpl_token_registration_queued")
+                    .add_attribute("issuer", issuer.clone())
+                    .add_attribute("currency", currency.clone())
+                    .add_attribute("denom", denom.clone()),
+            );
+        }
+        Err(e) => return Err(e),
+    }
+
+    Ok(response
+        .add_attribute("action", ContractActions::RegisterXRPLToken.as_str())
+        .add_attribute("sender", info.sender)
+        .add_attribute("issuer", issuer)
+        .add_attribute("currency", currency)
+        .add_attribute("denom", denom))
+}
+
+fn save_evidence(
+    mut deps: DepsMut,
     env: Env,
     sender: Addr,
     evidence: Evidence,
+    signature: Option<String>,
+    close_time: Option<u64>,
 ) -> CoreumResult<ContractError> {
+    // Evidences saved by a delegate are counted for the relayer that registered it
+    let relayer = evidence_relayer(deps.storage, &sender)?;
     check_authorization(
         deps.as_ref().storage,
-        &sender,
+        &relayer,
         &ContractActions::SaveEvidence,
     )?;
     // Evidences can only be sent under 2 conditions:
     // 1. The bridge is active -> All evidences are accepted
     // 2. The bridge is halted -> Only ticket allocation and rotate keys evidences (if there is a rotate keys ongoing) are allowed
-    let config = CONFIG.load(deps.storage)?;
+    let relayers_config = RELAYERS_CONFIG.load(deps.storage)?;
+    let bridge_state = BRIDGE_STATE_CONFIG.load(deps.storage)?.bridge_state;
+    let xrpl_config = XRPL_CONFIG.load(deps.storage)?;
 
     evidence.validate_basic()?;
+    validate_evidence_close_time(close_time, env.block.time.seconds())?;
+
+    verify_evidence_signature(
+        deps.api,
+        deps.storage,
+        &env.contract.address,
+        &relayer,
+        &evidence,
+        signature,
+    )?;
+
+    // If the light client verification mode is enabled, transfers must match a proven transaction before being counted
+    verify_evidence_against_proof(deps.storage, &xrpl_config, &evidence)?;
+
+    // Relayers marked as inactive are active again as soon as they save an evidence
+    let relayer_reactivated =
+        record_relayer_activity(deps.storage, &relayer, env.block.time.seconds())?;
 
-    let threshold_reached = handle_evidence(deps.storage, sender.clone(), &evidence)?;
+    let evidence_relayers = handle_evidence(
+        deps.storage,
+        &relayers_config,
+        env.block.time.seconds(),
+        relayer.clone(),
+        &evidence,
+    )?;
+    let threshold_reached = evidence_relayers.is_some();
+    let evidence_relayers = evidence_relayers.unwrap_or_default();
+
+    if threshold_reached {
+        reimburse_relayer(deps.storage, &relayer)?;
+
+        // The relayer whose evidence reaches the threshold pays the gas of delivering the transfers
+        let inbound_deliveries = match &evidence {
+            Evidence::XRPLToCoreumTransfer { .. } => 1,
+            Evidence::XRPLToCoreumMultiTransfer { transfers, .. } => transfers.len() as u64,
+            _ => 0,
+        };
+        if inbound_deliveries > 0 {
+            record_relayer_workload(deps.storage, &relayer, 0, inbound_deliveries)?;
+        }
+    }
+
+    let mut evidence_saved_event = Event::new("evidence_saved")
+        .add_attribute("relayer", relayer.clone())
+        .add_attribute("evidence_hash", evidence.get_hash());
+    // Invalid transaction results don't have a transaction hash
+    if let Some(tx_hash) = evidence.get_optional_tx_hash() {
+        evidence_saved_event = evidence_saved_event.add_attribute("tx_hash", tx_hash);
+    }
 
     let mut response = Response::new()
         .add_attribute("action", ContractActions::SaveEvidence.as_str())
-        .add_attribute("sender", sender);
+        .add_attribute("sender", sender)
+        .add_attribute("relayer", relayer)
+        .add_attribute("relayer_reactivated", relayer_reactivated.to_string())
+        .add_event(
+            evidence_saved_event
+                .add_attribute("evidence", evidence.to_json())
+                .add_attribute("threshold_reached", threshold_reached.to_string()),
+        );
 
     match evidence {
         Evidence::XRPLToCoreumTransfer {
@@ -559,120 +1330,99 @@ fn save_evidence(
             amount,
             recipient,
         } => {
-            if config.bridge_state == BridgeState::Halted {
+            if bridge_state == BridgeState::Halted {
                 return Err(ContractError::BridgeHalted {});
             }
-            deps.api.addr_validate(recipient.as_ref())?;
-
-            // If the recipient of the operation is the bridge contract address, we error
-            if recipient.eq(&env.contract.address) {
-                return Err(ContractError::ProhibitedAddress {});
-            }
-
-            // This means the token is not a Coreum originated token (the issuer is not the XRPL multisig address)
-            if issuer.ne(&config.bridge_xrpl_address) {
-                // Create issuer+currency key to find denom on coreum.
-                let key = build_xrpl_token_key(&issuer, &currency);
-
-                // To transfer a token it must be registered and activated
-                let token = XRPL_TOKENS
-                    .load(deps.storage, key)
-                    .map_err(|_| ContractError::TokenNotRegistered {})?;
-
-                if token.state.ne(&TokenState::Enabled) {
-                    return Err(ContractError::TokenNotEnabled {});
-                }
-
-                let decimals = if is_token_xrp(&token.issuer, &token.currency) {
-                    XRP_DECIMALS
-                } else {
-                    XRPL_TOKENS_DECIMALS
-                };
-
-                // We calculate the amount to send after applying the bridging fees for that token
-                let amount_after_bridge_fees =
-                    amount_after_bridge_fees(amount, token.bridging_fee)?;
-
-                // Here we simply truncate because the Coreum tokens corresponding to XRPL originated tokens have the same decimals as their corresponding Coreum tokens
-                let (amount_to_send, remainder) =
-                    truncate_amount(token.sending_precision, decimals, amount_after_bridge_fees)?;
-
-                // The amount the bridge can mint cannot exceed the max_holding_amount
-                if amount
-                    .checked_add(
-                        deps.querier
-                            .query_supply(token.coreum_denom.clone())?
-                            .amount,
-                    )?
-                    .gt(&token.max_holding_amount)
-                {
-                    return Err(ContractError::MaximumBridgedAmountReached {});
-                }
 
-                // If enough evidences are provided (threshold reached), we collect fees and mint the token for the recipient
-                if threshold_reached {
-                    let fee_collected = handle_fee_collection(
-                        deps.storage,
-                        token.bridging_fee,
-                        token.coreum_denom.clone(),
-                        remainder,
-                    )?;
+            response = process_xrpl_to_coreum_transfer(
+                &mut deps,
+                &env,
+                &xrpl_config,
+                tx_hash.clone(),
+                &issuer,
+                &currency,
+                amount,
+                &recipient,
+                Uint128::zero(),
+                threshold_reached,
+                evidence_relayers,
+                response,
+            )?;
 
-                    let mint_msg_fees = CosmosMsg::from(CoreumMsg::AssetFT(assetft::Msg::Mint {
-                        coin: coin(fee_collected.u128(), token.coreum_denom.clone()),
-                        recipient: None,
-                    }));
+            // Once the transfer is processed we don't need to keep its proof anymore
+            if threshold_reached && xrpl_config.light_client.is_some() {
+                PROVEN_XRPL_TRANSACTIONS.remove(deps.storage, tx_hash.to_uppercase());
+            }
 
-                    let mint_msg_for_recipient =
-                        CosmosMsg::from(CoreumMsg::AssetFT(assetft::Msg::Mint {
-                            coin: coin(amount_to_send.u128(), token.coreum_denom),
-                            recipient: Some(recipient.to_string()),
-                        }));
+            response = response
+                .add_attribute("hash", tx_hash)
+                .add_attribute("issuer", issuer)
+                .add_attribute("currency", currency)
+                .add_attribute("amount", amount.to_string())
+                .add_attribute("recipient", recipient.to_string())
+                .add_attribute("threshold_reached", threshold_reached.to_string());
+        }
+        Evidence::XRPLToCoreumMultiTransfer {
+            tx_hash,
+            issuer,
+            currency,
+            transfers,
+        } => {
+            if bridge_state == BridgeState::Halted {
+                return Err(ContractError::BridgeHalted {});
+            }
 
-                    response = response.add_messages([mint_msg_fees, mint_msg_for_recipient]);
-                }
-            } else {
-                // We check that the token is registered and enabled
-                let token = match COREUM_TOKENS
-                    .idx
-                    .xrpl_currency
-                    .item(deps.storage, currency.clone())?
-                    .map(|(_, ct)| ct)
-                {
-                    Some(token) => {
-                        if token.state.ne(&TokenState::Enabled) {
-                            return Err(ContractError::TokenNotEnabled {});
-                        }
-                        token
-                    }
-                    // In practice this will never happen because any token issued from the multisig address is a token that was bridged from Coreum so it will be registered.
-                    // This could theoretically happen if relayers agree and sign a transaction outside of bridge flow
-                    None => return Err(ContractError::TokenNotRegistered {}),
-                };
-
-                // We first convert the amount we receive with XRPL decimals to the corresponding decimals in Coreum and then we apply the truncation according to sending precision
-                let (amount_to_send, remainder) = convert_and_truncate_amount(
-                    token.sending_precision,
-                    XRPL_TOKENS_DECIMALS,
-                    token.decimals,
-                    amount,
-                    token.bridging_fee,
+            // Each transfer gets its own id so that receipts and holdbacks of the same transaction don't collide
+            let mut pending_mint = Uint128::zero();
+            for (index, transfer) in transfers.iter().enumerate() {
+                response = process_xrpl_to_coreum_transfer(
+                    &mut deps,
+                    &env,
+                    &xrpl_config,
+                    format!("{tx_hash}-{index}"),
+                    &issuer,
+                    &currency,
+                    transfer.amount,
+                    &transfer.recipient,
+                    pending_mint,
+                    threshold_reached,
+                    evidence_relayers.clone(),
+                    response,
                 )?;
+                pending_mint = pending_mint.checked_add(transfer.amount)?;
+            }
 
-                // If enough evidences are provided (threshold reached), we collect fees and send tokens from the bridge contract (it was holding them in escrow)
-                if threshold_reached {
-                    handle_fee_collection(
-                        deps.storage,
-                        token.bridging_fee,
-                        token.denom.clone(),
-                        remainder,
-                    )?;
+            response = response
+                .add_attribute("hash", tx_hash)
+                .add_attribute("issuer", issuer)
+                .add_attribute("currency", currency)
+                .add_attribute("transfers", transfers.len().to_string())
+                .add_attribute("threshold_reached", threshold_reached.to_string());
+        }
+        Evidence::XRPLToCoreumInvalidDeposit {
+            tx_hash,
+            issuer,
+            currency,
+            amount,
+            sender,
+        } => {
+            // The deposit is only recorded, it's delivered or returned once it's resolved
+            if threshold_reached {
+                INVALID_DEPOSITS.save(
+                    deps.storage,
+                    tx_hash.to_uppercase(),
+                    &InvalidDeposit {
+                        tx_hash: tx_hash.to_uppercase(),
+                        issuer: issuer.clone(),
+                        currency: currency.clone(),
+                        amount,
+                        sender: sender.clone(),
+                        recorded_at: env.block.time.seconds(),
+                    },
+                )?;
 
-                    let send_msg = BankMsg::Send {
-                        to_address: recipient.to_string(),
-                        amount: coins(amount_to_send.u128(), token.denom),
-                    };
-                    response = response.add_message(send_msg);
+                if xrpl_config.light_client.is_some() {
+                    PROVEN_XRPL_TRANSACTIONS.remove(deps.storage, tx_hash.to_uppercase());
                 }
             }
 
@@ -680,8 +1430,8 @@ fn save_evidence(
                 .add_attribute("hash", tx_hash)
                 .add_attribute("issuer", issuer)
                 .add_attribute("currency", currency)
-                .add_attribute("amount", amount.to_string())
-                .add_attribute("recipient", recipient.to_string())
+                .add_attribute("amount", amount)
+                .add_attribute("xrpl_sender", sender)
                 .add_attribute("threshold_reached", threshold_reached.to_string());
         }
         Evidence::XRPLTransactionResult {
@@ -706,6 +1456,19 @@ fn save_evidence(
                 _ => (),
             }
 
+            // The transaction reported must be the one built for the operation with the signatures collected for it
+            // Signatures are checked against the relayer set the operation was signed by, which might have been rotated since
+            if let Some(tx_hash) = &tx_hash {
+                let signers_config =
+                    operation_signers_config(deps.storage, &relayers_config, operation_id)?;
+                verify_operation_transaction_hash(
+                    &signers_config,
+                    &xrpl_config.bridge_xrpl_address,
+                    &operation,
+                    tx_hash,
+                )?;
+            }
+
             // If enough evidences are provided (threshold reached), we run the specific handler for each operation
             if threshold_reached {
                 // We run the handler for the operation, routing to the correct handler for each operation type
@@ -717,9 +1480,25 @@ fn save_evidence(
                     &tx_hash,
                     operation_id,
                     ticket_sequence,
+                    &env.block,
+                    &evidence_relayers,
                     &mut response,
                 )?;
 
+                // Transfers received while the TrustSet was pending are delivered as soon as the token is enabled
+                if matches!(operation.operation_type, OperationType::TrustSet { .. })
+                    && transaction_result.eq(&TransactionResult::Accepted)
+                {
+                    (response, _) = apply_deferred_inbound_transfers(
+                        &mut deps,
+                        &env,
+                        &bridge_state,
+                        &xrpl_config,
+                        MAX_PAGE_LIMIT as usize,
+                        response,
+                    )?;
+                }
+
                 // If the operation was not Invalid, we must register a used ticket
                 if transaction_result.ne(&TransactionResult::Invalid) && ticket_sequence.is_some() {
                     // If the operation must trigger a new ticket allocation we must know if we can trigger it
@@ -727,7 +1506,7 @@ fn save_evidence(
                     // we don't have available tickets left and we will notify with an attribute.
                     // NOTE: This will only happen in the particular case of a rejected ticket allocation
                     // operation.
-                    if !register_used_ticket(deps.storage, env.block.time.seconds())? {
+                    if !register_used_ticket(deps.storage, &env.block)? {
                         response = response.add_attribute(
                             "adding_ticket_allocation_operation_success",
                             false.to_string(),
@@ -746,14 +1525,495 @@ fn save_evidence(
                 response = response.add_attribute("tx_hash", tx_hash);
             }
         }
+        Evidence::XRPLIssuerConfigChange {
+            tx_hash,
+            issuer,
+            global_freeze,
+            transfer_rate,
+            clawback_enabled,
+        } => {
+            // Changes are only tracked for issuers of registered tokens
+            if xrpl_tokens_of_issuer(deps.storage, &issuer)?.is_empty() {
+                return Err(ContractError::TokenNotRegistered {});
+            }
+
+            if threshold_reached {
+                handle_issuer_config_change(
+                    deps.storage,
+                    issuer.clone(),
+                    XRPLIssuerConfig {
+                        global_freeze,
+                        transfer_rate,
+                        clawback_enabled,
+                        tx_hash: tx_hash.clone(),
+                        updated_at: env.block.time.seconds(),
+                    },
+                    env.block.time.seconds(),
+                    &mut response,
+                )?;
+            }
+
+            response = response
+                .add_attribute("hash", tx_hash)
+                .add_attribute("issuer", issuer)
+                .add_attribute("global_freeze", global_freeze.to_string())
+                .add_attribute("transfer_rate", transfer_rate.to_string())
+                .add_attribute("clawback_enabled", clawback_enabled.to_string())
+                .add_attribute("threshold_reached", threshold_reached.to_string());
+        }
+        Evidence::XRPLRecipientFlagsChange {
+            tx_hash,
+            account,
+            deposit_auth,
+            disallow_xrp,
+        } => {
+            if threshold_reached {
+                // We only keep recipients that have something that makes transfers to them fail
+                if deposit_auth || disallow_xrp {
+                    XRPL_RECIPIENT_FLAGS.save(
+                        deps.storage,
+                        account.clone(),
+                        &XRPLRecipientFlags {
+                            deposit_auth,
+                            disallow_xrp,
+                            tx_hash: tx_hash.clone(),
+                            updated_at: env.block.time.seconds(),
+                        },
+                    )?;
+                } else {
+                    XRPL_RECIPIENT_FLAGS.remove(deps.storage, account.clone());
+                }
+            }
+
+            response = response
+                .add_attribute("hash", tx_hash)
+                .add_attribute("account", account)
+                .add_attribute("deposit_auth", deposit_auth.to_string())
+                .add_attribute("disallow_xrp", disallow_xrp.to_string())
+                .add_attribute("threshold_reached", threshold_reached.to_string());
+        }
+        Evidence::XRPLTokenSupplyDrift {
+            tx_hash,
+            issuer,
+            currency,
+            amount,
+            decreased,
+        } => {
+            let key = build_xrpl_token_key(&issuer, &currency);
+            if !XRPL_TOKENS.has(deps.storage, key) {
+                return Err(ContractError::TokenNotRegistered {});
+            }
+
+            // The drift is only recorded, the owner decides when the bridged amount is reconciled with it
+            if threshold_reached {
+                XRPL_SUPPLY_DRIFTS.save(
+                    deps.storage,
+                    tx_hash.to_uppercase(),
+                    &XRPLSupplyDrift {
+                        tx_hash: tx_hash.to_uppercase(),
+                        issuer: issuer.clone(),
+                        currency: currency.clone(),
+                        amount,
+                        decreased,
+                        reported_at: env.block.time.seconds(),
+                    },
+                )?;
+            }
+
+            response = response
+                .add_attribute("hash", tx_hash)
+                .add_attribute("issuer", issuer)
+                .add_attribute("currency", currency)
+                .add_attribute("amount", amount)
+                .add_attribute("decreased", decreased.to_string())
+                .add_attribute("threshold_reached", threshold_reached.to_string());
+        }
+    }
+
+    Ok(response)
+}
+
+// Delivers an amount bridged from XRPL to its Coreum recipient once the evidence threshold is reached
+// The transfer id identifies the receipt and, if the transfer is held back, the pending refund of the transfer
+#[allow(clippy::too_many_arguments)]
+fn process_xrpl_to_coreum_transfer(
+    deps: &mut DepsMut,
+    env: &Env,
+    xrpl_config: &XRPLConfig,
+    transfer_id: String,
+    issuer: &str,
+    currency: &str,
+    amount: Uint128,
+    recipient: &Addr,
+    // Amount of the same token that is already being minted by the same evidence
+    pending_mint: Uint128,
+    threshold_reached: bool,
+    evidence_relayers: Vec<Addr>,
+    mut response: Response<CoreumMsg>,
+) -> Result<Response<CoreumMsg>, ContractError> {
+    deps.api.addr_validate(recipient.as_ref())?;
+
+    // If the recipient of the operation is the bridge contract address, we error
+    if recipient.eq(&env.contract.address) {
+        return Err(ContractError::ProhibitedAddress {});
+    }
+
+    // This means the token is not a Coreum originated token (the issuer is not the XRPL multisig address)
+    if issuer.ne(xrpl_config.bridge_xrpl_address.as_str()) {
+        // Create issuer+currency key to find denom on coreum.
+        let key = build_xrpl_token_key(issuer, currency);
+
+        // To transfer a token it must be registered and activated
+        let mut token = XRPL_TOKENS
+            .load(deps.storage, key.clone())
+            .map_err(|_| ContractError::TokenNotRegistered {})?;
+
+        match token.state {
+            TokenState::Enabled => (),
+            // The TrustSet of the token wasn't confirmed yet, so the transfer is delivered once the token is enabled
+            TokenState::Processing | TokenState::Inactive => {
+                if threshold_reached {
+                    DEFERRED_INBOUND_TRANSFERS.save(
+                        deps.storage,
+                        (key, transfer_id.clone()),
+                        &DeferredInboundTransfer {
+                            issuer: issuer.to_owned(),
+                            currency: currency.to_owned(),
+                            amount,
+                            recipient: recipient.clone(),
+                            evidence_relayers,
+                            deferred_at: env.block.time.seconds(),
+                        },
+                    )?;
+                    response = response.add_attribute("deferred_id", transfer_id);
+                }
+                return Ok(response);
+            }
+            TokenState::Disabled => return Err(ContractError::TokenNotEnabled {}),
+        }
+        assert_token_not_frozen(deps.storage, &token.coreum_denom, env.block.time.seconds())?;
+
+        let decimals = if is_token_xrp(&token.issuer, &token.currency) {
+            XRP_DECIMALS
+        } else {
+            XRPL_TOKENS_DECIMALS
+        };
+        token.bridging_fee = effective_bridging_fee(
+            deps.storage,
+            &token.coreum_denom,
+            decimals,
+            token.bridging_fee,
+            env.block.time.seconds(),
+        )?;
+
+        let (amount_to_send, remainder, _) = xrpl_to_coreum_amounts(
+            true,
+            token.sending_precision,
+            decimals,
+            token.bridging_fee,
+            &token.rounding_mode,
+            amount,
+        )?;
+
+        // The amount the bridge can mint cannot exceed the max_holding_amount
+        if amount
+            .checked_add(pending_mint)?
+            .checked_add(
+                deps.querier
+                    .query_supply(token.coreum_denom.clone())?
+                    .amount,
+            )?
+            .gt(&token.max_holding_amount)
+        {
+            return Err(ContractError::MaximumBridgedAmountReached {});
+        }
+
+        // If enough evidences are provided (threshold reached), we collect fees and mint the token for the recipient
+        if threshold_reached {
+            let (fee_collected, fee_event) = handle_fee_collection(
+                deps.storage,
+                token.bridging_fee,
+                token.coreum_denom.clone(),
+                remainder,
+            )?;
+            response = response.add_events(fee_event);
+
+            mint_amount(
+                deps.storage,
+                &token.coreum_denom,
+                amount_to_send.checked_add(fee_collected)?,
+            )?;
+
+            let mint_msg_fees = CosmosMsg::from(CoreumMsg::AssetFT(assetft::Msg::Mint {
+                coin: coin(fee_collected.u128(), token.coreum_denom.clone()),
+                recipient: None,
+            }));
+
+            create_xrpl_to_coreum_transfer_receipt(
+                deps.storage,
+                env,
+                transfer_id.clone(),
+                recipient,
+                coin(amount_to_send.u128(), token.coreum_denom.clone()),
+                fee_collected,
+                evidence_relayers,
+            )?;
+            record_transfer_activity(
+                deps.storage,
+                env.block.time.seconds(),
+                TransferDirection::XRPLToCoreum,
+                &coin(
+                    amount_to_send.checked_add(fee_collected)?.u128(),
+                    token.coreum_denom.clone(),
+                ),
+                fee_collected,
+            )?;
+
+            let denial_reason = check_transfer_compliance(
+                deps.storage,
+                &deps.querier,
+                TransferDirection::XRPLToCoreum,
+                None,
+                recipient.to_string(),
+                coin(amount_to_send.u128(), token.coreum_denom.clone()),
+            )?;
+
+            // If the compliance contract denies the transfer, the tokens are minted to the bridge and held back for the recipient
+            let mint_recipient = match denial_reason {
+                Some(reason) => {
+                    hold_back_transfer(
+                        deps.storage,
+                        recipient,
+                        transfer_id.clone(),
+                        coin(amount_to_send.u128(), token.coreum_denom.clone()),
+                        env.block.time.seconds(),
+                    )?;
+                    response = response
+                        .add_attribute("holdback_id", transfer_id.clone())
+                        .add_attribute("compliance_denial", reason);
+                    None
+                }
+                None => {
+                    record_inbound_transfer(
+                        deps.storage,
+                        recipient,
+                        transfer_id.clone(),
+                        coin(amount_to_send.u128(), token.coreum_denom.clone()),
+                        env.block.time.seconds(),
+                    )?;
+                    Some(recipient.to_string())
+                }
+            };
+
+            let mint_msg_for_recipient = CosmosMsg::from(CoreumMsg::AssetFT(assetft::Msg::Mint {
+                coin: coin(amount_to_send.u128(), token.coreum_denom),
+                recipient: mint_recipient,
+            }));
+
+            response = response.add_messages([mint_msg_fees, mint_msg_for_recipient]);
+        }
+    } else {
+        // We check that the token is registered and enabled
+        let mut token = match COREUM_TOKENS
+            .idx
+            .xrpl_currency
+            .item(deps.storage, currency.to_owned())?
+            .map(|(_, ct)| ct)
+        {
+            Some(token) => {
+                if token.state.ne(&TokenState::Enabled) {
+                    return Err(ContractError::TokenNotEnabled {});
+                }
+                token
+            }
+            // In practice this will never happen because any token issued from the multisig address is a token that was bridged from Coreum so it will be registered.
+            // This could theoretically happen if relayers agree and sign a transaction outside of bridge flow
+            None => return Err(ContractError::TokenNotRegistered {}),
+        };
+        assert_token_not_frozen(deps.storage, &token.denom, env.block.time.seconds())?;
+        token.bridging_fee = effective_bridging_fee(
+            deps.storage,
+            &token.denom,
+            token.decimals,
+            token.bridging_fee,
+            env.block.time.seconds(),
+        )?;
+
+        let (amount_to_send, remainder, _) = xrpl_to_coreum_amounts(
+            false,
+            token.sending_precision,
+            token.decimals,
+            token.bridging_fee,
+            &token.rounding_mode,
+            amount,
+        )?;
+
+        // If enough evidences are provided (threshold reached), we collect fees and send tokens from the bridge contract (it was holding them in escrow)
+        if threshold_reached {
+            let (fee_collected, fee_event) = handle_fee_collection(
+                deps.storage,
+                token.bridging_fee,
+                token.denom.clone(),
+                remainder,
+            )?;
+            response = response.add_events(fee_event);
+
+            // The whole amount that was locked is released, part goes to the recipient and the rest is kept as fees
+            unlock_amount(
+                deps.storage,
+                &token.denom,
+                amount_to_send.checked_add(fee_collected)?,
+            )?;
+
+            create_xrpl_to_coreum_transfer_receipt(
+                deps.storage,
+                env,
+                transfer_id.clone(),
+                recipient,
+                coin(amount_to_send.u128(), token.denom.clone()),
+                fee_collected,
+                evidence_relayers,
+            )?;
+            record_transfer_activity(
+                deps.storage,
+                env.block.time.seconds(),
+                TransferDirection::XRPLToCoreum,
+                &coin(
+                    amount_to_send.checked_add(fee_collected)?.u128(),
+                    token.denom.clone(),
+                ),
+                fee_collected,
+            )?;
+
+            match check_transfer_compliance(
+                deps.storage,
+                &deps.querier,
+                TransferDirection::XRPLToCoreum,
+                None,
+                recipient.to_string(),
+                coin(amount_to_send.u128(), token.denom.clone()),
+            )? {
+                // If the compliance contract denies the transfer, the tokens stay in the bridge and are held back for the recipient
+                Some(reason) => {
+                    hold_back_transfer(
+                        deps.storage,
+                        recipient,
+                        transfer_id.clone(),
+                        coin(amount_to_send.u128(), token.denom),
+                        env.block.time.seconds(),
+                    )?;
+                    response = response
+                        .add_attribute("holdback_id", transfer_id.clone())
+                        .add_attribute("compliance_denial", reason);
+                }
+                None => {
+                    // The token might be frozen for the contract or the recipient, in which case the delivery will fail
+                    // We keep the delivery information to convert it into a holdback that can be claimed later
+                    let mut pending_deliveries =
+                        PENDING_DELIVERY.may_load(deps.storage)?.unwrap_or_default();
+                    pending_deliveries.push(PendingDelivery {
+                        recipient: recipient.clone(),
+                        xrpl_tx_hash: transfer_id.clone(),
+                        coin: coin(amount_to_send.u128(), token.denom.clone()),
+                    });
+                    PENDING_DELIVERY.save(deps.storage, &pending_deliveries)?;
+
+                    let send_msg = BankMsg::Send {
+                        to_address: recipient.to_string(),
+                        amount: coins(amount_to_send.u128(), token.denom),
+                    };
+                    response =
+                        response.add_submessage(SubMsg::reply_always(send_msg, DELIVERY_REPLY_ID));
+                }
+            }
+        }
     }
 
     Ok(response)
 }
 
+// Delivers the deferred transfers from XRPL of the tokens that were enabled since the transfers were deferred
+// Transfers that can't be delivered yet (e.g. their token is frozen) stay deferred until the next attempt
+fn apply_deferred_inbound_transfers(
+    deps: &mut DepsMut,
+    env: &Env,
+    bridge_state: &BridgeState,
+    xrpl_config: &XRPLConfig,
+    limit: usize,
+    mut response: Response<CoreumMsg>,
+) -> Result<(Response<CoreumMsg>, usize), ContractError> {
+    if bridge_state.eq(&BridgeState::Halted) {
+        return Ok((response, 0));
+    }
+
+    let storage = deps.as_ref().storage;
+    let deferred_transfers: Vec<((String, String), DeferredInboundTransfer)> =
+        DEFERRED_INBOUND_TRANSFERS
+            .range(storage, None, None, Order::Ascending)
+            .filter(|item| match item {
+                Ok(((key, _), _)) => XRPL_TOKENS
+                    .may_load(storage, key.to_owned())
+                    .ok()
+                    .flatten()
+                    .is_some_and(|token| token.state == TokenState::Enabled),
+                Err(_) => true,
+            })
+            .take(limit)
+            .collect::<StdResult<_>>()?;
+
+    // Minted amounts are only reflected in the supply once the messages are executed, so we track them per token
+    let mut pending_mints: BTreeMap<String, Uint128> = BTreeMap::new();
+    let mut applied = 0;
+    for ((key, transfer_id), deferred_transfer) in deferred_transfers {
+        let pending_mint = pending_mints.get(&key).copied().unwrap_or_default();
+
+        // We check the transfer without delivering it first, so that a transfer that can't be delivered doesn't fail the execution
+        if process_xrpl_to_coreum_transfer(
+            deps,
+            env,
+            xrpl_config,
+            transfer_id.clone(),
+            &deferred_transfer.issuer,
+            &deferred_transfer.currency,
+            deferred_transfer.amount,
+            &deferred_transfer.recipient,
+            pending_mint,
+            false,
+            vec![],
+            Response::new(),
+        )
+        .is_err()
+        {
+            continue;
+        }
+
+        DEFERRED_INBOUND_TRANSFERS.remove(deps.storage, (key.clone(), transfer_id.clone()));
+        response = process_xrpl_to_coreum_transfer(
+            deps,
+            env,
+            xrpl_config,
+            transfer_id.clone(),
+            &deferred_transfer.issuer,
+            &deferred_transfer.currency,
+            deferred_transfer.amount,
+            &deferred_transfer.recipient,
+            pending_mint,
+            true,
+            deferred_transfer.evidence_relayers,
+            response,
+        )?
+        .add_attribute("applied_deferred_id", transfer_id);
+
+        pending_mints.insert(key, pending_mint.checked_add(deferred_transfer.amount)?);
+        applied += 1;
+    }
+
+    Ok((response, applied))
+}
+
 fn recover_tickets(
     deps: DepsMut,
-    timestamp: u64,
+    block: &BlockInfo,
     sender: Addr,
     account_sequence: u64,
     number_of_tickets: Option<u32>,
@@ -782,20 +2042,22 @@ fn recover_tickets(
 
     // If we don't provide a number of tickets to recover we will recover the ones that we already used.
     let number_to_allocate = number_of_tickets.unwrap_or(used_tickets);
+    let timestamp = block.time.seconds();
 
-    let config = CONFIG.load(deps.storage)?;
+    let xrpl_config = XRPL_CONFIG.load(deps.storage)?;
     // We check that number_to_allocate > config.used_ticket_sequence_threshold in order to cover the
     // reallocation with just one XRPL transaction, otherwise the relocation might cause the
     // additional reallocation.
-    if number_to_allocate <= config.used_ticket_sequence_threshold
+    if number_to_allocate <= xrpl_config.used_ticket_sequence_threshold
         || number_to_allocate > MAX_TICKETS
     {
         return Err(ContractError::InvalidTicketSequenceToAllocate {});
     }
+    check_xrpl_reserve(deps.storage, timestamp, number_to_allocate, 1)?;
 
     create_pending_operation(
         deps.storage,
-        timestamp,
+        block,
         None,
         Some(account_sequence),
         OperationType::AllocateTickets {
@@ -811,7 +2073,7 @@ fn recover_tickets(
 
 fn recover_xrpl_token_registration(
     deps: DepsMut,
-    timestamp: u64,
+    block: &BlockInfo,
     sender: Addr,
     issuer: String,
     currency: String,
@@ -834,27 +2096,41 @@ fn recover_xrpl_token_registration(
         return Err(ContractError::XRPLTokenNotInactive {});
     }
 
+    // The trust line of an inactive token was never created, so it will add to the reserve of the account
+    let timestamp = block.time.seconds();
+    check_xrpl_reserve(deps.storage, timestamp, 1, 1)?;
+
     // Put the state back to Processing since we are going to try to activate it again
     token.state = TokenState::Processing;
     XRPL_TOKENS.save(deps.storage, key, &token)?;
 
+    let state_event = record_token_state_transition(
+        deps.storage,
+        &token.coreum_denom,
+        Some(TokenState::Inactive),
+        TokenState::Processing,
+        TokenStateTransitionReason::RegistrationRecovery,
+        timestamp,
+    )?;
+
     // Create the pending operation to approve the token again
-    let config = CONFIG.load(deps.storage)?;
+    let xrpl_config = XRPL_CONFIG.load(deps.storage)?;
     let ticket = allocate_ticket(deps.storage)?;
 
     create_pending_operation(
         deps.storage,
-        timestamp,
+        block,
         Some(ticket),
         None,
         OperationType::TrustSet {
             issuer: issuer.clone(),
             currency: currency.clone(),
-            trust_set_limit_amount: config.trust_set_limit_amount,
+            trust_set_limit_amount: xrpl_config.trust_set_limit_amount,
         },
     )?;
 
     Ok(Response::new()
+        .add_event(state_event)
         .add_attribute(
             "action",
             ContractActions::RecoverXRPLTokenRegistration.as_str(),
@@ -866,59 +2142,265 @@ fn recover_xrpl_token_registration(
 
 fn save_signature(
     deps: DepsMut,
+    env: Env,
     sender: Addr,
     operation_id: u64,
     operation_version: u64,
     signature: &str,
 ) -> CoreumResult<ContractError> {
+    // Signatures saved by a delegate are saved for the relayer that registered it
+    let relayer = evidence_relayer(deps.storage, &sender)?;
     check_authorization(
         deps.as_ref().storage,
-        &sender,
+        &relayer,
         &ContractActions::SaveSignature,
     )?;
 
-    add_signature(
+    let relayer_reactivated =
+        record_relayer_activity(deps.storage, &relayer, env.block.time.seconds())?;
+
+    record_relayer_workload(deps.storage, &relayer, 1, 0)?;
+
+    let signing_progress = add_signature(
         deps,
         operation_id,
         operation_version,
-        sender.clone(),
+        relayer.clone(),
         signature.to_string(),
     )?;
 
     Ok(Response::new()
         .add_attribute("action", ContractActions::SaveSignature.as_str())
         .add_attribute("sender", sender)
+        .add_attribute("relayer", relayer)
+        .add_attribute("operation_id", operation_id.to_string())
+        .add_attribute("signature", signature)
+        .add_attribute("relayer_reactivated", relayer_reactivated.to_string())
+        .add_event(
+            Event::new("operation_signing_progress")
+                .add_attribute("operation_id", operation_id.to_string())
+                .add_attribute("operation_version", operation_version.to_string())
+                .add_attribute("signature_count", signing_progress.signatures.to_string())
+                .add_attribute("quorum", signing_progress.quorum.to_string()),
+        ))
+}
+
+fn save_transaction_submission(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    operation_id: u64,
+    operation_version: u64,
+    tx_hash: String,
+    tx_blob: Option<String>,
+) -> CoreumResult<ContractError> {
+    let relayer = evidence_relayer(deps.storage, &sender)?;
+    check_authorization(
+        deps.as_ref().storage,
+        &relayer,
+        &ContractActions::SaveTransactionSubmission,
+    )?;
+
+    let operation = PENDING_OPERATIONS
+        .load(deps.storage, operation_id)
+        .map_err(|_| ContractError::PendingOperationNotFound {})?;
+    if operation_version != operation.version {
+        return Err(ContractError::OperationVersionMismatch {});
+    }
+
+    // The transaction can only be submitted once the operation has the signatures the signer list needs
+    let relayers_config = RELAYERS_CONFIG.load(deps.storage)?;
+    let signers_config = operation_signers_config(deps.storage, &relayers_config, operation_id)?;
+    if operation.signatures.len() < signers_config.evidence_threshold as usize {
+        return Err(ContractError::OperationQuorumNotReached {});
+    }
+
+    if hex::decode(&tx_hash).map_or(true, |hash| hash.len() != 32) {
+        return Err(ContractError::InvalidTransactionSubmission {});
+    }
+    verify_operation_transaction_hash(
+        &signers_config,
+        &XRPL_CONFIG.load(deps.storage)?.bridge_xrpl_address,
+        &operation,
+        &tx_hash,
+    )?;
+
+    // Other relayers rebroadcast the blob as is, so it must be the transaction identified by the hash
+    if let Some(tx_blob) = &tx_blob {
+        if tx_blob.len() > MAX_TRANSACTION_BLOB_LENGTH {
+            return Err(ContractError::InvalidTransactionSubmission {});
+        }
+        let blob =
+            hex::decode(tx_blob).map_err(|_| ContractError::InvalidTransactionSubmission {})?;
+        if !hex::encode(compute_transaction_id(&blob)).eq_ignore_ascii_case(&tx_hash) {
+            return Err(ContractError::TransactionHashMismatch {});
+        }
+    }
+
+    OPERATION_SUBMISSIONS.save(
+        deps.storage,
+        operation_id,
+        &OperationSubmission {
+            relayer: relayer.clone(),
+            operation_version,
+            tx_hash: tx_hash.to_uppercase(),
+            tx_blob: tx_blob.map(|tx_blob| tx_blob.to_uppercase()),
+            submitted_at: env.block.time.seconds(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute(
+            "action",
+            ContractActions::SaveTransactionSubmission.as_str(),
+        )
+        .add_attribute("sender", sender)
+        .add_attribute("relayer", relayer)
         .add_attribute("operation_id", operation_id.to_string())
-        .add_attribute("signature", signature))
+        .add_attribute("operation_version", operation_version.to_string())
+        .add_attribute("tx_hash", tx_hash.to_uppercase()))
 }
 
+#[allow(clippy::too_many_arguments)]
 fn send_to_xrpl(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     recipient: String,
     deliver_amount: Option<Uint128>,
+    exact_amount: bool,
+    split_amount: bool,
+    priority: bool,
+    idempotency_key: Option<String>,
 ) -> CoreumResult<ContractError> {
     assert_bridge_active(deps.as_ref())?;
-    // Check that we are only sending 1 type of coin
-    let funds = one_coin(&info)?;
+
+    // A retried transfer returns the funds and the operation created the first time
+    if let Some(idempotency_key) = &idempotency_key {
+        if idempotency_key.is_empty() || idempotency_key.len() > MAX_IDEMPOTENCY_KEY_LENGTH {
+            return Err(ContractError::InvalidIdempotencyKey {});
+        }
+
+        if let Some(operation_id) = SEND_TO_XRPL_IDEMPOTENCY_KEYS
+            .may_load(deps.storage, (info.sender.clone(), idempotency_key.clone()))?
+        {
+            let mut response = Response::new()
+                .add_attribute("action", ContractActions::SendToXRPL.as_str())
+                .add_attribute("sender", info.sender.clone())
+                .add_attribute("idempotency_key", idempotency_key)
+                .add_attribute("operation_id", operation_id)
+                .add_attribute("duplicate", true.to_string());
+            if !info.funds.is_empty() {
+                response = response.add_message(BankMsg::Send {
+                    to_address: info.sender.to_string(),
+                    amount: info.funds,
+                });
+            }
+            return Ok(response);
+        }
+    }
+
+    // Check that we are only sending 1 type of coin, apart from the priority fee of prioritized transfers
+    let mut priority_fee_event = None;
+    let funds = if priority {
+        let priority_fee = FEES_CONFIG
+            .load(deps.storage)?
+            .priority_fee
+            .ok_or(ContractError::PriorityLaneDisabled {})?;
+        let funds = split_priority_fee(&info.funds, &priority_fee)?;
+        priority_fee_event = handle_priority_fee_collection(deps.storage, priority_fee)?;
+        funds
+    } else {
+        one_coin(&info)?
+    };
+
+    // Large transfers must be committed first so that their details can't be anticipated
+    if let Some(threshold) = COMMIT_REVEAL_THRESHOLDS.may_load(deps.storage, funds.denom.clone())? {
+        if funds.amount.ge(&threshold) {
+            return Err(ContractError::CommitmentRequired {});
+        }
+    }
+
+    let response = transfer_to_xrpl(
+        deps,
+        env,
+        info.sender,
+        funds,
+        recipient,
+        deliver_amount,
+        exact_amount,
+        split_amount,
+        priority,
+        idempotency_key,
+    )?;
+
+    Ok(response.add_events(priority_fee_event))
+}
+
+// Creates the operation of a transfer to XRPL of funds that are already held by the contract
+#[allow(clippy::too_many_arguments)]
+fn transfer_to_xrpl(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    funds: Coin,
+    recipient: String,
+    deliver_amount: Option<Uint128>,
+    exact_amount: bool,
+    split_amount: bool,
+    priority: bool,
+    idempotency_key: Option<String>,
+) -> CoreumResult<ContractError> {
+    assert_token_not_frozen(deps.storage, &funds.denom, env.block.time.seconds())?;
+
+    // Recipients can be X-addresses, in which case we decode them into a classic address and a destination tag
+    let (recipient, destination_tag) = decode_xrpl_recipient(deps.storage, &recipient)?;
 
     // Check that the recipient is a valid XRPL address and it's not prohibited
     validate_xrpl_address(deps.storage, recipient.clone())?;
+    validate_xrpl_recipient_rules(deps.storage, &recipient, destination_tag)?;
+
+    // Payments to recipients that only accept preauthorized senders would be rejected on XRPL after consuming a ticket
+    let recipient_flags = XRPL_RECIPIENT_FLAGS.may_load(deps.storage, recipient.clone())?;
+    if recipient_flags
+        .as_ref()
+        .is_some_and(|flags| flags.deposit_auth)
+    {
+        return Err(ContractError::RecipientDepositAuthEnabled {});
+    }
+
+    if let Some(reason) = check_transfer_compliance(
+        deps.storage,
+        &deps.querier,
+        TransferDirection::CoreumToXRPL,
+        Some(sender.to_string()),
+        recipient.clone(),
+        funds.clone(),
+    )? {
+        return Err(ContractError::TransferDenied { reason });
+    }
 
     // We check that deliver_amount is not greater than the funds sent
     if deliver_amount.is_some() && deliver_amount.unwrap().gt(&funds.amount) {
         return Err(ContractError::InvalidDeliverAmount {});
     }
 
+    // With a deliver amount the max amount of each chunk couldn't be known
+    if split_amount && deliver_amount.is_some() {
+        return Err(ContractError::SplitAmountWithDeliverAmount {});
+    }
+
     let decimals;
     let mut amount_to_send;
     let max_amount;
-    let remainder;
+    let dust;
+    let dust_as_pending_refund;
     let issuer;
     let currency;
+    let fee_charged;
+    let fee_event;
     // We check if the token we are sending is an XRPL originated token or not
-    if let Some(xrpl_token) = XRPL_TOKENS
+    if let Some(mut xrpl_token) = XRPL_TOKENS
         .idx
         .coreum_denom
         .item(deps.storage, funds.denom.clone())
@@ -936,32 +2418,54 @@ fn send_to_xrpl(
             if deliver_amount.is_some() {
                 return Err(ContractError::DeliverAmountIsProhibited {});
             }
+            // XRPL doesn't enforce this setting but the recipient asked not to be sent XRP
+            if recipient_flags.is_some_and(|flags| flags.disallow_xrp) {
+                return Err(ContractError::RecipientDisallowsXRP {});
+            }
             decimals = XRP_DECIMALS;
         } else {
             decimals = XRPL_TOKENS_DECIMALS;
         }
+        xrpl_token.bridging_fee = effective_bridging_fee(
+            deps.storage,
+            &xrpl_token.coreum_denom,
+            decimals,
+            xrpl_token.bridging_fee,
+            env.block.time.seconds(),
+        )?;
 
-        // We calculate the amount after applying the bridging fees for that token
-        let amount_after_bridge_fees =
-            amount_after_bridge_fees(funds.amount, xrpl_token.bridging_fee)?;
-
-        // We don't need any decimal conversion because the token is an XRPL originated token and they are issued with same decimals
-        (amount_to_send, remainder) = truncate_amount(
+        // Depending on the rounding mode of the token, the truncated amount is collected as fees, returned to the sender or the transfer is rejected
+        let rounding_mode = if exact_amount {
+            &RoundingMode::RejectIfImprecise
+        } else {
+            &xrpl_token.rounding_mode
+        };
+        let fee_remainder;
+        (amount_to_send, fee_remainder, dust) = coreum_to_xrpl_amounts(
+            true,
             xrpl_token.sending_precision,
             decimals,
-            amount_after_bridge_fees,
+            xrpl_token.bridging_fee,
+            rounding_mode,
+            funds.amount,
         )?;
+        dust_as_pending_refund = rounding_mode.eq(&RoundingMode::DustToPendingRefund);
 
         // If deliver_amount was sent, we must check that it's less or equal than amount_to_send after bridge fees (without truncating) are applied
         if deliver_amount.is_some() {
+            let amount_after_bridge_fees =
+                amount_after_bridge_fees(funds.amount, xrpl_token.bridging_fee)?;
             if deliver_amount.unwrap().gt(&amount_after_bridge_fees) {
                 return Err(ContractError::InvalidDeliverAmount {});
             }
-            let (truncated_amount, _) = truncate_amount(
+            let (truncated_amount, deliver_remainder) = truncate_amount(
                 xrpl_token.sending_precision,
                 decimals,
                 deliver_amount.unwrap(),
             )?;
+            if exact_amount && !deliver_remainder.is_zero() {
+                return Err(ContractError::ImpreciseAmount {});
+            }
 
             max_amount = Some(amount_to_send);
             amount_to_send = truncated_amount;
@@ -974,15 +2478,22 @@ fn send_to_xrpl(
             }
         }
 
-        handle_fee_collection(
+        // The amount sent is held until the operation is confirmed and then burned or refunded
+        lock_amount(
+            deps.storage,
+            &xrpl_token.coreum_denom,
+            max_amount.unwrap_or(amount_to_send),
+        )?;
+
+        (fee_charged, fee_event) = handle_fee_collection(
             deps.storage,
             xrpl_token.bridging_fee,
             xrpl_token.coreum_denom,
-            remainder,
+            fee_remainder,
         )?;
     } else {
         // If it's not an XRPL originated token we need to check that it's registered as a Coreum originated token and that it's enabled
-        let coreum_token = COREUM_TOKENS
+        let mut coreum_token = COREUM_TOKENS
             .load(deps.storage, funds.denom.clone())
             .map_err(|_| ContractError::TokenNotRegistered {})?;
         if coreum_token.state.ne(&TokenState::Enabled) {
@@ -994,36 +2505,58 @@ fn send_to_xrpl(
             return Err(ContractError::DeliverAmountIsProhibited {});
         }
 
-        let config = CONFIG.load(deps.storage)?;
+        let xrpl_config = XRPL_CONFIG.load(deps.storage)?;
 
         decimals = coreum_token.decimals;
-        issuer = config.bridge_xrpl_address;
+        issuer = xrpl_config.bridge_xrpl_address;
+        coreum_token.bridging_fee = effective_bridging_fee(
+            deps.storage,
+            &coreum_token.denom,
+            decimals,
+            coreum_token.bridging_fee,
+            env.block.time.seconds(),
+        )?;
         currency = coreum_token.xrpl_currency;
 
-        // Since this is a Coreum originated token with different decimals, we are first going to truncate according to sending precision and then we will convert
-        // to corresponding XRPL decimals
-        let remainder;
-        (amount_to_send, remainder) = truncate_and_convert_amount(
+        // Exact amount transfers are rejected if anything would be truncated, whatever the rounding mode of the token is
+        let rounding_mode = if exact_amount {
+            &RoundingMode::RejectIfImprecise
+        } else {
+            &coreum_token.rounding_mode
+        };
+        let fee_remainder;
+        (amount_to_send, fee_remainder, dust) = coreum_to_xrpl_amounts(
+            false,
             coreum_token.sending_precision,
             decimals,
-            XRPL_TOKENS_DECIMALS,
-            funds.amount,
             coreum_token.bridging_fee,
+            rounding_mode,
+            funds.amount,
         )?;
+        dust_as_pending_refund = rounding_mode.eq(&RoundingMode::DustToPendingRefund);
 
-        handle_fee_collection(
+        let fee_collected;
+        (fee_collected, fee_event) = handle_fee_collection(
             deps.storage,
             coreum_token.bridging_fee,
             coreum_token.denom.clone(),
-            remainder,
+            fee_remainder,
+        )?;
+
+        // Everything that is not collected as fees or returned to the sender stays locked in the contract while the tokens are on XRPL
+        lock_amount(
+            deps.storage,
+            &coreum_token.denom,
+            funds.amount.checked_sub(fee_collected)?.checked_sub(dust)?,
         )?;
 
         // For Coreum originated tokens we need to check that we are not going over the amount
         // that the bridge will hold in escrow
         if deps
             .querier
-            .query_balance(env.contract.address, coreum_token.denom)?
+            .query_balance(env.contract.address.as_str(), coreum_token.denom)?
             .amount
+            .checked_sub(dust)?
             .gt(&coreum_token.max_holding_amount)
         {
             return Err(ContractError::MaximumBridgedAmountReached {});
@@ -1031,629 +2564,5268 @@ fn send_to_xrpl(
 
         // Coreum originated tokens never have transfer rate so the max amount will be the same as amount to send
         max_amount = Some(amount_to_send);
+        fee_charged = fee_collected;
     }
 
-    // We validate that both amount and max_amount on the operation contain valid XRPL amounts
-    validate_xrpl_amount(amount_to_send)?;
-    if max_amount.is_some() {
-        validate_xrpl_amount(max_amount.unwrap())?;
+    // We validate that both amount and max_amount on the operation contain valid XRPL amounts, unless the amount can be split into valid ones
+    let amounts = if split_amount {
+        split_xrpl_amount(amount_to_send)
+    } else {
+        validate_xrpl_amount(amount_to_send)?;
+        if max_amount.is_some() {
+            validate_xrpl_amount(max_amount.unwrap())?;
+        }
+        vec![amount_to_send]
+    };
+
+    // Get a ticket and store the pending operation of each chunk. Without a deliver amount, max_amount is always the same as the amount
+    let mut operation_ids = vec![];
+    let mut tickets = vec![];
+    for amount in amounts {
+        let ticket = allocate_ticket(deps.storage)?;
+        operation_ids.push(create_pending_operation(
+            deps.storage,
+            &env.block,
+            Some(ticket),
+            None,
+            OperationType::CoreumToXRPLTransfer {
+                issuer: issuer.clone(),
+                currency: currency.clone(),
+                amount,
+                max_amount: if split_amount {
+                    max_amount.map(|_| amount)
+                } else {
+                    max_amount
+                },
+                sender: sender.clone(),
+                recipient: recipient.clone(),
+                destination_tag,
+            },
+        )?);
+        tickets.push(ticket);
+    }
+    let operation_id = operation_ids[0].clone();
+    let ticket = tickets[0];
+    if operation_ids.len() > 1 {
+        TRANSFER_CHUNKS.save(deps.storage, operation_id.clone(), &operation_ids)?;
+    }
+    if priority {
+        for ticket in tickets.iter() {
+            PRIORITY_OPERATIONS.save(deps.storage, *ticket, &Empty {})?;
+        }
+    }
+    if let Some(idempotency_key) = idempotency_key {
+        SEND_TO_XRPL_IDEMPOTENCY_KEYS.save(
+            deps.storage,
+            (sender.clone(), idempotency_key),
+            &operation_id,
+        )?;
     }
 
-    // Get a ticket and store the pending operation
-    let ticket = allocate_ticket(deps.storage)?;
-    create_pending_operation(
+    // The dust stays in the contract until the sender claims it. Its id is derived from the operation id so that it's unique
+    if dust_as_pending_refund && !dust.is_zero() {
+        store_pending_refund(
+            deps.storage,
+            format!("{operation_id}-dust"),
+            None,
+            sender.clone(),
+            coin(dust.u128(), funds.denom.clone()),
+        )?;
+    }
+
+    create_coreum_to_xrpl_transfer_receipt(
+        deps.storage,
+        &env,
+        operation_id.clone(),
+        ticket,
+        sender.clone(),
+        recipient.clone(),
+        funds.clone(),
+        fee_charged,
+    )?;
+    record_transfer_activity(
         deps.storage,
         env.block.time.seconds(),
-        Some(ticket),
-        None,
-        OperationType::CoreumToXRPLTransfer {
-            issuer,
-            currency,
-            amount: amount_to_send,
-            max_amount,
-            sender: info.sender.clone(),
-            recipient: recipient.clone(),
-        },
+        TransferDirection::CoreumToXRPL,
+        &funds,
+        fee_charged,
     )?;
 
-    Ok(Response::new()
+    let mut response = Response::new()
         .add_attribute("action", ContractActions::SendToXRPL.as_str())
-        .add_attribute("sender", info.sender)
+        .add_attribute("sender", sender.clone())
         .add_attribute("recipient", recipient)
-        .add_attribute("coin", funds.to_string()))
+        .add_attribute("coin", funds.to_string())
+        .add_attribute("priority", priority.to_string())
+        .add_attribute("operation_id", operation_id)
+        .add_events(fee_event);
+
+    if operation_ids.len() > 1 {
+        response = response
+            .add_attribute("parent_transfer_id", operation_ids[0].clone())
+            .add_attribute("chunk_operation_ids", operation_ids.join(","));
+    }
+
+    if !dust.is_zero() {
+        if !dust_as_pending_refund {
+            let send_msg = BankMsg::Send {
+                to_address: sender.to_string(),
+                amount: coins(dust.u128(), funds.denom),
+            };
+            response = response.add_message(send_msg);
+        }
+        response = response.add_attribute("refunded_dust", dust.to_string());
+    }
+
+    if let Some(destination_tag) = destination_tag {
+        response = response.add_attribute("destination_tag", destination_tag.to_string());
+    }
+
+    Ok(response)
 }
 
-#[allow(clippy::too_many_arguments)]
-fn update_xrpl_token(
+fn commit_send_to_xrpl(
     deps: DepsMut,
-    sender: Addr,
-    issuer: String,
-    currency: String,
-    state: Option<TokenState>,
-    sending_precision: Option<i32>,
-    bridging_fee: Option<Uint128>,
-    max_holding_amount: Option<Uint128>,
+    env: Env,
+    info: MessageInfo,
+    commitment: String,
 ) -> CoreumResult<ContractError> {
-    check_authorization(
-        deps.as_ref().storage,
-        &sender,
-        &ContractActions::UpdateXRPLToken,
-    )?;
     assert_bridge_active(deps.as_ref())?;
+    let funds = one_coin(&info)?;
 
-    let key = build_xrpl_token_key(&issuer, &currency);
-
-    let mut token = XRPL_TOKENS
-        .load(deps.storage, key.clone())
-        .map_err(|_| ContractError::TokenNotRegistered {})?;
-
-    set_token_state(&mut token.state, state)?;
-
-    let decimals = if is_token_xrp(&issuer, &currency) {
-        XRP_DECIMALS
-    } else {
-        XRPL_TOKENS_DECIMALS
-    };
-    set_token_sending_precision(&mut token.sending_precision, sending_precision, decimals)?;
+    if !is_token_registered(deps.storage, &funds.denom)? {
+        return Err(ContractError::TokenNotRegistered {});
+    }
 
-    set_token_bridging_fee(&mut token.bridging_fee, bridging_fee)?;
+    // Commitments are stored lowercase so that they are found regardless of how they are encoded when revealed
+    let commitment = commitment.to_lowercase();
+    if !matches!(hex::decode(&commitment), Ok(bytes) if bytes.len() == 32) {
+        return Err(ContractError::InvalidTransferCommitment {});
+    }
 
-    // Get the current bridged amount for this token to verify that we are not setting a max_holding_amount that is less than the current amount
-    let current_bridged_amount = deps
-        .querier
-        .query_supply(token.coreum_denom.clone())?
-        .amount;
+    let key = (info.sender.clone(), commitment.clone());
+    if TRANSFER_COMMITMENTS.has(deps.storage, key.clone()) {
+        return Err(ContractError::TransferCommitmentAlreadyExists {});
+    }
 
-    set_token_max_holding_amount(
-        current_bridged_amount,
-        &mut token.max_holding_amount,
-        max_holding_amount,
+    // The funds are owed to the sender until the transfer is revealed or the commitment is cancelled
+    reserve_refund(deps.storage, &funds.denom, funds.amount)?;
+    TRANSFER_COMMITMENTS.save(
+        deps.storage,
+        key,
+        &TransferCommitment {
+            coin: funds.clone(),
+            committed_at_height: env.block.height,
+        },
     )?;
 
-    XRPL_TOKENS.save(deps.storage, key, &token)?;
-
     Ok(Response::new()
-        .add_attribute("action", ContractActions::UpdateXRPLToken.as_str())
-        .add_attribute("sender", sender)
-        .add_attribute("issuer", issuer)
-        .add_attribute("currency", currency))
+        .add_attribute("action", ContractActions::CommitSendToXRPL.as_str())
+        .add_attribute("sender", info.sender)
+        .add_attribute("commitment", commitment)
+        .add_attribute("coin", funds.to_string()))
 }
 
 #[allow(clippy::too_many_arguments)]
-fn update_coreum_token(
+fn reveal_send_to_xrpl(
     deps: DepsMut,
     env: Env,
     sender: Addr,
-    denom: String,
-    state: Option<TokenState>,
-    sending_precision: Option<i32>,
-    bridging_fee: Option<Uint128>,
-    max_holding_amount: Option<Uint128>,
+    commitment: String,
+    recipient: String,
+    deliver_amount: Option<Uint128>,
+    exact_amount: bool,
+    salt: String,
 ) -> CoreumResult<ContractError> {
-    check_authorization(
-        deps.as_ref().storage,
-        &sender,
-        &ContractActions::UpdateCoreumToken,
-    )?;
     assert_bridge_active(deps.as_ref())?;
 
-    let mut token = COREUM_TOKENS
-        .load(deps.storage, denom.clone())
-        .map_err(|_| ContractError::TokenNotRegistered {})?;
+    let commitment = commitment.to_lowercase();
+    let key = (sender.clone(), commitment.clone());
+    let transfer_commitment = TRANSFER_COMMITMENTS
+        .may_load(deps.storage, key.clone())?
+        .ok_or(ContractError::TransferCommitmentNotFound {})?;
+
+    let operations_config = OPERATIONS_CONFIG.load(deps.storage)?;
+    if env.block.height
+        < transfer_commitment
+            .committed_at_height
+            .saturating_add(operations_config.commit_reveal_delay)
+    {
+        return Err(ContractError::CommitmentNotRevealable {});
+    }
+    if env.block.height
+        >= transfer_commitment
+            .committed_at_height
+            .saturating_add(operations_config.commit_reveal_expiration)
+    {
+        return Err(ContractError::CommitmentExpired {});
+    }
 
-    set_token_state(&mut token.state, state)?;
-    set_token_sending_precision(
-        &mut token.sending_precision,
-        sending_precision,
-        token.decimals,
-    )?;
-    set_token_bridging_fee(&mut token.bridging_fee, bridging_fee)?;
+    let preimage = TransferCommitmentPreimage {
+        sender: sender.clone(),
+        recipient: recipient.clone(),
+        deliver_amount,
+        exact_amount,
+        salt,
+    };
+    if hash_bytes(to_json_vec(&preimage)?).ne(&commitment) {
+        return Err(ContractError::TransferCommitmentMismatch {});
+    }
 
-    // Get the current bridged amount for this token to verify that we are not setting a max_holding_amount that is less than the current amount
-    let current_bridged_amount = deps
-        .querier
-        .query_balance(env.contract.address, token.denom.clone())?
-        .amount;
-    set_token_max_holding_amount(
-        current_bridged_amount,
-        &mut token.max_holding_amount,
-        max_holding_amount,
+    TRANSFER_COMMITMENTS.remove(deps.storage, key);
+    release_refund(
+        deps.storage,
+        &transfer_commitment.coin.denom,
+        transfer_commitment.coin.amount,
     )?;
 
-    COREUM_TOKENS.save(deps.storage, denom.clone(), &token)?;
+    // Once revealed, the transfer is processed like any other transfer to XRPL
+    let response = transfer_to_xrpl(
+        deps,
+        env,
+        sender,
+        transfer_commitment.coin,
+        recipient,
+        deliver_amount,
+        exact_amount,
+        false,
+        false,
+        None,
+    )?;
 
-    Ok(Response::new()
-        .add_attribute("action", ContractActions::UpdateCoreumToken.as_str())
-        .add_attribute("sender", sender)
-        .add_attribute("denom", denom))
+    Ok(response.add_attribute("commitment", commitment))
 }
 
-fn update_xrpl_base_fee(
+fn cancel_send_to_xrpl_commitment(
     deps: DepsMut,
+    env: Env,
     sender: Addr,
-    xrpl_base_fee: u64,
+    commitment_sender: String,
+    commitment: String,
 ) -> CoreumResult<ContractError> {
-    check_authorization(
-        deps.as_ref().storage,
-        &sender,
-        &ContractActions::UpdateXRPLBaseFee,
+    assert_bridge_not_halted(deps.as_ref())?;
+    let commitment_sender = deps.api.addr_validate(&commitment_sender)?;
+
+    let commitment = commitment.to_lowercase();
+    let key = (commitment_sender.clone(), commitment.clone());
+    let transfer_commitment = TRANSFER_COMMITMENTS
+        .may_load(deps.storage, key.clone())?
+        .ok_or(ContractError::TransferCommitmentNotFound {})?;
+
+    // Anyone can cancel abandoned commitments so that their funds can be claimed by their sender
+    let operations_config = OPERATIONS_CONFIG.load(deps.storage)?;
+    if sender.ne(&commitment_sender)
+        && env.block.height
+            < transfer_commitment
+                .committed_at_height
+                .saturating_add(operations_config.commit_reveal_expiration)
+    {
+        return Err(ContractError::CommitmentNotExpired {});
+    }
+
+    let pending_refund_id = refund_transfer_commitment(
+        deps.storage,
+        &commitment_sender,
+        &commitment,
+        transfer_commitment,
     )?;
 
-    // Update the value in config
-    let mut config = CONFIG.load(deps.storage)?;
-    config.xrpl_base_fee = xrpl_base_fee;
-    CONFIG.save(deps.storage, &config)?;
+    Ok(Response::new()
+        .add_attribute(
+            "action",
+            ContractActions::CancelSendToXRPLCommitment.as_str(),
+        )
+        .add_attribute("sender", sender)
+        .add_attribute("commitment_sender", commitment_sender)
+        .add_attribute("commitment", commitment)
+        .add_attribute("pending_refund_id", pending_refund_id))
+}
 
-    // Let's collect all operations in storage and update them
-    let operations: Vec<(u64, Operation)> = PENDING_OPERATIONS
-        .range(deps.storage, None, None, Order::Ascending)
-        .filter_map(Result::ok)
-        .collect();
+// Removes a transfer commitment and converts its funds into a pending refund of its sender
+fn refund_transfer_commitment(
+    storage: &mut dyn Storage,
+    commitment_sender: &Addr,
+    commitment: &str,
+    transfer_commitment: TransferCommitment,
+) -> Result<String, ContractError> {
+    TRANSFER_COMMITMENTS.remove(storage, (commitment_sender.clone(), commitment.to_owned()));
+    release_refund(
+        storage,
+        &transfer_commitment.coin.denom,
+        transfer_commitment.coin.amount,
+    )?;
 
-    // For each operation in PENDING_OPERATIONS we increase the version by 1 and delete all signatures
-    for operation in &operations {
-        PENDING_OPERATIONS.save(
-            deps.storage,
-            operation.0,
-            &Operation {
-                id: operation.1.id.clone(),
-                version: operation.1.version + 1,
-                ticket_sequence: operation.1.ticket_sequence,
-                account_sequence: operation.1.account_sequence,
-                signatures: vec![],
-                operation_type: operation.1.operation_type.clone(),
-                xrpl_base_fee,
-            },
-        )?;
-    }
+    // The same commitment can't be committed twice by a sender in the same block, so this id is unique
+    let pending_refund_id = format!("{}-{}", commitment, transfer_commitment.committed_at_height);
+    store_pending_refund(
+        storage,
+        pending_refund_id.clone(),
+        None,
+        commitment_sender.clone(),
+        transfer_commitment.coin,
+    )?;
 
-    Ok(Response::new()
-        .add_attribute("action", ContractActions::UpdateXRPLBaseFee.as_str())
-        .add_attribute("sender", sender)
-        .add_attribute("new_xrpl_base_fee", xrpl_base_fee.to_string()))
+    Ok(pending_refund_id)
 }
 
-fn claim_relayer_fees(
+fn create_recurring_transfer(
     deps: DepsMut,
-    sender: Addr,
-    amounts: Vec<Coin>,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+    amount: Uint128,
+    interval: u64,
 ) -> CoreumResult<ContractError> {
     assert_bridge_active(deps.as_ref())?;
+    let funds = one_coin(&info)?;
 
-    // If fees were never collected for this address we don't allow the claim
-    if FEES_COLLECTED
-        .may_load(deps.storage, sender.clone())?
-        .is_none()
-    {
-        return Err(ContractError::UnauthorizedSender {});
-    };
+    if amount.is_zero() || interval == 0 {
+        return Err(ContractError::InvalidRecurringTransfer {});
+    }
 
-    substract_relayer_fees(deps.storage, &sender, &amounts)?;
+    if !is_token_registered(deps.storage, &funds.denom)? {
+        return Err(ContractError::TokenNotRegistered {});
+    }
 
-    let send_msg = BankMsg::Send {
-        to_address: sender.to_string(),
-        amount: amounts,
-    };
+    // Recurring transfers are predictable by design, so they can't be used for amounts that must be committed
+    if let Some(threshold) = COMMIT_REVEAL_THRESHOLDS.may_load(deps.storage, funds.denom.clone())? {
+        if amount.ge(&threshold) {
+            return Err(ContractError::CommitmentRequired {});
+        }
+    }
+
+    let (decoded_recipient, _) = decode_xrpl_recipient(deps.storage, &recipient)?;
+    validate_xrpl_address(deps.storage, decoded_recipient)?;
+
+    let id = RECURRING_TRANSFERS_COUNTER
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    RECURRING_TRANSFERS_COUNTER.save(deps.storage, &(id + 1))?;
+
+    // The escrowed balance is owed to the sender until it's sent to XRPL or the recurring transfer is cancelled
+    reserve_refund(deps.storage, &funds.denom, funds.amount)?;
+    RECURRING_TRANSFERS.save(
+        deps.storage,
+        id,
+        &RecurringTransfer {
+            id,
+            sender: info.sender.clone(),
+            recipient: recipient.clone(),
+            amount,
+            denom: funds.denom.clone(),
+            interval,
+            next_execution_at: env.block.time.seconds(),
+            balance: funds.amount,
+        },
+    )?;
 
     Ok(Response::new()
-        .add_attribute("action", ContractActions::ClaimFees.as_str())
-        .add_attribute("sender", sender)
-        .add_message(send_msg))
+        .add_attribute("action", ContractActions::CreateRecurringTransfer.as_str())
+        .add_attribute("sender", info.sender)
+        .add_attribute("recurring_transfer_id", id.to_string())
+        .add_attribute("recipient", recipient)
+        .add_attribute("amount", coin(amount.u128(), funds.denom).to_string())
+        .add_attribute("interval", interval.to_string()))
 }
 
-fn claim_pending_refund(
-    deps: DepsMut,
-    sender: Addr,
-    pending_refund_id: String,
-) -> CoreumResult<ContractError> {
-    assert_bridge_active(deps.as_ref())?;
-    let coin = remove_pending_refund(deps.storage, &sender, pending_refund_id)?;
+fn load_recurring_transfer_of_sender(
+    storage: &dyn Storage,
+    sender: &Addr,
+    id: u64,
+) -> Result<RecurringTransfer, ContractError> {
+    let recurring_transfer = RECURRING_TRANSFERS
+        .may_load(storage, id)?
+        .ok_or(ContractError::RecurringTransferNotFound {})?;
 
-    let send_msg = BankMsg::Send {
-        to_address: sender.to_string(),
-        amount: vec![coin],
-    };
+    if recurring_transfer.sender.ne(sender) {
+        return Err(ContractError::UnauthorizedSender {});
+    }
 
-    Ok(Response::new()
-        .add_attribute("action", ContractActions::ClaimRefunds.as_str())
-        .add_attribute("sender", sender)
-        .add_message(send_msg))
+    Ok(recurring_transfer)
 }
 
-fn halt_bridge(deps: DepsMut, sender: Addr) -> CoreumResult<ContractError> {
-    check_authorization(deps.as_ref().storage, &sender, &ContractActions::HaltBridge)?;
-    // No point halting a bridge that is already halted
-    assert_bridge_active(deps.as_ref())?;
-    update_bridge_state(deps.storage, BridgeState::Halted)?;
+fn fund_recurring_transfer(
+    deps: DepsMut,
+    info: MessageInfo,
+    id: u64,
+) -> CoreumResult<ContractError> {
+    let mut recurring_transfer = load_recurring_transfer_of_sender(deps.storage, &info.sender, id)?;
+    let amount = must_pay(&info, &recurring_transfer.denom)?;
+
+    reserve_refund(deps.storage, &recurring_transfer.denom, amount)?;
+    recurring_transfer.balance = recurring_transfer.balance.checked_add(amount)?;
+    RECURRING_TRANSFERS.save(deps.storage, id, &recurring_transfer)?;
 
     Ok(Response::new()
-        .add_attribute("action", ContractActions::HaltBridge.as_str())
-        .add_attribute("sender", sender))
+        .add_attribute("action", ContractActions::FundRecurringTransfer.as_str())
+        .add_attribute("sender", info.sender)
+        .add_attribute("recurring_transfer_id", id.to_string())
+        .add_attribute("balance", recurring_transfer.balance.to_string()))
 }
 
-fn resume_bridge(deps: DepsMut, sender: Addr) -> CoreumResult<ContractError> {
-    check_authorization(
-        deps.as_ref().storage,
-        &sender,
-        &ContractActions::ResumeBridge,
+fn cancel_recurring_transfer(deps: DepsMut, sender: Addr, id: u64) -> CoreumResult<ContractError> {
+    let recurring_transfer = load_recurring_transfer_of_sender(deps.storage, &sender, id)?;
+
+    RECURRING_TRANSFERS.remove(deps.storage, id);
+    release_refund(
+        deps.storage,
+        &recurring_transfer.denom,
+        recurring_transfer.balance,
     )?;
 
-    // Can't resume the bridge if there is a pending rotate keys ongoing
-    if PENDING_ROTATE_KEYS.load(deps.storage)? {
-        return Err(ContractError::RotateKeysOngoing {});
+    let mut response = Response::new()
+        .add_attribute("action", ContractActions::CancelRecurringTransfer.as_str())
+        .add_attribute("sender", sender.clone())
+        .add_attribute("recurring_transfer_id", id.to_string());
+
+    if !recurring_transfer.balance.is_zero() {
+        let send_msg = BankMsg::Send {
+            to_address: sender.to_string(),
+            amount: coins(recurring_transfer.balance.u128(), recurring_transfer.denom),
+        };
+        response = response.add_message(send_msg);
     }
 
-    update_bridge_state(deps.storage, BridgeState::Active)?;
-
-    Ok(Response::new()
-        .add_attribute("action", ContractActions::ResumeBridge.as_str())
-        .add_attribute("sender", sender))
+    Ok(response)
 }
 
-fn rotate_keys(
+fn execute_recurring_transfer(
     deps: DepsMut,
     env: Env,
     sender: Addr,
-    new_relayers: Vec<Relayer>,
-    new_evidence_threshold: u32,
+    id: u64,
 ) -> CoreumResult<ContractError> {
-    check_authorization(deps.as_ref().storage, &sender, &ContractActions::RotateKeys)?;
-
-    // If there is already a pending rotate keys ongoing, we don't allow another one until that one is confirmed
-    if PENDING_ROTATE_KEYS.load(deps.storage)? {
-        return Err(ContractError::RotateKeysOngoing {});
-    }
-    // We set the pending rotate keys flag to true so that we don't allow another rotate keys operation until this one is confirmed
-    PENDING_ROTATE_KEYS.save(deps.storage, &true)?;
+    assert_bridge_active(deps.as_ref())?;
 
-    // We halt the bridge
-    update_bridge_state(deps.storage, BridgeState::Halted)?;
+    let mut recurring_transfer = RECURRING_TRANSFERS
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::RecurringTransferNotFound {})?;
 
-    // Validate the new relayer set so that we are sure that the new set is valid (e.g. no duplicated relayers, etc.)
-    validate_relayers(deps.as_ref(), &new_relayers, new_evidence_threshold)?;
+    if env.block.time.seconds() < recurring_transfer.next_execution_at {
+        return Err(ContractError::RecurringTransferNotDue {});
+    }
 
-    let ticket = allocate_ticket(deps.storage)?;
+    if recurring_transfer.balance.lt(&recurring_transfer.amount) {
+        return Err(ContractError::InsufficientRecurringTransferBalance {});
+    }
 
-    create_pending_operation(
+    // Executions that were missed can be caught up by executing the recurring transfer again
+    recurring_transfer.next_execution_at = recurring_transfer
+        .next_execution_at
+        .saturating_add(recurring_transfer.interval);
+    recurring_transfer.balance = recurring_transfer
+        .balance
+        .checked_sub(recurring_transfer.amount)?;
+    RECURRING_TRANSFERS.save(deps.storage, id, &recurring_transfer)?;
+    release_refund(
         deps.storage,
-        env.block.time.seconds(),
-        Some(ticket),
+        &recurring_transfer.denom,
+        recurring_transfer.amount,
+    )?;
+
+    // The transfer is processed like any other transfer to XRPL sent by the sender of the recurring transfer
+    let response = transfer_to_xrpl(
+        deps,
+        env,
+        recurring_transfer.sender,
+        coin(recurring_transfer.amount.u128(), recurring_transfer.denom),
+        recurring_transfer.recipient,
+        None,
+        false,
+        false,
+        false,
         None,
-        OperationType::RotateKeys {
-            new_relayers,
-            new_evidence_threshold,
-        },
     )?;
 
-    Ok(Response::new()
-        .add_attribute("action", ContractActions::RotateKeys.as_str())
-        .add_attribute("sender", sender))
+    Ok(response
+        .add_attribute("executed_by", sender)
+        .add_attribute("recurring_transfer_id", id.to_string())
+        .add_attribute(
+            "next_execution_at",
+            recurring_transfer.next_execution_at.to_string(),
+        ))
 }
 
-fn update_prohibited_xrpl_addresses(
+#[allow(clippy::too_many_arguments)]
+fn update_xrpl_token(
     deps: DepsMut,
+    timestamp: u64,
     sender: Addr,
-    prohibited_xrpl_addresses: Vec<String>,
+    issuer: String,
+    currency: String,
+    state: Option<TokenState>,
+    sending_precision: Option<i32>,
+    bridging_fee: Option<Uint128>,
+    max_holding_amount: Option<Uint128>,
+    rounding_mode: Option<RoundingMode>,
 ) -> CoreumResult<ContractError> {
     check_authorization(
         deps.as_ref().storage,
         &sender,
-        &ContractActions::UpdateProhibitedXRPLAddresses,
+        &ContractActions::UpdateXRPLToken,
     )?;
+    assert_bridge_not_halted(deps.as_ref())?;
 
-    // We clear the previous prohibited addresses
-    PROHIBITED_XRPL_ADDRESSES.clear(deps.storage);
+    let key = build_xrpl_token_key(&issuer, &currency);
 
-    // We add the current multisig address which is always prohibited
-    let config = CONFIG.load(deps.storage)?;
-    PROHIBITED_XRPL_ADDRESSES.save(deps.storage, config.bridge_xrpl_address, &Empty {})?;
+    let mut token = XRPL_TOKENS
+        .load(deps.storage, key.clone())
+        .map_err(|_| ContractError::TokenNotRegistered {})?;
+
+    let previous_state = token.state.clone();
+    set_token_state(&mut token.state, state)?;
+
+    let decimals = if is_token_xrp(&issuer, &currency) {
+        XRP_DECIMALS
+    } else {
+        XRPL_TOKENS_DECIMALS
+    };
+    set_token_sending_precision(&mut token.sending_precision, sending_precision, decimals)?;
+
+    set_token_bridging_fee(&mut token.bridging_fee, bridging_fee)?;
+
+    set_token_rounding_mode(&mut token.rounding_mode, rounding_mode);
+
+    // Get the current bridged amount for this token to verify that we are not setting a max_holding_amount that is less than the current amount
+    let current_bridged_amount = deps
+        .querier
+        .query_supply(token.coreum_denom.clone())?
+        .amount;
+
+    set_token_max_holding_amount(
+        current_bridged_amount,
+        &mut token.max_holding_amount,
+        max_holding_amount,
+    )?;
+
+    XRPL_TOKENS.save(deps.storage, key, &token)?;
 
-    // Add all prohibited addresses provided
-    for prohibited_xrpl_address in prohibited_xrpl_addresses {
-        // Validate the address that we are adding, to not add useless things
-        validate_xrpl_address_format(&prohibited_xrpl_address)?;
-        PROHIBITED_XRPL_ADDRESSES.save(deps.storage, prohibited_xrpl_address, &Empty {})?;
+    let mut response = Response::new();
+    if token.state.ne(&previous_state) {
+        let state_event = record_token_state_transition(
+            deps.storage,
+            &token.coreum_denom,
+            Some(previous_state),
+            token.state,
+            TokenStateTransitionReason::OwnerUpdate,
+            timestamp,
+        )?;
+        response = response.add_event(state_event);
     }
 
-    Ok(Response::new()
-        .add_attribute(
-            "action",
-            ContractActions::UpdateProhibitedXRPLAddresses.as_str(),
-        )
-        .add_attribute("sender", sender))
+    Ok(response
+        .add_attribute("action", ContractActions::UpdateXRPLToken.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("issuer", issuer)
+        .add_attribute("currency", currency))
 }
 
-fn cancel_pending_operation(
+fn update_xrpl_token_metadata(
     deps: DepsMut,
     sender: Addr,
-    operation_id: u64,
+    issuer: String,
+    currency: String,
+    description: Option<String>,
+    uri: Option<String>,
 ) -> CoreumResult<ContractError> {
     check_authorization(
         deps.as_ref().storage,
         &sender,
-        &ContractActions::CancelPendingOperation,
+        &ContractActions::UpdateXRPLTokenMetadata,
     )?;
 
-    let operation = check_operation_exists(deps.storage, operation_id)?;
-    // We'll provide a TransactionResult::Invalid evidence to the handlers so that they perform the right action
-    let transaction_result = &TransactionResult::Invalid;
-    let operation_result = match operation.operation_type {
-        OperationType::AllocateTickets { .. } => Some(TicketsAllocation { tickets: None }),
-        _ => None,
-    };
-    let mut response = Response::new();
+    let token = XRPL_TOKENS
+        .load(deps.storage, build_xrpl_token_key(&issuer, &currency))
+        .map_err(|_| ContractError::TokenNotRegistered {})?;
 
-    // We handle the operation with an invalid result
-    handle_operation(
-        deps.storage,
-        &operation,
-        &operation_result,
-        transaction_result,
-        &None,
-        operation_id,
-        operation.ticket_sequence,
-        &mut response,
-    )?;
+    validate_token_metadata(&description, &uri)?;
 
-    Ok(response
-        .add_attribute("action", ContractActions::CancelPendingOperation.as_str())
-        .add_attribute("sender", sender))
+    // Coreum doesn't allow changing the metadata of an FT once it's issued, so the contract keeps the up to date
+    // description and URI of the token, which are returned in the token metadata query.
+    // The symbol can't be updated because it is the one wallets see on chain.
+    let mut metadata = TOKEN_METADATA.load(deps.storage, token.coreum_denom.clone())?;
+    if description.is_some() {
+        metadata.description = description;
+    }
+    if uri.is_some() {
+        metadata.uri = uri;
+    }
+
+    TOKEN_METADATA.save(deps.storage, token.coreum_denom.clone(), &metadata)?;
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::UpdateXRPLTokenMetadata.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("issuer", issuer)
+        .add_attribute("currency", currency)
+        .add_attribute("denom", token.coreum_denom))
 }
 
-// ********** Queries **********
-#[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
-    match msg {
-        QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
-        QueryMsg::XRPLTokens {
-            start_after_key,
-            limit,
-        } => to_json_binary(&query_xrpl_tokens(deps, start_after_key, limit)),
-        QueryMsg::CoreumTokens {
-            start_after_key,
-            limit,
-        } => to_json_binary(&query_coreum_tokens(deps, start_after_key, limit)),
-        QueryMsg::Ownership {} => to_json_binary(&get_ownership(deps.storage)?),
-        QueryMsg::PendingOperations {
-            start_after_key,
-            limit,
-        } => to_json_binary(&query_pending_operations(deps, start_after_key, limit)),
-        QueryMsg::AvailableTickets {} => to_json_binary(&query_available_tickets(deps)?),
-        QueryMsg::PendingRefunds {
-            address,
-            start_after_key,
-            limit,
-        } => to_json_binary(&query_pending_refunds(
-            deps,
-            address,
-            start_after_key,
-            limit,
-        )),
-        QueryMsg::FeesCollected { relayer_address } => {
-            to_json_binary(&query_fees_collected(deps, relayer_address)?)
+#[allow(clippy::too_many_arguments)]
+fn update_coreum_token(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    denom: String,
+    state: Option<TokenState>,
+    sending_precision: Option<i32>,
+    bridging_fee: Option<Uint128>,
+    max_holding_amount: Option<Uint128>,
+    rounding_mode: Option<RoundingMode>,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateCoreumToken,
+    )?;
+    assert_bridge_not_halted(deps.as_ref())?;
+
+    let mut token = COREUM_TOKENS
+        .load(deps.storage, denom.clone())
+        .map_err(|_| ContractError::TokenNotRegistered {})?;
+
+    let previous_state = token.state.clone();
+    set_token_state(&mut token.state, state)?;
+    set_token_sending_precision(
+        &mut token.sending_precision,
+        sending_precision,
+        token.decimals,
+    )?;
+    set_token_bridging_fee(&mut token.bridging_fee, bridging_fee)?;
+    set_token_rounding_mode(&mut token.rounding_mode, rounding_mode);
+
+    // Get the current bridged amount for this token to verify that we are not setting a max_holding_amount that is less than the current amount
+    let current_bridged_amount = deps
+        .querier
+        .query_balance(env.contract.address, token.denom.clone())?
+        .amount;
+    set_token_max_holding_amount(
+        current_bridged_amount,
+        &mut token.max_holding_amount,
+        max_holding_amount,
+    )?;
+
+    COREUM_TOKENS.save(deps.storage, denom.clone(), &token)?;
+
+    let mut response = Response::new();
+    if token.state.ne(&previous_state) {
+        let state_event = record_token_state_transition(
+            deps.storage,
+            &denom,
+            Some(previous_state),
+            token.state,
+            TokenStateTransitionReason::OwnerUpdate,
+            env.block.time.seconds(),
+        )?;
+        response = response.add_event(state_event);
+    }
+
+    Ok(response
+        .add_attribute("action", ContractActions::UpdateCoreumToken.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("denom", denom))
+}
+
+fn update_xrpl_base_fee(
+    deps: DepsMut,
+    sender: Addr,
+    xrpl_base_fee: u64,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateXRPLBaseFee,
+    )?;
+
+    // Update the value in config
+    let mut fees_config = FEES_CONFIG.load(deps.storage)?;
+    fees_config.xrpl_base_fee = xrpl_base_fee;
+    FEES_CONFIG.save(deps.storage, &fees_config)?;
+
+    rebase_operations(deps.storage, xrpl_base_fee)?;
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::UpdateXRPLBaseFee.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("new_xrpl_base_fee", xrpl_base_fee.to_string()))
+}
+
+fn rebase_pending_operations(
+    deps: DepsMut,
+    sender: Addr,
+    xrpl_base_fee: Option<u64>,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::RebasePendingOperations,
+    )?;
+
+    // Relayers can't sign operations while the bridge is halted, so they can't race with the rebase
+    if BRIDGE_STATE_CONFIG
+        .load(deps.storage)?
+        .bridge_state
+        .ne(&BridgeState::Halted)
+    {
+        return Err(ContractError::BridgeNotHalted {});
+    }
+
+    let mut fees_config = FEES_CONFIG.load(deps.storage)?;
+
+    if let Some(xrpl_base_fee) = xrpl_base_fee {
+        fees_config.xrpl_base_fee = xrpl_base_fee;
+        FEES_CONFIG.save(deps.storage, &fees_config)?;
+    }
+
+    let operation_ids = rebase_operations(deps.storage, fees_config.xrpl_base_fee)?;
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::RebasePendingOperations.as_str())
+        .add_attribute("sender", sender)
+        .add_event(
+            Event::new("pending_operations_rebased")
+                .add_attribute("xrpl_base_fee", fees_config.xrpl_base_fee.to_string())
+                .add_attribute("operations_count", operation_ids.len().to_string())
+                .add_attribute(
+                    "operation_ids",
+                    operation_ids
+                        .iter()
+                        .map(|operation_id| operation_id.to_string())
+                        .collect::<Vec<String>>()
+                        .join(","),
+                ),
+        ))
+}
+
+// For each operation in PENDING_OPERATIONS we increase the version by 1, set the XRPL base fee and delete all signatures
+// Returns the ids of the operations that were updated
+fn rebase_operations(
+    storage: &mut dyn Storage,
+    xrpl_base_fee: u64,
+) -> Result<Vec<u64>, ContractError> {
+    let operations: Vec<(u64, Operation)> = PENDING_OPERATIONS
+        .range(storage, None, None, Order::Ascending)
+        .filter_map(Result::ok)
+        .collect();
+
+    for operation in &operations {
+        PENDING_OPERATIONS.save(
+            storage,
+            operation.0,
+            &Operation {
+                id: operation.1.id.clone(),
+                version: operation.1.version + 1,
+                ticket_sequence: operation.1.ticket_sequence,
+                account_sequence: operation.1.account_sequence,
+                signatures: vec![],
+                operation_type: operation.1.operation_type.clone(),
+                xrpl_base_fee,
+            },
+        )?;
+        reset_operation_relayer_set(storage, operation.0)?;
+    }
+
+    Ok(operations
+        .into_iter()
+        .map(|(operation_id, _)| operation_id)
+        .collect())
+}
+
+fn update_operation_xrpl_base_fee(
+    deps: DepsMut,
+    sender: Addr,
+    operation_id: u64,
+    xrpl_base_fee: u64,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateOperationXRPLBaseFee,
+    )?;
+    validate_xrpl_base_fee(xrpl_base_fee)?;
+
+    let mut operation = check_operation_exists(deps.storage, operation_id)?;
+
+    // Only this operation needs to be signed again, so we increase its version and delete its signatures
+    // If the fee doesn't change, the signatures collected are still valid and we keep them
+    if operation.xrpl_base_fee != xrpl_base_fee {
+        operation.version += 1;
+        operation.signatures = vec![];
+        operation.xrpl_base_fee = xrpl_base_fee;
+        PENDING_OPERATIONS.save(deps.storage, operation_id, &operation)?;
+        reset_operation_relayer_set(deps.storage, operation_id)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute(
+            "action",
+            ContractActions::UpdateOperationXRPLBaseFee.as_str(),
+        )
+        .add_attribute("sender", sender)
+        .add_attribute("operation_id", operation_id.to_string())
+        .add_attribute("operation_version", operation.version.to_string())
+        .add_attribute("new_xrpl_base_fee", xrpl_base_fee.to_string()))
+}
+
+fn claim_relayer_fees(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    amounts: Vec<Coin>,
+) -> CoreumResult<ContractError> {
+    assert_bridge_not_halted(deps.as_ref())?;
+
+    // If fees were never collected for this address we don't allow the claim
+    if FEES_COLLECTED
+        .may_load(deps.storage, sender.clone())?
+        .is_none()
+    {
+        return Err(ContractError::UnauthorizedSender {});
+    };
+
+    substract_relayer_fees(deps.storage, &sender, &amounts)?;
+    record_relayer_fee_claim(
+        deps.storage,
+        &sender,
+        amounts.clone(),
+        env.block.height,
+        env.block.time.seconds(),
+    )?;
+
+    let mut response = Response::new()
+        .add_attribute("action", ContractActions::ClaimFees.as_str())
+        .add_attribute("sender", sender.clone());
+
+    // Fees of relayers that opted in are consolidated into a single denom by the fee converter contract
+    // If the conversion fails the whole claim fails, so relayers can always opt out to claim their fees as they are
+    let fees_config = FEES_CONFIG.load(deps.storage)?;
+    let consolidation = match (
+        fees_config.fee_converter_contract,
+        RELAYER_FEE_CONSOLIDATION_DENOMS.may_load(deps.storage, sender.clone())?,
+    ) {
+        (Some(fee_converter_contract), Some(denom)) => Some((fee_converter_contract, denom)),
+        _ => None,
+    };
+    let (amounts, amounts_to_convert): (Vec<Coin>, Vec<Coin>) = match &consolidation {
+        Some((_, denom)) => amounts
+            .into_iter()
+            .partition(|amount| amount.denom.eq(denom)),
+        None => (amounts, vec![]),
+    };
+
+    if !amounts.is_empty() || amounts_to_convert.is_empty() {
+        response = response.add_message(BankMsg::Send {
+            to_address: sender.to_string(),
+            amount: amounts,
+        });
+    }
+
+    if let Some((fee_converter_contract, denom)) = consolidation {
+        if !amounts_to_convert.is_empty() {
+            response = response
+                .add_message(WasmMsg::Execute {
+                    contract_addr: fee_converter_contract.to_string(),
+                    msg: to_json_binary(&FeeConverterExecuteMsg::Convert {
+                        output_denom: denom.clone(),
+                        recipient: sender.to_string(),
+                    })?,
+                    funds: amounts_to_convert,
+                })
+                .add_attribute("consolidation_denom", denom);
         }
-        QueryMsg::BridgeState {} => to_json_binary(&query_bridge_state(deps)?),
-        QueryMsg::TransactionEvidence { hash } => {
-            to_json_binary(&query_transaction_evidence(deps, hash)?)
+    }
+
+    Ok(response)
+}
+
+fn update_fee_consolidation_denom(
+    deps: DepsMut,
+    sender: Addr,
+    denom: Option<String>,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateFeeConsolidationDenom,
+    )?;
+
+    match &denom {
+        Some(denom) => {
+            validate_coreum_denom(denom)?;
+            RELAYER_FEE_CONSOLIDATION_DENOMS.save(deps.storage, sender.clone(), denom)?;
         }
-        QueryMsg::TransactionEvidences {
-            start_after_key,
-            limit,
-        } => to_json_binary(&query_transaction_evidences(deps, start_after_key, limit)),
-        QueryMsg::ProcessedTx { hash } => to_json_binary(&query_processed_tx(deps, hash)),
-        QueryMsg::ProcessedTxs {
-            start_after_key,
-            limit,
-        } => to_json_binary(&query_processed_txs(deps, start_after_key, limit)),
-        QueryMsg::ProhibitedXRPLAddresses {} => {
-            to_json_binary(&query_prohibited_xrpl_addresses(deps))
+        None => RELAYER_FEE_CONSOLIDATION_DENOMS.remove(deps.storage, sender.clone()),
+    }
+
+    Ok(Response::new()
+        .add_attribute(
+            "action",
+            ContractActions::UpdateFeeConsolidationDenom.as_str(),
+        )
+        .add_attribute("sender", sender)
+        .add_attribute("denom", denom.unwrap_or_default()))
+}
+
+fn claim_pending_refund(
+    deps: DepsMut,
+    block: &BlockInfo,
+    sender: Addr,
+    pending_refund_id: String,
+    owner: Option<String>,
+) -> CoreumResult<ContractError> {
+    assert_bridge_not_halted(deps.as_ref())?;
+    let timestamp = block.time.seconds();
+
+    // The claim can be executed by anyone on behalf of the owner, but funds always go to the owner
+    let owner = match owner {
+        Some(owner) => deps.api.addr_validate(&owner)?,
+        None => sender.clone(),
+    };
+
+    // Claims of frozen tokens don't fail, the attempt is recorded so that it can be retried once the token is unfrozen
+    let pending_refund = PENDING_REFUNDS
+        .load(deps.storage, (owner.clone(), pending_refund_id.clone()))
+        .map_err(|_| ContractError::PendingRefundNotFound {})?;
+    if is_token_frozen(deps.storage, &pending_refund.coin.denom, timestamp)? {
+        let blocked_claim = record_blocked_claim(
+            deps.storage,
+            &owner,
+            pending_refund_id.clone(),
+            block.height,
+        )?;
+
+        return Ok(Response::new()
+            .add_attribute("action", ContractActions::ClaimRefunds.as_str())
+            .add_attribute("sender", sender)
+            .add_attribute("owner", owner)
+            .add_attribute("pending_refund_id", pending_refund_id)
+            .add_attribute("blocked", true.to_string())
+            .add_attribute("attempts", blocked_claim.attempts.to_string()));
+    }
+    let is_holdback =
+        UNDELIVERED_TRANSFERS.has(deps.storage, (owner.clone(), pending_refund_id.clone()));
+    let coin = remove_pending_refund(deps.storage, &owner, pending_refund_id, timestamp)?;
+
+    // Held back transfers from XRPL can only be claimed once the compliance contract allows them
+    if is_holdback {
+        if let Some(reason) = check_transfer_compliance(
+            deps.storage,
+            &deps.querier,
+            TransferDirection::XRPLToCoreum,
+            None,
+            owner.to_string(),
+            coin.clone(),
+        )? {
+            return Err(ContractError::TransferDenied { reason });
+        }
+    }
+
+    let send_msg = BankMsg::Send {
+        to_address: owner.to_string(),
+        amount: vec![coin],
+    };
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::ClaimRefunds.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("owner", owner)
+        .add_message(send_msg))
+}
+
+fn claim_all_pending_refunds(
+    deps: DepsMut,
+    block: &BlockInfo,
+    sender: Addr,
+    limit: Option<u32>,
+) -> CoreumResult<ContractError> {
+    assert_bridge_not_halted(deps.as_ref())?;
+    let timestamp = block.time.seconds();
+    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
+
+    let pending_refunds: Vec<_> = PENDING_REFUNDS
+        .idx
+        .address
+        .prefix(sender.clone())
+        .range(deps.storage, None, None, Order::Ascending)
+        .take(limit as usize)
+        .filter_map(Result::ok)
+        .map(|(_, pending_refund)| pending_refund)
+        .collect();
+
+    let mut claimed = 0u32;
+    let mut blocked = 0u32;
+    let mut amounts: BTreeMap<String, Uint128> = BTreeMap::new();
+    for pending_refund in pending_refunds {
+        // Refunds of frozen tokens are skipped and recorded as blocked claims
+        if is_token_frozen(deps.storage, &pending_refund.coin.denom, timestamp)? {
+            record_blocked_claim(deps.storage, &sender, pending_refund.id, block.height)?;
+            blocked += 1;
+            continue;
+        }
+
+        // Held back transfers from XRPL can only be claimed once the compliance contract allows them
+        if UNDELIVERED_TRANSFERS.has(deps.storage, (sender.clone(), pending_refund.id.clone()))
+            && check_transfer_compliance(
+                deps.storage,
+                &deps.querier,
+                TransferDirection::XRPLToCoreum,
+                None,
+                sender.to_string(),
+                pending_refund.coin.clone(),
+            )?
+            .is_some()
+        {
+            continue;
+        }
+
+        let coin = remove_pending_refund(deps.storage, &sender, pending_refund.id, timestamp)?;
+        let amount = amounts.entry(coin.denom).or_default();
+        *amount = amount.checked_add(coin.amount)?;
+        claimed += 1;
+    }
+
+    if claimed == 0 && blocked == 0 {
+        return Err(ContractError::PendingRefundNotFound {});
+    }
+
+    let send_msgs: Vec<BankMsg> = amounts
+        .into_iter()
+        .map(|(denom, amount)| BankMsg::Send {
+            to_address: sender.to_string(),
+            amount: coins(amount.u128(), denom),
+        })
+        .collect();
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::ClaimRefunds.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("claimed_refunds", claimed.to_string())
+        .add_attribute("blocked_refunds", blocked.to_string())
+        .add_messages(send_msgs))
+}
+
+fn return_undelivered_transfer(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    pending_refund_id: String,
+    recipient: String,
+) -> CoreumResult<ContractError> {
+    assert_bridge_active(deps.as_ref())?;
+
+    if !UNDELIVERED_TRANSFERS.has(deps.storage, (sender.clone(), pending_refund_id.clone())) {
+        return Err(ContractError::UndeliveredTransferNotFound {});
+    }
+
+    let (recipient, destination_tag) = decode_xrpl_recipient(deps.storage, &recipient)?;
+    validate_xrpl_address(deps.storage, recipient.clone())?;
+
+    let coin = remove_pending_refund(
+        deps.storage,
+        &sender,
+        pending_refund_id,
+        env.block.time.seconds(),
+    )?;
+
+    // Only Coreum originated tokens can fail to be delivered
+    let coreum_token = COREUM_TOKENS
+        .load(deps.storage, coin.denom.clone())
+        .map_err(|_| ContractError::TokenNotRegistered {})?;
+    if coreum_token.state.ne(&TokenState::Enabled) {
+        return Err(ContractError::TokenNotEnabled {});
+    }
+    assert_token_not_frozen(deps.storage, &coreum_token.denom, env.block.time.seconds())?;
+
+    // The bridging fee was already charged when the transfer came from XRPL, so we only truncate and convert the amount
+    let (amount_to_send, remainder) = truncate_and_convert_amount(
+        coreum_token.sending_precision,
+        coreum_token.decimals,
+        XRPL_TOKENS_DECIMALS,
+        coin.amount,
+        Uint128::zero(),
+    )?;
+    validate_xrpl_amount(amount_to_send)?;
+
+    let (_, fee_event) = handle_fee_collection(
+        deps.storage,
+        Uint128::zero(),
+        coreum_token.denom.clone(),
+        remainder,
+    )?;
+    lock_amount(
+        deps.storage,
+        &coreum_token.denom,
+        coin.amount.checked_sub(remainder)?,
+    )?;
+
+    let xrpl_config = XRPL_CONFIG.load(deps.storage)?;
+    let ticket = allocate_ticket(deps.storage)?;
+    create_pending_operation(
+        deps.storage,
+        &env.block,
+        Some(ticket),
+        None,
+        OperationType::CoreumToXRPLTransfer {
+            issuer: xrpl_config.bridge_xrpl_address,
+            currency: coreum_token.xrpl_currency,
+            amount: amount_to_send,
+            max_amount: Some(amount_to_send),
+            sender: sender.clone(),
+            recipient: recipient.clone(),
+            destination_tag,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute(
+            "action",
+            ContractActions::ReturnUndeliveredTransfer.as_str(),
+        )
+        .add_attribute("sender", sender)
+        .add_attribute("recipient", recipient)
+        .add_attribute("coin", coin.to_string())
+        .add_events(fee_event))
+}
+
+fn halt_bridge(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    reason: Option<HaltReason>,
+) -> CoreumResult<ContractError> {
+    check_authorization(deps.as_ref().storage, &sender, &ContractActions::HaltBridge)?;
+    // No point halting a bridge that is already halted, but a bridge in maintenance can be halted
+    assert_bridge_not_halted(deps.as_ref())?;
+
+    let reason = reason.unwrap_or(HaltReason {
+        code: HaltReasonCode::Unspecified,
+        message: None,
+    });
+    if let Some(message) = &reason.message {
+        if message.len() > MAX_HALT_MESSAGE_LENGTH {
+            return Err(ContractError::HaltMessageTooLong {});
+        }
+    }
+
+    update_bridge_state(deps.storage, BridgeState::Halted)?;
+    save_halt_info(
+        deps.storage,
+        HaltInfo {
+            reason: reason.clone(),
+            halted_by: sender.clone(),
+            halted_at: env.block.time.seconds(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::HaltBridge.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("halt_reason", reason.code.as_str())
+        .add_attribute("halt_message", reason.message.unwrap_or_default()))
+}
+
+fn resume_bridge(deps: DepsMut, sender: Addr) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::ResumeBridge,
+    )?;
+
+    // Can't resume the bridge if there is a pending rotate keys ongoing
+    if PENDING_ROTATE_KEYS.load(deps.storage)? {
+        return Err(ContractError::RotateKeysOngoing {});
+    }
+
+    // We clear the halt information but we emit the reason of the halt that is being resolved
+    let halt_info = BRIDGE_STATE_CONFIG.load(deps.storage)?.halt_info;
+    update_bridge_state(deps.storage, BridgeState::Active)?;
+
+    let mut response = Response::new()
+        .add_attribute("action", ContractActions::ResumeBridge.as_str())
+        .add_attribute("sender", sender);
+
+    if let Some(halt_info) = halt_info {
+        response = response.add_attribute("halt_reason", halt_info.reason.code.as_str());
+    }
+
+    Ok(response)
+}
+
+fn request_halt(deps: DepsMut, env: Env, sender: Addr) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::RequestHalt,
+    )?;
+    assert_bridge_not_halted(deps.as_ref())?;
+
+    let mut halt_requests = HALT_REQUESTS.load(deps.storage)?;
+    if halt_requests.contains(&sender) {
+        return Err(ContractError::HaltAlreadyRequested {});
+    }
+    halt_requests.push(sender.clone());
+
+    let relayers_config = RELAYERS_CONFIG.load(deps.storage)?;
+    let threshold_reached = halt_requests.len() >= relayers_config.halt_request_threshold as usize;
+
+    if threshold_reached {
+        // Changing the bridge state clears all the halt requests
+        update_bridge_state(deps.storage, BridgeState::Halted)?;
+        save_halt_info(
+            deps.storage,
+            HaltInfo {
+                reason: HaltReason {
+                    code: HaltReasonCode::Incident,
+                    message: None,
+                },
+                halted_by: sender.clone(),
+                halted_at: env.block.time.seconds(),
+            },
+        )?;
+    } else {
+        HALT_REQUESTS.save(deps.storage, &halt_requests)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::RequestHalt.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("threshold_reached", threshold_reached.to_string()))
+}
+
+fn acknowledge_bridge_state(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    bridge_state: BridgeState,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::AcknowledgeBridgeState,
+    )?;
+
+    if BRIDGE_STATE_CONFIG
+        .load(deps.storage)?
+        .bridge_state
+        .ne(&bridge_state)
+    {
+        return Err(ContractError::BridgeStateMismatch {});
+    }
+
+    if BRIDGE_STATE_ACKNOWLEDGEMENTS.has(deps.storage, sender.clone()) {
+        return Err(ContractError::BridgeStateAlreadyAcknowledged {});
+    }
+    BRIDGE_STATE_ACKNOWLEDGEMENTS.save(deps.storage, sender.clone(), &env.block.time.seconds())?;
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::AcknowledgeBridgeState.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("bridge_state", bridge_state.as_str()))
+}
+
+fn update_halt_request_threshold(
+    deps: DepsMut,
+    sender: Addr,
+    halt_request_threshold: u32,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateHaltRequestThreshold,
+    )?;
+
+    let mut relayers_config = RELAYERS_CONFIG.load(deps.storage)?;
+    // Same rules as for the evidence threshold
+    if halt_request_threshold == 0
+        || halt_request_threshold as usize > relayers_config.relayers.len()
+    {
+        return Err(ContractError::InvalidThreshold {});
+    }
+    relayers_config.halt_request_threshold = halt_request_threshold;
+    RELAYERS_CONFIG.save(deps.storage, &relayers_config)?;
+
+    Ok(Response::new()
+        .add_attribute(
+            "action",
+            ContractActions::UpdateHaltRequestThreshold.as_str(),
+        )
+        .add_attribute("sender", sender)
+        .add_attribute("halt_request_threshold", halt_request_threshold.to_string()))
+}
+
+fn request_token_freeze(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    denom: String,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::RequestTokenFreeze,
+    )?;
+
+    if !is_token_registered(deps.storage, &denom)? {
+        return Err(ContractError::TokenNotRegistered {});
+    }
+    let timestamp = env.block.time.seconds();
+    if is_token_frozen(deps.storage, &denom, timestamp)? {
+        return Err(ContractError::TokenFrozen {});
+    }
+
+    let mut freeze_requests = TOKEN_FREEZE_REQUESTS
+        .may_load(deps.storage, denom.clone())?
+        .unwrap_or_default();
+    if freeze_requests.contains(&sender) {
+        return Err(ContractError::TokenFreezeAlreadyRequested {});
+    }
+    freeze_requests.push(sender.clone());
+
+    let relayers_config = RELAYERS_CONFIG.load(deps.storage)?;
+    let operations_config = OPERATIONS_CONFIG.load(deps.storage)?;
+    let threshold_reached =
+        freeze_requests.len() >= relayers_config.token_freeze_request_threshold as usize;
+
+    if threshold_reached {
+        TOKEN_FREEZE_REQUESTS.remove(deps.storage, denom.clone());
+        // An expired freeze is replaced by the new one
+        TOKEN_FREEZES.save(
+            deps.storage,
+            denom.clone(),
+            &TokenFreeze {
+                requested_by: freeze_requests,
+                frozen_at: timestamp,
+                expires_at: Some(timestamp.saturating_add(operations_config.token_freeze_duration)),
+            },
+        )?;
+    } else {
+        TOKEN_FREEZE_REQUESTS.save(deps.storage, denom.clone(), &freeze_requests)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::RequestTokenFreeze.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("denom", denom)
+        .add_attribute("threshold_reached", threshold_reached.to_string()))
+}
+
+fn confirm_token_freeze(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    denom: String,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::ConfirmTokenFreeze,
+    )?;
+
+    // Expired freezes can't be confirmed, relayers need to request them again
+    if !is_token_frozen(deps.storage, &denom, env.block.time.seconds())? {
+        return Err(ContractError::TokenNotFrozen {});
+    }
+
+    let mut freeze = TOKEN_FREEZES.load(deps.storage, denom.clone())?;
+    freeze.expires_at = None;
+    TOKEN_FREEZES.save(deps.storage, denom.clone(), &freeze)?;
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::ConfirmTokenFreeze.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("denom", denom))
+}
+
+fn unfreeze_token(deps: DepsMut, sender: Addr, denom: String) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UnfreezeToken,
+    )?;
+
+    if !TOKEN_FREEZES.has(deps.storage, denom.clone()) {
+        return Err(ContractError::TokenNotFrozen {});
+    }
+    TOKEN_FREEZES.remove(deps.storage, denom.clone());
+    TOKEN_FREEZE_REQUESTS.remove(deps.storage, denom.clone());
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::UnfreezeToken.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("denom", denom))
+}
+
+fn update_token_global_freeze(
+    deps: DepsMut,
+    sender: Addr,
+    denom: String,
+    frozen: bool,
+) -> CoreumResult<ContractError> {
+    let action = if frozen {
+        ContractActions::GloballyFreezeToken
+    } else {
+        ContractActions::GloballyUnfreezeToken
+    };
+    check_authorization(deps.as_ref().storage, &sender, &action)?;
+
+    // Only XRPL originated tokens are issued by the bridge
+    if XRPL_TOKENS
+        .idx
+        .coreum_denom
+        .item(deps.storage, denom.clone())?
+        .is_none()
+    {
+        return Err(ContractError::TokenNotIssuedByBridge {});
+    }
+
+    Ok(Response::new()
+        .add_message(global_freeze_msg(denom.clone(), frozen))
+        .add_attribute("action", action.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("denom", denom))
+}
+
+// Globally freezes or unfreezes a page of the tokens issued by the bridge, so that responders don't need to list them
+// The key of the last token processed is returned so that the next page can be processed
+fn update_all_tokens_global_freeze(
+    deps: DepsMut,
+    sender: Addr,
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+    frozen: bool,
+) -> CoreumResult<ContractError> {
+    let action = if frozen {
+        ContractActions::GloballyFreezeToken
+    } else {
+        ContractActions::GloballyUnfreezeToken
+    };
+    check_authorization(deps.as_ref().storage, &sender, &action)?;
+
+    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
+    let start = start_after_key.map(Bound::exclusive);
+    let tokens: Vec<(String, XRPLToken)> = XRPL_TOKENS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit as usize)
+        .collect::<StdResult<_>>()?;
+
+    let last_key = tokens.last().map(|(key, _)| key.clone());
+    let msgs: Vec<CosmosMsg<CoreumMsg>> = tokens
+        .iter()
+        .map(|(_, token)| global_freeze_msg(token.coreum_denom.clone(), frozen))
+        .collect();
+
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_attribute("action", action.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("tokens", tokens.len().to_string())
+        .add_attribute("last_key", last_key.unwrap_or_default()))
+}
+
+fn global_freeze_msg(denom: String, frozen: bool) -> CosmosMsg<CoreumMsg> {
+    let msg = if frozen {
+        assetft::Msg::GloballyFreeze { denom }
+    } else {
+        assetft::Msg::GloballyUnfreeze { denom }
+    };
+    CosmosMsg::from(CoreumMsg::AssetFT(msg))
+}
+
+fn update_token_freeze_config(
+    deps: DepsMut,
+    sender: Addr,
+    token_freeze_request_threshold: Option<u32>,
+    token_freeze_duration: Option<u64>,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateTokenFreezeConfig,
+    )?;
+
+    let mut relayers_config = RELAYERS_CONFIG.load(deps.storage)?;
+    let mut operations_config = OPERATIONS_CONFIG.load(deps.storage)?;
+    if let Some(token_freeze_request_threshold) = token_freeze_request_threshold {
+        // Same rules as for the halt request threshold
+        if token_freeze_request_threshold == 0
+            || token_freeze_request_threshold as usize > relayers_config.relayers.len()
+        {
+            return Err(ContractError::InvalidThreshold {});
+        }
+        relayers_config.token_freeze_request_threshold = token_freeze_request_threshold;
+        RELAYERS_CONFIG.save(deps.storage, &relayers_config)?;
+    }
+    if let Some(token_freeze_duration) = token_freeze_duration {
+        if token_freeze_duration == 0 {
+            return Err(ContractError::InvalidTokenFreezeDuration {});
+        }
+        operations_config.token_freeze_duration = token_freeze_duration;
+        OPERATIONS_CONFIG.save(deps.storage, &operations_config)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::UpdateTokenFreezeConfig.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute(
+            "token_freeze_request_threshold",
+            relayers_config.token_freeze_request_threshold.to_string(),
+        )
+        .add_attribute(
+            "token_freeze_duration",
+            operations_config.token_freeze_duration.to_string(),
+        ))
+}
+
+fn update_auto_disable_on_issuer_change(
+    deps: DepsMut,
+    sender: Addr,
+    auto_disable_on_issuer_change: bool,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateAutoDisableOnIssuerChange,
+    )?;
+
+    let mut operations_config = OPERATIONS_CONFIG.load(deps.storage)?;
+    operations_config.auto_disable_on_issuer_change = auto_disable_on_issuer_change;
+    OPERATIONS_CONFIG.save(deps.storage, &operations_config)?;
+
+    Ok(Response::new()
+        .add_attribute(
+            "action",
+            ContractActions::UpdateAutoDisableOnIssuerChange.as_str(),
+        )
+        .add_attribute("sender", sender)
+        .add_attribute(
+            "auto_disable_on_issuer_change",
+            auto_disable_on_issuer_change.to_string(),
+        ))
+}
+
+fn update_token_failure_threshold(
+    deps: DepsMut,
+    sender: Addr,
+    token_failure_threshold: u32,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateTokenFailureThreshold,
+    )?;
+
+    let mut operations_config = OPERATIONS_CONFIG.load(deps.storage)?;
+    operations_config.token_failure_threshold = token_failure_threshold;
+    OPERATIONS_CONFIG.save(deps.storage, &operations_config)?;
+
+    Ok(Response::new()
+        .add_attribute(
+            "action",
+            ContractActions::UpdateTokenFailureThreshold.as_str(),
+        )
+        .add_attribute("sender", sender)
+        .add_attribute(
+            "token_failure_threshold",
+            token_failure_threshold.to_string(),
+        ))
+}
+
+fn update_evidence_ttl(
+    deps: DepsMut,
+    sender: Addr,
+    evidence_ttl: u64,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateEvidenceTTL,
+    )?;
+
+    if evidence_ttl == 0 {
+        return Err(ContractError::InvalidEvidenceTTL {});
+    }
+
+    let mut operations_config = OPERATIONS_CONFIG.load(deps.storage)?;
+    operations_config.evidence_ttl = evidence_ttl;
+    OPERATIONS_CONFIG.save(deps.storage, &operations_config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::UpdateEvidenceTTL.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("evidence_ttl", evidence_ttl.to_string()))
+}
+
+fn update_compliance_contract(
+    deps: DepsMut,
+    sender: Addr,
+    compliance_contract: Option<String>,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateComplianceContract,
+    )?;
+
+    let mut operations_config = OPERATIONS_CONFIG.load(deps.storage)?;
+    operations_config.compliance_contract = compliance_contract
+        .as_ref()
+        .map(|compliance_contract| deps.api.addr_validate(compliance_contract))
+        .transpose()?;
+    OPERATIONS_CONFIG.save(deps.storage, &operations_config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::UpdateComplianceContract.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute(
+            "compliance_contract",
+            compliance_contract.unwrap_or_default(),
+        ))
+}
+
+fn update_fee_converter_contract(
+    deps: DepsMut,
+    sender: Addr,
+    fee_converter_contract: Option<String>,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateFeeConverterContract,
+    )?;
+
+    let mut fees_config = FEES_CONFIG.load(deps.storage)?;
+    fees_config.fee_converter_contract = fee_converter_contract
+        .as_ref()
+        .map(|fee_converter_contract| deps.api.addr_validate(fee_converter_contract))
+        .transpose()?;
+    FEES_CONFIG.save(deps.storage, &fees_config)?;
+
+    Ok(Response::new()
+        .add_attribute(
+            "action",
+            ContractActions::UpdateFeeConverterContract.as_str(),
+        )
+        .add_attribute("sender", sender)
+        .add_attribute(
+            "fee_converter_contract",
+            fee_converter_contract.unwrap_or_default(),
+        ))
+}
+
+fn update_commit_reveal_threshold(
+    deps: DepsMut,
+    sender: Addr,
+    denom: String,
+    threshold: Option<Uint128>,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateCommitRevealThreshold,
+    )?;
+
+    if !is_token_registered(deps.storage, &denom)? {
+        return Err(ContractError::TokenNotRegistered {});
+    }
+
+    match threshold {
+        Some(threshold) => {
+            COMMIT_REVEAL_THRESHOLDS.save(deps.storage, denom.clone(), &threshold)?
+        }
+        None => COMMIT_REVEAL_THRESHOLDS.remove(deps.storage, denom.clone()),
+    }
+
+    Ok(Response::new()
+        .add_attribute(
+            "action",
+            ContractActions::UpdateCommitRevealThreshold.as_str(),
+        )
+        .add_attribute("sender", sender)
+        .add_attribute("denom", denom)
+        .add_attribute(
+            "threshold",
+            threshold
+                .map(|threshold| threshold.to_string())
+                .unwrap_or_default(),
+        ))
+}
+
+fn update_commit_reveal_config(
+    deps: DepsMut,
+    sender: Addr,
+    commit_reveal_delay: Option<u64>,
+    commit_reveal_expiration: Option<u64>,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateCommitRevealConfig,
+    )?;
+
+    let mut operations_config = OPERATIONS_CONFIG.load(deps.storage)?;
+    if let Some(commit_reveal_delay) = commit_reveal_delay {
+        operations_config.commit_reveal_delay = commit_reveal_delay;
+    }
+    if let Some(commit_reveal_expiration) = commit_reveal_expiration {
+        operations_config.commit_reveal_expiration = commit_reveal_expiration;
+    }
+    // There must be at least one block in which committed transfers can be revealed
+    if operations_config.commit_reveal_expiration <= operations_config.commit_reveal_delay {
+        return Err(ContractError::InvalidCommitRevealConfig {});
+    }
+    OPERATIONS_CONFIG.save(deps.storage, &operations_config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::UpdateCommitRevealConfig.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute(
+            "commit_reveal_delay",
+            operations_config.commit_reveal_delay.to_string(),
+        )
+        .add_attribute(
+            "commit_reveal_expiration",
+            operations_config.commit_reveal_expiration.to_string(),
+        ))
+}
+
+fn prune_stale_evidences(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    limit: Option<u32>,
+) -> CoreumResult<ContractError> {
+    let operations_config = OPERATIONS_CONFIG.load(deps.storage)?;
+    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
+
+    let evidences = stale_evidences(
+        deps.storage,
+        env.block.time.seconds(),
+        operations_config.evidence_ttl,
+        None,
+        limit,
+    )?;
+    for (evidence_hash, _) in evidences.iter() {
+        TX_EVIDENCES.remove(deps.storage, evidence_hash.to_owned());
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::PruneStaleEvidences.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("pruned", evidences.len().to_string()))
+}
+
+fn crank(
+    mut deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    limit: Option<u32>,
+) -> CoreumResult<ContractError> {
+    let fees_config = FEES_CONFIG.load(deps.storage)?;
+    let operations_config = OPERATIONS_CONFIG.load(deps.storage)?;
+    let timestamp = env.block.time.seconds();
+    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT) as usize;
+
+    // Every task uses what is left of the limit after the previous ones
+    let evidences = stale_evidences(
+        deps.storage,
+        timestamp,
+        operations_config.evidence_ttl,
+        None,
+        limit as u32,
+    )?;
+    for (evidence_hash, _) in evidences.iter() {
+        TX_EVIDENCES.remove(deps.storage, evidence_hash.to_owned());
+    }
+    let mut remaining = limit - evidences.len();
+
+    let expired_commitments: Vec<((Addr, String), TransferCommitment)> = TRANSFER_COMMITMENTS
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter(|item| match item {
+            Ok((_, transfer_commitment)) => {
+                env.block.height
+                    >= transfer_commitment
+                        .committed_at_height
+                        .saturating_add(operations_config.commit_reveal_expiration)
+            }
+            Err(_) => true,
+        })
+        .take(remaining)
+        .collect::<StdResult<_>>()?;
+    for ((commitment_sender, commitment), transfer_commitment) in expired_commitments.iter() {
+        refund_transfer_commitment(
+            deps.storage,
+            commitment_sender,
+            commitment,
+            transfer_commitment.clone(),
+        )?;
+    }
+    remaining -= expired_commitments.len();
+
+    // Expired freezes don't apply anymore, they are only kept until they are replaced or removed
+    let expired_freezes: Vec<String> = TOKEN_FREEZES
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter(|item| match item {
+            Ok((_, freeze)) => freeze
+                .expires_at
+                .is_some_and(|expires_at| timestamp >= expires_at),
+            Err(_) => true,
+        })
+        .take(remaining)
+        .map(|item| item.map(|(denom, _)| denom))
+        .collect::<StdResult<_>>()?;
+    for denom in expired_freezes.iter() {
+        TOKEN_FREEZES.remove(deps.storage, denom.to_owned());
+    }
+    remaining -= expired_freezes.len();
+
+    // If tickets ran out when the allocation was due, it can only be triggered again by a ticket recovery
+    let tickets_allocation_triggered = match allocate_tickets_if_needed(deps.storage, &env.block) {
+        Ok(triggered) => triggered,
+        Err(ContractError::NoAvailableTickets {}) => false,
+        Err(e) => return Err(e),
+    };
+
+    let response = Response::new()
+        .add_attribute("action", ContractActions::Crank.as_str())
+        .add_attribute("sender", sender.clone())
+        .add_attribute("pruned_evidences", evidences.len().to_string())
+        .add_attribute(
+            "refunded_commitments",
+            expired_commitments.len().to_string(),
+        )
+        .add_attribute("lifted_freezes", expired_freezes.len().to_string())
+        .add_attribute(
+            "tickets_allocation_triggered",
+            tickets_allocation_triggered.to_string(),
+        );
+
+    // Deferred transfers that couldn't be delivered when their token was enabled (e.g. the token was frozen) are delivered here
+    let (mut response, applied_deferred_transfers) = apply_deferred_inbound_transfers(
+        &mut deps,
+        &env,
+        &BRIDGE_STATE_CONFIG.load(deps.storage)?.bridge_state,
+        &XRPL_CONFIG.load(deps.storage)?,
+        remaining,
+        response,
+    )?;
+    response = response.add_attribute(
+        "applied_deferred_transfers",
+        applied_deferred_transfers.to_string(),
+    );
+
+    // Only housekeeping that had something to do is rewarded, so that the pool can't be drained with empty cranks
+    let work_done = !evidences.is_empty()
+        || !expired_commitments.is_empty()
+        || !expired_freezes.is_empty()
+        || applied_deferred_transfers > 0
+        || tickets_allocation_triggered;
+    if work_done {
+        if let Some(reward) = take_crank_reward(deps.storage, fees_config.crank_reward)? {
+            response = response
+                .add_attribute("reward", reward.to_string())
+                .add_message(BankMsg::Send {
+                    to_address: sender.to_string(),
+                    amount: vec![reward],
+                });
+        }
+    }
+
+    Ok(response)
+}
+
+fn update_crank_reward(
+    deps: DepsMut,
+    sender: Addr,
+    crank_reward: Uint128,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateCrankReward,
+    )?;
+
+    let mut fees_config = FEES_CONFIG.load(deps.storage)?;
+    fees_config.crank_reward = crank_reward;
+    FEES_CONFIG.save(deps.storage, &fees_config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::UpdateCrankReward.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("crank_reward", crank_reward.to_string()))
+}
+
+fn update_priority_fee(
+    deps: DepsMut,
+    sender: Addr,
+    priority_fee: Option<Coin>,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdatePriorityFee,
+    )?;
+
+    if priority_fee
+        .as_ref()
+        .is_some_and(|priority_fee| priority_fee.amount.is_zero())
+    {
+        return Err(ContractError::InvalidPriorityFee {});
+    }
+
+    let mut fees_config = FEES_CONFIG.load(deps.storage)?;
+    fees_config.priority_fee = priority_fee.clone();
+    FEES_CONFIG.save(deps.storage, &fees_config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::UpdatePriorityFee.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute(
+            "priority_fee",
+            priority_fee
+                .map(|priority_fee| priority_fee.to_string())
+                .unwrap_or_default(),
+        ))
+}
+
+fn update_price_oracle(
+    deps: DepsMut,
+    sender: Addr,
+    price_oracle: Option<String>,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdatePriceOracle,
+    )?;
+
+    let mut fees_config = FEES_CONFIG.load(deps.storage)?;
+    fees_config.price_oracle = price_oracle
+        .as_ref()
+        .map(|price_oracle| deps.api.addr_validate(price_oracle))
+        .transpose()?;
+    FEES_CONFIG.save(deps.storage, &fees_config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::UpdatePriceOracle.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("price_oracle", price_oracle.unwrap_or_default()))
+}
+
+fn update_usd_bridging_fee(
+    deps: DepsMut,
+    sender: Addr,
+    denom: String,
+    usd_bridging_fee: Option<USDBridgingFee>,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateUSDBridgingFee,
+    )?;
+
+    if !is_token_registered(deps.storage, &denom)? {
+        return Err(ContractError::TokenNotRegistered {});
+    }
+
+    let mut response = Response::new()
+        .add_attribute("action", ContractActions::UpdateUSDBridgingFee.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("denom", denom.clone());
+
+    match usd_bridging_fee {
+        Some(usd_bridging_fee) => {
+            if usd_bridging_fee.min_fee > usd_bridging_fee.max_fee {
+                return Err(ContractError::InvalidUSDBridgingFee {});
+            }
+            response = response
+                .add_attribute("usd_amount", usd_bridging_fee.usd_amount.to_string())
+                .add_attribute("min_fee", usd_bridging_fee.min_fee)
+                .add_attribute("max_fee", usd_bridging_fee.max_fee);
+            USD_BRIDGING_FEES.save(deps.storage, denom, &usd_bridging_fee)?;
+        }
+        None => USD_BRIDGING_FEES.remove(deps.storage, denom),
+    }
+
+    Ok(response)
+}
+
+fn update_token_prices(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    prices: Vec<TokenPriceUpdate>,
+) -> CoreumResult<ContractError> {
+    let fees_config = FEES_CONFIG.load(deps.storage)?;
+    if fees_config.price_oracle.as_ref() != Some(&sender) {
+        return Err(ContractError::UnauthorizedSender {});
+    }
+
+    for price in prices.iter() {
+        if !is_token_registered(deps.storage, &price.denom)? {
+            return Err(ContractError::TokenNotRegistered {});
+        }
+        if price.usd_price.is_zero() {
+            return Err(ContractError::InvalidTokenPrice {});
+        }
+        TOKEN_PRICES.save(
+            deps.storage,
+            price.denom.clone(),
+            &TokenPrice {
+                usd_price: price.usd_price,
+                updated_at: env.block.time.seconds(),
+            },
+        )?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::UpdateTokenPrices.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("prices_updated", prices.len().to_string()))
+}
+
+fn reconcile_xrpl_token_supply(
+    deps: DepsMut,
+    sender: Addr,
+    tx_hash: String,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::ReconcileXRPLTokenSupply,
+    )?;
+
+    let drift = XRPL_SUPPLY_DRIFTS
+        .may_load(deps.storage, tx_hash.to_uppercase())?
+        .ok_or(ContractError::XRPLSupplyDriftNotFound {})?;
+
+    let key = build_xrpl_token_key(&drift.issuer, &drift.currency);
+    let token = XRPL_TOKENS
+        .may_load(deps.storage, key)?
+        .ok_or(ContractError::TokenNotRegistered {})?;
+
+    let previous_minted = load_token_accounting(deps.storage, &token.coreum_denom)?.minted;
+    if drift.decreased {
+        burn_amount(deps.storage, &token.coreum_denom, drift.amount)?;
+    } else {
+        mint_amount(deps.storage, &token.coreum_denom, drift.amount)?;
+    }
+    let minted = load_token_accounting(deps.storage, &token.coreum_denom)?.minted;
+
+    XRPL_SUPPLY_DRIFTS.remove(deps.storage, drift.tx_hash.clone());
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::ReconcileXRPLTokenSupply.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("tx_hash", drift.tx_hash)
+        .add_attribute("issuer", drift.issuer)
+        .add_attribute("currency", drift.currency)
+        .add_attribute("denom", token.coreum_denom)
+        .add_attribute("amount", drift.amount)
+        .add_attribute("decreased", drift.decreased.to_string())
+        .add_attribute("previous_minted", previous_minted)
+        .add_attribute("minted", minted))
+}
+
+fn assign_invalid_deposit(
+    mut deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    tx_hash: String,
+    recipient: String,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::AssignInvalidDeposit,
+    )?;
+    assert_bridge_active(deps.as_ref())?;
+
+    let invalid_deposit = INVALID_DEPOSITS
+        .may_load(deps.storage, tx_hash.to_uppercase())?
+        .ok_or(ContractError::InvalidDepositNotFound {})?;
+    INVALID_DEPOSITS.remove(deps.storage, invalid_deposit.tx_hash.clone());
+
+    let recipient = deps.api.addr_validate(&recipient)?;
+    let xrpl_config = XRPL_CONFIG.load(deps.storage)?;
+
+    // The deposit was already attested by the relayers, so it's delivered like any other transfer from XRPL
+    let response = process_xrpl_to_coreum_transfer(
+        &mut deps,
+        &env,
+        &xrpl_config,
+        invalid_deposit.tx_hash.clone(),
+        &invalid_deposit.issuer,
+        &invalid_deposit.currency,
+        invalid_deposit.amount,
+        &recipient,
+        Uint128::zero(),
+        true,
+        vec![],
+        Response::new(),
+    )?;
+
+    Ok(response
+        .add_attribute("action", ContractActions::AssignInvalidDeposit.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("hash", invalid_deposit.tx_hash)
+        .add_attribute("recipient", recipient))
+}
+
+fn attest_bridge_xrpl_balance(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    balance: Uint128,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::AttestBridgeXRPLBalance,
+    )?;
+
+    if balance.is_zero() {
+        return Err(ContractError::InvalidAmount {});
+    }
+
+    // Each relayer only has one attestation, attesting again replaces the previous one
+    XRPL_BALANCE_ATTESTATIONS.save(
+        deps.storage,
+        sender.clone(),
+        &XRPLBalanceAttestation {
+            balance,
+            attested_at: env.block.time.seconds(),
+        },
+    )?;
+
+    // Signal that the account must be topped up so that it can be picked up by monitoring
+    let top_up_needed = check_xrpl_reserve(deps.storage, env.block.time.seconds(), 0, 0).is_err();
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::AttestBridgeXRPLBalance.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("balance", balance.to_string())
+        .add_attribute("top_up_needed", top_up_needed.to_string()))
+}
+
+fn update_xrpl_reserves(
+    deps: DepsMut,
+    sender: Addr,
+    xrpl_base_reserve: Uint128,
+    xrpl_owner_reserve: Uint128,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateXRPLReserves,
+    )?;
+
+    let mut xrpl_config = XRPL_CONFIG.load(deps.storage)?;
+    xrpl_config.xrpl_base_reserve = xrpl_base_reserve;
+    xrpl_config.xrpl_owner_reserve = xrpl_owner_reserve;
+    XRPL_CONFIG.save(deps.storage, &xrpl_config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::UpdateXRPLReserves.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("xrpl_base_reserve", xrpl_base_reserve.to_string())
+        .add_attribute("xrpl_owner_reserve", xrpl_owner_reserve.to_string()))
+}
+
+fn mark_relayer_inactive(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    relayer_address: Addr,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::MarkRelayerInactive,
+    )?;
+
+    if !is_relayer(deps.storage, &relayer_address)? {
+        return Err(ContractError::NotARelayer {});
+    }
+
+    let relayers_config = RELAYERS_CONFIG.load(deps.storage)?;
+    let mut activity = RELAYER_ACTIVITY.load(deps.storage, relayer_address.clone())?;
+    if activity.inactive_since.is_some() {
+        return Err(ContractError::RelayerAlreadyInactive {});
+    }
+    if activity
+        .last_active_at
+        .saturating_add(relayers_config.relayer_inactivity_period)
+        > env.block.time.seconds()
+    {
+        return Err(ContractError::RelayerStillActive {});
+    }
+
+    // The relayer stays in the signer list on XRPL, it's only excluded from the fee split and the evidence threshold until it's active again
+    activity.inactive_since = Some(env.block.time.seconds());
+    RELAYER_ACTIVITY.save(deps.storage, relayer_address.clone(), &activity)?;
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::MarkRelayerInactive.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("relayer_address", relayer_address)
+        .add_attribute("last_active_at", activity.last_active_at.to_string()))
+}
+
+fn report_conflicting_evidences(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    relayer_address: Addr,
+    evidence: Evidence,
+    conflicting_evidence: Evidence,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::ReportConflictingEvidences,
+    )?;
+
+    if !is_relayer(deps.storage, &relayer_address)? {
+        return Err(ContractError::NotARelayer {});
+    }
+
+    let infraction = handle_conflicting_evidences(
+        deps.storage,
+        env.block.time.seconds(),
+        sender.clone(),
+        relayer_address.clone(),
+        &evidence,
+        &conflicting_evidence,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute(
+            "action",
+            ContractActions::ReportConflictingEvidences.as_str(),
+        )
+        .add_attribute("sender", sender)
+        .add_event(
+            Event::new("relayer_infraction_recorded")
+                .add_attribute("relayer", relayer_address)
+                .add_attribute("tx_hash", infraction.tx_hash)
+                .add_attribute("evidence", evidence.to_json())
+                .add_attribute("conflicting_evidence", conflicting_evidence.to_json()),
+        ))
+}
+
+fn update_relayer_inactivity_period(
+    deps: DepsMut,
+    sender: Addr,
+    relayer_inactivity_period: u64,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateRelayerInactivityPeriod,
+    )?;
+
+    if relayer_inactivity_period == 0 {
+        return Err(ContractError::InvalidRelayerInactivityPeriod {});
+    }
+
+    let mut relayers_config = RELAYERS_CONFIG.load(deps.storage)?;
+    relayers_config.relayer_inactivity_period = relayer_inactivity_period;
+    RELAYERS_CONFIG.save(deps.storage, &relayers_config)?;
+
+    Ok(Response::new()
+        .add_attribute(
+            "action",
+            ContractActions::UpdateRelayerInactivityPeriod.as_str(),
+        )
+        .add_attribute("sender", sender)
+        .add_attribute(
+            "relayer_inactivity_period",
+            relayer_inactivity_period.to_string(),
+        ))
+}
+
+fn publish_xrpl_dex_quote(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    denom: String,
+    price: Decimal,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::PublishXRPLDexQuote,
+    )?;
+
+    if !is_token_registered(deps.storage, &denom)? {
+        return Err(ContractError::TokenNotRegistered {});
+    }
+    if price.is_zero() {
+        return Err(ContractError::InvalidXRPLDexQuote {});
+    }
+
+    // Each relayer only has one quote per token, publishing a new one replaces the previous one
+    XRPL_DEX_QUOTES.save(
+        deps.storage,
+        (denom.clone(), sender.clone()),
+        &XRPLDexQuote {
+            price,
+            published_at: env.block.time.seconds(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::PublishXRPLDexQuote.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("denom", denom)
+        .add_attribute("price", price.to_string()))
+}
+
+fn update_xrpl_dex_quote_ttl(
+    deps: DepsMut,
+    sender: Addr,
+    xrpl_dex_quote_ttl: u64,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateXRPLDexQuoteTTL,
+    )?;
+
+    if xrpl_dex_quote_ttl == 0 {
+        return Err(ContractError::InvalidXRPLDexQuoteTTL {});
+    }
+
+    let mut fees_config = FEES_CONFIG.load(deps.storage)?;
+    fees_config.xrpl_dex_quote_ttl = xrpl_dex_quote_ttl;
+    FEES_CONFIG.save(deps.storage, &fees_config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::UpdateXRPLDexQuoteTTL.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("xrpl_dex_quote_ttl", xrpl_dex_quote_ttl.to_string()))
+}
+
+fn update_operation_archive_size(
+    deps: DepsMut,
+    sender: Addr,
+    operation_archive_size: u32,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateOperationArchiveSize,
+    )?;
+
+    let mut operations_config = OPERATIONS_CONFIG.load(deps.storage)?;
+    operations_config.operation_archive_size = operation_archive_size;
+    OPERATIONS_CONFIG.save(deps.storage, &operations_config)?;
+
+    Ok(Response::new()
+        .add_attribute(
+            "action",
+            ContractActions::UpdateOperationArchiveSize.as_str(),
+        )
+        .add_attribute("sender", sender)
+        .add_attribute("operation_archive_size", operation_archive_size.to_string()))
+}
+
+fn prune_archived_operations(
+    deps: DepsMut,
+    sender: Addr,
+    limit: Option<u32>,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::PruneOperationArchive,
+    )?;
+
+    let operations_config = OPERATIONS_CONFIG.load(deps.storage)?;
+    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
+    let pruned = prune_operation_archive(
+        deps.storage,
+        operations_config.operation_archive_size,
+        limit,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::PruneOperationArchive.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("pruned", pruned.to_string()))
+}
+
+fn update_max_registered_xrpl_tokens(
+    deps: DepsMut,
+    sender: Addr,
+    max_registered_xrpl_tokens: u32,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateMaxRegisteredXRPLTokens,
+    )?;
+
+    // The maximum can be set below the amount of tokens already registered, which will just prevent new registrations
+    let mut xrpl_config = XRPL_CONFIG.load(deps.storage)?;
+    xrpl_config.max_registered_xrpl_tokens = max_registered_xrpl_tokens;
+    XRPL_CONFIG.save(deps.storage, &xrpl_config)?;
+
+    Ok(Response::new()
+        .add_attribute(
+            "action",
+            ContractActions::UpdateMaxRegisteredXRPLTokens.as_str(),
+        )
+        .add_attribute("sender", sender)
+        .add_attribute(
+            "max_registered_xrpl_tokens",
+            max_registered_xrpl_tokens.to_string(),
+        ))
+}
+
+fn update_relayer_reimbursement(
+    deps: DepsMut,
+    sender: Addr,
+    amount_per_operation: Coin,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateRelayerReimbursement,
+    )?;
+
+    let pool = match RELAYER_REIMBURSEMENT.may_load(deps.storage)? {
+        Some(reimbursement) => {
+            // The pool is kept in a single denom
+            if !reimbursement.pool.is_zero()
+                && reimbursement.amount_per_operation.denom != amount_per_operation.denom
+            {
+                return Err(ContractError::ReimbursementPoolNotEmpty {});
+            }
+            reimbursement.pool
+        }
+        None => Uint128::zero(),
+    };
+
+    RELAYER_REIMBURSEMENT.save(
+        deps.storage,
+        &RelayerReimbursement {
+            amount_per_operation: amount_per_operation.clone(),
+            pool,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute(
+            "action",
+            ContractActions::UpdateRelayerReimbursement.as_str(),
+        )
+        .add_attribute("sender", sender)
+        .add_attribute("amount_per_operation", amount_per_operation.to_string()))
+}
+
+fn fund_reimbursement_pool(deps: DepsMut, info: MessageInfo) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &info.sender,
+        &ContractActions::FundReimbursementPool,
+    )?;
+
+    let mut reimbursement = RELAYER_REIMBURSEMENT
+        .may_load(deps.storage)?
+        .ok_or(ContractError::RelayerReimbursementNotConfigured {})?;
+
+    let denom = reimbursement.amount_per_operation.denom.clone();
+    let amount = must_pay(&info, &denom)?;
+
+    // Funds in the pool are owed to relayers so they are accounted as reserved fees
+    reserve_fee(deps.storage, &denom, amount)?;
+    reimbursement.pool = reimbursement.pool.checked_add(amount)?;
+    RELAYER_REIMBURSEMENT.save(deps.storage, &reimbursement)?;
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::FundReimbursementPool.as_str())
+        .add_attribute("sender", info.sender)
+        .add_attribute("amount", coin(amount.u128(), denom).to_string())
+        .add_attribute("pool", reimbursement.pool.to_string()))
+}
+
+fn rescue_funds(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    denom: String,
+    amount: Uint128,
+    recipient: String,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::RescueFunds,
+    )?;
+    let recipient = deps.api.addr_validate(&recipient)?;
+
+    // Tokens that are not registered (or that never moved through the bridge) have no liabilities, so all their balance is surplus
+    let liabilities = load_token_accounting(deps.storage, &denom)?.liabilities()?;
+    let balance = deps
+        .querier
+        .query_balance(env.contract.address, denom.clone())?
+        .amount;
+    let surplus = balance.saturating_sub(liabilities);
+
+    if amount.is_zero() || amount.gt(&surplus) {
+        return Err(ContractError::NotEnoughSurplusToRescue { denom, surplus });
+    }
+
+    let send_msg = BankMsg::Send {
+        to_address: recipient.to_string(),
+        amount: coins(amount.u128(), denom.clone()),
+    };
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::RescueFunds.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("denom", denom)
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("recipient", recipient)
+        .add_message(send_msg))
+}
+
+fn sweep_fee_remainders(
+    deps: DepsMut,
+    sender: Addr,
+    denom: String,
+    threshold: Uint128,
+    recipient: String,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::SweepFeeRemainder,
+    )?;
+    let recipient = deps.api.addr_validate(&recipient)?;
+
+    let amount = sweep_fee_remainder(deps.storage, &denom, threshold)?;
+
+    let send_msg = BankMsg::Send {
+        to_address: recipient.to_string(),
+        amount: coins(amount.u128(), denom.clone()),
+    };
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::SweepFeeRemainder.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("denom", denom)
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("recipient", recipient)
+        .add_message(send_msg))
+}
+
+fn start_maintenance(deps: DepsMut, sender: Addr) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::StartMaintenance,
+    )?;
+    // Only an active bridge can be put in maintenance
+    assert_bridge_active(deps.as_ref())?;
+    update_bridge_state(deps.storage, BridgeState::Maintenance)?;
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::StartMaintenance.as_str())
+        .add_attribute("sender", sender))
+}
+
+fn rotate_keys(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    new_relayers: Vec<Relayer>,
+    new_evidence_threshold: u32,
+) -> CoreumResult<ContractError> {
+    check_authorization(deps.as_ref().storage, &sender, &ContractActions::RotateKeys)?;
+
+    // If there is already a pending rotate keys ongoing, we don't allow another one until that one is confirmed
+    if PENDING_ROTATE_KEYS.load(deps.storage)? {
+        return Err(ContractError::RotateKeysOngoing {});
+    }
+    // We set the pending rotate keys flag to true so that we don't allow another rotate keys operation until this one is confirmed
+    PENDING_ROTATE_KEYS.save(deps.storage, &true)?;
+
+    // We halt the bridge. If it was already halted we keep the original reason
+    if BRIDGE_STATE_CONFIG.load(deps.storage)?.halt_info.is_none() {
+        save_halt_info(
+            deps.storage,
+            HaltInfo {
+                reason: HaltReason {
+                    code: HaltReasonCode::RotateKeys,
+                    message: None,
+                },
+                halted_by: sender.clone(),
+                halted_at: env.block.time.seconds(),
+            },
+        )?;
+    }
+    update_bridge_state(deps.storage, BridgeState::Halted)?;
+
+    // Validate the new relayer set so that we are sure that the new set is valid (e.g. no duplicated relayers, etc.)
+    validate_relayers(
+        deps.as_ref(),
+        &new_relayers,
+        new_evidence_threshold,
+        RELAYERS_CONFIG.load(deps.storage)?.max_relayers,
+    )?;
+
+    // The signer list counts as a single object no matter how many signers it has, and SignerListSet replaces the current one,
+    // so the new relayer set doesn't add to the reserve but the account must still be able to pay for the transaction
+    check_xrpl_reserve(deps.storage, env.block.time.seconds(), 0, 1)?;
+
+    let ticket = allocate_ticket(deps.storage)?;
+
+    create_pending_operation(
+        deps.storage,
+        &env.block,
+        Some(ticket),
+        None,
+        OperationType::RotateKeys {
+            new_relayers,
+            new_evidence_threshold,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::RotateKeys.as_str())
+        .add_attribute("sender", sender))
+}
+
+fn add_prohibited_xrpl_recipient(
+    deps: DepsMut,
+    sender: Addr,
+    recipient: String,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::AddProhibitedXRPLRecipient,
+    )?;
+
+    // Validate the address that we are adding, to not add useless things
+    // X-addresses are stored as their classic address so that the address is prohibited for any destination tag
+    let (prohibited_xrpl_address, _) = decode_xrpl_recipient(deps.storage, &recipient)?;
+    PROHIBITED_XRPL_ADDRESSES.save(deps.storage, prohibited_xrpl_address.clone(), &Empty {})?;
+
+    Ok(Response::new()
+        .add_attribute(
+            "action",
+            ContractActions::AddProhibitedXRPLRecipient.as_str(),
+        )
+        .add_attribute("sender", sender)
+        .add_attribute("recipient", prohibited_xrpl_address))
+}
+
+fn remove_prohibited_xrpl_recipient(
+    deps: DepsMut,
+    sender: Addr,
+    recipient: String,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::RemoveProhibitedXRPLRecipient,
+    )?;
+
+    let (prohibited_xrpl_address, _) = decode_xrpl_recipient(deps.storage, &recipient)?;
+
+    // The current multisig address is always prohibited
+    let xrpl_config = XRPL_CONFIG.load(deps.storage)?;
+    if prohibited_xrpl_address == xrpl_config.bridge_xrpl_address {
+        return Err(ContractError::CannotRemoveBridgeXRPLAddress {});
+    }
+
+    PROHIBITED_XRPL_ADDRESSES.remove(deps.storage, prohibited_xrpl_address.clone());
+
+    Ok(Response::new()
+        .add_attribute(
+            "action",
+            ContractActions::RemoveProhibitedXRPLRecipient.as_str(),
+        )
+        .add_attribute("sender", sender)
+        .add_attribute("recipient", prohibited_xrpl_address))
+}
+
+fn set_xrpl_recipient_rule(
+    deps: DepsMut,
+    sender: Addr,
+    name: String,
+    rule: XRPLRecipientRule,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::SetXRPLRecipientRule,
+    )?;
+
+    if name.is_empty() {
+        return Err(ContractError::InvalidXRPLRecipientRule {});
+    }
+
+    if let XRPLRecipientRule::RequireDestinationTag { xrpl_addresses } = &rule {
+        if xrpl_addresses.is_empty() {
+            return Err(ContractError::InvalidXRPLRecipientRule {});
+        }
+        for xrpl_address in xrpl_addresses {
+            validate_xrpl_address_format(xrpl_address)?;
+        }
+    }
+
+    XRPL_RECIPIENT_RULES.save(deps.storage, name.clone(), &rule)?;
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::SetXRPLRecipientRule.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("name", name))
+}
+
+fn remove_xrpl_recipient_rule(
+    deps: DepsMut,
+    sender: Addr,
+    name: String,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::RemoveXRPLRecipientRule,
+    )?;
+
+    if !XRPL_RECIPIENT_RULES.has(deps.storage, name.clone()) {
+        return Err(ContractError::XRPLRecipientRuleNotFound {});
+    }
+    XRPL_RECIPIENT_RULES.remove(deps.storage, name.clone());
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::RemoveXRPLRecipientRule.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("name", name))
+}
+
+fn cancel_pending_operation(
+    deps: DepsMut,
+    block: &BlockInfo,
+    sender: Addr,
+    operation_id: u64,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::CancelPendingOperation,
+    )?;
+
+    let operation = check_operation_exists(deps.storage, operation_id)?;
+    let mut response = Response::new();
+    cancel_operation(deps.storage, &operation, operation_id, block, &mut response)?;
+
+    Ok(response
+        .add_attribute("action", ContractActions::CancelPendingOperation.as_str())
+        .add_attribute("sender", sender))
+}
+
+fn cancel_pending_operations(
+    deps: DepsMut,
+    block: &BlockInfo,
+    sender: Addr,
+    operation_ids: Vec<u64>,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::CancelPendingOperations,
+    )?;
+
+    if operation_ids.is_empty() || operation_ids.len() > MAX_OPERATIONS_PER_CANCELLATION {
+        return Err(ContractError::InvalidOperationsToCancel {});
+    }
+
+    // Each operation is cancelled as if it was cancelled on its own, so a duplicated id fails because it's not pending anymore
+    let mut response = Response::new();
+    for operation_id in operation_ids.iter() {
+        let operation = check_operation_exists(deps.storage, *operation_id)?;
+        cancel_operation(
+            deps.storage,
+            &operation,
+            *operation_id,
+            block,
+            &mut response,
+        )?;
+    }
+
+    Ok(response
+        .add_attribute("action", ContractActions::CancelPendingOperations.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute(
+            "operation_ids",
+            operation_ids
+                .iter()
+                .map(|operation_id| operation_id.to_string())
+                .collect::<Vec<String>>()
+                .join(","),
+        ))
+}
+
+fn expire_operation(
+    deps: DepsMut,
+    block: &BlockInfo,
+    sender: Addr,
+    operation_id: u64,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::ExpireOperation,
+    )?;
+
+    let operation = check_operation_exists(deps.storage, operation_id)?;
+    // Operations created before evidence deadlines existed can only be cancelled by the owner
+    let evidence_deadline = match OPERATION_EVIDENCE_DEADLINES
+        .may_load(deps.storage, operation_id)?
+    {
+        Some(evidence_deadline) if evidence_deadline < block.time.seconds() => evidence_deadline,
+        _ => return Err(ContractError::OperationNotExpired {}),
+    };
+
+    let mut response = Response::new();
+    cancel_operation(deps.storage, &operation, operation_id, block, &mut response)?;
+
+    Ok(response
+        .add_attribute("action", ContractActions::ExpireOperation.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("operation_id", operation_id.to_string())
+        .add_attribute("evidence_deadline", evidence_deadline.to_string()))
+}
+
+fn cancel_halted_transfer(
+    deps: DepsMut,
+    block: &BlockInfo,
+    sender: Addr,
+    operation_id: u64,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::CancelHaltedTransfer,
+    )?;
+
+    // Senders don't have to wait for the incident to be resolved to get their funds back
+    let bridge_state_config = BRIDGE_STATE_CONFIG.load(deps.storage)?;
+    if bridge_state_config.bridge_state.ne(&BridgeState::Halted) {
+        return Err(ContractError::BridgeNotHalted {});
+    }
+
+    let operation = check_operation_exists(deps.storage, operation_id)?;
+    match &operation.operation_type {
+        OperationType::CoreumToXRPLTransfer {
+            sender: transfer_sender,
+            ..
+        } if transfer_sender.eq(&sender) => (),
+        _ => return Err(ContractError::NotTransferSender {}),
+    }
+
+    // Once relayers started signing, the transaction might be submitted to XRPL
+    if !operation.signatures.is_empty() {
+        return Err(ContractError::OperationAlreadySigned {});
+    }
+
+    let mut response = Response::new();
+    cancel_operation(deps.storage, &operation, operation_id, block, &mut response)?;
+
+    Ok(response
+        .add_attribute("action", ContractActions::CancelHaltedTransfer.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("operation_id", operation_id.to_string()))
+}
+
+// Cancels a pending operation as if relayers had provided an evidence of an invalid transaction, so that the ticket is returned and transfers are refunded
+fn cancel_operation(
+    storage: &mut dyn Storage,
+    operation: &Operation,
+    operation_id: u64,
+    block: &BlockInfo,
+    response: &mut Response<CoreumMsg>,
+) -> Result<(), ContractError> {
+    // We'll provide a TransactionResult::Invalid evidence to the handlers so that they perform the right action
+    let transaction_result = &TransactionResult::Invalid;
+    let operation_result = match operation.operation_type {
+        OperationType::AllocateTickets { .. } => Some(TicketsAllocation { tickets: None }),
+        _ => None,
+    };
+
+    // We handle the operation with an invalid result
+    handle_operation(
+        storage,
+        operation,
+        &operation_result,
+        transaction_result,
+        &None,
+        operation_id,
+        operation.ticket_sequence,
+        block,
+        &[],
+        response,
+    )
+}
+
+fn update_operation_evidence_period(
+    deps: DepsMut,
+    sender: Addr,
+    operation_evidence_period: u64,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateOperationEvidencePeriod,
+    )?;
+
+    if operation_evidence_period == 0 {
+        return Err(ContractError::InvalidOperationEvidencePeriod {});
+    }
+
+    let mut operations_config = OPERATIONS_CONFIG.load(deps.storage)?;
+    operations_config.operation_evidence_period = operation_evidence_period;
+    OPERATIONS_CONFIG.save(deps.storage, &operations_config)?;
+
+    Ok(Response::new()
+        .add_attribute(
+            "action",
+            ContractActions::UpdateOperationEvidencePeriod.as_str(),
+        )
+        .add_attribute("sender", sender)
+        .add_attribute(
+            "operation_evidence_period",
+            operation_evidence_period.to_string(),
+        ))
+}
+
+fn update_light_client_config(
+    deps: DepsMut,
+    sender: Addr,
+    light_client: Option<LightClientConfig>,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateLightClientConfig,
+    )?;
+
+    if let Some(light_client) = &light_client {
+        validate_light_client_config(light_client)?;
+    }
+
+    let mut xrpl_config = XRPL_CONFIG.load(deps.storage)?;
+    xrpl_config.light_client = light_client;
+    XRPL_CONFIG.save(deps.storage, &xrpl_config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::UpdateLightClientConfig.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute(
+            "light_client_enabled",
+            xrpl_config.light_client.is_some().to_string(),
+        ))
+}
+
+fn save_xrpl_transaction_proof(
+    deps: DepsMut,
+    sender: Addr,
+    proof: XRPLTransactionProof,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::SaveXRPLTransactionProof,
+    )?;
+
+    let xrpl_config = XRPL_CONFIG.load(deps.storage)?;
+    let light_client = xrpl_config
+        .light_client
+        .as_ref()
+        .ok_or(ContractError::LightClientNotEnabled {})?;
+
+    let proven_transaction =
+        verify_xrpl_transaction_proof(deps.api, &xrpl_config, light_client, &proof)?;
+
+    // No point proving a transaction that was already processed
+    if PROCESSED_TXS.has(deps.storage, proven_transaction.tx_hash.clone()) {
+        return Err(ContractError::OperationAlreadyExecuted {});
+    }
+
+    PROVEN_XRPL_TRANSACTIONS.save(
+        deps.storage,
+        proven_transaction.tx_hash.clone(),
+        &proven_transaction,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::SaveXRPLTransactionProof.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("tx_hash", proven_transaction.tx_hash)
+        .add_attribute("ledger_index", proven_transaction.ledger_index.to_string()))
+}
+
+fn update_evidence_signing_key(
+    deps: DepsMut,
+    sender: Addr,
+    relayer_coreum_address: Addr,
+    pub_key: Option<String>,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateEvidenceSigningKey,
+    )?;
+
+    if !is_relayer(deps.storage, &relayer_coreum_address)? {
+        return Err(ContractError::NotARelayer {});
+    }
+
+    match &pub_key {
+        Some(pub_key) => {
+            validate_evidence_signing_key(pub_key)?;
+            EVIDENCE_SIGNING_KEYS.save(deps.storage, relayer_coreum_address.clone(), pub_key)?;
+        }
+        None => EVIDENCE_SIGNING_KEYS.remove(deps.storage, relayer_coreum_address.clone()),
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::UpdateEvidenceSigningKey.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("relayer_coreum_address", relayer_coreum_address)
+        .add_attribute("pub_key", pub_key.unwrap_or_default()))
+}
+
+fn update_evidence_delegate(
+    deps: DepsMut,
+    sender: Addr,
+    delegate: Option<String>,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateEvidenceDelegate,
+    )?;
+
+    match &delegate {
+        Some(delegate) => {
+            let delegate = deps.api.addr_validate(delegate)?;
+            // Delegates must not be able to act as anyone else than the relayer that registered them
+            if is_owner(deps.storage, &delegate)?
+                || is_relayer(deps.storage, &delegate)?
+                || is_evidence_delegate(deps.storage, &delegate)?
+            {
+                return Err(ContractError::InvalidEvidenceDelegate {});
+            }
+            EVIDENCE_DELEGATES.save(deps.storage, sender.clone(), &delegate)?;
+        }
+        None => EVIDENCE_DELEGATES.remove(deps.storage, sender.clone()),
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::UpdateEvidenceDelegate.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("delegate", delegate.unwrap_or_default()))
+}
+
+fn update_evidence_source(
+    deps: DepsMut,
+    sender: Addr,
+    source: Option<String>,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateEvidenceSource,
+    )?;
+
+    match &source {
+        Some(source) => {
+            if source.is_empty() || source.len() > MAX_EVIDENCE_SOURCE_LENGTH {
+                return Err(ContractError::InvalidEvidenceSource {});
+            }
+            EVIDENCE_SOURCES.save(deps.storage, sender.clone(), source)?;
+        }
+        None => EVIDENCE_SOURCES.remove(deps.storage, sender.clone()),
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::UpdateEvidenceSource.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("source", source.unwrap_or_default()))
+}
+
+fn update_min_distinct_evidence_sources(
+    deps: DepsMut,
+    sender: Addr,
+    min_distinct_evidence_sources: u32,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateMinDistinctEvidenceSources,
+    )?;
+
+    let mut relayers_config = RELAYERS_CONFIG.load(deps.storage)?;
+    if min_distinct_evidence_sources > relayers_config.relayers.len() as u32 {
+        return Err(ContractError::InvalidThreshold {});
+    }
+    relayers_config.min_distinct_evidence_sources = min_distinct_evidence_sources;
+    RELAYERS_CONFIG.save(deps.storage, &relayers_config)?;
+
+    Ok(Response::new()
+        .add_attribute(
+            "action",
+            ContractActions::UpdateMinDistinctEvidenceSources.as_str(),
+        )
+        .add_attribute("sender", sender)
+        .add_attribute(
+            "min_distinct_evidence_sources",
+            min_distinct_evidence_sources.to_string(),
+        ))
+}
+
+fn update_max_relayers(
+    deps: DepsMut,
+    sender: Addr,
+    max_relayers: u32,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateMaxRelayers,
+    )?;
+
+    // The current relayers must still fit and XRPL doesn't accept signer lists with more signers
+    let mut relayers_config = RELAYERS_CONFIG.load(deps.storage)?;
+    if max_relayers > XRPL_MAX_SIGNERS || (max_relayers as usize) < relayers_config.relayers.len() {
+        return Err(ContractError::InvalidMaxRelayers {});
+    }
+    relayers_config.max_relayers = max_relayers;
+    RELAYERS_CONFIG.save(deps.storage, &relayers_config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::UpdateMaxRelayers.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute("max_relayers", max_relayers.to_string()))
+}
+
+fn update_relayer_attestation(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    xrpl_node_fingerprint: Option<String>,
+    software_version: Option<String>,
+    contact: Option<String>,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::UpdateRelayerAttestation,
+    )?;
+
+    for field in [&xrpl_node_fingerprint, &software_version, &contact]
+        .into_iter()
+        .flatten()
+    {
+        if field.is_empty() || field.len() > MAX_RELAYER_ATTESTATION_FIELD_LENGTH {
+            return Err(ContractError::InvalidRelayerAttestation {});
+        }
+    }
+
+    RELAYER_ATTESTATIONS.save(
+        deps.storage,
+        sender.clone(),
+        &RelayerAttestation {
+            xrpl_node_fingerprint: xrpl_node_fingerprint.clone(),
+            software_version: software_version.clone(),
+            contact: contact.clone(),
+            updated_at: env.block.time.seconds(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", ContractActions::UpdateRelayerAttestation.as_str())
+        .add_attribute("sender", sender)
+        .add_attribute(
+            "xrpl_node_fingerprint",
+            xrpl_node_fingerprint.unwrap_or_default(),
+        )
+        .add_attribute("software_version", software_version.unwrap_or_default())
+        .add_attribute("contact", contact.unwrap_or_default()))
+}
+
+fn execute_emergency_withdrawal(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+) -> CoreumResult<ContractError> {
+    check_authorization(
+        deps.as_ref().storage,
+        &sender,
+        &ContractActions::ExecuteEmergencyWithdrawal,
+    )?;
+
+    let emergency_withdrawal = EMERGENCY_WITHDRAWAL
+        .may_load(deps.storage)?
+        .ok_or(ContractError::EmergencyWithdrawalNotScheduled {})?;
+
+    if env.block.time.seconds() < emergency_withdrawal.executable_at {
+        return Err(ContractError::EmergencyWithdrawalNotReady {
+            executable_at: emergency_withdrawal.executable_at,
+        });
+    }
+
+    EMERGENCY_WITHDRAWAL.remove(deps.storage);
+
+    let amounts = to_json_string(&emergency_withdrawal.amounts)?;
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: emergency_withdrawal.recipient.to_string(),
+            amount: emergency_withdrawal.amounts,
+        })
+        .add_attribute(
+            "action",
+            ContractActions::ExecuteEmergencyWithdrawal.as_str(),
+        )
+        .add_attribute("sender", sender)
+        .add_event(
+            Event::new("emergency_withdrawal_executed")
+                .add_attribute("recipient", emergency_withdrawal.recipient)
+                .add_attribute("amounts", amounts),
+        ))
+}
+
+// ********** Sudo **********
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn sudo(deps: DepsMut<CoreumQueries>, env: Env, msg: SudoMsg) -> CoreumResult<ContractError> {
+    match msg {
+        SudoMsg::ScheduleEmergencyWithdrawal { recipient, amounts } => {
+            schedule_emergency_withdrawal(deps.into_empty(), env, recipient, amounts)
+        }
+        SudoMsg::CancelEmergencyWithdrawal {} => cancel_emergency_withdrawal(deps.into_empty()),
+    }
+}
+
+fn schedule_emergency_withdrawal(
+    deps: DepsMut,
+    env: Env,
+    recipient: String,
+    amounts: Vec<Coin>,
+) -> CoreumResult<ContractError> {
+    if EMERGENCY_WITHDRAWAL.exists(deps.storage) {
+        return Err(ContractError::EmergencyWithdrawalAlreadyScheduled {});
+    }
+
+    let recipient = deps.api.addr_validate(&recipient)?;
+
+    if amounts.is_empty() {
+        return Err(ContractError::InvalidEmergencyWithdrawal {});
+    }
+    let mut denoms = Vec::with_capacity(amounts.len());
+    for amount in amounts.iter() {
+        if amount.amount.is_zero() || denoms.contains(&amount.denom) {
+            return Err(ContractError::InvalidEmergencyWithdrawal {});
+        }
+        denoms.push(amount.denom.clone());
+    }
+
+    let scheduled_at = env.block.time.seconds();
+    let executable_at = scheduled_at.saturating_add(EMERGENCY_WITHDRAWAL_DELAY);
+
+    EMERGENCY_WITHDRAWAL.save(
+        deps.storage,
+        &EmergencyWithdrawal {
+            recipient: recipient.clone(),
+            amounts: amounts.clone(),
+            scheduled_at,
+            executable_at,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute(
+            "action",
+            ContractActions::ScheduleEmergencyWithdrawal.as_str(),
+        )
+        .add_event(
+            Event::new("emergency_withdrawal_scheduled")
+                .add_attribute("recipient", recipient)
+                .add_attribute("amounts", to_json_string(&amounts)?)
+                .add_attribute("executable_at", executable_at.to_string()),
+        ))
+}
+
+fn cancel_emergency_withdrawal(deps: DepsMut) -> CoreumResult<ContractError> {
+    if !EMERGENCY_WITHDRAWAL.exists(deps.storage) {
+        return Err(ContractError::EmergencyWithdrawalNotScheduled {});
+    }
+
+    EMERGENCY_WITHDRAWAL.remove(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute(
+            "action",
+            ContractActions::CancelEmergencyWithdrawal.as_str(),
+        )
+        .add_event(Event::new("emergency_withdrawal_cancelled")))
+}
+
+// ********** Replies **********
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut<CoreumQueries>, env: Env, msg: Reply) -> CoreumResult<ContractError> {
+    match msg.id {
+        DELIVERY_REPLY_ID => {
+            handle_delivery_reply(deps.into_empty(), env.block.time.seconds(), msg.result)
+        }
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
+
+fn handle_delivery_reply(
+    deps: DepsMut,
+    timestamp: u64,
+    result: SubMsgResult,
+) -> CoreumResult<ContractError> {
+    // Submessages are executed in the same order they were added, so the first pending delivery is the one replying
+    let mut pending_deliveries = PENDING_DELIVERY.load(deps.storage)?;
+    let pending_delivery = pending_deliveries.remove(0);
+    if pending_deliveries.is_empty() {
+        PENDING_DELIVERY.remove(deps.storage);
+    } else {
+        PENDING_DELIVERY.save(deps.storage, &pending_deliveries)?;
+    }
+
+    match result {
+        SubMsgResult::Ok(_) => {
+            record_inbound_transfer(
+                deps.storage,
+                &pending_delivery.recipient,
+                pending_delivery.xrpl_tx_hash,
+                pending_delivery.coin,
+                timestamp,
+            )?;
+            Ok(Response::new())
+        }
+        // If the delivery failed we don't error so that the evidence is still processed
+        // The recipient can claim the tokens like any other pending refund once the delivery is possible
+        SubMsgResult::Err(error) => {
+            hold_back_transfer(
+                deps.storage,
+                &pending_delivery.recipient,
+                pending_delivery.xrpl_tx_hash.clone(),
+                pending_delivery.coin.clone(),
+                timestamp,
+            )?;
+
+            Ok(Response::new()
+                .add_attribute("holdback_id", pending_delivery.xrpl_tx_hash)
+                .add_attribute("recipient", pending_delivery.recipient)
+                .add_attribute("coin", pending_delivery.coin.to_string())
+                .add_attribute("delivery_error", error))
+        }
+    }
+}
+
+// Keeps a transfer from XRPL that couldn't be delivered so that the recipient can claim it later
+fn hold_back_transfer(
+    storage: &mut dyn Storage,
+    recipient: &Addr,
+    xrpl_tx_hash: String,
+    coin: Coin,
+    timestamp: u64,
+) -> Result<(), ContractError> {
+    // The XRPL transaction hash is unique so we use it as the id of the holdback
+    store_pending_refund(
+        storage,
+        xrpl_tx_hash.clone(),
+        Some(xrpl_tx_hash.clone()),
+        recipient.clone(),
+        coin,
+    )?;
+    UNDELIVERED_TRANSFERS.save(
+        storage,
+        (recipient.clone(), xrpl_tx_hash.clone()),
+        &Empty {},
+    )?;
+    record_undelivered_transfer(storage, &xrpl_tx_hash, timestamp)
+}
+
+// ********** Queries **********
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
+        QueryMsg::XRPLTokens {
+            start_after_key,
+            limit,
+        } => to_json_binary(&query_xrpl_tokens(deps, start_after_key, limit)),
+        QueryMsg::InvalidDeposits {
+            start_after_key,
+            limit,
+        } => to_json_binary(&query_invalid_deposits(deps, start_after_key, limit)),
+        QueryMsg::XRPLReserve {} => to_json_binary(&query_xrpl_reserve(deps, env)?),
+        QueryMsg::RelayersActivity {} => to_json_binary(&query_relayers_activity(deps)?),
+        QueryMsg::OperationSubmission { operation_id } => {
+            to_json_binary(&query_operation_submission(deps, operation_id)?)
+        }
+        QueryMsg::OperationEvidenceDeadline { operation_id } => {
+            to_json_binary(&query_operation_evidence_deadline(deps, env, operation_id)?)
+        }
+        QueryMsg::PendingHighRiskActions {} => {
+            to_json_binary(&query_pending_high_risk_actions(deps)?)
+        }
+        QueryMsg::BlockedClaims {
+            start_after_key,
+            limit,
+        } => to_json_binary(&query_blocked_claims(deps, env, start_after_key, limit)?),
+        QueryMsg::DeferredInboundTransfers {
+            start_after_key,
+            limit,
+        } => to_json_binary(&query_deferred_inbound_transfers(
+            deps,
+            env,
+            start_after_key,
+            limit,
+        )?),
+        QueryMsg::StaleOperations {
+            min_age,
+            missing_quorum_for,
+            start_after_key,
+            limit,
+        } => to_json_binary(&query_stale_operations(
+            deps,
+            env,
+            min_age,
+            missing_quorum_for,
+            start_after_key,
+            limit,
+        )?),
+        QueryMsg::XRPLSupplyDrifts {
+            start_after_key,
+            limit,
+        } => to_json_binary(&query_xrpl_supply_drifts(deps, start_after_key, limit)),
+        QueryMsg::XRPLTokenSupplies {
+            start_after_key,
+            limit,
+        } => to_json_binary(&query_xrpl_token_supplies(deps, start_after_key, limit)?),
+        QueryMsg::CoreumTokens {
+            start_after_key,
+            limit,
+        } => to_json_binary(&query_coreum_tokens(deps, start_after_key, limit)),
+        QueryMsg::Ownership {} => to_json_binary(&get_ownership(deps.storage)?),
+        QueryMsg::PendingOperations {
+            start_after_key,
+            limit,
+        } => to_json_binary(&query_pending_operations(deps, start_after_key, limit)),
+        QueryMsg::PrioritizedPendingOperations {
+            start_after_key,
+            limit,
+        } => to_json_binary(&query_prioritized_pending_operations(
+            deps,
+            start_after_key,
+            limit,
+        )?),
+        QueryMsg::OperationSigningPayload { operation_id } => {
+            to_json_binary(&query_operation_signing_payload(deps, operation_id)?)
+        }
+        QueryMsg::AvailableTickets {} => to_json_binary(&query_available_tickets(deps)?),
+        QueryMsg::PendingRefunds {
+            address,
+            start_after_key,
+            limit,
+        } => to_json_binary(&query_pending_refunds(
+            deps,
+            address,
+            start_after_key,
+            limit,
+        )),
+        QueryMsg::PendingRefundTotals { address } => {
+            to_json_binary(&query_pending_refund_totals(deps, address)?)
+        }
+        QueryMsg::FeesCollected { relayer_address } => {
+            to_json_binary(&query_fees_collected(deps, relayer_address)?)
+        }
+        QueryMsg::DustAccounting { denom } => to_json_binary(&query_dust_accounting(deps, denom)?),
+        QueryMsg::FeeAccounting { denom } => to_json_binary(&query_fee_accounting(deps, denom)?),
+        QueryMsg::FeeRemainders {
+            start_after_key,
+            limit,
+        } => to_json_binary(&query_fee_remainders(deps, start_after_key, limit)?),
+        QueryMsg::UndeliveredTransfers {
+            start_after_key,
+            limit,
+        } => to_json_binary(&query_undelivered_transfers(deps, start_after_key, limit)?),
+        QueryMsg::BridgeState {} => to_json_binary(&query_bridge_state(deps)?),
+        QueryMsg::HaltRequests {} => to_json_binary(&query_halt_requests(deps)?),
+        QueryMsg::BridgeStateAcknowledgements {} => {
+            to_json_binary(&query_bridge_state_acknowledgements(deps)?)
+        }
+        QueryMsg::XRPLDexQuote { denom } => {
+            to_json_binary(&query_xrpl_dex_quote(deps, env, denom)?)
+        }
+        QueryMsg::XRPLRecipientFlags { account } => {
+            to_json_binary(&query_xrpl_recipient_flags(deps, account)?)
+        }
+        QueryMsg::XRPLIssuerConfig { issuer } => {
+            to_json_binary(&query_xrpl_issuer_config(deps, issuer)?)
+        }
+        QueryMsg::TokenFreeze { denom } => {
+            to_json_binary(&query_token_freeze(deps, env.block.time.seconds(), denom)?)
+        }
+        QueryMsg::RelayerReimbursement {} => to_json_binary(&query_relayer_reimbursement(deps)?),
+        QueryMsg::RegisteredXRPLTokens {} => to_json_binary(&query_registered_xrpl_tokens(deps)?),
+        QueryMsg::TransactionEvidence { hash } => {
+            to_json_binary(&query_transaction_evidence(deps, hash)?)
+        }
+        QueryMsg::TransactionEvidences {
+            start_after_key,
+            limit,
+        } => to_json_binary(&query_transaction_evidences(deps, start_after_key, limit)),
+        QueryMsg::StaleEvidences {
+            start_after_key,
+            limit,
+        } => to_json_binary(&query_stale_evidences(
+            deps,
+            env.block.time.seconds(),
+            start_after_key,
+            limit,
+        )?),
+        QueryMsg::ProcessedTx { hash } => to_json_binary(&query_processed_tx(deps, hash)),
+        QueryMsg::ProcessedTxs {
+            start_after_key,
+            limit,
+        } => to_json_binary(&query_processed_txs(deps, start_after_key, limit)),
+        QueryMsg::ProhibitedXRPLAddresses {
+            start_after_key,
+            limit,
+        } => to_json_binary(&query_prohibited_xrpl_addresses(
+            deps,
+            start_after_key,
+            limit,
+        )),
+        QueryMsg::XRPLRecipientRules {
+            start_after_key,
+            limit,
+        } => to_json_binary(&query_xrpl_recipient_rules(deps, start_after_key, limit)?),
+        QueryMsg::ProvenXRPLTransaction { tx_hash } => {
+            to_json_binary(&query_proven_xrpl_transaction(deps, tx_hash)?)
+        }
+        QueryMsg::EvidenceSigningKeys {} => to_json_binary(&query_evidence_signing_keys(deps)),
+        QueryMsg::EvidenceDelegates {} => to_json_binary(&query_evidence_delegates(deps)),
+        QueryMsg::EvidenceSources {} => to_json_binary(&query_evidence_sources(deps)?),
+        QueryMsg::RelayerAttestations {} => to_json_binary(&query_relayer_attestations(deps)),
+        QueryMsg::EmergencyWithdrawal {} => to_json_binary(&query_emergency_withdrawal(deps)?),
+        QueryMsg::RecurringTransfers {
+            start_after_key,
+            limit,
+        } => to_json_binary(&query_recurring_transfers(deps, start_after_key, limit)),
+        QueryMsg::CommitRevealThreshold { denom } => {
+            to_json_binary(&query_commit_reveal_threshold(deps, denom)?)
+        }
+        QueryMsg::TransferCommitments {
+            sender,
+            start_after_key,
+            limit,
+        } => to_json_binary(&query_transfer_commitments(
+            deps,
+            sender,
+            start_after_key,
+            limit,
+        )?),
+        QueryMsg::XRPLAddress { address } => to_json_binary(&query_xrpl_address(deps, address)),
+        QueryMsg::CoreumToXRPLAmount { denom, amount } => {
+            to_json_binary(&query_coreum_to_xrpl_amount(deps, env, denom, amount)?)
+        }
+        QueryMsg::XRPLToCoreumAmount {
+            issuer,
+            currency,
+            amount,
+        } => to_json_binary(&query_xrpl_to_coreum_amount(
+            deps, env, issuer, currency, amount,
+        )?),
+        QueryMsg::USDBridgingFee { denom } => {
+            to_json_binary(&query_usd_bridging_fee(deps, env, denom)?)
+        }
+        QueryMsg::Solvency {
+            start_after_key,
+            limit,
+        } => to_json_binary(&query_solvency(deps, env, start_after_key, limit)?),
+        QueryMsg::XRPLCurrencyForDenom { denom } => {
+            to_json_binary(&query_xrpl_currency_for_denom(denom)?)
+        }
+        QueryMsg::TokenMetadata { denom } => to_json_binary(&query_token_metadata(deps, denom)?),
+        QueryMsg::TokenStateHistory {
+            denom,
+            issuer,
+            currency,
+        } => to_json_binary(&query_token_state_history(deps, denom, issuer, currency)?),
+        QueryMsg::BridgeActivitySnapshot { epoch } => {
+            to_json_binary(&query_bridge_activity_snapshot(deps, env, epoch)?)
+        }
+        QueryMsg::RelayerFeeClaims {
+            relayer_address,
+            start_after_key,
+            limit,
+        } => to_json_binary(&query_relayer_fee_claims(
+            deps,
+            relayer_address,
+            start_after_key,
+            limit,
+        )),
+        QueryMsg::RelayerInfractions {
+            relayer_address,
+            start_after_key,
+            limit,
+        } => to_json_binary(&query_relayer_infractions(
+            deps,
+            relayer_address,
+            start_after_key,
+            limit,
+        )),
+        QueryMsg::InboundTransfers {
+            recipient,
+            start_after_key,
+            limit,
+        } => to_json_binary(&query_inbound_transfers(
+            deps,
+            recipient,
+            start_after_key,
+            limit,
+        )),
+        QueryMsg::ArchivedOperations {
+            start_after_key,
+            limit,
+        } => to_json_binary(&query_archived_operations(deps, start_after_key, limit)),
+        QueryMsg::StorageLayout {} => to_json_binary(&query_storage_layout()),
+        QueryMsg::TransferReceipt { id_or_hash } => {
+            to_json_binary(&query_transfer_receipt(deps, id_or_hash)?)
+        }
+        QueryMsg::TransferChunks { parent_transfer_id } => {
+            to_json_binary(&query_transfer_chunks(deps, parent_transfer_id)?)
+        }
+    }
+}
+
+fn query_config(deps: Deps) -> StdResult<Config> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(config)
+}
+
+fn query_bridge_state(deps: Deps) -> StdResult<BridgeStateResponse> {
+    let bridge_state_config = BRIDGE_STATE_CONFIG.load(deps.storage)?;
+    Ok(BridgeStateResponse {
+        state: bridge_state_config.bridge_state,
+        halt_info: bridge_state_config.halt_info,
+    })
+}
+
+fn query_token_state_history(
+    deps: Deps,
+    denom: Option<String>,
+    issuer: Option<String>,
+    currency: Option<String>,
+) -> StdResult<TokenStateHistoryResponse> {
+    // XRPL originated tokens can also be found by their issuer and currency
+    let denom = match (denom, issuer, currency) {
+        (Some(denom), None, None) => denom,
+        (None, Some(issuer), Some(currency)) => {
+            XRPL_TOKENS
+                .load(deps.storage, build_xrpl_token_key(&issuer, &currency))
+                .map_err(|_| {
+                    StdError::generic_err(ContractError::TokenNotRegistered {}.to_string())
+                })?
+                .coreum_denom
+        }
+        _ => {
+            return Err(StdError::generic_err(
+                ContractError::InvalidTokenIdentifier {}.to_string(),
+            ))
+        }
+    };
+
+    let transitions = TOKEN_STATE_HISTORY
+        .may_load(deps.storage, denom.clone())?
+        .unwrap_or_default();
+
+    Ok(TokenStateHistoryResponse { denom, transitions })
+}
+
+fn query_token_metadata(deps: Deps, denom: String) -> StdResult<TokenMetadata> {
+    let metadata = TOKEN_METADATA.load(deps.storage, denom)?;
+    Ok(metadata)
+}
+
+fn query_xrpl_currency_for_denom(denom: String) -> StdResult<XRPLCurrencyForDenomResponse> {
+    validate_coreum_denom(&denom)
+        .and_then(|_| generate_xrpl_currency(&denom))
+        .map(|xrpl_currency| XRPLCurrencyForDenomResponse { xrpl_currency })
+        .map_err(|e| StdError::generic_err(e.to_string()))
+}
+
+fn query_solvency(
+    deps: Deps,
+    env: Env,
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<SolvencyResponse> {
+    calculate_solvency(deps, env, start_after_key, limit)
+        .map_err(|e| StdError::generic_err(e.to_string()))
+}
+
+fn calculate_solvency(
+    deps: Deps,
+    env: Env,
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> Result<SolvencyResponse, ContractError> {
+    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT) as usize;
+
+    // Tokens of both origins are paginated together in the order of their denoms. A page can't have more than limit tokens of each origin,
+    // so we take that many of each and keep the first ones of both
+    let xrpl_denoms = XRPL_TOKENS
+        .idx
+        .coreum_denom
+        .range(
+            deps.storage,
+            start_after_key.clone().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .filter_map(Result::ok)
+        .map(|(_, token)| (token.coreum_denom, true));
+    let coreum_denoms = COREUM_TOKENS
+        .range(
+            deps.storage,
+            start_after_key.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .filter_map(Result::ok)
+        .map(|(denom, _)| (denom, false));
+    let mut denoms: Vec<(String, bool)> = xrpl_denoms.chain(coreum_denoms).collect();
+    denoms.sort();
+    denoms.truncate(limit);
+    let last_key = denoms.last().map(|(denom, _)| denom.clone());
+
+    let mut tokens = vec![];
+    for (denom, xrpl_originated) in denoms {
+        let accounting = load_token_accounting(deps.storage, &denom)?;
+        let liabilities = accounting.liabilities()?;
+
+        let balance = deps
+            .querier
+            .query_balance(env.contract.address.clone(), denom.clone())?
+            .amount;
+
+        tokens.push(TokenSolvency {
+            denom,
+            xrpl_originated,
+            minted: accounting.minted,
+            locked: accounting.locked,
+            refund_reserved: accounting.refund_reserved,
+            fee_reserved: accounting.fee_reserved,
+            liabilities,
+            balance,
+            surplus: balance.saturating_sub(liabilities),
+            shortfall: liabilities.saturating_sub(balance),
+        });
+    }
+
+    Ok(SolvencyResponse {
+        last_key,
+        solvent: tokens.iter().all(|token| token.shortfall.is_zero()),
+        tokens,
+    })
+}
+
+fn query_halt_requests(deps: Deps) -> StdResult<HaltRequestsResponse> {
+    let relayers_config = RELAYERS_CONFIG.load(deps.storage)?;
+    Ok(HaltRequestsResponse {
+        relayer_addresses: HALT_REQUESTS.load(deps.storage)?,
+        halt_request_threshold: relayers_config.halt_request_threshold,
+    })
+}
+
+fn query_bridge_state_acknowledgements(
+    deps: Deps,
+) -> StdResult<BridgeStateAcknowledgementsResponse> {
+    let relayers_config = RELAYERS_CONFIG.load(deps.storage)?;
+    let bridge_state_config = BRIDGE_STATE_CONFIG.load(deps.storage)?;
+    let mut acknowledgements = vec![];
+    let mut pending_relayers = vec![];
+    for relayer in relayers_config.relayers {
+        match BRIDGE_STATE_ACKNOWLEDGEMENTS
+            .may_load(deps.storage, relayer.coreum_address.clone())?
+        {
+            Some(acknowledged_at) => acknowledgements.push(BridgeStateAcknowledgement {
+                relayer_coreum_address: relayer.coreum_address,
+                acknowledged_at,
+            }),
+            None => pending_relayers.push(relayer.coreum_address),
+        }
+    }
+
+    Ok(BridgeStateAcknowledgementsResponse {
+        bridge_state: bridge_state_config.bridge_state,
+        acknowledgements,
+        pending_relayers,
+    })
+}
+
+fn query_xrpl_issuer_config(deps: Deps, issuer: String) -> StdResult<XRPLIssuerConfigResponse> {
+    Ok(XRPLIssuerConfigResponse {
+        config: XRPL_ISSUER_CONFIGS.may_load(deps.storage, issuer)?,
+    })
+}
+
+fn query_usd_bridging_fee(
+    deps: Deps,
+    env: Env,
+    denom: String,
+) -> StdResult<USDBridgingFeeResponse> {
+    // The fixed bridging fee and the decimals of the token are needed to know the fee that would be charged
+    let (bridging_fee, decimals) = match XRPL_TOKENS
+        .idx
+        .coreum_denom
+        .item(deps.storage, denom.clone())?
+        .map(|(_, token)| token)
+    {
+        Some(token) if is_token_xrp(&token.issuer, &token.currency) => {
+            (token.bridging_fee, XRP_DECIMALS)
+        }
+        Some(token) => (token.bridging_fee, XRPL_TOKENS_DECIMALS),
+        None => {
+            let token = COREUM_TOKENS.load(deps.storage, denom.clone())?;
+            (token.bridging_fee, token.decimals)
+        }
+    };
+
+    let bridging_fee = effective_bridging_fee(
+        deps.storage,
+        &denom,
+        decimals,
+        bridging_fee,
+        env.block.time.seconds(),
+    )
+    .map_err(|e| StdError::generic_err(e.to_string()))?;
+
+    Ok(USDBridgingFeeResponse {
+        usd_bridging_fee: USD_BRIDGING_FEES.may_load(deps.storage, denom.clone())?,
+        price: TOKEN_PRICES.may_load(deps.storage, denom.clone())?,
+        bridging_fee,
+        denom,
+    })
+}
+
+fn query_xrpl_dex_quote(deps: Deps, env: Env, denom: String) -> StdResult<XRPLDexQuoteResponse> {
+    let relayers_config = RELAYERS_CONFIG.load(deps.storage)?;
+    let fees_config = FEES_CONFIG.load(deps.storage)?;
+    let timestamp = env.block.time.seconds();
+
+    // Quotes of relayers that were removed or that weren't refreshed within the TTL are ignored
+    let quotes = XRPL_DEX_QUOTES
+        .prefix(denom.clone())
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter(|item| match item {
+            Ok((relayer, quote)) => {
+                relayers_config
+                    .relayers
+                    .iter()
+                    .any(|r| r.coreum_address.eq(relayer))
+                    && quote
+                        .published_at
+                        .saturating_add(fees_config.xrpl_dex_quote_ttl)
+                        > timestamp
+            }
+            Err(_) => true,
+        })
+        .map(|item| item.map(|(_, quote)| quote))
+        .collect::<StdResult<Vec<XRPLDexQuote>>>()?;
+
+    // Taking the median prevents a single relayer from moving the price
+    let mut price = None;
+    let mut oldest_quote_at = None;
+    if quotes.len() >= relayers_config.evidence_threshold as usize {
+        let mut prices: Vec<Decimal> = quotes.iter().map(|quote| quote.price).collect();
+        prices.sort();
+        let middle = prices.len() / 2;
+        price = if prices.len() % 2 == 0 {
+            Some((prices[middle - 1] + prices[middle]) * Decimal::percent(50))
+        } else {
+            Some(prices[middle])
+        };
+        oldest_quote_at = quotes.iter().map(|quote| quote.published_at).min();
+    }
+
+    Ok(XRPLDexQuoteResponse {
+        denom,
+        price,
+        quotes: quotes.len() as u32,
+        oldest_quote_at,
+    })
+}
+
+fn query_xrpl_recipient_flags(
+    deps: Deps,
+    account: String,
+) -> StdResult<XRPLRecipientFlagsResponse> {
+    Ok(XRPLRecipientFlagsResponse {
+        flags: XRPL_RECIPIENT_FLAGS.may_load(deps.storage, account)?,
+    })
+}
+
+fn query_token_freeze(deps: Deps, timestamp: u64, denom: String) -> StdResult<TokenFreezeResponse> {
+    let relayers_config = RELAYERS_CONFIG.load(deps.storage)?;
+    Ok(TokenFreezeResponse {
+        frozen: is_token_frozen(deps.storage, &denom, timestamp)?,
+        freeze: TOKEN_FREEZES.may_load(deps.storage, denom.clone())?,
+        freeze_requests: TOKEN_FREEZE_REQUESTS
+            .may_load(deps.storage, denom)?
+            .unwrap_or_default(),
+        token_freeze_request_threshold: relayers_config.token_freeze_request_threshold,
+    })
+}
+
+fn query_storage_layout() -> StorageLayoutResponse {
+    StorageLayoutResponse {
+        contract_version: CONTRACT_VERSION.to_string(),
+        value_encoding: "json".to_string(),
+        entries: STORAGE_LAYOUT
+            .iter()
+            .map(|descriptor| StorageLayoutEntry {
+                name: descriptor.name.to_string(),
+                namespace: descriptor.namespace.to_string(),
+                kind: descriptor.kind.as_str().to_string(),
+                key_encoding: descriptor.key_encoding.to_string(),
+                value_type: descriptor.value_type.to_string(),
+                version: descriptor.version,
+            })
+            .collect(),
+    }
+}
+
+fn query_transfer_receipt(deps: Deps, id_or_hash: String) -> StdResult<TransferReceipt> {
+    load_transfer_receipt(deps.storage, id_or_hash)
+        .map_err(|e| StdError::generic_err(e.to_string()))
+}
+
+fn query_transfer_chunks(
+    deps: Deps,
+    parent_transfer_id: String,
+) -> StdResult<TransferChunksResponse> {
+    let operation_ids = TRANSFER_CHUNKS
+        .may_load(deps.storage, parent_transfer_id.clone())?
+        .unwrap_or_default();
+
+    Ok(TransferChunksResponse {
+        parent_transfer_id,
+        operation_ids,
+    })
+}
+
+fn query_relayer_reimbursement(deps: Deps) -> StdResult<RelayerReimbursementResponse> {
+    let reimbursement = RELAYER_REIMBURSEMENT.may_load(deps.storage)?;
+
+    Ok(RelayerReimbursementResponse {
+        amount_per_operation: reimbursement
+            .as_ref()
+            .map(|r| r.amount_per_operation.clone()),
+        pool: reimbursement.map_or(Uint128::zero(), |r| r.pool),
+    })
+}
+
+fn query_registered_xrpl_tokens(deps: Deps) -> StdResult<RegisteredXRPLTokensResponse> {
+    let xrpl_config = XRPL_CONFIG.load(deps.storage)?;
+    Ok(RegisteredXRPLTokensResponse {
+        registered_xrpl_tokens: count_registered_xrpl_tokens(deps.storage),
+        max_registered_xrpl_tokens: xrpl_config.max_registered_xrpl_tokens,
+    })
+}
+
+fn query_xrpl_tokens(
+    deps: Deps,
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> XRPLTokensResponse {
+    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
+    let start = start_after_key.map(Bound::exclusive);
+    let mut last_key = None;
+    let tokens: Vec<XRPLToken> = XRPL_TOKENS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit as usize)
+        .filter_map(Result::ok)
+        .map(|(key, v)| {
+            last_key = Some(key);
+            v
+        })
+        .collect();
+
+    XRPLTokensResponse { last_key, tokens }
+}
+
+fn query_invalid_deposits(
+    deps: Deps,
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> InvalidDepositsResponse {
+    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
+    let start = start_after_key.map(Bound::exclusive);
+    let mut last_key = None;
+    let invalid_deposits: Vec<InvalidDeposit> = INVALID_DEPOSITS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit as usize)
+        .filter_map(Result::ok)
+        .map(|(key, invalid_deposit)| {
+            last_key = Some(key);
+            invalid_deposit
+        })
+        .collect();
+
+    InvalidDepositsResponse {
+        last_key,
+        invalid_deposits,
+    }
+}
+
+fn query_relayers_activity(deps: Deps) -> StdResult<RelayersActivityResponse> {
+    let relayers_config = RELAYERS_CONFIG.load(deps.storage)?;
+    let relayers = relayers_config
+        .relayers
+        .iter()
+        .map(|relayer| {
+            let activity =
+                RELAYER_ACTIVITY.may_load(deps.storage, relayer.coreum_address.clone())?;
+            let workload =
+                RELAYER_WORKLOADS.may_load(deps.storage, relayer.coreum_address.clone())?;
+            Ok(RelayerActivityInfo {
+                relayer_coreum_address: relayer.coreum_address.clone(),
+                last_active_at: activity.as_ref().map(|activity| activity.last_active_at),
+                inactive_since: activity.and_then(|activity| activity.inactive_since),
+                signatures_saved: workload
+                    .as_ref()
+                    .map_or(0, |workload| workload.signatures_saved),
+                inbound_deliveries: workload.map_or(0, |workload| workload.inbound_deliveries),
+            })
+        })
+        .collect::<StdResult<Vec<RelayerActivityInfo>>>()?;
+
+    Ok(RelayersActivityResponse {
+        relayers,
+        relayer_inactivity_period: relayers_config.relayer_inactivity_period,
+    })
+}
+
+fn query_operation_evidence_deadline(
+    deps: Deps,
+    env: Env,
+    operation_id: u64,
+) -> StdResult<OperationEvidenceDeadlineResponse> {
+    if !PENDING_OPERATIONS.has(deps.storage, operation_id) {
+        return Err(StdError::generic_err(
+            ContractError::PendingOperationNotFound {}.to_string(),
+        ));
+    }
+    let evidence_deadline = OPERATION_EVIDENCE_DEADLINES.may_load(deps.storage, operation_id)?;
+
+    Ok(OperationEvidenceDeadlineResponse {
+        operation_id,
+        evidence_deadline,
+        expired: evidence_deadline
+            .is_some_and(|evidence_deadline| evidence_deadline < env.block.time.seconds()),
+    })
+}
+
+fn query_operation_submission(
+    deps: Deps,
+    operation_id: u64,
+) -> StdResult<OperationSubmissionResponse> {
+    Ok(OperationSubmissionResponse {
+        operation_id,
+        submission: OPERATION_SUBMISSIONS.may_load(deps.storage, operation_id)?,
+    })
+}
+
+fn query_pending_high_risk_actions(deps: Deps) -> StdResult<PendingHighRiskActionsResponse> {
+    let actions = PENDING_HIGH_RISK_ACTIONS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            item.map(|(action, pending_action)| PendingHighRiskActionInfo {
+                action,
+                msg_hash: pending_action.msg_hash,
+                proposed_by: pending_action.proposed_by,
+                proposed_at_height: pending_action.proposed_at_height,
+                expires_at: pending_action.expires_at,
+            })
+        })
+        .collect::<StdResult<Vec<PendingHighRiskActionInfo>>>()?;
+
+    Ok(PendingHighRiskActionsResponse { actions })
+}
+
+fn query_stale_operations(
+    deps: Deps,
+    env: Env,
+    min_age: Option<u64>,
+    missing_quorum_for: Option<u64>,
+    start_after_key: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<StaleOperationsResponse> {
+    let relayers_config = RELAYERS_CONFIG.load(deps.storage)?;
+    let timestamp = env.block.time.seconds();
+    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
+    let start = start_after_key.map(Bound::exclusive);
+
+    let mut operations = vec![];
+    let mut last_key = None;
+    for item in PENDING_OPERATIONS.range(deps.storage, start, None, Order::Ascending) {
+        let (key, operation) = item?;
+        let creation = OPERATION_CREATIONS.may_load(deps.storage, key)?;
+        // Operation ids start with the timestamp in which they were created
+        let created_at = creation.as_ref().map_or_else(
+            || {
+                operation
+                    .id
+                    .split_once('-')
+                    .and_then(|(created_at, _)| created_at.parse().ok())
+                    .unwrap_or_default()
+            },
+            |creation| creation.timestamp,
+        );
+        let age = timestamp.saturating_sub(created_at);
+        let signatures = operation.signatures.len() as u32;
+
+        let old = min_age.is_some_and(|min_age| age >= min_age);
+        let missing_quorum = missing_quorum_for.is_some_and(|missing_quorum_for| {
+            signatures < relayers_config.evidence_threshold && age >= missing_quorum_for
+        });
+        if (min_age.is_none() && missing_quorum_for.is_none()) || old || missing_quorum {
+            operations.push(StaleOperation {
+                operation,
+                created_at_height: creation.map(|creation| creation.height),
+                created_at,
+                age,
+                signatures,
+                evidence_threshold: relayers_config.evidence_threshold,
+            });
+            last_key = Some(key);
+            if operations.len() == limit as usize {
+                break;
+            }
+        }
+    }
+
+    Ok(StaleOperationsResponse {
+        last_key,
+        operations,
+    })
+}
+
+fn query_xrpl_reserve(deps: Deps, env: Env) -> StdResult<XRPLReserveResponse> {
+    let xrpl_config = XRPL_CONFIG.load(deps.storage)?;
+    let relayers_config = RELAYERS_CONFIG.load(deps.storage)?;
+    let owner_count =
+        bridge_xrpl_owner_count(deps.storage).map_err(|e| StdError::generic_err(e.to_string()))?;
+    let required_reserve = required_xrpl_reserve(&xrpl_config, owner_count)
+        .map_err(|e| StdError::generic_err(e.to_string()))?;
+    let bridged_xrp =
+        bridged_xrp(deps.storage).map_err(|e| StdError::generic_err(e.to_string()))?;
+    let (attestations, attested_balance) =
+        attested_bridge_xrpl_balance(deps.storage, &relayers_config, env.block.time.seconds())?;
+
+    let top_up_amount = attested_balance.map(|balance| {
+        required_reserve
+            .saturating_add(bridged_xrp)
+            .saturating_sub(balance)
+    });
+
+    Ok(XRPLReserveResponse {
+        owner_count,
+        required_reserve,
+        attestations,
+        attested_balance,
+        bridged_xrp,
+        top_up_amount,
+    })
+}
+
+fn query_xrpl_supply_drifts(
+    deps: Deps,
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> XRPLSupplyDriftsResponse {
+    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
+    let start = start_after_key.map(Bound::exclusive);
+    let mut last_key = None;
+    let drifts: Vec<XRPLSupplyDrift> = XRPL_SUPPLY_DRIFTS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit as usize)
+        .filter_map(Result::ok)
+        .map(|(key, drift)| {
+            last_key = Some(key);
+            drift
+        })
+        .collect();
+
+    XRPLSupplyDriftsResponse { last_key, drifts }
+}
+
+fn query_xrpl_token_supplies(
+    deps: Deps,
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<XRPLTokenSuppliesResponse> {
+    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
+    let start = start_after_key.map(Bound::exclusive);
+    let tokens: Vec<(String, XRPLToken)> = XRPL_TOKENS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit as usize)
+        .filter_map(Result::ok)
+        .collect();
+
+    let last_key = tokens.last().map(|(key, _)| key.clone());
+    let mut supplies = vec![];
+    for (_, token) in tokens {
+        let minted = TOKEN_ACCOUNTING
+            .may_load(deps.storage, token.coreum_denom.clone())?
+            .unwrap_or_default()
+            .minted;
+        let total_supply = deps
+            .querier
+            .query_supply(token.coreum_denom.clone())?
+            .amount;
+
+        supplies.push(XRPLTokenSupply {
+            issuer: token.issuer,
+            currency: token.currency,
+            coreum_denom: token.coreum_denom,
+            minted,
+            total_supply,
+        });
+    }
+
+    Ok(XRPLTokenSuppliesResponse { last_key, supplies })
+}
+
+fn query_coreum_tokens(
+    deps: Deps,
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> CoreumTokensResponse {
+    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
+    let start = start_after_key.map(Bound::exclusive);
+    let mut last_key = None;
+    let tokens: Vec<CoreumToken> = COREUM_TOKENS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit as usize)
+        .filter_map(Result::ok)
+        .map(|(key, ct)| {
+            last_key = Some(key);
+            ct
+        })
+        .collect();
+
+    CoreumTokensResponse { last_key, tokens }
+}
+
+fn query_pending_operations(
+    deps: Deps,
+    start_after_key: Option<u64>,
+    limit: Option<u32>,
+) -> PendingOperationsResponse {
+    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
+    let start = start_after_key.map(Bound::exclusive);
+    let mut last_key = None;
+    let operations: Vec<Operation> = PENDING_OPERATIONS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit as usize)
+        .filter_map(Result::ok)
+        .map(|(key, v)| {
+            last_key = Some(key);
+            v
+        })
+        .collect();
+
+    PendingOperationsResponse {
+        last_key,
+        operations,
+    }
+}
+
+// Pending operations are returned in signing order: the priority lane first and then the rest, each of them by ticket/sequence
+// Pending operations are bounded by the amount of tickets, so they can all be loaded to sort them
+fn query_prioritized_pending_operations(
+    deps: Deps,
+    start_after_key: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<PrioritizedPendingOperationsResponse> {
+    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
+    let (priority_lane, normal_lane): (Vec<_>, Vec<_>) = PENDING_OPERATIONS
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(u64, Operation)>>>()?
+        .into_iter()
+        .map(|(key, operation)| (key, PRIORITY_OPERATIONS.has(deps.storage, key), operation))
+        .partition(|(_, priority, _)| *priority);
+
+    let mut queue = priority_lane.into_iter().chain(normal_lane).peekable();
+    if let Some(start_after_key) = start_after_key {
+        while queue
+            .next_if(|(key, _, _)| *key != start_after_key)
+            .is_some()
+        {}
+        queue.next();
+    }
+
+    let mut last_key = None;
+    let operations = queue
+        .take(limit as usize)
+        .map(|(key, priority, operation)| {
+            last_key = Some(key);
+            PrioritizedOperation {
+                priority,
+                operation,
+            }
+        })
+        .collect();
+
+    Ok(PrioritizedPendingOperationsResponse {
+        last_key,
+        operations,
+    })
+}
+
+fn query_operation_signing_payload(
+    deps: Deps,
+    operation_id: u64,
+) -> StdResult<OperationSigningPayloadResponse> {
+    let relayers_config = RELAYERS_CONFIG.load(deps.storage)?;
+    let xrpl_config = XRPL_CONFIG.load(deps.storage)?;
+    let operation = PENDING_OPERATIONS
+        .may_load(deps.storage, operation_id)?
+        .ok_or_else(|| {
+            StdError::generic_err(ContractError::PendingOperationNotFound {}.to_string())
+        })?;
+
+    // The transaction is built for the relayer set the operation must be signed by, with the base fee of the operation
+    let signers_config = operation_signers_config(deps.storage, &relayers_config, operation_id)
+        .map_err(|e| StdError::generic_err(e.to_string()))?;
+    let transaction = serialize_operation_transaction(&xrpl_config.bridge_xrpl_address, &operation)
+        .map_err(|e| StdError::generic_err(e.to_string()))?;
+
+    let payloads = signers_config
+        .relayers
+        .iter()
+        .map(|relayer| {
+            Ok(RelayerSigningPayload {
+                relayer_coreum_address: relayer.coreum_address.clone(),
+                xrpl_address: relayer.xrpl_address.clone(),
+                payload: hex::encode_upper(operation_signing_payload(
+                    &transaction,
+                    &relayer.xrpl_address,
+                )?),
+            })
+        })
+        .collect::<Result<Vec<RelayerSigningPayload>, ContractError>>()
+        .map_err(|e| StdError::generic_err(e.to_string()))?;
+
+    Ok(OperationSigningPayloadResponse {
+        operation_id,
+        operation_version: operation.version,
+        tx_blob: hex::encode_upper(transaction),
+        payloads,
+    })
+}
+
+fn query_available_tickets(deps: Deps) -> StdResult<AvailableTicketsResponse> {
+    let mut tickets = AVAILABLE_TICKETS.load(deps.storage)?;
+
+    Ok(AvailableTicketsResponse {
+        tickets: tickets.make_contiguous().to_vec(),
+    })
+}
+
+fn query_fees_collected(deps: Deps, relayer_address: Addr) -> StdResult<FeesCollectedResponse> {
+    let fees_collected = FEES_COLLECTED
+        .may_load(deps.storage, relayer_address.clone())?
+        .unwrap_or_default();
+    let consolidation_denom =
+        RELAYER_FEE_CONSOLIDATION_DENOMS.may_load(deps.storage, relayer_address)?;
+
+    Ok(FeesCollectedResponse {
+        fees_collected,
+        consolidation_denom,
+    })
+}
+
+fn query_dust_accounting(deps: Deps, denom: String) -> StdResult<DustAccountingResponse> {
+    let dust_accounting = DUST_ACCOUNTING
+        .may_load(deps.storage, denom.clone())?
+        .unwrap_or_default();
+    let fee_remainder = FEE_REMAINDERS
+        .may_load(deps.storage, denom.clone())?
+        .unwrap_or_default();
+
+    Ok(DustAccountingResponse {
+        denom,
+        truncation_dust: dust_accounting.truncation_dust,
+        fee_remainder,
+        swept: dust_accounting.swept,
+    })
+}
+
+fn query_fee_accounting(deps: Deps, denom: String) -> StdResult<FeeAccountingResponse> {
+    let fee_accounting = FEE_ACCOUNTING
+        .may_load(deps.storage, denom.clone())?
+        .unwrap_or_default();
+    let dust_accounting = DUST_ACCOUNTING
+        .may_load(deps.storage, denom.clone())?
+        .unwrap_or_default();
+
+    Ok(FeeAccountingResponse {
+        denom,
+        bridging_fees: fee_accounting.bridging_fees,
+        priority_fees: fee_accounting.priority_fees,
+        truncation_fees: dust_accounting.truncation_dust,
+    })
+}
+
+fn query_fee_remainders(
+    deps: Deps,
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<FeeRemaindersResponse> {
+    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
+    let start = start_after_key.map(Bound::exclusive);
+    let mut last_key = None;
+
+    let fee_remainders = FEE_REMAINDERS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|item| {
+            item.map(|(denom, amount)| {
+                last_key = Some(denom.clone());
+                coin(amount.u128(), denom)
+            })
+        })
+        .collect::<StdResult<Vec<Coin>>>()?;
+
+    Ok(FeeRemaindersResponse {
+        last_key,
+        fee_remainders,
+    })
+}
+
+fn query_pending_refunds(
+    deps: Deps,
+    address: Addr,
+    start_after_key: Option<(Addr, String)>,
+    limit: Option<u32>,
+) -> PendingRefundsResponse {
+    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
+    let start = start_after_key.map(Bound::exclusive);
+    let mut last_key = None;
+
+    let pending_refunds: Vec<PendingRefund> = PENDING_REFUNDS
+        .idx
+        .address
+        .prefix(address)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit as usize)
+        .filter_map(Result::ok)
+        .map(|(key, pr)| {
+            last_key = Some(key);
+            PendingRefund {
+                id: pr.id,
+                xrpl_tx_hash: pr.xrpl_tx_hash,
+                coin: pr.coin,
+            }
+        })
+        .collect();
+
+    PendingRefundsResponse {
+        last_key,
+        pending_refunds,
+    }
+}
+
+fn query_pending_refund_totals(
+    deps: Deps,
+    address: Option<Addr>,
+) -> StdResult<PendingRefundTotalsResponse> {
+    let totals = match &address {
+        Some(address) => {
+            let mut amounts: BTreeMap<String, Uint128> = BTreeMap::new();
+            for pending_refund in PENDING_REFUNDS.idx.address.prefix(address.clone()).range(
+                deps.storage,
+                None,
+                None,
+                Order::Ascending,
+            ) {
+                let (_, pending_refund) = pending_refund?;
+                let amount = amounts.entry(pending_refund.coin.denom).or_default();
+                *amount = amount.checked_add(pending_refund.coin.amount)?;
+            }
+            amounts
+                .into_iter()
+                .map(|(denom, amount)| coin(amount.u128(), denom))
+                .collect()
         }
+        // Refund reservations are already tracked per denom by the token accounting
+        None => TOKEN_ACCOUNTING
+            .range(deps.storage, None, None, Order::Ascending)
+            .filter_map(Result::ok)
+            .filter(|(_, accounting)| !accounting.refund_reserved.is_zero())
+            .map(|(denom, accounting)| coin(accounting.refund_reserved.u128(), denom))
+            .collect(),
+    };
+
+    Ok(PendingRefundTotalsResponse { address, totals })
+}
+
+fn query_undelivered_transfers(
+    deps: Deps,
+    start_after_key: Option<(Addr, String)>,
+    limit: Option<u32>,
+) -> StdResult<UndeliveredTransfersResponse> {
+    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
+    let start = start_after_key.map(Bound::exclusive);
+    let mut last_key = None;
+
+    let keys: Vec<(Addr, String)> = UNDELIVERED_TRANSFERS
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit as usize)
+        .filter_map(Result::ok)
+        .collect();
+
+    let mut undelivered_transfers = vec![];
+    for key in keys {
+        let pending_refund = PENDING_REFUNDS.load(deps.storage, key.clone())?;
+        undelivered_transfers.push(UndeliveredTransfer {
+            recipient: pending_refund.address,
+            id: pending_refund.id,
+            xrpl_tx_hash: pending_refund.xrpl_tx_hash,
+            coin: pending_refund.coin,
+        });
+        last_key = Some(key);
     }
+
+    Ok(UndeliveredTransfersResponse {
+        last_key,
+        undelivered_transfers,
+    })
 }
 
-fn query_config(deps: Deps) -> StdResult<Config> {
-    let config = CONFIG.load(deps.storage)?;
-    Ok(config)
+fn query_blocked_claims(
+    deps: Deps,
+    env: Env,
+    start_after_key: Option<(Addr, String)>,
+    limit: Option<u32>,
+) -> StdResult<BlockedClaimsResponse> {
+    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
+    let start = start_after_key.map(Bound::exclusive);
+    let mut last_key = None;
+
+    let entries: Vec<((Addr, String), BlockedClaim)> = BLOCKED_CLAIMS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit as usize)
+        .filter_map(Result::ok)
+        .collect();
+
+    let mut blocked_claims = vec![];
+    for (key, blocked_claim) in entries {
+        let pending_refund = PENDING_REFUNDS.load(deps.storage, key.clone())?;
+        blocked_claims.push(BlockedClaimInfo {
+            owner: pending_refund.address,
+            pending_refund_id: pending_refund.id,
+            claimable: !is_token_frozen(
+                deps.storage,
+                &pending_refund.coin.denom,
+                env.block.time.seconds(),
+            )?,
+            coin: pending_refund.coin,
+            first_blocked_at_height: blocked_claim.first_blocked_at_height,
+            last_attempt_height: blocked_claim.last_attempt_height,
+            attempts: blocked_claim.attempts,
+        });
+        last_key = Some(key);
+    }
+
+    Ok(BlockedClaimsResponse {
+        last_key,
+        blocked_claims,
+    })
 }
 
-fn query_bridge_state(deps: Deps) -> StdResult<BridgeStateResponse> {
-    let config = CONFIG.load(deps.storage)?;
-    Ok(BridgeStateResponse {
-        state: config.bridge_state,
+fn query_deferred_inbound_transfers(
+    deps: Deps,
+    env: Env,
+    start_after_key: Option<(String, String)>,
+    limit: Option<u32>,
+) -> StdResult<DeferredInboundTransfersResponse> {
+    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
+    let start = start_after_key.map(Bound::exclusive);
+    let mut last_key = None;
+
+    let entries: Vec<((String, String), DeferredInboundTransfer)> = DEFERRED_INBOUND_TRANSFERS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit as usize)
+        .filter_map(Result::ok)
+        .collect();
+
+    let mut deferred_transfers = vec![];
+    for ((token_key, transfer_id), deferred_transfer) in entries {
+        let token = XRPL_TOKENS.load(deps.storage, token_key.clone())?;
+        deferred_transfers.push(DeferredInboundTransferInfo {
+            transfer_id: transfer_id.clone(),
+            issuer: deferred_transfer.issuer,
+            currency: deferred_transfer.currency,
+            frozen: is_token_frozen(deps.storage, &token.coreum_denom, env.block.time.seconds())?,
+            coreum_denom: token.coreum_denom,
+            amount: deferred_transfer.amount,
+            recipient: deferred_transfer.recipient,
+            deferred_at: deferred_transfer.deferred_at,
+            age: env
+                .block
+                .time
+                .seconds()
+                .saturating_sub(deferred_transfer.deferred_at),
+            token_state: token.state,
+        });
+        last_key = Some((token_key, transfer_id));
+    }
+
+    Ok(DeferredInboundTransfersResponse {
+        last_key,
+        deferred_transfers,
     })
 }
 
-fn query_xrpl_tokens(
+fn query_bridge_activity_snapshot(
     deps: Deps,
-    start_after_key: Option<String>,
+    env: Env,
+    epoch: Option<u64>,
+) -> StdResult<BridgeActivitySnapshotResponse> {
+    let epoch = epoch.unwrap_or_else(|| activity_epoch(env.block.time.seconds()));
+    let activity = BRIDGE_ACTIVITY
+        .may_load(deps.storage, epoch)?
+        .unwrap_or_default();
+
+    Ok(BridgeActivitySnapshotResponse {
+        epoch,
+        epoch_start: epoch * ACTIVITY_EPOCH_DURATION,
+        epoch_end: (epoch + 1) * ACTIVITY_EPOCH_DURATION,
+        operation_failure_rate: activity.operation_failure_rate(),
+        activity,
+    })
+}
+
+fn query_relayer_fee_claims(
+    deps: Deps,
+    relayer_address: Addr,
+    start_after_key: Option<u64>,
     limit: Option<u32>,
-) -> XRPLTokensResponse {
+) -> RelayerFeeClaimsResponse {
     let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
     let start = start_after_key.map(Bound::exclusive);
     let mut last_key = None;
-    let tokens: Vec<XRPLToken> = XRPL_TOKENS
+
+    let claims = RELAYER_FEE_CLAIMS
+        .prefix(relayer_address)
         .range(deps.storage, start, None, Order::Ascending)
         .take(limit as usize)
         .filter_map(Result::ok)
-        .map(|(key, v)| {
+        .map(|(key, claim)| {
             last_key = Some(key);
-            v
+            claim
         })
         .collect();
 
-    XRPLTokensResponse { last_key, tokens }
+    RelayerFeeClaimsResponse { last_key, claims }
 }
 
-fn query_coreum_tokens(
+fn query_relayer_infractions(
     deps: Deps,
+    relayer_address: Addr,
     start_after_key: Option<String>,
     limit: Option<u32>,
-) -> CoreumTokensResponse {
+) -> RelayerInfractionsResponse {
     let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
     let start = start_after_key.map(Bound::exclusive);
     let mut last_key = None;
-    let tokens: Vec<CoreumToken> = COREUM_TOKENS
+
+    let infractions = RELAYER_INFRACTIONS
+        .prefix(relayer_address)
         .range(deps.storage, start, None, Order::Ascending)
         .take(limit as usize)
         .filter_map(Result::ok)
-        .map(|(key, ct)| {
+        .map(|(key, infraction)| {
             last_key = Some(key);
-            ct
+            infraction
         })
         .collect();
 
-    CoreumTokensResponse { last_key, tokens }
+    RelayerInfractionsResponse {
+        last_key,
+        infractions,
+    }
 }
 
-fn query_pending_operations(
+fn query_inbound_transfers(
     deps: Deps,
+    recipient: Addr,
     start_after_key: Option<u64>,
     limit: Option<u32>,
-) -> PendingOperationsResponse {
+) -> InboundTransfersResponse {
     let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
     let start = start_after_key.map(Bound::exclusive);
     let mut last_key = None;
-    let operations: Vec<Operation> = PENDING_OPERATIONS
+
+    let transfers = INBOUND_TRANSFERS
+        .prefix(recipient)
         .range(deps.storage, start, None, Order::Ascending)
         .take(limit as usize)
         .filter_map(Result::ok)
-        .map(|(key, v)| {
+        .map(|(key, transfer)| {
             last_key = Some(key);
-            v
+            transfer
         })
         .collect();
 
-    PendingOperationsResponse {
+    InboundTransfersResponse {
+        last_key,
+        transfers,
+    }
+}
+
+fn query_archived_operations(
+    deps: Deps,
+    start_after_key: Option<u64>,
+    limit: Option<u32>,
+) -> ArchivedOperationsResponse {
+    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
+    let start = start_after_key.map(Bound::exclusive);
+    let mut last_key = None;
+
+    let operations = OPERATION_ARCHIVE
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit as usize)
+        .filter_map(Result::ok)
+        .map(|(key, operation)| {
+            last_key = Some(key);
+            operation
+        })
+        .collect();
+
+    ArchivedOperationsResponse {
         last_key,
         operations,
     }
 }
 
-fn query_available_tickets(deps: Deps) -> StdResult<AvailableTicketsResponse> {
-    let mut tickets = AVAILABLE_TICKETS.load(deps.storage)?;
+fn query_transaction_evidence(deps: Deps, hash: String) -> StdResult<TransactionEvidence> {
+    let relayer_addresses = TX_EVIDENCES
+        .may_load(deps.storage, hash.clone())?
+        .map(|e| e.relayer_coreum_addresses);
 
-    Ok(AvailableTicketsResponse {
-        tickets: tickets.make_contiguous().to_vec(),
+    Ok(TransactionEvidence {
+        hash,
+        relayer_addresses: relayer_addresses.unwrap_or_default(),
     })
 }
 
-fn query_fees_collected(deps: Deps, relayer_address: Addr) -> StdResult<FeesCollectedResponse> {
-    let fees_collected = FEES_COLLECTED
-        .may_load(deps.storage, relayer_address)?
-        .unwrap_or_default();
+fn query_transaction_evidences(
+    deps: Deps,
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> TransactionEvidencesResponse {
+    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
+    let start = start_after_key.map(Bound::exclusive);
+    let mut last_key = None;
+    let transaction_evidences: Vec<TransactionEvidence> = TX_EVIDENCES
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit as usize)
+        .filter_map(Result::ok)
+        .map(|(evidence_hash, e)| {
+            last_key = Some(evidence_hash.clone());
+            TransactionEvidence {
+                hash: evidence_hash,
+                relayer_addresses: e.relayer_coreum_addresses,
+            }
+        })
+        .collect();
+
+    TransactionEvidencesResponse {
+        last_key,
+        transaction_evidences,
+    }
+}
+
+fn query_stale_evidences(
+    deps: Deps,
+    timestamp: u64,
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<StaleEvidencesResponse> {
+    let operations_config = OPERATIONS_CONFIG.load(deps.storage)?;
+    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
+
+    let evidences: Vec<StaleEvidence> = stale_evidences(
+        deps.storage,
+        timestamp,
+        operations_config.evidence_ttl,
+        start_after_key,
+        limit,
+    )?
+    .into_iter()
+    .map(|(evidence_hash, e)| StaleEvidence {
+        hash: evidence_hash,
+        relayer_addresses: e.relayer_coreum_addresses,
+        created_at: e.created_at,
+    })
+    .collect();
+
+    Ok(StaleEvidencesResponse {
+        last_key: evidences.last().map(|e| e.hash.clone()),
+        evidence_ttl: operations_config.evidence_ttl,
+        evidences,
+    })
+}
+
+fn query_processed_tx(deps: Deps, hash: String) -> bool {
+    PROCESSED_TXS.has(deps.storage, hash)
+}
+
+fn query_processed_txs(
+    deps: Deps,
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> ProcessedTxsResponse {
+    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
+    let start = start_after_key.map(Bound::exclusive);
+    let mut last_key = None;
+    let processed_txs: Vec<String> = PROCESSED_TXS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit as usize)
+        .filter_map(Result::ok)
+        .map(|(hash, _)| {
+            last_key = Some(hash.clone());
+            hash
+        })
+        .collect();
+
+    ProcessedTxsResponse {
+        last_key,
+        processed_txs,
+    }
+}
+
+fn query_prohibited_xrpl_addresses(
+    deps: Deps,
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> ProhibitedXRPLAddressesResponse {
+    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
+    let start = start_after_key.map(Bound::exclusive);
+    let mut last_key = None;
+    let prohibited_xrpl_addresses: Vec<String> = PROHIBITED_XRPL_ADDRESSES
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit as usize)
+        .filter_map(Result::ok)
+        .map(|(addr, _)| {
+            last_key = Some(addr.clone());
+            addr
+        })
+        .collect();
+
+    ProhibitedXRPLAddressesResponse {
+        last_key,
+        prohibited_xrpl_addresses,
+    }
+}
+
+fn query_xrpl_recipient_rules(
+    deps: Deps,
+    start_after_key: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<XRPLRecipientRulesResponse> {
+    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
+    let start = start_after_key.map(Bound::exclusive);
+    let mut last_key = None;
+    let rules = XRPL_RECIPIENT_RULES
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|item| {
+            let (name, rule) = item?;
+            last_key = Some(name.clone());
+            Ok(XRPLRecipientRuleInfo { name, rule })
+        })
+        .collect::<StdResult<Vec<XRPLRecipientRuleInfo>>>()?;
+
+    Ok(XRPLRecipientRulesResponse { last_key, rules })
+}
+
+fn query_proven_xrpl_transaction(
+    deps: Deps,
+    tx_hash: String,
+) -> StdResult<ProvenXRPLTransactionResponse> {
+    let proven_transaction =
+        PROVEN_XRPL_TRANSACTIONS.may_load(deps.storage, tx_hash.to_uppercase())?;
+
+    Ok(ProvenXRPLTransactionResponse { proven_transaction })
+}
+
+fn query_evidence_signing_keys(deps: Deps) -> EvidenceSigningKeysResponse {
+    let evidence_signing_keys: Vec<EvidenceSigningKey> = EVIDENCE_SIGNING_KEYS
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter_map(Result::ok)
+        .map(|(relayer_coreum_address, pub_key)| EvidenceSigningKey {
+            relayer_coreum_address,
+            pub_key,
+        })
+        .collect();
+
+    EvidenceSigningKeysResponse {
+        evidence_signing_keys,
+    }
+}
+
+fn query_evidence_delegates(deps: Deps) -> EvidenceDelegatesResponse {
+    let evidence_delegates: Vec<EvidenceDelegate> = EVIDENCE_DELEGATES
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter_map(Result::ok)
+        .map(|(relayer_coreum_address, delegate)| EvidenceDelegate {
+            relayer_coreum_address,
+            delegate,
+        })
+        .collect();
+
+    EvidenceDelegatesResponse { evidence_delegates }
+}
+
+fn query_evidence_sources(deps: Deps) -> StdResult<EvidenceSourcesResponse> {
+    let evidence_sources: Vec<EvidenceSource> = EVIDENCE_SOURCES
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter_map(Result::ok)
+        .map(|(relayer_coreum_address, source)| EvidenceSource {
+            relayer_coreum_address,
+            source,
+        })
+        .collect();
+
+    Ok(EvidenceSourcesResponse {
+        evidence_sources,
+        min_distinct_evidence_sources: RELAYERS_CONFIG
+            .load(deps.storage)?
+            .min_distinct_evidence_sources,
+    })
+}
+
+fn query_relayer_attestations(deps: Deps) -> RelayerAttestationsResponse {
+    let attestations: Vec<RelayerAttestationInfo> = RELAYER_ATTESTATIONS
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter_map(Result::ok)
+        .map(
+            |(relayer_coreum_address, attestation)| RelayerAttestationInfo {
+                relayer_coreum_address,
+                attestation,
+            },
+        )
+        .collect();
+
+    RelayerAttestationsResponse { attestations }
+}
 
-    Ok(FeesCollectedResponse { fees_collected })
+fn query_emergency_withdrawal(deps: Deps) -> StdResult<EmergencyWithdrawalResponse> {
+    Ok(EmergencyWithdrawalResponse {
+        emergency_withdrawal: EMERGENCY_WITHDRAWAL.may_load(deps.storage)?,
+    })
 }
 
-fn query_pending_refunds(
+fn query_recurring_transfers(
     deps: Deps,
-    address: Addr,
-    start_after_key: Option<(Addr, String)>,
+    start_after_key: Option<u64>,
     limit: Option<u32>,
-) -> PendingRefundsResponse {
+) -> RecurringTransfersResponse {
     let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
     let start = start_after_key.map(Bound::exclusive);
     let mut last_key = None;
 
-    let pending_refunds: Vec<PendingRefund> = PENDING_REFUNDS
-        .idx
-        .address
-        .prefix(address)
+    let recurring_transfers: Vec<RecurringTransfer> = RECURRING_TRANSFERS
         .range(deps.storage, start, None, Order::Ascending)
         .take(limit as usize)
         .filter_map(Result::ok)
-        .map(|(key, pr)| {
-            last_key = Some(key);
-            PendingRefund {
-                id: pr.id,
-                xrpl_tx_hash: pr.xrpl_tx_hash,
-                coin: pr.coin,
-            }
+        .map(|(id, recurring_transfer)| {
+            last_key = Some(id);
+            recurring_transfer
         })
         .collect();
 
-    PendingRefundsResponse {
+    RecurringTransfersResponse {
         last_key,
-        pending_refunds,
+        recurring_transfers,
     }
 }
 
-fn query_transaction_evidence(deps: Deps, hash: String) -> StdResult<TransactionEvidence> {
-    let relayer_addresses = TX_EVIDENCES
-        .may_load(deps.storage, hash.clone())?
-        .map(|e| e.relayer_coreum_addresses);
+fn query_commit_reveal_threshold(
+    deps: Deps,
+    denom: String,
+) -> StdResult<CommitRevealThresholdResponse> {
+    let threshold = COMMIT_REVEAL_THRESHOLDS.may_load(deps.storage, denom.clone())?;
 
-    Ok(TransactionEvidence {
-        hash,
-        relayer_addresses: relayer_addresses.unwrap_or_default(),
-    })
+    Ok(CommitRevealThresholdResponse { denom, threshold })
 }
 
-fn query_transaction_evidences(
+fn query_transfer_commitments(
     deps: Deps,
+    sender: Addr,
     start_after_key: Option<String>,
     limit: Option<u32>,
-) -> TransactionEvidencesResponse {
+) -> StdResult<TransferCommitmentsResponse> {
+    let operations_config = OPERATIONS_CONFIG.load(deps.storage)?;
     let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
     let start = start_after_key.map(Bound::exclusive);
     let mut last_key = None;
-    let transaction_evidences: Vec<TransactionEvidence> = TX_EVIDENCES
+
+    let transfer_commitments: Vec<TransferCommitmentInfo> = TRANSFER_COMMITMENTS
+        .prefix(sender)
         .range(deps.storage, start, None, Order::Ascending)
         .take(limit as usize)
         .filter_map(Result::ok)
-        .map(|(evidence_hash, e)| {
-            last_key = Some(evidence_hash.clone());
-            TransactionEvidence {
-                hash: evidence_hash,
-                relayer_addresses: e.relayer_coreum_addresses,
+        .map(|(commitment, transfer_commitment)| {
+            last_key = Some(commitment.clone());
+            TransferCommitmentInfo {
+                commitment,
+                revealable_at_height: transfer_commitment
+                    .committed_at_height
+                    .saturating_add(operations_config.commit_reveal_delay),
+                expires_at_height: transfer_commitment
+                    .committed_at_height
+                    .saturating_add(operations_config.commit_reveal_expiration),
+                coin: transfer_commitment.coin,
+                committed_at_height: transfer_commitment.committed_at_height,
             }
         })
         .collect();
 
-    TransactionEvidencesResponse {
+    Ok(TransferCommitmentsResponse {
         last_key,
-        transaction_evidences,
+        transfer_commitments,
+    })
+}
+
+fn query_xrpl_address(deps: Deps, address: String) -> XRPLAddressResponse {
+    let is_x_address = is_x_address(&address);
+
+    // Invalid addresses don't make the query fail, we return the reason so that it can be displayed to the user
+    match decode_xrpl_recipient(deps.storage, &address) {
+        Ok((classic_address, destination_tag)) => XRPLAddressResponse {
+            valid: true,
+            error: None,
+            error_code: None,
+            is_x_address,
+            prohibited: PROHIBITED_XRPL_ADDRESSES.has(deps.storage, classic_address.clone()),
+            classic_address: Some(classic_address),
+            destination_tag,
+        },
+        Err(error) => XRPLAddressResponse {
+            valid: false,
+            error: Some(error.to_string()),
+            error_code: Some(error.code()),
+            is_x_address,
+            classic_address: None,
+            destination_tag: None,
+            prohibited: false,
+        },
     }
 }
 
-fn query_processed_tx(deps: Deps, hash: String) -> bool {
-    PROCESSED_TXS.has(deps.storage, hash)
+// Uses the same calculations as the send_to_xrpl execution (without a deliver amount)
+fn query_coreum_to_xrpl_amount(
+    deps: Deps,
+    env: Env,
+    denom: String,
+    amount: Uint128,
+) -> StdResult<AmountConversionResponse> {
+    calculate_coreum_to_xrpl_amount(deps, env, denom, amount)
+        .map_err(|e| StdError::generic_err(e.to_string()))
 }
 
-fn query_processed_txs(
+fn calculate_coreum_to_xrpl_amount(
     deps: Deps,
-    start_after_key: Option<String>,
-    limit: Option<u32>,
-) -> ProcessedTxsResponse {
-    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
-    let start = start_after_key.map(Bound::exclusive);
-    let mut last_key = None;
-    let processed_txs: Vec<String> = PROCESSED_TXS
-        .range(deps.storage, start, None, Order::Ascending)
-        .take(limit as usize)
-        .filter_map(Result::ok)
-        .map(|(hash, _)| {
-            last_key = Some(hash.clone());
-            hash
-        })
-        .collect();
+    env: Env,
+    denom: String,
+    amount: Uint128,
+) -> Result<AmountConversionResponse, ContractError> {
+    let (amount_to_send, remainder, dust, bridging_fee) = if let Some(mut xrpl_token) = XRPL_TOKENS
+        .idx
+        .coreum_denom
+        .item(deps.storage, denom.clone())
+        .map(|res| res.map(|pk_token| pk_token.1))?
+    {
+        if xrpl_token.state.ne(&TokenState::Enabled) {
+            return Err(ContractError::TokenNotEnabled {});
+        }
 
-    ProcessedTxsResponse {
-        last_key,
-        processed_txs,
-    }
+        let decimals = if is_token_xrp(&xrpl_token.issuer, &xrpl_token.currency) {
+            XRP_DECIMALS
+        } else {
+            XRPL_TOKENS_DECIMALS
+        };
+        xrpl_token.bridging_fee = effective_bridging_fee(
+            deps.storage,
+            &xrpl_token.coreum_denom,
+            decimals,
+            xrpl_token.bridging_fee,
+            env.block.time.seconds(),
+        )?;
+
+        let (amount_to_send, remainder, dust) = coreum_to_xrpl_amounts(
+            true,
+            xrpl_token.sending_precision,
+            decimals,
+            xrpl_token.bridging_fee,
+            &xrpl_token.rounding_mode,
+            amount,
+        )?;
+
+        (amount_to_send, remainder, dust, xrpl_token.bridging_fee)
+    } else {
+        let mut coreum_token = COREUM_TOKENS
+            .load(deps.storage, denom)
+            .map_err(|_| ContractError::TokenNotRegistered {})?;
+        if coreum_token.state.ne(&TokenState::Enabled) {
+            return Err(ContractError::TokenNotEnabled {});
+        }
+        coreum_token.bridging_fee = effective_bridging_fee(
+            deps.storage,
+            &coreum_token.denom,
+            coreum_token.decimals,
+            coreum_token.bridging_fee,
+            env.block.time.seconds(),
+        )?;
+
+        let (amount_to_send, remainder, dust) = coreum_to_xrpl_amounts(
+            false,
+            coreum_token.sending_precision,
+            coreum_token.decimals,
+            coreum_token.bridging_fee,
+            &coreum_token.rounding_mode,
+            amount,
+        )?;
+
+        (amount_to_send, remainder, dust, coreum_token.bridging_fee)
+    };
+
+    validate_xrpl_amount(amount_to_send)?;
+
+    Ok(AmountConversionResponse {
+        amount: amount_to_send,
+        bridging_fee,
+        remainder,
+        dust,
+    })
 }
 
-fn query_prohibited_xrpl_addresses(deps: Deps) -> ProhibitedXRPLAddressesResponse {
-    let prohibited_xrpl_addresses: Vec<String> = PROHIBITED_XRPL_ADDRESSES
-        .range(deps.storage, None, None, Order::Ascending)
-        .filter_map(Result::ok)
-        .map(|(addr, _)| addr)
-        .collect();
+// Uses the same calculations as the save_evidence execution for transfers from XRPL
+fn query_xrpl_to_coreum_amount(
+    deps: Deps,
+    env: Env,
+    issuer: String,
+    currency: String,
+    amount: Uint128,
+) -> StdResult<AmountConversionResponse> {
+    calculate_xrpl_to_coreum_amount(deps, env, issuer, currency, amount)
+        .map_err(|e| StdError::generic_err(e.to_string()))
+}
 
-    ProhibitedXRPLAddressesResponse {
-        prohibited_xrpl_addresses,
+fn calculate_xrpl_to_coreum_amount(
+    deps: Deps,
+    env: Env,
+    issuer: String,
+    currency: String,
+    amount: Uint128,
+) -> Result<AmountConversionResponse, ContractError> {
+    let xrpl_config = XRPL_CONFIG.load(deps.storage)?;
+
+    // Same as in save_evidence, if the issuer is not the XRPL multisig address the token is an XRPL originated token
+    if issuer.ne(&xrpl_config.bridge_xrpl_address) {
+        let mut token = XRPL_TOKENS
+            .load(deps.storage, build_xrpl_token_key(&issuer, &currency))
+            .map_err(|_| ContractError::TokenNotRegistered {})?;
+        if token.state.ne(&TokenState::Enabled) {
+            return Err(ContractError::TokenNotEnabled {});
+        }
+
+        let decimals = if is_token_xrp(&token.issuer, &token.currency) {
+            XRP_DECIMALS
+        } else {
+            XRPL_TOKENS_DECIMALS
+        };
+        token.bridging_fee = effective_bridging_fee(
+            deps.storage,
+            &token.coreum_denom,
+            decimals,
+            token.bridging_fee,
+            env.block.time.seconds(),
+        )?;
+
+        let (amount_to_send, remainder, dust) = xrpl_to_coreum_amounts(
+            true,
+            token.sending_precision,
+            decimals,
+            token.bridging_fee,
+            &token.rounding_mode,
+            amount,
+        )?;
+
+        return Ok(AmountConversionResponse {
+            amount: amount_to_send,
+            bridging_fee: token.bridging_fee,
+            remainder,
+            dust,
+        });
+    }
+
+    let mut token = COREUM_TOKENS
+        .idx
+        .xrpl_currency
+        .item(deps.storage, currency)?
+        .map(|(_, ct)| ct)
+        .ok_or(ContractError::TokenNotRegistered {})?;
+    if token.state.ne(&TokenState::Enabled) {
+        return Err(ContractError::TokenNotEnabled {});
     }
+    token.bridging_fee = effective_bridging_fee(
+        deps.storage,
+        &token.denom,
+        token.decimals,
+        token.bridging_fee,
+        env.block.time.seconds(),
+    )?;
+
+    let (amount_to_send, remainder, dust) = xrpl_to_coreum_amounts(
+        false,
+        token.sending_precision,
+        token.decimals,
+        token.bridging_fee,
+        &token.rounding_mode,
+        amount,
+    )?;
+
+    Ok(AmountConversionResponse {
+        amount: amount_to_send,
+        bridging_fee: token.bridging_fee,
+        remainder,
+        dust,
+    })
 }
 
 // ********** Helpers **********
@@ -1725,6 +7897,40 @@ pub fn validate_sending_precision(
     if sending_precision > decimals as i32 {
         return Err(ContractError::InvalidSendingPrecision {});
     }
+
+    // The smallest amount that can be sent must fit in a Uint128, otherwise nothing could be bridged.
+    // Because the sending precision is at most 15, this also guarantees that amounts with the 15 decimals of XRPL can be scaled to the token decimals
+    if checked_decimals_factor((decimals as i32 - sending_precision).unsigned_abs()).is_none() {
+        return Err(ContractError::UnsupportedTokenPrecision {
+            decimals,
+            sending_precision,
+        });
+    }
+
+    Ok(())
+}
+
+// The symbol of an FT follows the same rules as a denom
+fn validate_token_symbol(symbol: &str) -> Result<(), ContractError> {
+    validate_coreum_denom(symbol).map_err(|_| ContractError::InvalidTokenSymbol {})
+}
+
+fn validate_token_metadata(
+    description: &Option<String>,
+    uri: &Option<String>,
+) -> Result<(), ContractError> {
+    if let Some(description) = description {
+        if description.len() > MAX_TOKEN_DESCRIPTION_LENGTH {
+            return Err(ContractError::InvalidTokenDescription {});
+        }
+    }
+
+    if let Some(uri) = uri {
+        if uri.len() > MAX_TOKEN_URI_LENGTH {
+            return Err(ContractError::InvalidTokenURI {});
+        }
+    }
+
     Ok(())
 }
 
@@ -1747,6 +7953,17 @@ fn validate_coreum_denom(denom: &str) -> Result<(), ContractError> {
         }
     }
 
+    // Tokens that arrived to Coreum over IBC must have a well formed denom hash
+    if let Some(hash) = denom.strip_prefix(IBC_DENOM_PREFIX) {
+        if hash.len() != IBC_DENOM_HASH_LENGTH
+            || !hash
+                .chars()
+                .all(|c| c.is_ascii_digit() || ('A'..='F').contains(&c))
+        {
+            return Err(ContractError::InvalidIBCDenom {});
+        }
+    }
+
     Ok(())
 }
 
@@ -1761,37 +7978,21 @@ fn truncate_amount(
     // Example: if sending precision = 14. Exponent will be 15 - 14 = 1 for XRPL tokens so we will divide the original amount by 10
     let exponent = decimals as i32 - sending_precision;
 
-    let amount_to_send = amount.checked_div(Uint128::new(10u128.pow(exponent.unsigned_abs())))?;
+    // If the factor doesn't fit in a Uint128, no amount is big enough to be sent
+    let factor = checked_decimals_factor(exponent.unsigned_abs())
+        .ok_or(ContractError::AmountSentIsZeroAfterTruncation {})?;
+
+    let amount_to_send = amount.checked_div(factor)?;
 
     if amount_to_send.is_zero() {
         return Err(ContractError::AmountSentIsZeroAfterTruncation {});
     }
 
-    let truncated_amount =
-        amount_to_send.checked_mul(Uint128::new(10u128.pow(exponent.unsigned_abs())))?;
+    let truncated_amount = amount_to_send.checked_mul(factor)?;
     let remainder = amount.checked_sub(truncated_amount)?;
     Ok((truncated_amount, remainder))
 }
 
-// Function used to convert the amount received from XRPL with XRPL decimals to the Coreum amount with Coreum decimals
-pub fn convert_amount_decimals(
-    from_decimals: u32,
-    to_decimals: u32,
-    amount: Uint128,
-) -> Result<Uint128, ContractError> {
-    let converted_amount = match from_decimals.cmp(&to_decimals) {
-        std::cmp::Ordering::Less => amount.checked_mul(Uint128::new(
-            10u128.pow(to_decimals.saturating_sub(from_decimals)),
-        ))?,
-        std::cmp::Ordering::Greater => amount.checked_div(Uint128::new(
-            10u128.pow(from_decimals.saturating_sub(to_decimals)),
-        ))?,
-        std::cmp::Ordering::Equal => amount,
-    };
-
-    Ok(converted_amount)
-}
-
 // Helper function to combine the conversion and truncation of amounts including substracting fees.
 fn convert_and_truncate_amount(
     sending_precision: i32,
@@ -1830,6 +8031,74 @@ fn truncate_and_convert_amount(
     Ok((converted_amount, remainder))
 }
 
+// Calculates the amount of a transfer to XRPL (in XRPL decimals), the truncated part collected as fees and the dust returned to the sender
+// Both the execution and its simulation use it, so that the simulated amounts are the ones that are charged
+fn coreum_to_xrpl_amounts(
+    xrpl_originated: bool,
+    sending_precision: i32,
+    decimals: u32,
+    bridging_fee: Uint128,
+    rounding_mode: &RoundingMode,
+    amount: Uint128,
+) -> Result<(Uint128, Uint128, Uint128), ContractError> {
+    let (amount_to_send, remainder) = if xrpl_originated {
+        // We don't need any decimal conversion because XRPL originated tokens are issued with the same decimals
+        truncate_amount(
+            sending_precision,
+            decimals,
+            amount_after_bridge_fees(amount, bridging_fee)?,
+        )?
+    } else {
+        // Coreum originated tokens are first truncated according to the sending precision and then converted to the XRPL decimals
+        truncate_and_convert_amount(
+            sending_precision,
+            decimals,
+            XRPL_TOKENS_DECIMALS,
+            amount,
+            bridging_fee,
+        )?
+    };
+
+    // Depending on the rounding mode, the truncated amount is collected as fees, returned to the sender or the transfer is rejected
+    let (fee_remainder, dust) = split_truncation_remainder(rounding_mode, remainder, true)?;
+
+    Ok((amount_to_send, fee_remainder, dust))
+}
+
+// Calculates the amount of a transfer from XRPL delivered to the recipient (including the dust), the truncated part collected as fees and the dust
+// Both the execution and its simulation use it, so that the simulated amounts are the ones that are charged
+fn xrpl_to_coreum_amounts(
+    xrpl_originated: bool,
+    sending_precision: i32,
+    decimals: u32,
+    bridging_fee: Uint128,
+    rounding_mode: &RoundingMode,
+    amount: Uint128,
+) -> Result<(Uint128, Uint128, Uint128), ContractError> {
+    let (amount_to_send, remainder) = if xrpl_originated {
+        // Here we simply truncate because the Coreum tokens corresponding to XRPL originated tokens have the same decimals
+        truncate_amount(
+            sending_precision,
+            decimals,
+            amount_after_bridge_fees(amount, bridging_fee)?,
+        )?
+    } else {
+        // We first convert the amount with XRPL decimals to the decimals of the Coreum token and then we truncate according to the sending precision
+        convert_and_truncate_amount(
+            sending_precision,
+            XRPL_TOKENS_DECIMALS,
+            decimals,
+            amount,
+            bridging_fee,
+        )?
+    };
+
+    // Transfers from XRPL can't be rejected, the dust that is not collected as fees is delivered to the recipient
+    let (fee_remainder, dust) = split_truncation_remainder(rounding_mode, remainder, false)?;
+
+    Ok((amount_to_send.checked_add(dust)?, fee_remainder, dust))
+}
+
 // Helper function to validate that we are not sending an invalid amount to XRPL
 // A valid amount is one that doesn't have more than 16 digits after trimming trailing zeroes
 // Example: 1000000000000000000000000000 is valid
@@ -1846,6 +8115,42 @@ fn validate_xrpl_amount(amount: Uint128) -> Result<(), ContractError> {
     Ok(())
 }
 
+// Helper function to split an amount that is not valid to send to XRPL into valid amounts, keeping the most significant digits in the first one
+// Example: 12345678901234567890000 is split into 12345678901234560000000 and 7890000
+fn split_xrpl_amount(amount: Uint128) -> Vec<Uint128> {
+    let mut amounts = vec![];
+    let mut remaining = amount.u128();
+    loop {
+        let remaining_str = remaining.to_string();
+        if remaining_str.trim_end_matches('0').len() <= XRPL_MAX_TRUNCATED_AMOUNT_LENGTH {
+            amounts.push(Uint128::new(remaining));
+            return amounts;
+        }
+
+        let unit = 10u128.pow((remaining_str.len() - XRPL_MAX_TRUNCATED_AMOUNT_LENGTH) as u32);
+        amounts.push(Uint128::new(remaining - remaining % unit));
+        remaining %= unit;
+    }
+}
+
+// The currency is generated only from the denom so that it can be known before registering the token
+fn generate_xrpl_currency(denom: &str) -> Result<String, ContractError> {
+    let hex_string = hash_bytes(denom.as_bytes().to_vec())
+        .get(0..10)
+        .unwrap()
+        .to_string()
+        .to_lowercase();
+
+    // Format will be the hex representation in XRPL of the string coreum<hash> in uppercase
+    let xrpl_currency =
+        convert_currency_to_xrpl_hexadecimal(format!("{COREUM_CURRENCY_PREFIX}{hex_string}"));
+
+    // Validate XRPL currency just in case we got an invalid XRPL currency (starting with 0x00)
+    validate_xrpl_currency(&xrpl_currency)?;
+
+    Ok(xrpl_currency)
+}
+
 fn convert_currency_to_xrpl_hexadecimal(currency: String) -> String {
     // Fill with zeros to get the correct hex representation in XRPL of our currency.
     format!("{:0<40}", hex::encode(currency)).to_uppercase()
@@ -1878,8 +8183,20 @@ fn check_authorization(
 
 // Helper function to check that bridge is active
 pub fn assert_bridge_active(deps: Deps) -> Result<(), ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-    if config.bridge_state.ne(&BridgeState::Active) {
+    match BRIDGE_STATE_CONFIG.load(deps.storage)?.bridge_state {
+        BridgeState::Active => Ok(()),
+        BridgeState::Halted => Err(ContractError::BridgeHalted {}),
+        BridgeState::Maintenance => Err(ContractError::BridgeInMaintenance {}),
+    }
+}
+
+// Actions that don't initiate new transfers are still allowed while the bridge is in maintenance
+pub fn assert_bridge_not_halted(deps: Deps) -> Result<(), ContractError> {
+    if BRIDGE_STATE_CONFIG
+        .load(deps.storage)?
+        .bridge_state
+        .eq(&BridgeState::Halted)
+    {
         return Err(ContractError::BridgeHalted {});
     }
     Ok(())
@@ -1889,8 +8206,23 @@ fn update_bridge_state(
     storage: &mut dyn Storage,
     bridge_state: BridgeState,
 ) -> Result<(), ContractError> {
-    let mut config = CONFIG.load(storage)?;
-    config.bridge_state = bridge_state;
-    CONFIG.save(storage, &config)?;
+    // Halt requests and acknowledgements are only valid for the state they were made in
+    HALT_REQUESTS.save(storage, &vec![])?;
+    BRIDGE_STATE_ACKNOWLEDGEMENTS.clear(storage);
+
+    let mut bridge_state_config = BRIDGE_STATE_CONFIG.load(storage)?;
+    // The halt information is only kept while the bridge is halted
+    if bridge_state.eq(&BridgeState::Active) {
+        bridge_state_config.halt_info = None;
+    }
+    bridge_state_config.bridge_state = bridge_state;
+    BRIDGE_STATE_CONFIG.save(storage, &bridge_state_config)?;
+    Ok(())
+}
+
+fn save_halt_info(storage: &mut dyn Storage, halt_info: HaltInfo) -> Result<(), ContractError> {
+    let mut bridge_state_config = BRIDGE_STATE_CONFIG.load(storage)?;
+    bridge_state_config.halt_info = Some(halt_info);
+    BRIDGE_STATE_CONFIG.save(storage, &bridge_state_config)?;
     Ok(())
 }
@@ -3,201 +3,767 @@ use cw_ownable::OwnershipError;
 use cw_utils::PaymentError;
 use thiserror::Error;
 
-use crate::contract::{MAX_COREUM_TOKEN_DECIMALS, MAX_RELAYERS, MAX_TICKETS};
+use crate::contract::{
+    MAX_COREUM_TOKEN_DECIMALS, MAX_EVIDENCE_SOURCE_LENGTH, MAX_HALT_MESSAGE_LENGTH,
+    MAX_IDEMPOTENCY_KEY_LENGTH, MAX_OPERATIONS_PER_CANCELLATION,
+    MAX_RELAYER_ATTESTATION_FIELD_LENGTH, MAX_TICKETS, MAX_TOKEN_DESCRIPTION_LENGTH,
+    MAX_TOKEN_URI_LENGTH, MAX_TRANSACTION_BLOB_LENGTH, XRPL_MAX_SIGNERS,
+};
 
 #[derive(Error, Debug)]
 pub enum ContractError {
-    #[error("{0}")]
+    #[error("[E0001] Std: {0}")]
     Std(#[from] StdError),
 
-    #[error(transparent)]
+    #[error("[E0002] Ownership: {0}")]
     Ownership(#[from] OwnershipError),
 
-    #[error(transparent)]
+    #[error("[E0003] OverflowError: {0}")]
     OverflowError(#[from] OverflowError),
 
-    #[error(transparent)]
+    #[error("[E0004] DivideByZeroError: {0}")]
     DivideByZeroError(#[from] DivideByZeroError),
 
-    #[error("Payment error: {0}")]
+    #[error("[E0005] Payment: {0}")]
     Payment(#[from] PaymentError),
 
-    #[error("InvalidThreshold: Threshold can not be 0 or higher than amount of relayers")]
+    #[error("[E0006] InvalidThreshold: Threshold can not be 0 or higher than amount of relayers")]
     InvalidThreshold {},
 
-    #[error("InvalidXRPLAddress: XRPL address {} is not valid", address)]
+    #[error("[E0007] InvalidXRPLAddress: XRPL address {} is not valid", address)]
     InvalidXRPLAddress { address: String },
 
-    #[error("DuplicatedRelayer: All relayers must have different XRPL addresses, public keys and coreum addresses")]
+    #[error("[E0008] DuplicatedRelayer: All relayers must have different XRPL addresses, public keys and coreum addresses")]
     DuplicatedRelayer {},
 
-    #[error("CoreumTokenAlreadyRegistered: Token {} already registered", denom)]
+    #[error(
+        "[E0009] CoreumTokenAlreadyRegistered: Token {} already registered",
+        denom
+    )]
     CoreumTokenAlreadyRegistered { denom: String },
 
     #[error(
-        "XRPLTokenAlreadyRegistered: Token with issuer: {} and currency: {} is already registered",
+        "[E0010] XRPLTokenAlreadyRegistered: Token with issuer: {} and currency: {} is already registered",
         issuer,
         currency
     )]
     XRPLTokenAlreadyRegistered { issuer: String, currency: String },
 
-    #[error("InvalidFundsAmount: Need to send exactly the issue fee amount")]
+    #[error("[E0011] InvalidFundsAmount: Need to send exactly the issue fee amount")]
     InvalidFundsAmount {},
 
-    #[error("RegistrationFailure: Currency/denom generated already exists, please try again")]
+    #[error(
+        "[E0012] RegistrationFailure: Currency/denom generated already exists, please try again"
+    )]
     RegistrationFailure {},
 
-    #[error("UnauthorizedSender: Sender is not authorized for this operation")]
+    #[error("[E0013] UnauthorizedSender: Sender is not authorized for this operation")]
     UnauthorizedSender {},
 
-    #[error("TokenNotRegistered: The token must be registered first before bridging")]
+    #[error(
+        "[E0014] UndeliveredTransferNotFound: There is no undelivered transfer with this id for the sender"
+    )]
+    UndeliveredTransferNotFound {},
+
+    #[error(
+        "[E0015] UnknownReplyId: Reply with id {} is not handled by the contract",
+        id
+    )]
+    UnknownReplyId { id: u64 },
+
+    #[error("[E0016] TokenNotRegistered: The token must be registered first before bridging")]
     TokenNotRegistered {},
 
-    #[error("OperationAlreadyExecuted: The operation has already been executed")]
+    #[error("[E0017] OperationAlreadyExecuted: The operation has already been executed")]
     OperationAlreadyExecuted {},
 
     #[error(
-        "EvidenceAlreadyProvided: The relayer already provided its evidence for the operation"
+        "[E0018] EvidenceAlreadyProvided: The relayer already provided its evidence for the operation"
     )]
     EvidenceAlreadyProvided {},
 
-    #[error("InvalidAmount: Amount must be more than 0")]
+    #[error("[E0019] InvalidAmount: Amount must be more than 0")]
     InvalidAmount {},
 
-    #[error("InvalidUsedTicketSequenceThreshold: Used ticket sequences threshold must be more than 1 and less or equal than {}", MAX_TICKETS)]
+    #[error(
+        "[E0020] InvalidTransferRate: The transfer rate must be 0 or between 1000000000 and 2000000000"
+    )]
+    InvalidTransferRate {},
+
+    #[error("[E0021] InvalidUsedTicketSequenceThreshold: Used ticket sequences threshold must be more than 1 and less or equal than {}", MAX_TICKETS)]
     InvalidUsedTicketSequenceThreshold {},
 
-    #[error("NoAvailableTickets: There are no available tickets")]
+    #[error("[E0022] NoAvailableTickets: There are no available tickets")]
     NoAvailableTickets {},
 
-    #[error("LastTicketReserved: Last available ticket is reserved for updating tickets")]
+    #[error("[E0023] LastTicketReserved: Last available ticket is reserved for updating tickets")]
     LastTicketReserved {},
 
-    #[error("StillHaveAvailableTickets: Can't recover tickets if we still have tickets available")]
+    #[error("[E0024] StillHaveAvailableTickets: Can't recover tickets if we still have tickets available")]
     StillHaveAvailableTickets {},
 
     #[error(
-        "PendingTicketUpdate: There is a pending ticket update operation already in the queue"
+        "[E0025] PendingTicketUpdate: There is a pending ticket update operation already in the queue"
     )]
     PendingTicketUpdate {},
 
-    #[error("InvalidTransactionResultEvidence: An evidence must contain only one of sequence number or ticket number")]
+    #[error("[E0026] InvalidTransactionResultEvidence: An evidence must contain only one of sequence number or ticket number")]
     InvalidTransactionResultEvidence {},
 
-    #[error("InvalidSuccessfulTransactionResultEvidence: An evidence with a successful transaction must contain a transaction hash")]
+    #[error(
+        "[E0027] InvalidMultiTransferEvidence: An evidence must contain between 1 and 50 transfers"
+    )]
+    InvalidMultiTransferEvidence {},
+
+    #[error("[E0028] InvalidSuccessfulTransactionResultEvidence: An evidence with a successful transaction must contain a transaction hash")]
     InvalidSuccessfulTransactionResultEvidence {},
 
-    #[error("InvalidFailedTransactionResultEvidence: An evidence with an failed transaction can't have a transaction hash")]
+    #[error("[E0029] InvalidFailedTransactionResultEvidence: An evidence with an failed transaction can't have a transaction hash")]
     InvalidFailedTransactionResultEvidence {},
 
-    #[error("InvalidTicketAllocationEvidence: Tickets have to be present if operation is accepted and absent if operation is rejected or invalid")]
+    #[error("[E0030] InvalidTicketAllocationEvidence: Tickets have to be present if operation is accepted and absent if operation is rejected or invalid")]
     InvalidTicketAllocationEvidence {},
 
     #[error(
-        "PendingOperationNotFound: There is no pending operation with this ticket/sequence number"
+        "[E0031] PendingOperationNotFound: There is no pending operation with this ticket/sequence number"
     )]
     PendingOperationNotFound {},
 
     #[error(
-    "PendingOperationAlreadyExists: There is already a pending operation with this operation id"
+    "[E0032] PendingOperationAlreadyExists: There is already a pending operation with this operation id"
     )]
     PendingOperationAlreadyExists {},
 
-    #[error("SignatureAlreadyProvided: There is already a signature provided for this relayer and this operation")]
+    #[error("[E0033] SignatureAlreadyProvided: There is already a signature provided for this relayer and this operation")]
     SignatureAlreadyProvided {},
 
-    #[error("InvalidTicketSequenceToAllocate: The number of tickets to recover must be greater than used ticket threshold and less than or equal to max allowed")]
+    #[error("[E0034] InvalidTicketSequenceToAllocate: The number of tickets to recover must be greater than used ticket threshold and less than or equal to max allowed")]
     InvalidTicketSequenceToAllocate {},
 
-    #[error("InvalidXRPLCurrency: The currency must be a valid XRPL currency")]
+    #[error("[E0035] InvalidXRPLCurrency: The currency must be a valid XRPL currency")]
     InvalidXRPLCurrency {},
 
-    #[error("TokenNotEnabled: This token must be enabled to be bridged")]
+    #[error("[E0036] TokenNotEnabled: This token must be enabled to be bridged")]
     TokenNotEnabled {},
 
-    #[error("XRPLTokenNotInactive: To recover this token it must be inactive")]
+    #[error("[E0037] XRPLTokenNotInactive: To recover this token it must be inactive")]
     XRPLTokenNotInactive {},
 
-    #[error("AmountSentIsZeroAfterTruncation: Amount sent is zero after truncating to sending precision")]
+    #[error("[E0038] AmountSentIsZeroAfterTruncation: Amount sent is zero after truncating to sending precision")]
     AmountSentIsZeroAfterTruncation {},
 
-    #[error("MaximumBridgedAmountReached: The maximum amount this contract can have bridged has been reached")]
+    #[error("[E0039] MaximumBridgedAmountReached: The maximum amount this contract can have bridged has been reached")]
     MaximumBridgedAmountReached {},
 
     #[error(
-    "InvalidSendingPrecision: The sending precision can't be more than the token decimals or less than the negative token decimals"
+    "[E0040] InvalidSendingPrecision: The sending precision can't be more than the token decimals or less than the negative token decimals"
     )]
     InvalidSendingPrecision {},
 
     #[error(
-        "InvalidDecimals: registered Coreum token can't have more than {} decimals",
+        "[E0041] InvalidDecimals: registered Coreum token can't have more than {} decimals",
         MAX_COREUM_TOKEN_DECIMALS
     )]
     InvalidDecimals {},
 
-    #[error("InvalidOperationResult: OperationResult doesn't match a Pending Operation with the right Operation Type")]
+    #[error("[E0042] InvalidOperationResult: OperationResult doesn't match a Pending Operation with the right Operation Type")]
     InvalidOperationResult {},
 
-    #[error("CannotCoverBridgingFees: The amount sent is not enough to cover the bridging fees")]
+    #[error(
+        "[E0043] CannotCoverBridgingFees: The amount sent is not enough to cover the bridging fees"
+    )]
     CannotCoverBridgingFees {},
 
-    #[error("TokenStateIsImmutable: Current token state is immutable")]
+    #[error("[E0044] TokenStateIsImmutable: Current token state is immutable")]
     TokenStateIsImmutable {},
 
-    #[error("InvalidTargetTokenState: A token state can only be updated to enabled or disabled")]
+    #[error(
+        "[E0045] InvalidTargetTokenState: A token state can only be updated to enabled or disabled"
+    )]
     InvalidTargetTokenState {},
 
-    #[error("InvalidTargetMaxHoldingAmount: Max holding amount can't be less than the current amount of tokens held in the bridge")]
+    #[error("[E0046] InvalidTargetMaxHoldingAmount: Max holding amount can't be less than the current amount of tokens held in the bridge")]
     InvalidTargetMaxHoldingAmount {},
 
     #[error(
-        "PendingRefundNotFound: There is no pending refund for this user and pending operation id"
+        "[E0047] PendingRefundNotFound: There is no pending refund for this user and pending operation id"
     )]
     PendingRefundNotFound {},
 
     #[error(
-        "NotEnoughFeesToClaim: The fee {} {} is not claimable because there are not enough fees collected",
+        "[E0048] NotEnoughSurplusToRescue: Only {} {} can be rescued without using funds the bridge is accountable for",
+        surplus,
+        denom
+    )]
+    NotEnoughSurplusToRescue { denom: String, surplus: Uint128 },
+
+    #[error(
+        "[E0049] NotEnoughFeesToClaim: The fee {} {} is not claimable because there are not enough fees collected",
         amount,
         denom
     )]
     NotEnoughFeesToClaim { denom: String, amount: Uint128 },
 
+    #[error("[E0050] TooManyRelayers: too many relayers provided, max allowed is {max_relayers}")]
+    TooManyRelayers { max_relayers: u32 },
+
     #[error(
-        "TooManyRelayers: too many relayers provided, max allowed is {}",
-        MAX_RELAYERS
+        "[E0051] BridgeHalted: The bridge is currently halted and this operation is not authorized"
     )]
-    TooManyRelayers {},
-
-    #[error("BridgeHalted: The bridge is currently halted and this operation is not authorized")]
     BridgeHalted {},
 
-    #[error("RotateKeysOngoing: Can't perform this operation while there is a rotate key operation ongoing")]
+    #[error("[E0052] BridgeNotHalted: The bridge must be halted to do this")]
+    BridgeNotHalted {},
+
+    #[error("[E0053] BridgeStateMismatch: The bridge state acknowledged is not the current one")]
+    BridgeStateMismatch {},
+
+    #[error(
+        "[E0054] BridgeStateAlreadyAcknowledged: The relayer already acknowledged the current bridge state"
+    )]
+    BridgeStateAlreadyAcknowledged {},
+
+    #[error("[E0055] RotateKeysOngoing: Can't perform this operation while there is a rotate key operation ongoing")]
     RotateKeysOngoing {},
 
     #[error(
-        "OperationVersionMismatch: Can't add a signature for an operation with a different version"
+        "[E0056] OperationVersionMismatch: Can't add a signature for an operation with a different version"
     )]
     OperationVersionMismatch {},
 
-    #[error("ProhibitedAddress: The address is prohibited")]
+    #[error("[E0057] ProhibitedAddress: The address is prohibited")]
     ProhibitedAddress {},
 
-    #[error("DeliverAmountIsProhibited: Optional deliver_amount field is only used for XRPL originated tokens (except XRP) being bridged back")]
+    #[error("[E0058] CannotRemoveBridgeXRPLAddress: The XRPL address of the bridge is always prohibited")]
+    CannotRemoveBridgeXRPLAddress {},
+
+    #[error("[E0059] InvalidXRPLRecipientRule: The XRPL recipient rule must have a name and at least one address")]
+    InvalidXRPLRecipientRule {},
+
+    #[error("[E0060] XRPLRecipientRuleNotFound: There is no XRPL recipient rule with this name")]
+    XRPLRecipientRuleNotFound {},
+
+    #[error(
+        "[E0061] XRPLRecipientRuleViolated: The recipient is prohibited by the XRPL recipient rule {}",
+        name
+    )]
+    XRPLRecipientRuleViolated { name: String },
+
+    #[error(
+        "[E0062] SplitAmountWithDeliverAmount: Amounts can't be split into multiple transfers if a deliver amount is provided"
+    )]
+    SplitAmountWithDeliverAmount {},
+
+    #[error("[E0063] DeliverAmountIsProhibited: Optional deliver_amount field is only used for XRPL originated tokens (except XRP) being bridged back")]
     DeliverAmountIsProhibited {},
 
     #[error(
-        "InvalidDeliverAmount: Field deliver_amount can't be greater than funds attached minus fees"
+        "[E0064] InvalidDeliverAmount: Field deliver_amount can't be greater than funds attached minus fees"
     )]
     InvalidDeliverAmount {},
 
-    #[error("InvalidSignatureLength: The signature sent can't be longer than 200 characters")]
+    #[error(
+        "[E0065] InvalidSignatureLength: The signature sent can't be longer than 200 characters"
+    )]
     InvalidSignatureLength {},
 
     #[error(
-        "InvalidXRPLAmount: Amounts sent to XRPL can't have more than 17 digits after trimming trailing zeroes"
+        "[E0066] InvalidXRPLAmount: Amounts sent to XRPL can't have more than 17 digits after trimming trailing zeroes"
     )]
     InvalidXRPLAmount {},
 
-    #[error("InvalidDenom: A valid denom must fulfil the following Regex criteria: [a-zA-Z][a-zA-Z0-9/:._-]{{2,127}}")]
+    #[error("[E0067] InvalidDenom: A valid denom must fulfil the following Regex criteria: [a-zA-Z][a-zA-Z0-9/:._-]{{2,127}}")]
     InvalidDenom {},
+
+    #[error("[E0068] InvalidIBCDenom: An IBC denom must be ibc/ followed by the 64 characters uppercase hex hash of its denom trace")]
+    InvalidIBCDenom {},
+
+    #[error("[E0069] InvalidTokenSymbol: A valid token symbol must fulfil the following Regex criteria: [a-zA-Z][a-zA-Z0-9/:._-]{{2,127}}")]
+    InvalidTokenSymbol {},
+
+    #[error(
+        "[E0070] InvalidTokenDescription: The token description can't be longer than {} characters",
+        MAX_TOKEN_DESCRIPTION_LENGTH
+    )]
+    InvalidTokenDescription {},
+
+    #[error(
+        "[E0071] InvalidTokenURI: The token URI can't be longer than {} characters",
+        MAX_TOKEN_URI_LENGTH
+    )]
+    InvalidTokenURI {},
+
+    #[error("[E0072] InvalidTokenIdentifier: Either the denom or the issuer and currency of the token must be provided")]
+    InvalidTokenIdentifier {},
+
+    #[error("[E0073] MaxRegisteredXRPLTokensReached: The maximum amount of XRPL tokens that can be registered has been reached")]
+    MaxRegisteredXRPLTokensReached {},
+
+    #[error("[E0074] RelayerReimbursementNotConfigured: The relayer reimbursement must be configured before funding its pool")]
+    RelayerReimbursementNotConfigured {},
+
+    #[error("[E0075] ReimbursementPoolNotEmpty: The denom of the relayer reimbursement can't be changed while its pool has funds")]
+    ReimbursementPoolNotEmpty {},
+
+    #[error("[E0076] TransferReceiptNotFound: There is no transfer receipt for this id or XRPL transaction hash")]
+    TransferReceiptNotFound {},
+
+    #[error("[E0077] XRPLCurrencyAlreadyInUse: The XRPL currency is already used by another registered Coreum token")]
+    XRPLCurrencyAlreadyInUse {},
+
+    #[error("[E0078] InvalidLightClientConfig: Validation quorum must be more than 0 and not higher than the amount of validators, which must be unique valid XRPL public keys")]
+    InvalidLightClientConfig {},
+
+    #[error("[E0079] LightClientNotEnabled: The light client verification mode is not enabled")]
+    LightClientNotEnabled {},
+
+    #[error("[E0080] InvalidXRPLTransactionProof: The proof doesn't prove a successful payment to the bridge included in the given ledger")]
+    InvalidXRPLTransactionProof {},
+
+    #[error("[E0081] NotEnoughXRPLValidations: The ledger was not validated by enough trusted validators")]
+    NotEnoughXRPLValidations {},
+
+    #[error("[E0082] XRPLTransactionNotProven: The XRPL transaction must be proven before providing an evidence for it")]
+    XRPLTransactionNotProven {},
+
+    #[error(
+        "[E0083] XRPLTransactionProofMismatch: The evidence doesn't match the proven XRPL transaction"
+    )]
+    XRPLTransactionProofMismatch {},
+
+    #[error(
+        "[E0084] HaltMessageTooLong: The halt message can't be longer than {} characters",
+        MAX_HALT_MESSAGE_LENGTH
+    )]
+    HaltMessageTooLong {},
+
+    #[error("[E0085] BridgeInMaintenance: The bridge is in maintenance")]
+    BridgeInMaintenance {},
+
+    #[error("[E0086] HaltAlreadyRequested: The relayer already requested the bridge to be halted")]
+    HaltAlreadyRequested {},
+
+    #[error("[E0087] TokenFrozen: The token is frozen and can't be transferred")]
+    TokenFrozen {},
+
+    #[error(
+        "[E0088] TokenFreezeAlreadyRequested: The relayer already requested the token to be frozen"
+    )]
+    TokenFreezeAlreadyRequested {},
+
+    #[error("[E0089] TokenNotFrozen: The token is not frozen")]
+    TokenNotFrozen {},
+
+    #[error("[E0090] InvalidTokenFreezeDuration: The token freeze duration must be more than 0")]
+    InvalidTokenFreezeDuration {},
+
+    #[error("[E0091] InvalidEvidenceTTL: The evidence TTL must be more than 0")]
+    InvalidEvidenceTTL {},
+
+    #[error("[E0092] ImpreciseAmount: The amount can't be sent without truncating it to the sending precision of the token")]
+    ImpreciseAmount {},
+
+    #[error("[E0093] NothingToSweep: The fee remainder of the token is not above the threshold")]
+    NothingToSweep {},
+
+    #[error(
+        "[E0094] TransferDenied: The compliance contract denied the transfer: {}",
+        reason
+    )]
+    TransferDenied { reason: String },
+
+    #[error(
+        "[E0095] CommitmentRequired: Transfers of this amount must be committed before being revealed"
+    )]
+    CommitmentRequired {},
+
+    #[error("[E0096] InvalidTransferCommitment: Commitments must be hex encoded SHA256 hashes")]
+    InvalidTransferCommitment {},
+
+    #[error(
+        "[E0097] TransferCommitmentAlreadyExists: A transfer with this commitment was already committed"
+    )]
+    TransferCommitmentAlreadyExists {},
+
+    #[error(
+        "[E0098] TransferCommitmentNotFound: There is no transfer committed with this commitment"
+    )]
+    TransferCommitmentNotFound {},
+
+    #[error(
+        "[E0099] TransferCommitmentMismatch: The revealed transfer doesn't match the commitment"
+    )]
+    TransferCommitmentMismatch {},
+
+    #[error("[E0100] CommitmentNotRevealable: The commit reveal delay has not passed yet")]
+    CommitmentNotRevealable {},
+
+    #[error("[E0101] CommitmentExpired: The commitment expired and can only be cancelled")]
+    CommitmentExpired {},
+
+    #[error(
+        "[E0102] CommitmentNotExpired: Only the sender can cancel a commitment that didn't expire"
+    )]
+    CommitmentNotExpired {},
+
+    #[error("[E0103] InvalidCommitRevealConfig: The commit reveal expiration must be greater than the commit reveal delay")]
+    InvalidCommitRevealConfig {},
+
+    #[error("[E0104] InvalidRecurringTransfer: The amount and the interval of recurring transfers must be greater than 0")]
+    InvalidRecurringTransfer {},
+
+    #[error("[E0105] RecurringTransferNotFound: The recurring transfer doesn't exist")]
+    RecurringTransferNotFound {},
+
+    #[error(
+        "[E0106] RecurringTransferNotDue: The next execution of the recurring transfer is not due yet"
+    )]
+    RecurringTransferNotDue {},
+
+    #[error("[E0107] InsufficientRecurringTransferBalance: The escrowed balance doesn't cover the next execution of the recurring transfer")]
+    InsufficientRecurringTransferBalance {},
+
+    #[error("[E0108] RecipientDepositAuthEnabled: The XRPL recipient only accepts payments from preauthorized accounts")]
+    RecipientDepositAuthEnabled {},
+
+    #[error("[E0109] RecipientDisallowsXRP: The XRPL recipient doesn't accept XRP")]
+    RecipientDisallowsXRP {},
+
+    #[error("[E0110] InvalidXRPLDexQuote: The quoted price must be more than 0")]
+    InvalidXRPLDexQuote {},
+
+    #[error(
+        "[E0111] InvalidXRPLDexQuoteTTL: The time during which DEX quotes are valid must be more than 0"
+    )]
+    InvalidXRPLDexQuoteTTL {},
+
+    #[error(
+        "[E0112] InvalidUSDBridgingFee: The minimum fee can't be greater than the maximum fee"
+    )]
+    InvalidUSDBridgingFee {},
+
+    #[error("[E0113] InvalidTokenPrice: Token prices must be more than 0")]
+    InvalidTokenPrice {},
+
+    #[error(
+        "[E0114] XRPLSupplyDriftNotFound: There is no reported supply drift for this XRPL transaction"
+    )]
+    XRPLSupplyDriftNotFound {},
+
+    #[error(
+        "[E0115] InvalidDepositNotFound: There is no invalid deposit for this XRPL transaction"
+    )]
+    InvalidDepositNotFound {},
+
+    #[error("[E0116] InsufficientXRPLReserve: The bridge XRPL account needs {required} drops for its reserve but only holds {available} drops that weren't bridged")]
+    InsufficientXRPLReserve {
+        required: Uint128,
+        available: Uint128,
+    },
+
+    #[error(
+        "[E0117] RelayerStillActive: The relayer was active within the relayer inactivity period"
+    )]
+    RelayerStillActive {},
+
+    #[error("[E0118] RelayerAlreadyInactive: The relayer is already marked as inactive")]
+    RelayerAlreadyInactive {},
+
+    #[error(
+        "[E0119] InvalidRelayerInactivityPeriod: The period after which relayers can be marked as inactive must be more than 0"
+    )]
+    InvalidRelayerInactivityPeriod {},
+
+    #[error(
+        "[E0120] InvalidOperationEvidencePeriod: The period during which operations wait for their evidence must be more than 0"
+    )]
+    InvalidOperationEvidencePeriod {},
+
+    #[error(
+        "[E0121] OperationNotExpired: The evidence deadline of the pending operation hasn't passed"
+    )]
+    OperationNotExpired {},
+
+    #[error("[E0122] NotARelayer: The address is not a relayer of the bridge")]
+    NotARelayer {},
+
+    #[error("[E0123] InvalidEvidenceSigningKey: Evidence signing keys must be hex encoded compressed secp256k1 or ed25519 (prefixed with ED) public keys")]
+    InvalidEvidenceSigningKey {},
+
+    #[error("[E0124] InvalidEvidenceDelegate: Evidence delegates can't be the owner, a relayer or the delegate of another relayer")]
+    InvalidEvidenceDelegate {},
+
+    #[error("[E0125] EvidenceSignatureRequired: Evidences of this relayer must be signed with its evidence signing key")]
+    EvidenceSignatureRequired {},
+
+    #[error("[E0126] InvalidEvidenceSignature: The evidence signature couldn't be verified with the evidence signing key of the relayer")]
+    InvalidEvidenceSignature {},
+
+    #[error("[E0127] EvidencesNotConflicting: The evidences must be different and for the same XRPL transaction")]
+    EvidencesNotConflicting {},
+
+    #[error("[E0128] ConflictingEvidenceNotFound: The evidence is not stored or it wasn't provided by the relayer")]
+    ConflictingEvidenceNotFound {},
+
+    #[error("[E0129] RelayerInfractionRecorded: The relayer provided conflicting evidences for this transaction and can't provide evidences for it anymore")]
+    RelayerInfractionRecorded {},
+
+    #[error("[E0130] PriorityLaneDisabled: The priority lane is disabled, no priority fee is set")]
+    PriorityLaneDisabled {},
+
+    #[error("[E0131] InvalidPriorityFee: The priority fee must be a non zero amount, sent exactly as a separate coin or included in the coin sent")]
+    InvalidPriorityFee {},
+
+    #[error("[E0132] EvidenceCloseTimeInFuture: The XRPL close time of the evidence ({close_time}) is later than the maximum allowed ({max_close_time})")]
+    EvidenceCloseTimeInFuture {
+        close_time: u64,
+        max_close_time: u64,
+    },
+
+    #[error("[E0133] TransactionHashMismatch: The transaction hash doesn't match the transaction built with the signatures of the operation")]
+    TransactionHashMismatch {},
+
+    #[error(
+        "[E0134] NotTransferSender: Only the sender of a pending transfer to XRPL can cancel it"
+    )]
+    NotTransferSender {},
+
+    #[error("[E0135] OperationAlreadySigned: Signatures were already collected for the operation")]
+    OperationAlreadySigned {},
+
+    #[error(
+        "[E0136] InvalidOperationsToCancel: Between 1 and {} operations can be cancelled at once",
+        MAX_OPERATIONS_PER_CANCELLATION
+    )]
+    InvalidOperationsToCancel {},
+
+    #[error(
+        "[E0137] InvalidIdempotencyKey: The idempotency key must have between 1 and {} characters",
+        MAX_IDEMPOTENCY_KEY_LENGTH
+    )]
+    InvalidIdempotencyKey {},
+
+    #[error("[E0138] OperationRelayerSetOutdated: The operation was created for a previous relayer set and must be rebased before it can be signed")]
+    OperationRelayerSetOutdated {},
+
+    #[error("[E0139] TokenNotIssuedByBridge: The token was not issued by the bridge")]
+    TokenNotIssuedByBridge {},
+
+    #[error("[E0140] OperationQuorumNotReached: The operation doesn't have enough signatures to be submitted to XRPL")]
+    OperationQuorumNotReached {},
+
+    #[error(
+        "[E0141] InvalidTransactionSubmission: The transaction hash must be 32 hex encoded bytes and the transaction blob at most {} hex characters",
+        MAX_TRANSACTION_BLOB_LENGTH
+    )]
+    InvalidTransactionSubmission {},
+
+    #[error(
+        "[E0142] InvalidEvidenceSource: The evidence source must have between 1 and {} characters",
+        MAX_EVIDENCE_SOURCE_LENGTH
+    )]
+    InvalidEvidenceSource {},
+
+    #[error(
+        "[E0143] InvalidRelayerAttestation: Each field of the attestation must have between 1 and {} characters",
+        MAX_RELAYER_ATTESTATION_FIELD_LENGTH
+    )]
+    InvalidRelayerAttestation {},
+
+    #[error("[E0144] EmergencyWithdrawalAlreadyScheduled: An emergency withdrawal is already scheduled, it must be cancelled first")]
+    EmergencyWithdrawalAlreadyScheduled {},
+
+    #[error("[E0145] EmergencyWithdrawalNotScheduled: There is no emergency withdrawal scheduled")]
+    EmergencyWithdrawalNotScheduled {},
+
+    #[error("[E0146] EmergencyWithdrawalNotReady: The emergency withdrawal can't be executed before {executable_at}")]
+    EmergencyWithdrawalNotReady { executable_at: u64 },
+
+    #[error("[E0147] InvalidEmergencyWithdrawal: An emergency withdrawal must include at least one non-zero amount and each denom only once")]
+    InvalidEmergencyWithdrawal {},
+
+    #[error(
+        "[E0148] InvalidMaxRelayers: The maximum amount of relayers can't be lower than the current amount of relayers or higher than {}",
+        XRPL_MAX_SIGNERS
+    )]
+    InvalidMaxRelayers {},
+
+    #[error("[E0149] DecimalsConversionOverflow: The amount can't be converted from {from_decimals} to {to_decimals} decimals because the result doesn't fit in 128 bits")]
+    DecimalsConversionOverflow {
+        from_decimals: u32,
+        to_decimals: u32,
+    },
+
+    #[error("[E0150] UnsupportedTokenPrecision: A token with {decimals} decimals can't be bridged with sending precision {sending_precision} because its smallest sendable amount doesn't fit in 128 bits")]
+    UnsupportedTokenPrecision {
+        decimals: u32,
+        sending_precision: i32,
+    },
+
+    #[error("[E0151] InvalidXRPLBaseFee: The XRPL base fee must be higher than 0 and the multisigning fee computed from it must fit in 64 bits")]
+    InvalidXRPLBaseFee {},
+
+    #[error("[E0152] XAddressNetworkMismatch: X-address {address} belongs to a different XRPL network than the bridge")]
+    XAddressNetworkMismatch { address: String },
+}
+
+impl ContractError {
+    // Stable code of the error, also included at the beginning of its message, so that clients can branch on it
+    // instead of matching the message. Codes of removed errors must never be reused and new errors get the next code
+    pub const fn code(&self) -> u32 {
+        match self {
+            Self::Std(_) => 1,
+            Self::Ownership(_) => 2,
+            Self::OverflowError(_) => 3,
+            Self::DivideByZeroError(_) => 4,
+            Self::Payment(_) => 5,
+            Self::InvalidThreshold { .. } => 6,
+            Self::InvalidXRPLAddress { .. } => 7,
+            Self::DuplicatedRelayer { .. } => 8,
+            Self::CoreumTokenAlreadyRegistered { .. } => 9,
+            Self::XRPLTokenAlreadyRegistered { .. } => 10,
+            Self::InvalidFundsAmount { .. } => 11,
+            Self::RegistrationFailure { .. } => 12,
+            Self::UnauthorizedSender { .. } => 13,
+            Self::UndeliveredTransferNotFound { .. } => 14,
+            Self::UnknownReplyId { .. } => 15,
+            Self::TokenNotRegistered { .. } => 16,
+            Self::OperationAlreadyExecuted { .. } => 17,
+            Self::EvidenceAlreadyProvided { .. } => 18,
+            Self::InvalidAmount { .. } => 19,
+            Self::InvalidTransferRate { .. } => 20,
+            Self::InvalidUsedTicketSequenceThreshold { .. } => 21,
+            Self::NoAvailableTickets { .. } => 22,
+            Self::LastTicketReserved { .. } => 23,
+            Self::StillHaveAvailableTickets { .. } => 24,
+            Self::PendingTicketUpdate { .. } => 25,
+            Self::InvalidTransactionResultEvidence { .. } => 26,
+            Self::InvalidMultiTransferEvidence { .. } => 27,
+            Self::InvalidSuccessfulTransactionResultEvidence { .. } => 28,
+            Self::InvalidFailedTransactionResultEvidence { .. } => 29,
+            Self::InvalidTicketAllocationEvidence { .. } => 30,
+            Self::PendingOperationNotFound { .. } => 31,
+            Self::PendingOperationAlreadyExists { .. } => 32,
+            Self::SignatureAlreadyProvided { .. } => 33,
+            Self::InvalidTicketSequenceToAllocate { .. } => 34,
+            Self::InvalidXRPLCurrency { .. } => 35,
+            Self::TokenNotEnabled { .. } => 36,
+            Self::XRPLTokenNotInactive { .. } => 37,
+            Self::AmountSentIsZeroAfterTruncation { .. } => 38,
+            Self::MaximumBridgedAmountReached { .. } => 39,
+            Self::InvalidSendingPrecision { .. } => 40,
+            Self::InvalidDecimals { .. } => 41,
+            Self::InvalidOperationResult { .. } => 42,
+            Self::CannotCoverBridgingFees { .. } => 43,
+            Self::TokenStateIsImmutable { .. } => 44,
+            Self::InvalidTargetTokenState { .. } => 45,
+            Self::InvalidTargetMaxHoldingAmount { .. } => 46,
+            Self::PendingRefundNotFound { .. } => 47,
+            Self::NotEnoughSurplusToRescue { .. } => 48,
+            Self::NotEnoughFeesToClaim { .. } => 49,
+            Self::TooManyRelayers { .. } => 50,
+            Self::BridgeHalted { .. } => 51,
+            Self::BridgeNotHalted { .. } => 52,
+            Self::BridgeStateMismatch { .. } => 53,
+            Self::BridgeStateAlreadyAcknowledged { .. } => 54,
+            Self::RotateKeysOngoing { .. } => 55,
+            Self::OperationVersionMismatch { .. } => 56,
+            Self::ProhibitedAddress { .. } => 57,
+            Self::CannotRemoveBridgeXRPLAddress { .. } => 58,
+            Self::InvalidXRPLRecipientRule { .. } => 59,
+            Self::XRPLRecipientRuleNotFound { .. } => 60,
+            Self::XRPLRecipientRuleViolated { .. } => 61,
+            Self::SplitAmountWithDeliverAmount { .. } => 62,
+            Self::DeliverAmountIsProhibited { .. } => 63,
+            Self::InvalidDeliverAmount { .. } => 64,
+            Self::InvalidSignatureLength { .. } => 65,
+            Self::InvalidXRPLAmount { .. } => 66,
+            Self::InvalidDenom { .. } => 67,
+            Self::InvalidIBCDenom { .. } => 68,
+            Self::InvalidTokenSymbol { .. } => 69,
+            Self::InvalidTokenDescription { .. } => 70,
+            Self::InvalidTokenURI { .. } => 71,
+            Self::InvalidTokenIdentifier { .. } => 72,
+            Self::MaxRegisteredXRPLTokensReached { .. } => 73,
+            Self::RelayerReimbursementNotConfigured { .. } => 74,
+            Self::ReimbursementPoolNotEmpty { .. } => 75,
+            Self::TransferReceiptNotFound { .. } => 76,
+            Self::XRPLCurrencyAlreadyInUse { .. } => 77,
+            Self::InvalidLightClientConfig { .. } => 78,
+            Self::LightClientNotEnabled { .. } => 79,
+            Self::InvalidXRPLTransactionProof { .. } => 80,
+            Self::NotEnoughXRPLValidations { .. } => 81,
+            Self::XRPLTransactionNotProven { .. } => 82,
+            Self::XRPLTransactionProofMismatch { .. } => 83,
+            Self::HaltMessageTooLong { .. } => 84,
+            Self::BridgeInMaintenance { .. } => 85,
+            Self::HaltAlreadyRequested { .. } => 86,
+            Self::TokenFrozen { .. } => 87,
+            Self::TokenFreezeAlreadyRequested { .. } => 88,
+            Self::TokenNotFrozen { .. } => 89,
+            Self::InvalidTokenFreezeDuration { .. } => 90,
+            Self::InvalidEvidenceTTL { .. } => 91,
+            Self::ImpreciseAmount { .. } => 92,
+            Self::NothingToSweep { .. } => 93,
+            Self::TransferDenied { .. } => 94,
+            Self::CommitmentRequired { .. } => 95,
+            Self::InvalidTransferCommitment { .. } => 96,
+            Self::TransferCommitmentAlreadyExists { .. } => 97,
+            Self::TransferCommitmentNotFound { .. } => 98,
+            Self::TransferCommitmentMismatch { .. } => 99,
+            Self::CommitmentNotRevealable { .. } => 100,
+            Self::CommitmentExpired { .. } => 101,
+            Self::CommitmentNotExpired { .. } => 102,
+            Self::InvalidCommitRevealConfig { .. } => 103,
+            Self::InvalidRecurringTransfer { .. } => 104,
+            Self::RecurringTransferNotFound { .. } => 105,
+            Self::RecurringTransferNotDue { .. } => 106,
+            Self::InsufficientRecurringTransferBalance { .. } => 107,
+            Self::RecipientDepositAuthEnabled { .. } => 108,
+            Self::RecipientDisallowsXRP { .. } => 109,
+            Self::InvalidXRPLDexQuote { .. } => 110,
+            Self::InvalidXRPLDexQuoteTTL { .. } => 111,
+            Self::InvalidUSDBridgingFee { .. } => 112,
+            Self::InvalidTokenPrice { .. } => 113,
+            Self::XRPLSupplyDriftNotFound { .. } => 114,
+            Self::InvalidDepositNotFound { .. } => 115,
+            Self::InsufficientXRPLReserve { .. } => 116,
+            Self::RelayerStillActive { .. } => 117,
+            Self::RelayerAlreadyInactive { .. } => 118,
+            Self::InvalidRelayerInactivityPeriod { .. } => 119,
+            Self::InvalidOperationEvidencePeriod { .. } => 120,
+            Self::OperationNotExpired { .. } => 121,
+            Self::NotARelayer { .. } => 122,
+            Self::InvalidEvidenceSigningKey { .. } => 123,
+            Self::InvalidEvidenceDelegate { .. } => 124,
+            Self::EvidenceSignatureRequired { .. } => 125,
+            Self::InvalidEvidenceSignature { .. } => 126,
+            Self::EvidencesNotConflicting { .. } => 127,
+            Self::ConflictingEvidenceNotFound { .. } => 128,
+            Self::RelayerInfractionRecorded { .. } => 129,
+            Self::PriorityLaneDisabled { .. } => 130,
+            Self::InvalidPriorityFee { .. } => 131,
+            Self::EvidenceCloseTimeInFuture { .. } => 132,
+            Self::TransactionHashMismatch { .. } => 133,
+            Self::NotTransferSender { .. } => 134,
+            Self::OperationAlreadySigned { .. } => 135,
+            Self::InvalidOperationsToCancel { .. } => 136,
+            Self::InvalidIdempotencyKey { .. } => 137,
+            Self::OperationRelayerSetOutdated { .. } => 138,
+            Self::TokenNotIssuedByBridge { .. } => 139,
+            Self::OperationQuorumNotReached { .. } => 140,
+            Self::InvalidTransactionSubmission { .. } => 141,
+            Self::InvalidEvidenceSource { .. } => 142,
+            Self::InvalidRelayerAttestation { .. } => 143,
+            Self::EmergencyWithdrawalAlreadyScheduled { .. } => 144,
+            Self::EmergencyWithdrawalNotScheduled { .. } => 145,
+            Self::EmergencyWithdrawalNotReady { .. } => 146,
+            Self::InvalidEmergencyWithdrawal { .. } => 147,
+            Self::InvalidMaxRelayers { .. } => 148,
+            Self::DecimalsConversionOverflow { .. } => 149,
+            Self::UnsupportedTokenPrecision { .. } => 150,
+            Self::InvalidXRPLBaseFee { .. } => 151,
+            Self::XAddressNetworkMismatch { .. } => 152,
+        }
+    }
 }
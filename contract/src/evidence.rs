@@ -1,10 +1,18 @@
+use std::collections::BTreeSet;
+
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Empty, Storage, Uint128};
+use cosmwasm_std::{Addr, Api, Empty, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::Bound;
 use sha2::{Digest, Sha256};
 
 use crate::{
+    address::validate_xrpl_address_format,
     error::ContractError,
-    state::{CONFIG, PROCESSED_TXS, TX_EVIDENCES},
+    relayer::effective_evidence_threshold,
+    state::{
+        RelayerInfraction, RelayersConfig, EVIDENCE_SIGNING_KEYS, EVIDENCE_SOURCES, PROCESSED_TXS,
+        RELAYER_INFRACTIONS, TX_EVIDENCES,
+    },
 };
 
 #[cw_serde]
@@ -18,6 +26,26 @@ pub enum Evidence {
         amount: Uint128,
         recipient: Addr,
     },
+    // This evidence is used for XRPL transactions that transfer tokens to several Coreum recipients at once
+    // All the transfers of the transaction are processed together once the threshold is reached
+    #[serde(rename = "xrpl_to_coreum_multi_transfer")]
+    XRPLToCoreumMultiTransfer {
+        tx_hash: String,
+        issuer: String,
+        currency: String,
+        transfers: Vec<XRPLToCoreumTransferEntry>,
+    },
+    // This evidence is used for transfers to the bridge from which relayers couldn't decode a valid Coreum recipient
+    // The deposit is recorded so that it can be resolved later instead of being left in the bridge account
+    #[serde(rename = "xrpl_to_coreum_invalid_deposit")]
+    XRPLToCoreumInvalidDeposit {
+        tx_hash: String,
+        issuer: String,
+        currency: String,
+        amount: Uint128,
+        // XRPL account that sent the deposit
+        sender: String,
+    },
     // This type will be used for ANY transaction that comes from XRPL and that is notifying a confirmation or rejection
     #[serde(rename = "xrpl_transaction_result")]
     XRPLTransactionResult {
@@ -27,8 +55,56 @@ pub enum Evidence {
         transaction_result: TransactionResult,
         operation_result: Option<OperationResult>,
     },
+    // This evidence is used to notify changes in the account settings of an issuer of registered XRPL tokens
+    #[serde(rename = "xrpl_issuer_config_change")]
+    XRPLIssuerConfigChange {
+        tx_hash: String,
+        issuer: String,
+        global_freeze: bool,
+        // XRPL transfer rate of the issuer, 0 means no transfer fee
+        transfer_rate: u32,
+        clawback_enabled: bool,
+    },
+    // This evidence is used to notify that an XRPL account changed the settings that make payments from the bridge fail
+    // or that it doesn't want to receive, so that transfers to it are rejected before consuming a ticket
+    #[serde(rename = "xrpl_recipient_flags_change")]
+    XRPLRecipientFlagsChange {
+        tx_hash: String,
+        account: String,
+        // The account only accepts payments from preauthorized senders and the bridge is not one of them
+        deposit_auth: bool,
+        disallow_xrp: bool,
+    },
+    // This evidence is used to notify that the balance of the bridge account for a registered XRPL token was changed outside
+    // of the bridge (e.g. clawback or direct issuer action), so that the owner can reconcile the bridged amount
+    #[serde(rename = "xrpl_token_supply_drift")]
+    XRPLTokenSupplyDrift {
+        tx_hash: String,
+        issuer: String,
+        currency: String,
+        amount: Uint128,
+        // True if the bridge account is holding less than before because of the transaction
+        decreased: bool,
+    },
 }
 
+#[cw_serde]
+pub struct XRPLToCoreumTransferEntry {
+    pub recipient: Addr,
+    pub amount: Uint128,
+}
+
+// Maximum amount of transfers that a single evidence can carry
+pub const MAX_TRANSFERS_PER_EVIDENCE: usize = 50;
+
+// Seconds that the XRPL close time of an evidence can be ahead of the block time, to tolerate clock differences
+// between both chains. Evidences further in the future are fabricated or come from misconfigured relayers
+pub const MAX_EVIDENCE_CLOCK_SKEW: u64 = 60;
+
+// Transfer rates on XRPL are either 0 (no fee) or between 1_000_000_000 (0%) and 2_000_000_000 (100%)
+const MIN_XRPL_TRANSFER_RATE: u32 = 1_000_000_000;
+const MAX_XRPL_TRANSFER_RATE: u32 = 2_000_000_000;
+
 #[cw_serde]
 pub enum TransactionResult {
     // Transactions that were accepted in XRPL and have their corresponding Transaction Hash
@@ -58,25 +134,49 @@ pub enum OperationResult {
 impl Evidence {
     // We hash the entire Evidence struct to avoid having to deal with different types of hashes
     pub fn get_hash(&self) -> String {
-        let to_hash_bytes = serde_json::to_string(self).unwrap().into_bytes();
+        let to_hash_bytes = self.to_json().into_bytes();
         hash_bytes(to_hash_bytes)
     }
 
+    // Full details of the evidence, emitted in events since only its hash is stored
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+
     pub fn get_tx_hash(&self) -> String {
         match self {
             Self::XRPLToCoreumTransfer { tx_hash, .. } => tx_hash.clone(),
+            Self::XRPLToCoreumMultiTransfer { tx_hash, .. } => tx_hash.clone(),
+            Self::XRPLToCoreumInvalidDeposit { tx_hash, .. } => tx_hash.clone(),
             Self::XRPLTransactionResult { tx_hash, .. } => tx_hash.clone().unwrap(),
+            Self::XRPLIssuerConfigChange { tx_hash, .. } => tx_hash.clone(),
+            Self::XRPLRecipientFlagsChange { tx_hash, .. } => tx_hash.clone(),
+            Self::XRPLTokenSupplyDrift { tx_hash, .. } => tx_hash.clone(),
         }
         .to_uppercase()
     }
+
+    // Invalid transaction results are the only evidences without a transaction hash
+    pub fn get_optional_tx_hash(&self) -> Option<String> {
+        match self {
+            Self::XRPLTransactionResult { tx_hash: None, .. } => None,
+            _ => Some(self.get_tx_hash()),
+        }
+    }
     pub fn is_operation_valid(&self) -> bool {
         match self {
             // All transfers are valid operations
             Self::XRPLToCoreumTransfer { .. } => true,
+            Self::XRPLToCoreumMultiTransfer { .. } => true,
+            Self::XRPLToCoreumInvalidDeposit { .. } => true,
             // All rejected/confirmed transactions are valid operations
             Self::XRPLTransactionResult {
                 transaction_result, ..
             } => transaction_result.clone() != TransactionResult::Invalid,
+            // Changes are only reported once they are executed
+            Self::XRPLIssuerConfigChange { .. } => true,
+            Self::XRPLRecipientFlagsChange { .. } => true,
+            Self::XRPLTokenSupplyDrift { .. } => true,
         }
     }
     // Function for basic validation of evidences in case relayers send something that is not valid
@@ -88,6 +188,21 @@ impl Evidence {
                 }
                 Ok(())
             }
+            Self::XRPLToCoreumMultiTransfer { transfers, .. } => {
+                if transfers.is_empty() || transfers.len() > MAX_TRANSFERS_PER_EVIDENCE {
+                    return Err(ContractError::InvalidMultiTransferEvidence {});
+                }
+                if transfers.iter().any(|transfer| transfer.amount.is_zero()) {
+                    return Err(ContractError::InvalidAmount {});
+                }
+                Ok(())
+            }
+            Self::XRPLToCoreumInvalidDeposit { amount, sender, .. } => {
+                if amount.is_zero() {
+                    return Err(ContractError::InvalidAmount {});
+                }
+                validate_xrpl_address_format(sender)
+            }
             Self::XRPLTransactionResult {
                 tx_hash,
                 account_sequence,
@@ -133,13 +248,33 @@ impl Evidence {
 
                 Ok(())
             }
+            Self::XRPLIssuerConfigChange { transfer_rate, .. } => {
+                if *transfer_rate != 0
+                    && (*transfer_rate < MIN_XRPL_TRANSFER_RATE
+                        || *transfer_rate > MAX_XRPL_TRANSFER_RATE)
+                {
+                    return Err(ContractError::InvalidTransferRate {});
+                }
+                Ok(())
+            }
+            Self::XRPLRecipientFlagsChange { account, .. } => validate_xrpl_address_format(account),
+            Self::XRPLTokenSupplyDrift { amount, .. } => {
+                if amount.is_zero() {
+                    return Err(ContractError::InvalidAmount {});
+                }
+                Ok(())
+            }
         }
     }
 }
 
+// Compact record stored for each evidence under its hash until it reaches the threshold.
+// The details of the evidence are not stored, they are emitted in the evidence_saved event instead
 #[cw_serde]
 pub struct Evidences {
     pub relayer_coreum_addresses: Vec<Addr>,
+    // Time at which the first evidence was provided, used to find evidences that never reached the threshold
+    pub created_at: u64,
 }
 
 pub fn hash_bytes(bytes: Vec<u8>) -> String {
@@ -149,17 +284,97 @@ pub fn hash_bytes(bytes: Vec<u8>) -> String {
     hex::encode(output)
 }
 
+// Evidence signing keys can be secp256k1 keys (like Coreum and XRPL keys) or ed25519 keys (XRPL keys prefixed with 0xED)
+pub fn validate_evidence_signing_key(pub_key: &str) -> Result<(), ContractError> {
+    let pub_key = hex::decode(pub_key).map_err(|_| ContractError::InvalidEvidenceSigningKey {})?;
+    if pub_key.len() != 33 || ![0x02, 0x03, 0xED].contains(&pub_key[0]) {
+        return Err(ContractError::InvalidEvidenceSigningKey {});
+    }
+
+    Ok(())
+}
+
+// Relayers sign the SHA-256 hash of the bridge contract address followed by the JSON encoded evidence.
+// Including the contract address prevents the same signature from being replayed in a different bridge
+pub fn evidence_signing_hash(contract_address: &Addr, evidence: &Evidence) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update(contract_address.as_bytes());
+    hasher.update(serde_json::to_vec(evidence).unwrap());
+    hasher.finalize().to_vec()
+}
+
+pub fn validate_evidence_close_time(
+    close_time: Option<u64>,
+    block_time: u64,
+) -> Result<(), ContractError> {
+    if let Some(close_time) = close_time {
+        let max_close_time = block_time.saturating_add(MAX_EVIDENCE_CLOCK_SKEW);
+        if close_time > max_close_time {
+            return Err(ContractError::EvidenceCloseTimeInFuture {
+                close_time,
+                max_close_time,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+// If the relayer has an evidence signing key registered, the evidence must be signed with it.
+// This way a compromised Coreum account key is not enough to provide fake evidences
+pub fn verify_evidence_signature(
+    api: &dyn Api,
+    storage: &dyn Storage,
+    contract_address: &Addr,
+    sender: &Addr,
+    evidence: &Evidence,
+    signature: Option<String>,
+) -> Result<(), ContractError> {
+    let pub_key = match EVIDENCE_SIGNING_KEYS.may_load(storage, sender.clone())? {
+        Some(pub_key) => pub_key,
+        None => return Ok(()),
+    };
+
+    let signature = signature.ok_or(ContractError::EvidenceSignatureRequired {})?;
+    let signature =
+        hex::decode(signature).map_err(|_| ContractError::InvalidEvidenceSignature {})?;
+    let pub_key = hex::decode(pub_key).map_err(|_| ContractError::InvalidEvidenceSigningKey {})?;
+    let message = evidence_signing_hash(contract_address, evidence);
+
+    let verified = if pub_key[0] == 0xED {
+        api.ed25519_verify(&message, &signature, &pub_key[1..])
+    } else {
+        api.secp256k1_verify(&message, &signature, &pub_key)
+    }
+    .map_err(|_| ContractError::InvalidEvidenceSignature {})?;
+
+    if !verified {
+        return Err(ContractError::InvalidEvidenceSignature {});
+    }
+
+    Ok(())
+}
+
+// Once the threshold is reached, returns the relayers that provided the evidence
 pub fn handle_evidence(
     storage: &mut dyn Storage,
+    relayers_config: &RelayersConfig,
+    timestamp: u64,
     sender: Addr,
     evidence: &Evidence,
-) -> Result<bool, ContractError> {
+) -> Result<Option<Vec<Addr>>, ContractError> {
     let operation_valid = evidence.is_operation_valid();
 
     if operation_valid && PROCESSED_TXS.has(storage, evidence.get_tx_hash()) {
         return Err(ContractError::OperationAlreadyExecuted {});
     }
 
+    if let Some(tx_hash) = evidence.get_optional_tx_hash() {
+        if RELAYER_INFRACTIONS.has(storage, (sender.clone(), tx_hash)) {
+            return Err(ContractError::RelayerInfractionRecorded {});
+        }
+    }
+
     let mut evidences: Evidences;
     // Relayers can only provide the evidence once
     match TX_EVIDENCES.may_load(storage, evidence.get_hash())? {
@@ -173,12 +388,20 @@ pub fn handle_evidence(
         None => {
             evidences = Evidences {
                 relayer_coreum_addresses: vec![sender],
+                created_at: timestamp,
             };
         }
     }
 
-    let config = CONFIG.load(storage)?;
-    if evidences.relayer_coreum_addresses.len() >= config.evidence_threshold as usize {
+    if evidences.relayer_coreum_addresses.len()
+        >= effective_evidence_threshold(storage, relayers_config)? as usize
+        && has_distinct_evidence_sources(
+            storage,
+            relayers_config,
+            evidence,
+            &evidences.relayer_coreum_addresses,
+        )?
+    {
         // We only registered the transaction as processed if its execution didn't fail (it wasn't Invalid)
         if operation_valid {
             PROCESSED_TXS.save(storage, evidence.get_tx_hash(), &Empty {})?;
@@ -187,10 +410,107 @@ pub fn handle_evidence(
         if evidences.relayer_coreum_addresses.len() != 1 {
             TX_EVIDENCES.remove(storage, evidence.get_hash());
         }
-        return Ok(true);
+        return Ok(Some(evidences.relayer_coreum_addresses));
     }
 
     TX_EVIDENCES.save(storage, evidence.get_hash(), &evidences)?;
 
-    Ok(false)
+    Ok(None)
+}
+
+// Results of operations must be confirmed by relayers that declared enough distinct sources, so that a single
+// compromised XRPL node feeding all relayers can't confirm a result on its own. Relayers without a source don't count
+fn has_distinct_evidence_sources(
+    storage: &dyn Storage,
+    relayers_config: &RelayersConfig,
+    evidence: &Evidence,
+    relayers: &[Addr],
+) -> Result<bool, ContractError> {
+    if relayers_config.min_distinct_evidence_sources == 0
+        || !matches!(evidence, Evidence::XRPLTransactionResult { .. })
+    {
+        return Ok(true);
+    }
+
+    let mut sources = BTreeSet::new();
+    for relayer in relayers {
+        if let Some(source) = EVIDENCE_SOURCES.may_load(storage, relayer.clone())? {
+            sources.insert(source);
+        }
+    }
+
+    Ok(sources.len() >= relayers_config.min_distinct_evidence_sources as usize)
+}
+
+// Records that a relayer provided two different evidences for the same XRPL transaction, both still waiting for the threshold.
+// The relayer is removed from both evidences so that none of them can be executed with its vote
+pub fn handle_conflicting_evidences(
+    storage: &mut dyn Storage,
+    timestamp: u64,
+    reporter: Addr,
+    relayer: Addr,
+    evidence: &Evidence,
+    conflicting_evidence: &Evidence,
+) -> Result<RelayerInfraction, ContractError> {
+    let tx_hash = match (
+        evidence.get_optional_tx_hash(),
+        conflicting_evidence.get_optional_tx_hash(),
+    ) {
+        (Some(tx_hash), Some(conflicting_tx_hash)) if tx_hash == conflicting_tx_hash => tx_hash,
+        _ => return Err(ContractError::EvidencesNotConflicting {}),
+    };
+
+    let evidence_hashes = vec![evidence.get_hash(), conflicting_evidence.get_hash()];
+    if evidence_hashes[0] == evidence_hashes[1] {
+        return Err(ContractError::EvidencesNotConflicting {});
+    }
+
+    for evidence_hash in &evidence_hashes {
+        let mut evidences = TX_EVIDENCES
+            .may_load(storage, evidence_hash.to_owned())?
+            .filter(|evidences| evidences.relayer_coreum_addresses.contains(&relayer))
+            .ok_or(ContractError::ConflictingEvidenceNotFound {})?;
+
+        evidences
+            .relayer_coreum_addresses
+            .retain(|address| *address != relayer);
+        if evidences.relayer_coreum_addresses.is_empty() {
+            TX_EVIDENCES.remove(storage, evidence_hash.to_owned());
+        } else {
+            TX_EVIDENCES.save(storage, evidence_hash.to_owned(), &evidences)?;
+        }
+    }
+
+    let infraction = RelayerInfraction {
+        tx_hash: tx_hash.clone(),
+        evidence_hashes,
+        reporter,
+        reported_at: timestamp,
+    };
+    RELAYER_INFRACTIONS.save(storage, (relayer, tx_hash), &infraction)?;
+
+    Ok(infraction)
+}
+
+// Evidences that didn't reach the threshold within the evidence TTL, e.g. because of a relayer bug, and will never be completed
+pub fn stale_evidences(
+    storage: &dyn Storage,
+    timestamp: u64,
+    evidence_ttl: u64,
+    start_after_key: Option<String>,
+    limit: u32,
+) -> StdResult<Vec<(String, Evidences)>> {
+    TX_EVIDENCES
+        .range(
+            storage,
+            start_after_key.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .filter(|item| match item {
+            Ok((_, evidences)) => evidences.created_at.saturating_add(evidence_ttl) <= timestamp,
+            Err(_) => true,
+        })
+        .take(limit as usize)
+        .collect()
 }
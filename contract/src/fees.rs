@@ -1,10 +1,32 @@
-use cosmwasm_std::{coin, Addr, Coin, Storage, Uint128};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{coin, Addr, Coin, Event, Order, StdResult, Storage, Uint128, Uint256};
 
 use crate::{
+    accounting::{release_fee, reserve_fee},
     error::ContractError,
-    state::{CONFIG, FEES_COLLECTED, FEE_REMAINDERS},
+    relayer::active_relayers,
+    state::{
+        RelayerFeeClaim, RoundingMode, DUST_ACCOUNTING, FEES_COLLECTED, FEE_ACCOUNTING,
+        FEE_REMAINDERS, RELAYERS_CONFIG, RELAYER_FEE_CLAIMS, RELAYER_FEE_CLAIMS_COUNTER,
+        RELAYER_REIMBURSEMENT, TOKEN_PRICES, USD_BRIDGING_FEES,
+    },
 };
 
+// Message the bridge sends to the fee converter contract, together with the claimed fees, when a relayer claims fees
+// The converter must send the result of converting all the funds to output_denom to the recipient
+#[cw_serde]
+pub enum FeeConverterExecuteMsg {
+    Convert {
+        output_denom: String,
+        recipient: String,
+    },
+}
+
+// Seconds after which a price pushed by the price oracle is not used anymore to convert USD bridging fees
+pub const MAX_TOKEN_PRICE_AGE: u64 = 3600;
+// Fee claims kept for each relayer, older ones are removed
+pub const MAX_FEE_CLAIMS_PER_RELAYER: usize = 50;
+
 pub fn amount_after_bridge_fees(
     amount: Uint128,
     bridging_fee: Uint128,
@@ -16,23 +38,164 @@ pub fn amount_after_bridge_fees(
     Ok(amount_after_bridge_fees)
 }
 
+// Bridging fee charged when a token is bridged. If the owner set a USD bridging fee for the token and the price oracle pushed a recent price,
+// the USD amount is converted to the token (with the given decimals) and kept within the bounds. Otherwise the fixed bridging fee is used
+pub fn effective_bridging_fee(
+    storage: &dyn Storage,
+    denom: &str,
+    decimals: u32,
+    bridging_fee: Uint128,
+    timestamp: u64,
+) -> Result<Uint128, ContractError> {
+    let usd_bridging_fee = match USD_BRIDGING_FEES.may_load(storage, denom.to_owned())? {
+        Some(usd_bridging_fee) => usd_bridging_fee,
+        None => return Ok(bridging_fee),
+    };
+    let token_price = match TOKEN_PRICES.may_load(storage, denom.to_owned())? {
+        Some(token_price)
+            if token_price.updated_at.saturating_add(MAX_TOKEN_PRICE_AGE) > timestamp =>
+        {
+            token_price
+        }
+        _ => return Ok(bridging_fee),
+    };
+
+    // Both decimals have the same fractional digits so we can divide their atomics directly
+    let fee = Uint128::new(10)
+        .checked_pow(decimals)?
+        .full_mul(usd_bridging_fee.usd_amount.atomics())
+        .checked_div(Uint256::from(token_price.usd_price.atomics()))?;
+    // A fee that doesn't fit is above any maximum anyway
+    let fee = Uint128::try_from(fee).unwrap_or(Uint128::MAX);
+
+    Ok(fee.clamp(usd_bridging_fee.min_fee, usd_bridging_fee.max_fee))
+}
+
+// Splits the amount truncated because of the sending precision into the part collected as fees and the dust that goes back to the user
+// Transfers from XRPL can't be rejected, so for them the dust of imprecise amounts is always returned
+pub fn split_truncation_remainder(
+    rounding_mode: &RoundingMode,
+    remainder: Uint128,
+    rejectable: bool,
+) -> Result<(Uint128, Uint128), ContractError> {
+    match rounding_mode {
+        RoundingMode::TruncateToFee => Ok((remainder, Uint128::zero())),
+        RoundingMode::RejectIfImprecise if rejectable && !remainder.is_zero() => {
+            Err(ContractError::ImpreciseAmount {})
+        }
+        // The dust is not collected so it's not split between the relayers
+        RoundingMode::RefundDust
+        | RoundingMode::RejectIfImprecise
+        | RoundingMode::DustToPendingRefund => Ok((Uint128::zero(), remainder)),
+    }
+}
+
+// Separates the priority fee from the funds sent for a prioritized transfer and returns the amount to transfer
+// The fee can be included in the coin sent if it's of the same denom or sent as a separate coin
+pub fn split_priority_fee(funds: &[Coin], priority_fee: &Coin) -> Result<Coin, ContractError> {
+    match funds {
+        [sent] if sent.denom == priority_fee.denom => {
+            let amount = sent
+                .amount
+                .checked_sub(priority_fee.amount)
+                .map_err(|_| ContractError::InvalidPriorityFee {})?;
+            if amount.is_zero() {
+                return Err(ContractError::InvalidPriorityFee {});
+            }
+            Ok(coin(amount.u128(), sent.denom.clone()))
+        }
+        [first, second] if first.eq(priority_fee) && !second.amount.is_zero() => Ok(second.clone()),
+        [first, second] if second.eq(priority_fee) && !first.amount.is_zero() => Ok(first.clone()),
+        _ => Err(ContractError::InvalidPriorityFee {}),
+    }
+}
+
+// Collects the bridging fee and the amount truncated from a transfer, returning the total collected and the event reporting both separately
 pub fn handle_fee_collection(
     storage: &mut dyn Storage,
     bridging_fee: Uint128,
     token_denom: String,
     remainder: Uint128,
-) -> Result<Uint128, ContractError> {
+) -> Result<(Uint128, Option<Event>), ContractError> {
     // We add the bridging fee we charged and the truncated portion after all fees were charged
     let fee_collected = bridging_fee.checked_add(remainder)?;
 
+    let event = record_fee_accounting(
+        storage,
+        &token_denom,
+        bridging_fee,
+        Uint128::zero(),
+        remainder,
+    )?;
+
     collect_fees(storage, coin(fee_collected.u128(), token_denom))?;
-    Ok(fee_collected)
+    Ok((fee_collected, event))
+}
+
+// The priority fee is split between the relayers like the bridging fees, but it's accounted separately
+pub fn handle_priority_fee_collection(
+    storage: &mut dyn Storage,
+    priority_fee: Coin,
+) -> Result<Option<Event>, ContractError> {
+    let event = record_fee_accounting(
+        storage,
+        &priority_fee.denom,
+        Uint128::zero(),
+        priority_fee.amount,
+        Uint128::zero(),
+    )?;
+
+    collect_fees(storage, priority_fee)?;
+    Ok(event)
+}
+
+// Adds the collected fees to the totals of the token, the truncated amount being kept with the rest of the dust of the token
+fn record_fee_accounting(
+    storage: &mut dyn Storage,
+    denom: &str,
+    bridging_fee: Uint128,
+    priority_fee: Uint128,
+    truncation_fee: Uint128,
+) -> Result<Option<Event>, ContractError> {
+    if bridging_fee.is_zero() && priority_fee.is_zero() && truncation_fee.is_zero() {
+        return Ok(None);
+    }
+
+    if !bridging_fee.is_zero() || !priority_fee.is_zero() {
+        let mut fee_accounting = FEE_ACCOUNTING
+            .may_load(storage, denom.to_owned())?
+            .unwrap_or_default();
+        fee_accounting.bridging_fees = fee_accounting.bridging_fees.checked_add(bridging_fee)?;
+        fee_accounting.priority_fees = fee_accounting.priority_fees.checked_add(priority_fee)?;
+        FEE_ACCOUNTING.save(storage, denom.to_owned(), &fee_accounting)?;
+    }
+
+    if !truncation_fee.is_zero() {
+        let mut dust_accounting = DUST_ACCOUNTING
+            .may_load(storage, denom.to_owned())?
+            .unwrap_or_default();
+        dust_accounting.truncation_dust = dust_accounting
+            .truncation_dust
+            .checked_add(truncation_fee)?;
+        DUST_ACCOUNTING.save(storage, denom.to_owned(), &dust_accounting)?;
+    }
+
+    Ok(Some(
+        Event::new("fees_collected")
+            .add_attribute("denom", denom)
+            .add_attribute("bridging_fee", bridging_fee.to_string())
+            .add_attribute("priority_fee", priority_fee.to_string())
+            .add_attribute("truncation_fee", truncation_fee.to_string()),
+    ))
 }
 
 fn collect_fees(storage: &mut dyn Storage, fee: Coin) -> Result<(), ContractError> {
     // We only collect fees if there is something to collect
     // If for some reason there is a coin that we are not charging fees for, we don't collect it
     if !fee.amount.is_zero() {
+        // The whole fee is held by the contract until relayers claim it
+        reserve_fee(storage, &fee.denom, fee.amount)?;
+
         let fees_remainder = FEE_REMAINDERS.may_load(storage, fee.denom.clone())?;
         // We add the new fees to the possible remainders that we had before and use those amounts to allocate them to relayers
         let total_fee = match fees_remainder {
@@ -41,27 +204,19 @@ fn collect_fees(storage: &mut dyn Storage, fee: Coin) -> Result<(), ContractErro
         };
 
         // We will divide the total fee by the number of relayers to know how much we need to send to each relayer and the remainder will be saved for the next fee collection
-        let relayers = CONFIG.load(storage)?.relayers;
+        // Relayers marked as inactive don't get a share of the fees
+        let relayers = active_relayers(storage, &RELAYERS_CONFIG.load(storage)?.relayers)?;
         let amount_for_each_relayer =
             total_fee.checked_div(Uint128::new(relayers.len().try_into().unwrap()))?;
 
         // If the amount is 0, there's nothing to send to the relayers
         if !amount_for_each_relayer.is_zero() {
             for relayer in &relayers {
-                // We get previous relayer fees collected to update them. If it's the first time the relayer gets fees, we initialize the array
-                let mut fees_collected = FEES_COLLECTED
-                    .may_load(storage, relayer.coreum_address.clone())?
-                    .unwrap_or_default();
-
-                // Add fees to the relayer fees collected
-                match fees_collected.iter_mut().find(|c| c.denom == fee.denom) {
-                    Some(coin) => coin.amount += amount_for_each_relayer,
-                    None => {
-                        fees_collected.push(coin(amount_for_each_relayer.u128(), fee.denom.clone()))
-                    }
-                }
-
-                FEES_COLLECTED.save(storage, relayer.coreum_address.clone(), &fees_collected)?;
+                add_relayer_fees(
+                    storage,
+                    &relayer.coreum_address,
+                    coin(amount_for_each_relayer.u128(), fee.denom.clone()),
+                )?;
             }
         }
 
@@ -78,6 +233,100 @@ fn collect_fees(storage: &mut dyn Storage, fee: Coin) -> Result<(), ContractErro
     Ok(())
 }
 
+// Removes the part of the fee remainder of a token that is above the threshold so that it can be sent to the treasury
+// Remainders are smaller than the amount of relayers, so they might never be distributed if no more fees are collected for the token
+pub fn sweep_fee_remainder(
+    storage: &mut dyn Storage,
+    denom: &str,
+    threshold: Uint128,
+) -> Result<Uint128, ContractError> {
+    let fee_remainder = FEE_REMAINDERS
+        .may_load(storage, denom.to_owned())?
+        .unwrap_or_default();
+    if fee_remainder.le(&threshold) {
+        return Err(ContractError::NothingToSweep {});
+    }
+
+    let amount = fee_remainder.checked_sub(threshold)?;
+    FEE_REMAINDERS.save(storage, denom.to_owned(), &threshold)?;
+    release_fee(storage, denom, amount)?;
+
+    let mut dust_accounting = DUST_ACCOUNTING
+        .may_load(storage, denom.to_owned())?
+        .unwrap_or_default();
+    dust_accounting.swept = dust_accounting.swept.checked_add(amount)?;
+    DUST_ACCOUNTING.save(storage, denom.to_owned(), &dust_accounting)?;
+
+    Ok(amount)
+}
+
+fn add_relayer_fees(
+    storage: &mut dyn Storage,
+    relayer: &Addr,
+    amount: Coin,
+) -> Result<(), ContractError> {
+    // We get previous relayer fees collected to update them. If it's the first time the relayer gets fees, we initialize the array
+    let mut fees_collected = FEES_COLLECTED
+        .may_load(storage, relayer.clone())?
+        .unwrap_or_default();
+
+    // Add fees to the relayer fees collected
+    match fees_collected.iter_mut().find(|c| c.denom == amount.denom) {
+        Some(coin) => coin.amount += amount.amount,
+        None => fees_collected.push(amount),
+    }
+
+    FEES_COLLECTED.save(storage, relayer.clone(), &fees_collected)?;
+
+    Ok(())
+}
+
+// The relayer whose evidence reached the threshold paid the gas for the state transition, so it's reimbursed from the pool
+// The reimbursement is added to the relayer fees so that it's claimed with them
+pub fn reimburse_relayer(storage: &mut dyn Storage, relayer: &Addr) -> Result<(), ContractError> {
+    let mut reimbursement = match RELAYER_REIMBURSEMENT.may_load(storage)? {
+        Some(reimbursement) => reimbursement,
+        None => return Ok(()),
+    };
+
+    // If the pool ran out of funds relayers won't be reimbursed until the owner funds it again
+    let amount = reimbursement.amount_per_operation.amount;
+    if amount.is_zero() || reimbursement.pool < amount {
+        return Ok(());
+    }
+
+    // Funds in the pool were already reserved as fees when it was funded
+    reimbursement.pool -= amount;
+    RELAYER_REIMBURSEMENT.save(storage, &reimbursement)?;
+
+    add_relayer_fees(storage, relayer, reimbursement.amount_per_operation)
+}
+
+// Keepers that trigger housekeeping are rewarded from the same pool used to reimburse relayers
+// Returns the reward to send to the keeper, if the pool can pay it
+pub fn take_crank_reward(
+    storage: &mut dyn Storage,
+    amount: Uint128,
+) -> Result<Option<Coin>, ContractError> {
+    let mut reimbursement = match RELAYER_REIMBURSEMENT.may_load(storage)? {
+        Some(reimbursement) => reimbursement,
+        None => return Ok(None),
+    };
+
+    if amount.is_zero() || reimbursement.pool < amount {
+        return Ok(None);
+    }
+
+    reimbursement.pool -= amount;
+    RELAYER_REIMBURSEMENT.save(storage, &reimbursement)?;
+
+    // Funds in the pool were reserved as fees when it was funded and they leave the contract now
+    let denom = reimbursement.amount_per_operation.denom;
+    release_fee(storage, &denom, amount)?;
+
+    Ok(Some(coin(amount.u128(), denom)))
+}
+
 pub fn substract_relayer_fees(
     storage: &mut dyn Storage,
     sender: &Addr,
@@ -91,7 +340,10 @@ pub fn substract_relayer_fees(
             .iter_mut()
             .find(|f| f.denom == coin.denom && f.amount >= coin.amount)
         {
-            Some(found_coin) => found_coin.amount -= coin.amount,
+            Some(found_coin) => {
+                found_coin.amount -= coin.amount;
+                release_fee(storage, &coin.denom, coin.amount)?;
+            }
             None => {
                 return Err(ContractError::NotEnoughFeesToClaim {
                     denom: coin.denom.clone(),
@@ -108,3 +360,38 @@ pub fn substract_relayer_fees(
 
     Ok(())
 }
+
+pub fn record_relayer_fee_claim(
+    storage: &mut dyn Storage,
+    relayer: &Addr,
+    amounts: Vec<Coin>,
+    height: u64,
+    timestamp: u64,
+) -> Result<(), ContractError> {
+    let counter = RELAYER_FEE_CLAIMS_COUNTER
+        .may_load(storage)?
+        .unwrap_or_default();
+    RELAYER_FEE_CLAIMS_COUNTER.save(storage, &(counter + 1))?;
+
+    RELAYER_FEE_CLAIMS.save(
+        storage,
+        (relayer.clone(), counter),
+        &RelayerFeeClaim {
+            amounts,
+            height,
+            timestamp,
+        },
+    )?;
+
+    // Only one claim is added each time so removing the oldest one is enough to stay within the limit
+    let keys: Vec<u64> = RELAYER_FEE_CLAIMS
+        .prefix(relayer.clone())
+        .keys(storage, None, None, Order::Ascending)
+        .take(MAX_FEE_CLAIMS_PER_RELAYER + 1)
+        .collect::<StdResult<_>>()?;
+    if keys.len() > MAX_FEE_CLAIMS_PER_RELAYER {
+        RELAYER_FEE_CLAIMS.remove(storage, (relayer.clone(), keys[0]));
+    }
+
+    Ok(())
+}
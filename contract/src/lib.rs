@@ -1,13 +1,24 @@
+pub mod accounting;
+pub mod activity;
 pub mod address;
+pub mod compliance;
 pub mod contract;
 pub mod error;
 pub mod evidence;
 pub mod fees;
+pub mod light_client;
 pub mod msg;
+#[cfg(any(test, feature = "multitest"))]
+pub mod multitest;
 pub mod operation;
+pub mod receipt;
 pub mod relayer;
+pub mod reserve;
 pub mod signatures;
+pub mod signing_payload;
 pub mod state;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 #[cfg(test)]
 mod tests;
 pub mod tickets;
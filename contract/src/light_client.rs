@@ -0,0 +1,769 @@
+use std::collections::HashSet;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Api, Storage, Uint128};
+use sha2::{Digest, Sha512};
+
+use crate::{
+    address::{decode_xrpl_address, encode_xrpl_address},
+    contract::{XRPL_TOKENS_DECIMALS, XRP_CURRENCY, XRP_ISSUER},
+    error::ContractError,
+    evidence::Evidence,
+    state::{XRPLConfig, PROVEN_XRPL_TRANSACTIONS},
+};
+
+// Hash prefixes used by XRPL to domain separate the different objects that are hashed
+// See https://github.com/XRPLF/rippled/blob/develop/src/ripple/protocol/HashPrefix.h
+const TRANSACTION_ID_PREFIX: [u8; 4] = *b"TXN\0";
+const TRANSACTION_NODE_PREFIX: [u8; 4] = *b"SND\0";
+const INNER_NODE_PREFIX: [u8; 4] = *b"MIN\0";
+const LEDGER_HEADER_PREFIX: [u8; 4] = *b"LWR\0";
+const VALIDATION_PREFIX: [u8; 4] = *b"VAL\0";
+
+// A SHAMap key is 256 bits long, so a branch can't be deeper than 64 nibbles
+const MAX_SHAMAP_DEPTH: usize = 64;
+const SHAMAP_BRANCHES: usize = 16;
+
+// Field codes (type code, field code) of the serialized fields we need to read
+const TRANSACTION_TYPE_FIELD: (u8, u8) = (1, 2);
+const LEDGER_SEQUENCE_FIELD: (u8, u8) = (2, 6);
+const LEDGER_HASH_FIELD: (u8, u8) = (5, 1);
+const AMOUNT_FIELD: (u8, u8) = (6, 1);
+const DELIVERED_AMOUNT_FIELD: (u8, u8) = (6, 18);
+const SIGNING_PUB_KEY_FIELD: (u8, u8) = (7, 3);
+const MEMO_DATA_FIELD: (u8, u8) = (7, 13);
+const DESTINATION_FIELD: (u8, u8) = (8, 3);
+const MEMOS_FIELD: (u8, u8) = (15, 9);
+const TRANSACTION_RESULT_FIELD: (u8, u8) = (16, 3);
+
+// Serialized type codes
+const ST_UINT16: u8 = 1;
+const ST_UINT32: u8 = 2;
+const ST_UINT64: u8 = 3;
+const ST_HASH128: u8 = 4;
+const ST_HASH256: u8 = 5;
+const ST_AMOUNT: u8 = 6;
+const ST_BLOB: u8 = 7;
+const ST_ACCOUNT_ID: u8 = 8;
+const ST_OBJECT: u8 = 14;
+const ST_ARRAY: u8 = 15;
+const ST_UINT8: u8 = 16;
+const ST_HASH160: u8 = 17;
+const ST_PATH_SET: u8 = 18;
+const ST_VECTOR256: u8 = 19;
+const ST_HASH192: u8 = 21;
+const ST_ISSUE: u8 = 24;
+const ST_CURRENCY: u8 = 26;
+// The field code used by the end markers of objects and arrays
+const END_MARKER_FIELD: u8 = 1;
+
+const PAYMENT_TRANSACTION_TYPE: u16 = 0;
+const TES_SUCCESS: u8 = 0;
+// Offset applied to the exponent of issued currency amounts when serialized
+const ISSUED_AMOUNT_EXPONENT_OFFSET: i32 = 97;
+
+// Memo type used by users to specify the Coreum recipient of their transfer
+const BRIDGE_MEMO_TYPE: &str = "coreumbridge-xrpl-v1";
+
+#[cw_serde]
+pub struct LightClientConfig {
+    // Signing public keys (hex encoded) of the XRPL validators whose validations we trust
+    pub validators: Vec<String>,
+    // Amount of different validators that must have validated a ledger for us to trust it
+    pub validation_quorum: u32,
+}
+
+#[cw_serde]
+pub struct XRPLLedgerHeader {
+    pub ledger_index: u32,
+    pub total_drops: u64,
+    pub parent_hash: String,
+    pub transaction_hash: String,
+    pub account_hash: String,
+    pub parent_close_time: u32,
+    pub close_time: u32,
+    pub close_time_resolution: u8,
+    pub close_flags: u8,
+}
+
+#[cw_serde]
+pub struct XRPLValidation {
+    // Hex encoded serialized validation, without the signature field
+    pub blob: String,
+    // Hex encoded signature of the validation
+    pub signature: String,
+}
+
+#[cw_serde]
+pub struct XRPLTransactionProof {
+    pub ledger_header: XRPLLedgerHeader,
+    // Hex encoded serialized transaction and its metadata
+    pub tx_blob: String,
+    pub meta_blob: String,
+    // Inner nodes of the transaction tree, starting from the parent of the transaction leaf up to the root.
+    // Each inner node contains the hex encoded hashes of its 16 children, the one leading to the transaction must be left empty.
+    pub inner_nodes: Vec<Vec<String>>,
+    pub validations: Vec<XRPLValidation>,
+}
+
+// The information extracted from a transaction that was proven to be included in a validated ledger
+#[cw_serde]
+pub struct ProvenXRPLTransaction {
+    pub tx_hash: String,
+    pub ledger_index: u32,
+    pub issuer: String,
+    pub currency: String,
+    pub amount: Uint128,
+    pub recipient: Option<String>,
+}
+
+enum STValue {
+    Bytes(Vec<u8>),
+    Object(Vec<STField>),
+    Array(Vec<STField>),
+}
+
+struct STField {
+    type_code: u8,
+    field_code: u8,
+    value: STValue,
+}
+
+pub fn validate_light_client_config(
+    light_client_config: &LightClientConfig,
+) -> Result<(), ContractError> {
+    if light_client_config.validation_quorum == 0
+        || light_client_config.validation_quorum as usize > light_client_config.validators.len()
+    {
+        return Err(ContractError::InvalidLightClientConfig {});
+    }
+
+    let mut validators = HashSet::new();
+    for validator in &light_client_config.validators {
+        let public_key =
+            hex::decode(validator).map_err(|_| ContractError::InvalidLightClientConfig {})?;
+        // XRPL public keys are 33 bytes long. Secp256k1 keys are compressed (0x02 or 0x03 prefix) and ed25519 keys are prefixed with 0xED
+        if public_key.len() != 33 || ![0x02, 0x03, 0xED].contains(&public_key[0]) {
+            return Err(ContractError::InvalidLightClientConfig {});
+        }
+        if !validators.insert(public_key) {
+            return Err(ContractError::InvalidLightClientConfig {});
+        }
+    }
+
+    Ok(())
+}
+
+// Verifies that the transaction is included in a ledger validated by enough trusted validators and returns the transfer it contains
+pub fn verify_xrpl_transaction_proof(
+    api: &dyn Api,
+    xrpl_config: &XRPLConfig,
+    light_client_config: &LightClientConfig,
+    proof: &XRPLTransactionProof,
+) -> Result<ProvenXRPLTransaction, ContractError> {
+    let tx_blob = decode_hex(&proof.tx_blob)?;
+    let meta_blob = decode_hex(&proof.meta_blob)?;
+
+    let tx_id = compute_transaction_id(&tx_blob);
+    let transaction_tree_root =
+        compute_transaction_tree_root(&tx_id, &tx_blob, &meta_blob, &proof.inner_nodes)?;
+
+    if transaction_tree_root.to_vec() != decode_hex(&proof.ledger_header.transaction_hash)? {
+        return Err(ContractError::InvalidXRPLTransactionProof {});
+    }
+
+    let ledger_hash = compute_ledger_hash(&proof.ledger_header)?;
+    let validators = count_valid_validations(
+        api,
+        light_client_config,
+        &ledger_hash,
+        proof.ledger_header.ledger_index,
+        &proof.validations,
+    )?;
+    if validators < light_client_config.validation_quorum as usize {
+        return Err(ContractError::NotEnoughXRPLValidations {});
+    }
+
+    // Now that we trust the transaction, we extract the transfer it made to the bridge
+    let tx = parse_object(&tx_blob)?;
+    let meta = parse_object(&meta_blob)?;
+
+    match find_field(&tx, TRANSACTION_TYPE_FIELD) {
+        Some(STValue::Bytes(bytes)) if read_uint(bytes)? == PAYMENT_TRANSACTION_TYPE as u64 => (),
+        _ => return Err(ContractError::InvalidXRPLTransactionProof {}),
+    }
+
+    match find_field(&tx, DESTINATION_FIELD) {
+        Some(STValue::Bytes(destination))
+            if *destination == decode_xrpl_address(&xrpl_config.bridge_xrpl_address)? => {}
+        _ => return Err(ContractError::InvalidXRPLTransactionProof {}),
+    }
+
+    match find_field(&meta, TRANSACTION_RESULT_FIELD) {
+        Some(STValue::Bytes(result)) if *result == vec![TES_SUCCESS] => (),
+        _ => return Err(ContractError::InvalidXRPLTransactionProof {}),
+    }
+
+    // Partial payments can deliver less than the amount of the transaction, so we always use the delivered amount if present
+    let amount =
+        match find_field(&meta, DELIVERED_AMOUNT_FIELD).or_else(|| find_field(&tx, AMOUNT_FIELD)) {
+            Some(STValue::Bytes(amount)) => amount,
+            _ => return Err(ContractError::InvalidXRPLTransactionProof {}),
+        };
+    let (issuer, currency, amount) = decode_amount(amount)?;
+
+    Ok(ProvenXRPLTransaction {
+        tx_hash: hex::encode_upper(tx_id),
+        ledger_index: proof.ledger_header.ledger_index,
+        issuer,
+        currency,
+        amount,
+        recipient: decode_recipient_from_memos(&tx),
+    })
+}
+
+// When the light client is enabled, evidences of transfers must match a transaction that was proven beforehand
+pub fn verify_evidence_against_proof(
+    storage: &dyn Storage,
+    xrpl_config: &XRPLConfig,
+    evidence: &Evidence,
+) -> Result<(), ContractError> {
+    if xrpl_config.light_client.is_none() {
+        return Ok(());
+    }
+
+    if let Evidence::XRPLToCoreumTransfer {
+        issuer,
+        currency,
+        amount,
+        recipient,
+        ..
+    } = evidence
+    {
+        let proven_transaction = PROVEN_XRPL_TRANSACTIONS
+            .may_load(storage, evidence.get_tx_hash())?
+            .ok_or(ContractError::XRPLTransactionNotProven {})?;
+
+        if proven_transaction.issuer.ne(issuer)
+            || proven_transaction.currency.ne(currency)
+            || proven_transaction.amount.ne(amount)
+            || proven_transaction
+                .recipient
+                .ne(&Some(recipient.to_string()))
+        {
+            return Err(ContractError::XRPLTransactionProofMismatch {});
+        }
+    }
+
+    if let Evidence::XRPLToCoreumInvalidDeposit {
+        issuer,
+        currency,
+        amount,
+        ..
+    } = evidence
+    {
+        let proven_transaction = PROVEN_XRPL_TRANSACTIONS
+            .may_load(storage, evidence.get_tx_hash())?
+            .ok_or(ContractError::XRPLTransactionNotProven {})?;
+
+        if proven_transaction.issuer.ne(issuer)
+            || proven_transaction.currency.ne(currency)
+            || proven_transaction.amount.ne(amount)
+        {
+            return Err(ContractError::XRPLTransactionProofMismatch {});
+        }
+    }
+
+    // Proven transactions are decoded with a single recipient, so transfers to several recipients can't be verified
+    if let Evidence::XRPLToCoreumMultiTransfer { .. } = evidence {
+        return Err(ContractError::XRPLTransactionProofMismatch {});
+    }
+
+    Ok(())
+}
+
+pub fn compute_transaction_id(tx_blob: &[u8]) -> [u8; 32] {
+    let mut data = TRANSACTION_ID_PREFIX.to_vec();
+    data.extend_from_slice(tx_blob);
+    sha512_half(&data)
+}
+
+// Computes the root of the transaction tree by hashing the transaction leaf together with all the inner nodes of its branch
+pub fn compute_transaction_tree_root(
+    tx_id: &[u8; 32],
+    tx_blob: &[u8],
+    meta_blob: &[u8],
+    inner_nodes: &[Vec<String>],
+) -> Result<[u8; 32], ContractError> {
+    if inner_nodes.is_empty() || inner_nodes.len() > MAX_SHAMAP_DEPTH {
+        return Err(ContractError::InvalidXRPLTransactionProof {});
+    }
+
+    // The leaf contains the transaction and its metadata (both prefixed with their length) followed by the transaction ID
+    let mut leaf = TRANSACTION_NODE_PREFIX.to_vec();
+    leaf.extend(encode_variable_length(tx_blob.len())?);
+    leaf.extend_from_slice(tx_blob);
+    leaf.extend(encode_variable_length(meta_blob.len())?);
+    leaf.extend_from_slice(meta_blob);
+    leaf.extend_from_slice(tx_id);
+    let mut current = sha512_half(&leaf);
+
+    for (i, inner_node) in inner_nodes.iter().enumerate() {
+        if inner_node.len() != SHAMAP_BRANCHES {
+            return Err(ContractError::InvalidXRPLTransactionProof {});
+        }
+
+        // The first inner node is the deepest one, the branch taken at each depth is given by the nibble of the key at that depth
+        let depth = inner_nodes.len() - 1 - i;
+        let nibble = if depth % 2 == 0 {
+            tx_id[depth / 2] >> 4
+        } else {
+            tx_id[depth / 2] & 0x0F
+        };
+        let branch = nibble as usize;
+
+        let mut data = INNER_NODE_PREFIX.to_vec();
+        for (j, child) in inner_node.iter().enumerate() {
+            if j == branch {
+                if !child.is_empty() {
+                    return Err(ContractError::InvalidXRPLTransactionProof {});
+                }
+                data.extend_from_slice(&current);
+            } else {
+                let child_hash = decode_hex(child)?;
+                if child_hash.len() != 32 {
+                    return Err(ContractError::InvalidXRPLTransactionProof {});
+                }
+                data.extend_from_slice(&child_hash);
+            }
+        }
+        current = sha512_half(&data);
+    }
+
+    Ok(current)
+}
+
+pub fn compute_ledger_hash(header: &XRPLLedgerHeader) -> Result<[u8; 32], ContractError> {
+    let parent_hash = decode_hash(&header.parent_hash)?;
+    let transaction_hash = decode_hash(&header.transaction_hash)?;
+    let account_hash = decode_hash(&header.account_hash)?;
+
+    let mut data = LEDGER_HEADER_PREFIX.to_vec();
+    data.extend_from_slice(&header.ledger_index.to_be_bytes());
+    data.extend_from_slice(&header.total_drops.to_be_bytes());
+    data.extend(parent_hash);
+    data.extend(transaction_hash);
+    data.extend(account_hash);
+    data.extend_from_slice(&header.parent_close_time.to_be_bytes());
+    data.extend_from_slice(&header.close_time.to_be_bytes());
+    data.push(header.close_time_resolution);
+    data.push(header.close_flags);
+
+    Ok(sha512_half(&data))
+}
+
+// Returns the amount of different trusted validators that signed a validation for the ledger
+fn count_valid_validations(
+    api: &dyn Api,
+    light_client_config: &LightClientConfig,
+    ledger_hash: &[u8; 32],
+    ledger_index: u32,
+    validations: &[XRPLValidation],
+) -> Result<usize, ContractError> {
+    let mut validators = HashSet::new();
+
+    for validation in validations {
+        let blob = decode_hex(&validation.blob)?;
+        let signature = decode_hex(&validation.signature)?;
+        let fields = parse_object(&blob)?;
+
+        let public_key = match find_field(&fields, SIGNING_PUB_KEY_FIELD) {
+            Some(STValue::Bytes(public_key)) => public_key,
+            _ => continue,
+        };
+
+        // We ignore validations from validators we don't trust
+        if !light_client_config
+            .validators
+            .iter()
+            .any(|validator| decode_hex(validator).ok().as_ref() == Some(public_key))
+        {
+            continue;
+        }
+
+        // The validation must be for the ledger we are proving
+        match find_field(&fields, LEDGER_HASH_FIELD) {
+            Some(STValue::Bytes(hash)) if hash.as_slice() == ledger_hash => (),
+            _ => continue,
+        }
+        if let Some(STValue::Bytes(sequence)) = find_field(&fields, LEDGER_SEQUENCE_FIELD) {
+            if read_uint(sequence)? != ledger_index as u64 {
+                continue;
+            }
+        }
+
+        let message = [VALIDATION_PREFIX.as_slice(), blob.as_slice()].concat();
+        let verified = if public_key[0] == 0xED {
+            // Ed25519 signatures are done over the message itself
+            api.ed25519_verify(&message, &signature, &public_key[1..])
+                .unwrap_or(false)
+        } else {
+            // Secp256k1 signatures are done over the SHA512-Half of the message and are DER encoded
+            match decode_der_signature(&signature) {
+                Some(signature) => api
+                    .secp256k1_verify(&sha512_half(&message), &signature, public_key)
+                    .unwrap_or(false),
+                None => false,
+            }
+        };
+
+        if verified {
+            validators.insert(public_key.clone());
+        }
+    }
+
+    Ok(validators.len())
+}
+
+// Converts a DER encoded secp256k1 signature into its 64 bytes (r || s) representation
+fn decode_der_signature(signature: &[u8]) -> Option<Vec<u8>> {
+    // 0x30 <length> 0x02 <r length> <r> 0x02 <s length> <s>
+    if signature.len() < 8 || signature[0] != 0x30 || signature[1] as usize != signature.len() - 2 {
+        return None;
+    }
+
+    let mut compact = vec![];
+    let mut pos = 2;
+    for _ in 0..2 {
+        if signature.get(pos) != Some(&0x02) {
+            return None;
+        }
+        let length = *signature.get(pos + 1)? as usize;
+        let integer = signature.get(pos + 2..pos + 2 + length)?;
+        // Integers can have a leading zero to keep them positive, and can be shorter than 32 bytes
+        let integer = match integer.iter().position(|b| *b != 0) {
+            Some(start) => &integer[start..],
+            None => &integer[integer.len()..],
+        };
+        if integer.len() > 32 {
+            return None;
+        }
+        compact.extend(std::iter::repeat(0u8).take(32 - integer.len()));
+        compact.extend_from_slice(integer);
+        pos += 2 + length;
+    }
+
+    if pos != signature.len() {
+        return None;
+    }
+
+    Some(compact)
+}
+
+// Decodes a serialized amount into its issuer, currency and amount (with the decimals the bridge uses for each token)
+fn decode_amount(bytes: &[u8]) -> Result<(String, String, Uint128), ContractError> {
+    let value = read_uint(&bytes[..8])?;
+
+    // If the first bit is not set, it's an XRP amount in drops
+    if value & (1 << 63) == 0 {
+        // Second bit is the sign bit, we can't receive negative amounts
+        if value & (1 << 62) == 0 && value != 0 {
+            return Err(ContractError::InvalidXRPLTransactionProof {});
+        }
+        let drops = value & ((1 << 62) - 1);
+        return Ok((
+            XRP_ISSUER.to_string(),
+            XRP_CURRENCY.to_string(),
+            Uint128::from(drops),
+        ));
+    }
+
+    if bytes.len() != 48 {
+        return Err(ContractError::InvalidXRPLTransactionProof {});
+    }
+
+    let currency = decode_currency(&bytes[8..28]);
+    let issuer = encode_xrpl_address(&bytes[28..48]);
+
+    // Issued amounts are represented as mantissa * 10^exponent. Zero is represented with only the first bit set
+    let amount = if value == 1 << 63 {
+        Uint128::zero()
+    } else {
+        if value & (1 << 62) == 0 {
+            return Err(ContractError::InvalidXRPLTransactionProof {});
+        }
+        let mantissa = Uint128::from(value & ((1 << 54) - 1));
+        let exponent = ((value >> 54) & 0xFF) as i32 - ISSUED_AMOUNT_EXPONENT_OFFSET;
+
+        // The bridge represents XRPL issued tokens with 15 decimals
+        let shift = exponent + XRPL_TOKENS_DECIMALS as i32;
+        if shift >= 0 {
+            mantissa
+                .checked_mul(
+                    Uint128::new(10)
+                        .checked_pow(shift as u32)
+                        .map_err(|_| ContractError::InvalidXRPLTransactionProof {})?,
+                )
+                .map_err(|_| ContractError::InvalidXRPLTransactionProof {})?
+        } else {
+            match Uint128::new(10).checked_pow(shift.unsigned_abs()) {
+                Ok(divisor) => mantissa.checked_div(divisor)?,
+                // The divisor is bigger than any mantissa so the amount is truncated to 0
+                Err(_) => Uint128::zero(),
+            }
+        }
+    };
+
+    Ok((issuer, currency, amount))
+}
+
+// Converts a 160-bit currency code into its string representation
+fn decode_currency(bytes: &[u8]) -> String {
+    // Standard currency codes are 3 ASCII characters in bytes 12 to 14, with all the other bytes set to 0
+    if bytes[..12].iter().all(|b| *b == 0) && bytes[15..].iter().all(|b| *b == 0) {
+        if bytes[12..15].iter().all(|b| *b == 0) {
+            return XRP_CURRENCY.to_string();
+        }
+        return String::from_utf8_lossy(&bytes[12..15]).to_string();
+    }
+
+    hex::encode_upper(bytes)
+}
+
+// Looks for the bridge memo in the transaction and returns the Coreum recipient specified in it
+fn decode_recipient_from_memos(tx: &[STField]) -> Option<String> {
+    let memos = match find_field(tx, MEMOS_FIELD) {
+        Some(STValue::Array(memos)) => memos,
+        _ => return None,
+    };
+
+    for memo in memos {
+        let memo_data = match &memo.value {
+            STValue::Object(fields) => match find_field(fields, MEMO_DATA_FIELD) {
+                Some(STValue::Bytes(memo_data)) if !memo_data.is_empty() => memo_data,
+                _ => continue,
+            },
+            _ => continue,
+        };
+
+        let bridge_memo: serde_json::Value = serde_json::from_slice(memo_data).ok()?;
+        if bridge_memo.get("type")?.as_str()? != BRIDGE_MEMO_TYPE {
+            return None;
+        }
+        return bridge_memo
+            .get("coreum_recipient")?
+            .as_str()
+            .map(|recipient| recipient.to_string());
+    }
+
+    None
+}
+
+fn find_field(fields: &[STField], (type_code, field_code): (u8, u8)) -> Option<&STValue> {
+    fields
+        .iter()
+        .find(|f| f.type_code == type_code && f.field_code == field_code)
+        .map(|f| &f.value)
+}
+
+// Parses the top level of a serialized XRPL object (transaction, metadata or validation)
+fn parse_object(data: &[u8]) -> Result<Vec<STField>, ContractError> {
+    let mut pos = 0;
+    let fields = parse_fields(data, &mut pos, None)?;
+    if pos != data.len() {
+        return Err(ContractError::InvalidXRPLTransactionProof {});
+    }
+    Ok(fields)
+}
+
+fn parse_fields(
+    data: &[u8],
+    pos: &mut usize,
+    end_marker_type: Option<u8>,
+) -> Result<Vec<STField>, ContractError> {
+    let mut fields = vec![];
+
+    while *pos < data.len() {
+        let (type_code, field_code) = read_field_header(data, pos)?;
+
+        if Some(type_code) == end_marker_type && field_code == END_MARKER_FIELD {
+            return Ok(fields);
+        }
+
+        let value = match type_code {
+            ST_OBJECT => STValue::Object(parse_fields(data, pos, Some(ST_OBJECT))?),
+            ST_ARRAY => STValue::Array(parse_fields(data, pos, Some(ST_ARRAY))?),
+            _ => STValue::Bytes(read_value(data, pos, type_code)?),
+        };
+
+        fields.push(STField {
+            type_code,
+            field_code,
+            value,
+        });
+    }
+
+    // If we were expecting an end marker, the data is truncated
+    if end_marker_type.is_some() {
+        return Err(ContractError::InvalidXRPLTransactionProof {});
+    }
+
+    Ok(fields)
+}
+
+fn read_field_header(data: &[u8], pos: &mut usize) -> Result<(u8, u8), ContractError> {
+    let first = read_bytes(data, pos, 1)?[0];
+    let mut type_code = first >> 4;
+    let mut field_code = first & 0x0F;
+
+    // Codes that don't fit in 4 bits are stored in the following bytes
+    if type_code == 0 {
+        type_code = read_bytes(data, pos, 1)?[0];
+    }
+    if field_code == 0 {
+        field_code = read_bytes(data, pos, 1)?[0];
+    }
+
+    Ok((type_code, field_code))
+}
+
+fn read_value(data: &[u8], pos: &mut usize, type_code: u8) -> Result<Vec<u8>, ContractError> {
+    let length = match type_code {
+        ST_UINT8 => 1,
+        ST_UINT16 => 2,
+        ST_UINT32 => 4,
+        ST_UINT64 => 8,
+        ST_HASH128 => 16,
+        ST_HASH160 | ST_CURRENCY => 20,
+        ST_HASH192 => 24,
+        ST_HASH256 => 32,
+        ST_AMOUNT => {
+            // Issued currency amounts have the first bit set and include the currency and issuer
+            if *data
+                .get(*pos)
+                .ok_or(ContractError::InvalidXRPLTransactionProof {})?
+                & 0x80
+                != 0
+            {
+                48
+            } else {
+                8
+            }
+        }
+        ST_BLOB | ST_ACCOUNT_ID | ST_VECTOR256 => read_variable_length(data, pos)?,
+        ST_ISSUE => {
+            // An issue is a currency, followed by an issuer unless it's XRP
+            let currency = data
+                .get(*pos..*pos + 20)
+                .ok_or(ContractError::InvalidXRPLTransactionProof {})?;
+            if currency.iter().all(|b| *b == 0) {
+                20
+            } else {
+                40
+            }
+        }
+        ST_PATH_SET => path_set_length(data, *pos)?,
+        _ => return Err(ContractError::InvalidXRPLTransactionProof {}),
+    };
+
+    Ok(read_bytes(data, pos, length)?.to_vec())
+}
+
+// A path set is a list of paths of path steps. Each step starts with a type byte telling which of the
+// account, currency and issuer (20 bytes each) are present. 0xFF separates paths and 0x00 ends the set.
+fn path_set_length(data: &[u8], start: usize) -> Result<usize, ContractError> {
+    let mut pos = start;
+    loop {
+        let step_type = *data
+            .get(pos)
+            .ok_or(ContractError::InvalidXRPLTransactionProof {})?;
+        pos += 1;
+        match step_type {
+            0x00 => return Ok(pos - start),
+            0xFF => (),
+            _ => {
+                pos += 20
+                    * [0x01, 0x10, 0x20]
+                        .iter()
+                        .filter(|flag| step_type & **flag != 0)
+                        .count()
+            }
+        }
+    }
+}
+
+fn read_variable_length(data: &[u8], pos: &mut usize) -> Result<usize, ContractError> {
+    let first = read_bytes(data, pos, 1)?[0] as usize;
+    match first {
+        0..=192 => Ok(first),
+        193..=240 => {
+            let second = read_bytes(data, pos, 1)?[0] as usize;
+            Ok(193 + (first - 193) * 256 + second)
+        }
+        241..=254 => {
+            let next = read_bytes(data, pos, 2)?;
+            Ok(12481 + (first - 241) * 65536 + next[0] as usize * 256 + next[1] as usize)
+        }
+        _ => Err(ContractError::InvalidXRPLTransactionProof {}),
+    }
+}
+
+fn encode_variable_length(length: usize) -> Result<Vec<u8>, ContractError> {
+    match length {
+        0..=192 => Ok(vec![length as u8]),
+        193..=12480 => {
+            let length = length - 193;
+            Ok(vec![193 + (length >> 8) as u8, (length & 0xFF) as u8])
+        }
+        12481..=918744 => {
+            let length = length - 12481;
+            Ok(vec![
+                241 + (length >> 16) as u8,
+                ((length >> 8) & 0xFF) as u8,
+                (length & 0xFF) as u8,
+            ])
+        }
+        _ => Err(ContractError::InvalidXRPLTransactionProof {}),
+    }
+}
+
+fn read_bytes<'a>(
+    data: &'a [u8],
+    pos: &mut usize,
+    length: usize,
+) -> Result<&'a [u8], ContractError> {
+    let bytes = data
+        .get(*pos..*pos + length)
+        .ok_or(ContractError::InvalidXRPLTransactionProof {})?;
+    *pos += length;
+    Ok(bytes)
+}
+
+// Reads a big endian unsigned integer of up to 8 bytes
+fn read_uint(bytes: &[u8]) -> Result<u64, ContractError> {
+    if bytes.len() > 8 {
+        return Err(ContractError::InvalidXRPLTransactionProof {});
+    }
+    Ok(bytes.iter().fold(0u64, |acc, b| (acc << 8) | *b as u64))
+}
+
+fn decode_hex(data: &str) -> Result<Vec<u8>, ContractError> {
+    hex::decode(data).map_err(|_| ContractError::InvalidXRPLTransactionProof {})
+}
+
+fn decode_hash(data: &str) -> Result<Vec<u8>, ContractError> {
+    let hash = decode_hex(data)?;
+    if hash.len() != 32 {
+        return Err(ContractError::InvalidXRPLTransactionProof {});
+    }
+    Ok(hash)
+}
+
+// XRPL uses the first half of a SHA-512 hash for all its hashes
+fn sha512_half(data: &[u8]) -> [u8; 32] {
+    let output = Sha512::digest(data);
+
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(&output[..32]);
+    hash
+}
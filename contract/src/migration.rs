@@ -1,24 +1,235 @@
-use crate::error::ContractError;
+use std::collections::BTreeMap;
 
-use cosmwasm_std::entry_point;
+use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    DepsMut, Env, Response, StdError,
+    entry_point, Addr, DepsMut, Env, Order, QuerierWrapper, Response, StdError, StdResult, Storage,
+    Uint128,
 };
-
 use cw2::set_contract_version;
+use cw_storage_plus::Item;
+
+use crate::{
+    contract::{
+        DEFAULT_COMMIT_REVEAL_DELAY, DEFAULT_COMMIT_REVEAL_EXPIRATION, DEFAULT_EVIDENCE_TTL,
+        DEFAULT_MAX_REGISTERED_XRPL_TOKENS, DEFAULT_MAX_RELAYERS, DEFAULT_OPERATION_ARCHIVE_SIZE,
+        DEFAULT_OPERATION_EVIDENCE_PERIOD, DEFAULT_RELAYER_INACTIVITY_PERIOD,
+        DEFAULT_TOKEN_FREEZE_DURATION, DEFAULT_XRPL_BASE_RESERVE, DEFAULT_XRPL_DEX_QUOTE_TTL,
+        DEFAULT_XRPL_OWNER_RESERVE,
+    },
+    error::ContractError,
+    msg::MigrateMsg,
+    operation::OperationType,
+    relayer::{init_relayers_activity, Relayer},
+    state::{
+        BridgeState, Config, CoreumToken, TokenAccounting, XRPLNetwork, XRPLToken, CONFIG,
+        COREUM_TOKENS, FEES_COLLECTED, FEE_REMAINDERS, PENDING_OPERATIONS, PENDING_REFUNDS,
+        TOKEN_ACCOUNTING, XRPL_TOKENS,
+    },
+    token::build_xrpl_token_key,
+};
+
+// Config of the contracts deployed before it was split in components
+#[cw_serde]
+pub struct LegacyConfig {
+    pub relayers: Vec<Relayer>,
+    pub evidence_threshold: u32,
+    pub used_ticket_sequence_threshold: u32,
+    pub trust_set_limit_amount: Uint128,
+    pub bridge_xrpl_address: String,
+    pub bridge_state: BridgeState,
+    pub xrpl_base_fee: u64,
+}
+
+pub const LEGACY_CONFIG: Item<LegacyConfig> = Item::new("1");
 
 const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
-use crate::msg::{MigrateMsg};
-
 #[entry_point]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
     let ver = cw2::get_contract_version(deps.storage)?;
     if ver.contract != CONTRACT_NAME {
         return Err(StdError::generic_err("Can only upgrade from same contract type").into());
     }
-    // TODO Add migration logic, and version validation
+
+    // Everything else reads the config components, so they must be saved first
+    if let Some(legacy_config) = LEGACY_CONFIG.may_load(deps.storage)? {
+        split_legacy_config(
+            deps.storage,
+            legacy_config,
+            msg.xrpl_network.unwrap_or(XRPLNetwork::Mainnet),
+            env.block.time.seconds(),
+        )?;
+    }
+
+    rebuild_token_indexes(deps.storage)?;
+
+    // Contracts deployed before the token accounting was introduced don't track any amount yet
+    if TOKEN_ACCOUNTING
+        .keys(deps.storage, None, None, Order::Ascending)
+        .next()
+        .is_none()
+    {
+        seed_token_accounting(deps.storage, &deps.querier, &env.contract.address)?;
+    }
+
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     Ok(Response::default())
 }
+
+// Saves the legacy config as the config components, using the same values for what was added since then as a new deployment would
+fn split_legacy_config(
+    storage: &mut dyn Storage,
+    legacy_config: LegacyConfig,
+    xrpl_network: XRPLNetwork,
+    timestamp: u64,
+) -> Result<(), ContractError> {
+    let config = Config {
+        relayers: legacy_config.relayers,
+        evidence_threshold: legacy_config.evidence_threshold,
+        used_ticket_sequence_threshold: legacy_config.used_ticket_sequence_threshold,
+        trust_set_limit_amount: legacy_config.trust_set_limit_amount,
+        bridge_xrpl_address: legacy_config.bridge_xrpl_address,
+        bridge_state: legacy_config.bridge_state,
+        xrpl_base_fee: legacy_config.xrpl_base_fee,
+        light_client: None,
+        halt_info: None,
+        halt_request_threshold: legacy_config.evidence_threshold,
+        max_registered_xrpl_tokens: DEFAULT_MAX_REGISTERED_XRPL_TOKENS,
+        token_freeze_request_threshold: legacy_config.evidence_threshold,
+        token_freeze_duration: DEFAULT_TOKEN_FREEZE_DURATION,
+        auto_disable_on_issuer_change: false,
+        operation_archive_size: DEFAULT_OPERATION_ARCHIVE_SIZE,
+        evidence_ttl: DEFAULT_EVIDENCE_TTL,
+        compliance_contract: None,
+        commit_reveal_delay: DEFAULT_COMMIT_REVEAL_DELAY,
+        commit_reveal_expiration: DEFAULT_COMMIT_REVEAL_EXPIRATION,
+        crank_reward: Uint128::zero(),
+        xrpl_dex_quote_ttl: DEFAULT_XRPL_DEX_QUOTE_TTL,
+        price_oracle: None,
+        xrpl_base_reserve: Uint128::new(DEFAULT_XRPL_BASE_RESERVE),
+        xrpl_owner_reserve: Uint128::new(DEFAULT_XRPL_OWNER_RESERVE),
+        relayer_inactivity_period: DEFAULT_RELAYER_INACTIVITY_PERIOD,
+        operation_evidence_period: DEFAULT_OPERATION_EVIDENCE_PERIOD,
+        token_failure_threshold: 0,
+        priority_fee: None,
+        fee_converter_contract: None,
+        min_distinct_evidence_sources: 0,
+        max_relayers: DEFAULT_MAX_RELAYERS,
+        xrpl_network,
+    };
+
+    CONFIG.save(storage, &config)?;
+    // The relayers are considered active since the upgrade, like they are since the instantiation on new deployments
+    init_relayers_activity(storage, &config.relayers, timestamp)?;
+    LEGACY_CONFIG.remove(storage);
+
+    Ok(())
+}
+
+// Saves every token again so that the tokens registered before the upgrade are added to the indexes introduced since then
+// Saving a token that is already indexed leaves its index entries as they were
+fn rebuild_token_indexes(storage: &mut dyn Storage) -> Result<(), ContractError> {
+    let xrpl_tokens: Vec<(String, XRPLToken)> = XRPL_TOKENS
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for (key, xrpl_token) in xrpl_tokens {
+        XRPL_TOKENS.save(storage, key, &xrpl_token)?;
+    }
+
+    let coreum_tokens: Vec<(String, CoreumToken)> = COREUM_TOKENS
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for (denom, coreum_token) in coreum_tokens {
+        COREUM_TOKENS.save(storage, denom, &coreum_token)?;
+    }
+
+    Ok(())
+}
+
+// Derives the accounting of every token from what the contract owes and holds, so that the amounts of transfers, refunds and fees
+// of before the upgrade can be released afterwards
+fn seed_token_accounting(
+    storage: &mut dyn Storage,
+    querier: &QuerierWrapper,
+    contract_address: &Addr,
+) -> Result<(), ContractError> {
+    let mut accounting: BTreeMap<String, TokenAccounting> = BTreeMap::new();
+
+    // Amounts that the senders of failed transfers can claim back
+    for pending_refund in PENDING_REFUNDS.range(storage, None, None, Order::Ascending) {
+        let (_, pending_refund) = pending_refund?;
+        let token_accounting = accounting.entry(pending_refund.coin.denom).or_default();
+        token_accounting.refund_reserved = token_accounting
+            .refund_reserved
+            .checked_add(pending_refund.coin.amount)?;
+    }
+
+    // Fees that the relayers didn't claim yet and the remainders that weren't distributed between them
+    for fees_collected in FEES_COLLECTED.range(storage, None, None, Order::Ascending) {
+        let (_, fees_collected) = fees_collected?;
+        for fee in fees_collected {
+            let token_accounting = accounting.entry(fee.denom).or_default();
+            token_accounting.fee_reserved =
+                token_accounting.fee_reserved.checked_add(fee.amount)?;
+        }
+    }
+    for fee_remainder in FEE_REMAINDERS.range(storage, None, None, Order::Ascending) {
+        let (denom, fee_remainder) = fee_remainder?;
+        let token_accounting = accounting.entry(denom).or_default();
+        token_accounting.fee_reserved = token_accounting.fee_reserved.checked_add(fee_remainder)?;
+    }
+
+    // XRPL originated tokens sent to XRPL are held until their operation is confirmed and then burned or refunded
+    for operation in PENDING_OPERATIONS.range(storage, None, None, Order::Ascending) {
+        let (_, operation) = operation?;
+        if let OperationType::CoreumToXRPLTransfer {
+            issuer,
+            currency,
+            amount,
+            max_amount,
+            ..
+        } = operation.operation_type
+        {
+            if let Some(xrpl_token) =
+                XRPL_TOKENS.may_load(storage, build_xrpl_token_key(&issuer, &currency))?
+            {
+                let token_accounting = accounting.entry(xrpl_token.coreum_denom).or_default();
+                token_accounting.locked = token_accounting
+                    .locked
+                    .checked_add(max_amount.unwrap_or(amount))?;
+            }
+        }
+    }
+
+    // The whole supply of XRPL originated tokens was minted by the bridge
+    for xrpl_token in XRPL_TOKENS.range(storage, None, None, Order::Ascending) {
+        let (_, xrpl_token) = xrpl_token?;
+        let minted = querier
+            .query_supply(xrpl_token.coreum_denom.clone())?
+            .amount;
+        accounting
+            .entry(xrpl_token.coreum_denom)
+            .or_default()
+            .minted = minted;
+    }
+
+    // What the contract holds of a Coreum originated token, apart from refunds and fees, is locked for the transfers to XRPL
+    // This includes the pending transfers and the amounts that are currently on XRPL
+    for denom in COREUM_TOKENS.keys(storage, None, None, Order::Ascending) {
+        let denom = denom?;
+        let balance = querier
+            .query_balance(contract_address, denom.clone())?
+            .amount;
+        let token_accounting = accounting.entry(denom).or_default();
+        token_accounting.locked = balance
+            .saturating_sub(token_accounting.refund_reserved)
+            .saturating_sub(token_accounting.fee_reserved);
+    }
+
+    for (denom, token_accounting) in accounting {
+        TOKEN_ACCOUNTING.save(storage, denom, &token_accounting)?;
+    }
+
+    Ok(())
+}
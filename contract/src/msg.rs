@@ -1,14 +1,23 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Coin, Uint128};
+use cosmwasm_std::{Addr, Coin, Decimal, Uint128};
 use cw_ownable::{cw_ownable_execute, cw_ownable_query};
 
 #[allow(unused_imports)]
-use crate::state::{Config, CoreumToken, XRPLToken};
+use crate::state::{
+    ArchivedOperation, BridgeActivity, Config, CoreumToken, EmergencyWithdrawal, InboundTransfer,
+    InvalidDeposit, OperationSubmission, RecurringTransfer, RelayerAttestation, RelayerFeeClaim,
+    RelayerInfraction, TokenFreeze, TokenMetadata, TokenStateTransition, TransferReceipt,
+    XRPLIssuerConfig, XRPLRecipientFlags, XRPLRecipientRule, XRPLSupplyDrift, XRPLToken,
+};
 use crate::{
     evidence::Evidence,
+    light_client::{LightClientConfig, ProvenXRPLTransaction, XRPLTransactionProof},
     operation::Operation,
     relayer::Relayer,
-    state::{BridgeState, TokenState},
+    state::{
+        BridgeState, HaltInfo, HaltReason, RoundingMode, TokenPrice, TokenState, USDBridgingFee,
+        XRPLNetwork,
+    },
 };
 
 #[cw_serde]
@@ -26,15 +35,33 @@ pub struct InstantiateMsg {
     pub bridge_xrpl_address: String,
     // XRPL base fee used for executing transactions on XRPL
     pub xrpl_base_fee: u64,
+    // XRPL network of the multisig account, only X-addresses of this network are accepted
+    pub xrpl_network: XRPLNetwork,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {
+    // XRPL network of contracts deployed before the config stored it, Mainnet if not provided
+    pub xrpl_network: Option<XRPLNetwork>,
 }
 
+// Messages that can only be sent by the chain itself, i.e. through governance proposals
 #[cw_serde]
-pub struct MigrateMsg {}
+pub enum SudoMsg {
+    // Schedules the transfer of the provided contract balances to the recipient, executable once the emergency withdrawal delay passed.
+    // Last resort recovery path if both the owner and the relayers are compromised
+    ScheduleEmergencyWithdrawal {
+        recipient: String,
+        amounts: Vec<Coin>,
+    },
+    // Cancels the scheduled emergency withdrawal
+    CancelEmergencyWithdrawal {},
+}
 
 #[cw_ownable_execute]
 #[cw_serde]
 pub enum ExecuteMsg {
-    // Registers a Coreum token so that it can be bridged to XRPL
+    // Registers a Coreum token so that it can be bridged to XRPL. IBC vouchers (ibc/<HASH>) can also be registered
     // Only the owner can do this
     RegisterCoreumToken {
         denom: String,
@@ -42,6 +69,10 @@ pub enum ExecuteMsg {
         sending_precision: i32,
         max_holding_amount: Uint128,
         bridging_fee: Uint128,
+        // XRPL currency (standard 3 characters or 40 characters hex) to use for the token. If not provided, one will be generated
+        xrpl_currency: Option<String>,
+        // How amounts truncated because of the sending precision are handled. Truncated amounts are collected as fees by default
+        rounding_mode: Option<RoundingMode>,
     },
     // Registers an XRPL originated token so that it can be bridge to Coreum
     // Only the owner can do this
@@ -52,6 +83,15 @@ pub enum ExecuteMsg {
         sending_precision: i32,
         max_holding_amount: Uint128,
         bridging_fee: Uint128,
+        // Metadata of the Coreum FT that will be issued for the token. If no symbol is provided, the generated subunit will be used
+        symbol: Option<String>,
+        description: Option<String>,
+        uri: Option<String>,
+        // If there are no tickets available, queue the registration instead of failing. Its TrustSet operation will be created
+        // once new tickets are allocated
+        queue_if_no_tickets: Option<bool>,
+        // How amounts truncated because of the sending precision are handled. Truncated amounts are collected as fees by default
+        rounding_mode: Option<RoundingMode>,
     },
     // Perform a ticket recovery in case the bridge has run out of tickets due to rejected ticket allocation operations on XRPL
     // Only the owner can do this
@@ -74,10 +114,25 @@ pub enum ExecuteMsg {
         operation_version: u64,
         signature: String,
     },
+    // Record the XRPL transaction submitted for a pending operation that reached the quorum of signatures, so that
+    // any relayer can rebroadcast the exact same transaction if the submitter goes offline
+    // Only relayers can do this
+    SaveTransactionSubmission {
+        operation_id: u64,
+        operation_version: u64,
+        tx_hash: String,
+        // Hex encoded multisigned transaction, whose hash must be tx_hash
+        tx_blob: Option<String>,
+    },
     // Provide an evidence for a specific operation that was executed on XRPL
     // Only relayers can do this
     SaveEvidence {
         evidence: Evidence,
+        // Hex encoded signature of the evidence, required if an evidence signing key is registered for the relayer
+        signature: Option<String>,
+        // Close time of the XRPL ledger that included the transaction, as a Unix timestamp in seconds
+        // If provided, it can't be further in the future than the maximum evidence clock skew relative to the block time
+        close_time: Option<u64>,
     },
     #[serde(rename = "send_to_xrpl")]
     // Send a Token from Coreum to XRPL
@@ -90,6 +145,78 @@ pub enum ExecuteMsg {
         // 2. If the token is XRPL originated, if this is not sent, amount = max_amount = funds sent - bridging_fee
         // 3. If the token is XRPL originated, if this is sent, amount = deliver_amount, max_amount = funds sent - bridging fee
         deliver_amount: Option<Uint128>,
+        // If true, the transfer is rejected if any amount would be truncated because of the sending precision of the token,
+        // instead of handling the truncated amount according to the rounding mode of the token
+        exact_amount: Option<bool>,
+        // If true, an amount that can't be represented on XRPL is split into multiple transfers whose amounts can,
+        // tracked under the id of the first one. Can't be used together with deliver_amount
+        split_amount: Option<bool>,
+        // If true, the operation is placed in the priority lane, which relayers sign first. The priority fee must be sent
+        // together with the funds, either as a separate coin or included in the coin sent if it has the same denom
+        priority: Option<bool>,
+        // Key chosen by the sender to retry the transfer safely. If a transfer of the sender with the same key already
+        // created an operation, the funds are returned and the id of the existing operation is returned instead of creating a new one
+        idempotency_key: Option<String>,
+    },
+    // Lock the funds of a transfer to XRPL without disclosing its details. Required for amounts above the commit reveal threshold of the token
+    // The commitment is the hex encoded SHA256 of the JSON encoded TransferCommitmentPreimage of the transfer
+    // Anyone can do this
+    #[serde(rename = "commit_send_to_xrpl")]
+    CommitSendToXRPL {
+        commitment: String,
+    },
+    // Reveal the details of a committed transfer to XRPL and send it. Only possible once the commit reveal delay has passed
+    // and before the commitment expires
+    // Only the sender of the commitment can do this
+    #[serde(rename = "reveal_send_to_xrpl")]
+    RevealSendToXRPL {
+        commitment: String,
+        recipient: String,
+        deliver_amount: Option<Uint128>,
+        exact_amount: Option<bool>,
+        salt: String,
+    },
+    // Cancel a committed transfer to XRPL, its funds become a pending refund of the sender of the commitment
+    // The sender of the commitment can do this at any time, anyone else only once the commitment expired
+    #[serde(rename = "cancel_send_to_xrpl_commitment")]
+    CancelSendToXRPLCommitment {
+        sender: String,
+        commitment: String,
+    },
+    // Register a transfer to XRPL of amount (in the denom of the funds sent) that is sent every interval (in seconds), starting now
+    // The funds sent are escrowed in the contract to pay for the executions
+    // Anyone can do this
+    CreateRecurringTransfer {
+        recipient: String,
+        amount: Uint128,
+        interval: u64,
+    },
+    // Add the funds sent to the escrowed balance of a recurring transfer
+    // Only the sender of the recurring transfer can do this
+    FundRecurringTransfer {
+        id: u64,
+    },
+    // Remove a recurring transfer and return its escrowed balance
+    // Only the sender of the recurring transfer can do this
+    CancelRecurringTransfer {
+        id: u64,
+    },
+    // Send the next transfer to XRPL of a recurring transfer that is due
+    // Anyone can do this
+    ExecuteRecurringTransfer {
+        id: u64,
+    },
+    // Set the minimum amount of a token from which transfers to XRPL must be committed before being revealed, or remove it if no threshold is provided
+    // Only the owner can do this
+    UpdateCommitRevealThreshold {
+        denom: String,
+        threshold: Option<Uint128>,
+    },
+    // Update the blocks that must pass before a committed transfer can be revealed and the blocks after which it expires
+    // Only the owner can do this
+    UpdateCommitRevealConfig {
+        commit_reveal_delay: Option<u64>,
+        commit_reveal_expiration: Option<u64>,
     },
     // Update the configuration of an XRPL originated token
     // Only the owner can do this
@@ -103,6 +230,16 @@ pub enum ExecuteMsg {
         sending_precision: Option<i32>,
         bridging_fee: Option<Uint128>,
         max_holding_amount: Option<Uint128>,
+        rounding_mode: Option<RoundingMode>,
+    },
+    // Update the metadata (description and URI) of the Coreum FT issued for an XRPL originated token
+    // Only the owner can do this
+    #[serde(rename = "update_xrpl_token_metadata")]
+    UpdateXRPLTokenMetadata {
+        issuer: String,
+        currency: String,
+        description: Option<String>,
+        uri: Option<String>,
     },
     // Update the configuration of a Coreum originated token
     UpdateCoreumToken {
@@ -113,6 +250,7 @@ pub enum ExecuteMsg {
         sending_precision: Option<i32>,
         bridging_fee: Option<Uint128>,
         max_holding_amount: Option<Uint128>,
+        rounding_mode: Option<RoundingMode>,
     },
     // Updates the XRPL base fee in config. When this operation is completed, all signatures on current pending operations will be deleted
     // and we will increase the version of all current pending operations.
@@ -122,32 +260,327 @@ pub enum ExecuteMsg {
         xrpl_base_fee: u64,
     },
     // Claim refund. User who can claim amounts due to failed transactions can do it with this message
+    // If owner is provided, the claim is executed on behalf of that address and the funds are sent to it
+    // If the token is frozen nothing is sent and the attempt is recorded as a blocked claim
     // Anyone can do this
     ClaimRefund {
         pending_refund_id: String,
+        owner: Option<String>,
+    },
+    // Claim up to limit pending refunds of the sender at once, sending one aggregated amount per denom
+    // Held back transfers that are still denied by the compliance contract are skipped, refunds of frozen tokens are recorded as blocked claims
+    // Anyone can do this
+    ClaimAllRefunds {
+        limit: Option<u32>,
     },
     // Any relayer can claim fees at any point in time. They need to provide what they want to claim
     // Only relayers can do this
     ClaimRelayerFees {
         amounts: Vec<Coin>,
     },
+    // Elect the denom in which all the fees claimed by the sender are received, or opt out if no denom is provided
+    // Claimed fees in other denoms are converted by the fee converter contract. If it's not set, fees are sent as they are
+    // Only relayers can do this
+    UpdateFeeConsolidationDenom {
+        denom: Option<String>,
+    },
     // Halt the bridge. This will prevent certain new operations to be created
+    // A reason can be provided so that it's clear why the bridge was halted
     // Only the owner or a relayer can do this
-    HaltBridge {},
-    // Resume a bridge in halted state and with no pending key rotations
+    HaltBridge {
+        reason: Option<HaltReason>,
+    },
+    // Resume a bridge in halted (with no pending key rotations) or maintenance state
     // Only the owner can do this
     ResumeBridge {},
+    // Put an active bridge in maintenance. New transfers to XRPL will be rejected but operations in flight can still be completed
+    // Only the owner can do this
+    StartMaintenance {},
+    // Request the bridge to be halted. When enough relayers (halt request threshold) request it, the bridge is halted
+    // This way relayers can halt the bridge if something is wrong even if the owner is not available
+    // Only relayers can do this
+    RequestHalt {},
+    // Update the amount of relayers that need to request a halt for the bridge to be halted
+    // Only the owner can do this
+    UpdateHaltRequestThreshold {
+        halt_request_threshold: u32,
+    },
+    // Request a token to be frozen because of anomalous activity (e.g. mass issuance by its issuer on XRPL)
+    // When enough relayers (token freeze request threshold) request it, the token can't be transferred in any direction
+    // until the freeze expires, unless the owner confirms it
+    // Only relayers can do this
+    RequestTokenFreeze {
+        denom: String,
+    },
+    // Confirm a token freeze requested by relayers so that it doesn't expire
+    // Only the owner can do this
+    ConfirmTokenFreeze {
+        denom: String,
+    },
+    // Lift the freeze of a token and clear its pending freeze requests
+    // Only the owner can do this
+    UnfreezeToken {
+        denom: String,
+    },
+    // Globally freeze a token issued by the bridge on Coreum, so that no holder can transfer it
+    // Used to contain an exploit involving an XRPL originated token. Only tokens issued with the freezing feature can be frozen
+    // Only the owner can do this
+    GloballyFreezeToken {
+        denom: String,
+    },
+    // Lift the global freeze of a token issued by the bridge on Coreum
+    // Only the owner can do this
+    GloballyUnfreezeToken {
+        denom: String,
+    },
+    // Globally freeze up to limit tokens issued by the bridge, in the order of their XRPL token keys
+    // The key of the last token frozen is returned in the last_key attribute, to continue from it if there are more
+    // Only the owner can do this
+    GloballyFreezeAllTokens {
+        start_after_key: Option<String>,
+        limit: Option<u32>,
+    },
+    // Lift the global freeze of up to limit tokens issued by the bridge, paginated like GloballyFreezeAllTokens
+    // Only the owner can do this
+    GloballyUnfreezeAllTokens {
+        start_after_key: Option<String>,
+        limit: Option<u32>,
+    },
+    // Update the amount of relayers that need to request a token freeze and how long unconfirmed freezes last
+    // Only the owner can do this
+    UpdateTokenFreezeConfig {
+        token_freeze_request_threshold: Option<u32>,
+        token_freeze_duration: Option<u64>,
+    },
+    // Set whether the tokens of an issuer are disabled when relayers report that it enabled a global freeze or clawback on XRPL
+    // Only the owner can do this
+    UpdateAutoDisableOnIssuerChange {
+        auto_disable_on_issuer_change: bool,
+    },
+    // Set after how many consecutive transfers to XRPL rejected on XRPL a token is disabled, 0 never disables it
+    // Only the owner can do this
+    UpdateTokenFailureThreshold {
+        token_failure_threshold: u32,
+    },
+    // Set the extra fee paid to place transfers to XRPL in the priority lane, or disable the priority lane if no fee is provided
+    // Only the owner can do this
+    UpdatePriorityFee {
+        priority_fee: Option<Coin>,
+    },
+    // Update the maximum amount of resolved operations kept in the operation archive, 0 disables the archive
+    // Only the owner can do this
+    UpdateOperationArchiveSize {
+        operation_archive_size: u32,
+    },
+    // Remove the oldest archived operations that exceed the operation archive size
+    // Only the owner can do this
+    PruneOperationArchive {
+        limit: Option<u32>,
+    },
+    // Update the seconds after which evidences that didn't reach the threshold are considered stale
+    // Only the owner can do this
+    #[serde(rename = "update_evidence_ttl")]
+    UpdateEvidenceTTL {
+        evidence_ttl: u64,
+    },
+    // Set the contract that screens transfers, or remove it if no contract is provided
+    // Transfers to XRPL it denies are refused and transfers from XRPL it denies are held back until it allows them
+    // Only the owner can do this
+    UpdateComplianceContract {
+        compliance_contract: Option<String>,
+    },
+    // Set the contract that converts the fees claimed by relayers to their consolidation denom, or remove it if no contract is provided
+    // Only the owner can do this
+    UpdateFeeConverterContract {
+        fee_converter_contract: Option<String>,
+    },
+    // Remove evidences that didn't reach the threshold within the evidence TTL
+    // Anyone can do this
+    PruneStaleEvidences {
+        limit: Option<u32>,
+    },
+    // Perform up to limit housekeeping tasks: prune stale evidences, refund expired transfer commitments, lift expired token freezes
+    // and allocate tickets if needed. If there was anything to do, the sender is paid the crank reward
+    // Anyone can do this
+    Crank {
+        limit: Option<u32>,
+    },
+    // Update the amount paid from the relayer reimbursement pool to whoever triggers housekeeping that had work to do
+    // Only the owner can do this
+    UpdateCrankReward {
+        crank_reward: Uint128,
+    },
+    // Publish the current price of a registered token on the XRPL DEX/AMM, in XRP per unit of the token
+    // Quotes of at least evidence threshold relayers are aggregated and expire after the DEX quote TTL
+    // Only relayers can do this
+    #[serde(rename = "publish_xrpl_dex_quote")]
+    PublishXRPLDexQuote {
+        denom: String,
+        price: Decimal,
+    },
+    // Update the seconds during which published DEX quotes are taken into account
+    // Only the owner can do this
+    #[serde(rename = "update_xrpl_dex_quote_ttl")]
+    UpdateXRPLDexQuoteTTL {
+        xrpl_dex_quote_ttl: u64,
+    },
+    // Set the address that pushes the USD prices of tokens, or remove it if no address is provided
+    // Only the owner can do this
+    UpdatePriceOracle {
+        price_oracle: Option<String>,
+    },
+    // Set the bridging fee of a token in USD, or go back to its fixed bridging fee if no USD fee is provided
+    // The fixed bridging fee is still used while the price oracle doesn't have a recent price for the token
+    // Only the owner can do this
+    #[serde(rename = "update_usd_bridging_fee")]
+    UpdateUSDBridgingFee {
+        denom: String,
+        usd_bridging_fee: Option<USDBridgingFee>,
+    },
+    // Push the USD price of one whole token for one or more registered tokens
+    // Only the price oracle can do this
+    UpdateTokenPrices {
+        prices: Vec<TokenPriceUpdate>,
+    },
+    // Apply a supply drift reported by relayers to the amount tracked as bridged for the XRPL token
+    // Only the owner can do this
+    #[serde(rename = "reconcile_xrpl_token_supply")]
+    ReconcileXRPLTokenSupply {
+        tx_hash: String,
+    },
+    // Deliver a deposit from XRPL that didn't have a valid Coreum recipient to the recipient it was meant for
+    // Only the owner can do this
+    AssignInvalidDeposit {
+        tx_hash: String,
+        recipient: String,
+    },
+    // Attest the current XRP balance (in drops) of the bridge XRPL account, used to check that it can afford its reserve
+    // Only relayers can do this
+    #[serde(rename = "attest_bridge_xrpl_balance")]
+    AttestBridgeXRPLBalance {
+        balance: Uint128,
+    },
+    // Update the XRP (in drops) that XRPL requires the bridge account to hold, in total and for each object it owns
+    // Only the owner can do this
+    #[serde(rename = "update_xrpl_reserves")]
+    UpdateXRPLReserves {
+        xrpl_base_reserve: Uint128,
+        xrpl_owner_reserve: Uint128,
+    },
+    // Exclude a relayer that didn't save evidences or signatures within the relayer inactivity period from fee splits and the evidence threshold
+    // The relayer is active again as soon as it saves an evidence or a signature
+    // Only the owner can do this
+    MarkRelayerInactive {
+        relayer_address: Addr,
+    },
+    // Update the seconds without activity after which a relayer can be marked as inactive
+    // Only the owner can do this
+    UpdateRelayerInactivityPeriod {
+        relayer_inactivity_period: u64,
+    },
+    // Report a relayer that provided two different evidences for the same XRPL transaction, both still waiting for the threshold
+    // The relayer is removed from both evidences and can't provide evidences for the transaction anymore
+    // Anyone can do this
+    ReportConflictingEvidences {
+        relayer_address: Addr,
+        evidence: Evidence,
+        conflicting_evidence: Evidence,
+    },
+    // Cancels a pending operation whose evidence wasn't saved before its deadline, refunding the user if it was a transfer
+    // Anyone can do this
+    ExpireOperation {
+        operation_id: u64,
+    },
+    // Cancels a pending transfer to XRPL while the bridge is halted, if no signatures were collected for it yet.
+    // The funds are stored as a pending refund that can be claimed immediately
+    // Only the sender of the transfer can do this
+    CancelHaltedTransfer {
+        operation_id: u64,
+    },
+    // Update the seconds new pending operations wait for their evidence before they can be expired
+    // Only the owner can do this
+    UpdateOperationEvidencePeriod {
+        operation_evidence_period: u64,
+    },
+    // Increases the version of all pending operations at once and deletes their signatures, so that relayers sign them again
+    // with the XRPL base fee provided (the current one if not provided) and the current relayer set, e.g. after a key rotation
+    // The bridge must be halted
+    // Only the owner can do this
+    RebasePendingOperations {
+        xrpl_base_fee: Option<u64>,
+    },
+    // Confirm that the relayer observed the current state of the bridge, e.g. after a halt or a resume
+    // Acknowledgements are cleared every time the bridge state changes
+    // Only relayers can do this
+    AcknowledgeBridgeState {
+        bridge_state: BridgeState,
+    },
+    // Update the maximum amount of XRPL originated tokens that can be registered
+    // Only the owner can do this
+    #[serde(rename = "update_max_registered_xrpl_tokens")]
+    UpdateMaxRegisteredXRPLTokens {
+        max_registered_xrpl_tokens: u32,
+    },
+    // Set the amount a relayer is reimbursed (from the reimbursement pool) each time its evidence triggers a state transition
+    // Only the owner can do this
+    UpdateRelayerReimbursement {
+        amount_per_operation: Coin,
+    },
+    // Add the funds sent to the pool used to reimburse relayers
+    // Only the owner can do this
+    FundReimbursementPool {},
+    // Send funds that are not owed to anyone (e.g. sent to the contract by mistake) to a recovery address
+    // Only the amount above what the bridge is accountable for can be rescued
+    // Only the owner can do this
+    // This is a high risk action, so it must be executed twice with the same message, the first execution only proposes it
+    RescueFunds {
+        denom: String,
+        amount: Uint128,
+        recipient: String,
+    },
+    // Send the part of the fee division remainder of a token that is above the threshold to a treasury address
+    // Only the owner can do this
+    SweepFeeRemainder {
+        denom: String,
+        threshold: Uint128,
+        recipient: String,
+    },
+    // Send a transfer that couldn't be delivered on Coreum (e.g. the recipient is not whitelisted for the token) back to XRPL
+    // Only the recipient of the undelivered transfer can do this
+    ReturnUndeliveredTransfer {
+        pending_refund_id: String,
+        recipient: String,
+    },
     // Trigger a rotate keys operation, removing and/or adding relayers, and specifying a new threshold
     // Only the owner can do this
+    // This is a high risk action, so it must be executed twice with the same message, the first execution only proposes it
     RotateKeys {
         new_relayers: Vec<Relayer>,
         new_evidence_threshold: u32,
     },
-    // Update the prohibited addresses list
+    // Add a single address to the prohibited addresses list. Adding an address that is already prohibited does nothing
+    // Only the owner can do this
+    #[serde(rename = "add_prohibited_xrpl_recipient")]
+    AddProhibitedXRPLRecipient {
+        recipient: String,
+    },
+    // Remove a single address from the prohibited addresses list. The XRPL address of the bridge can't be removed
+    // Only the owner can do this
+    #[serde(rename = "remove_prohibited_xrpl_recipient")]
+    RemoveProhibitedXRPLRecipient {
+        recipient: String,
+    },
+    // Add or replace a rule that prohibits all the XRPL recipients matching it
     // Only the owner can do this
-    #[serde(rename = "update_prohibited_xrpl_addresses")]
-    UpdateProhibitedXRPLAddresses {
-        prohibited_xrpl_addresses: Vec<String>,
+    #[serde(rename = "set_xrpl_recipient_rule")]
+    SetXRPLRecipientRule {
+        name: String,
+        rule: XRPLRecipientRule,
+    },
+    // Only the owner can do this
+    #[serde(rename = "remove_xrpl_recipient_rule")]
+    RemoveXRPLRecipientRule {
+        name: String,
     },
     // Cancels a pending operation, considering it as invalid
     // This will almost NEVER be used, unless there is some expected operation that causes an error on relayers
@@ -155,6 +588,69 @@ pub enum ExecuteMsg {
     CancelPendingOperation {
         operation_id: u64,
     },
+    // Cancels several pending operations at once during the recovery from an incident, considering them as invalid
+    // Tickets are returned and transfers are refunded. At most 50 operations can be cancelled at once
+    // Only owner can do this
+    CancelPendingOperations {
+        operation_ids: Vec<u64>,
+    },
+    // Enables the light client verification mode for transfers from XRPL, or disables it if no config is provided
+    // Only the owner can do this
+    UpdateLightClientConfig {
+        light_client: Option<LightClientConfig>,
+    },
+    // Provide a proof that an XRPL transaction was included in a validated ledger. When the light client verification mode
+    // is enabled, transfers from XRPL must be proven before relayers can provide evidences for them
+    // Only relayers can do this
+    #[serde(rename = "save_xrpl_transaction_proof")]
+    SaveXRPLTransactionProof {
+        proof: XRPLTransactionProof,
+    },
+    // Register the key that a relayer must use to sign its evidences, or remove it if no key is provided
+    // Only the owner can do this
+    UpdateEvidenceSigningKey {
+        relayer_coreum_address: Addr,
+        pub_key: Option<String>,
+    },
+    // Register an address that can only save evidences and signatures on behalf of the sender, or revoke it if no delegate is provided
+    // Only relayers can do this
+    UpdateEvidenceDelegate {
+        delegate: Option<String>,
+    },
+    // Declare the source the sender reads XRPL data from (e.g. the XRPL node it's connected to), or remove it if no source is provided
+    // Only relayers can do this
+    UpdateEvidenceSource {
+        source: Option<String>,
+    },
+    // Set how many distinct sources the relayers confirming the result of an operation must have declared, 0 disables the rule
+    // Only the owner can do this
+    UpdateMinDistinctEvidenceSources {
+        min_distinct_evidence_sources: u32,
+    },
+    // Set the maximum amount of relayers, which can't be lower than the current amount of relayers or higher than the amount
+    // of signers XRPL allows in a signer list. Relayer sets provided in key rotations can't have more relayers than this
+    // Only the owner can do this
+    UpdateMaxRelayers {
+        max_relayers: u32,
+    },
+    // Publish the configuration of the sender, replacing the one it published before
+    // Only relayers can do this
+    UpdateRelayerAttestation {
+        xrpl_node_fingerprint: Option<String>,
+        software_version: Option<String>,
+        contact: Option<String>,
+    },
+    // Executes the emergency withdrawal scheduled by governance once its delay passed, funds are sent to the recipient chosen by governance
+    // Anyone can do this
+    ExecuteEmergencyWithdrawal {},
+    // Updates the XRPL base fee of a single pending operation (e.g. if it's stuck because the fee is too low).
+    // Signatures of this operation will be deleted and its version increased, other pending operations are not affected.
+    // Only the owner can do this
+    #[serde(rename = "update_operation_xrpl_base_fee")]
+    UpdateOperationXRPLBaseFee {
+        operation_id: u64,
+        xrpl_base_fee: u64,
+    },
 }
 
 #[cw_ownable_query]
@@ -169,6 +665,20 @@ pub enum QueryMsg {
         start_after_key: Option<String>,
         limit: Option<u32>,
     },
+    // Returns the supply drifts reported by relayers that weren't reconciled yet
+    #[returns(XRPLSupplyDriftsResponse)]
+    #[serde(rename = "xrpl_supply_drifts")]
+    XRPLSupplyDrifts {
+        start_after_key: Option<String>,
+        limit: Option<u32>,
+    },
+    // Returns, for each XRPL originated token, the amount minted on Coreum by the bridge that wasn't burned yet
+    #[returns(XRPLTokenSuppliesResponse)]
+    #[serde(rename = "xrpl_token_supplies")]
+    XRPLTokenSupplies {
+        start_after_key: Option<String>,
+        limit: Option<u32>,
+    },
     #[returns(CoreumTokensResponse)]
     CoreumTokens {
         start_after_key: Option<String>,
@@ -179,16 +689,120 @@ pub enum QueryMsg {
         start_after_key: Option<u64>,
         limit: Option<u32>,
     },
+    // Returns the pending operations in the order relayers are expected to sign them: the priority lane first
+    #[returns(PrioritizedPendingOperationsResponse)]
+    PrioritizedPendingOperations {
+        start_after_key: Option<u64>,
+        limit: Option<u32>,
+    },
+    // Returns the bytes each current relayer must sign for the current version of a pending operation
+    #[returns(OperationSigningPayloadResponse)]
+    OperationSigningPayload { operation_id: u64 },
     #[returns(AvailableTicketsResponse)]
     AvailableTickets {},
     #[returns(FeesCollectedResponse)]
     FeesCollected { relayer_address: Addr },
+    // Returns the truncation dust collected for a token, its current fee division remainder and how much of it was swept
+    #[returns(DustAccountingResponse)]
+    DustAccounting { denom: String },
+    // Returns the fees collected for a token, with the bridging fees, priority fees and truncated amounts reported separately
+    #[returns(FeeAccountingResponse)]
+    FeeAccounting { denom: String },
+    // Returns the fee remainders of all denoms: fees collected that couldn't be split evenly between relayers and are
+    // kept for the next fee collection of the same denom
+    #[returns(FeeRemaindersResponse)]
+    FeeRemainders {
+        start_after_key: Option<String>,
+        limit: Option<u32>,
+    },
     #[returns(PendingRefundsResponse)]
     PendingRefunds {
         address: Addr,
         start_after_key: Option<(Addr, String)>,
         limit: Option<u32>,
     },
+    // Returns the sum of the pending refunds per denom, for a single address if provided or for all addresses otherwise
+    #[returns(PendingRefundTotalsResponse)]
+    PendingRefundTotals { address: Option<Addr> },
+    // Returns the deposits from XRPL without a valid Coreum recipient that weren't resolved yet
+    #[returns(InvalidDepositsResponse)]
+    InvalidDeposits {
+        start_after_key: Option<String>,
+        limit: Option<u32>,
+    },
+    // Returns the reserve the bridge XRPL account must hold for the objects it owns and whether it needs to be topped up
+    #[returns(XRPLReserveResponse)]
+    #[serde(rename = "xrpl_reserve")]
+    XRPLReserve {},
+    // Returns the last activity of each relayer and whether it's marked as inactive
+    #[returns(RelayersActivityResponse)]
+    RelayersActivity {},
+    // Returns the block time after which a pending operation can be expired
+    #[returns(OperationEvidenceDeadlineResponse)]
+    OperationEvidenceDeadline { operation_id: u64 },
+    // Returns the XRPL transaction recorded as submitted for a pending operation, if any
+    #[returns(OperationSubmissionResponse)]
+    OperationSubmission { operation_id: u64 },
+    // Returns the high risk actions proposed by the owner that are waiting to be confirmed
+    #[returns(PendingHighRiskActionsResponse)]
+    PendingHighRiskActions {},
+    // Returns the pending refunds whose claims failed because their token is frozen, and whether they can be claimed now
+    #[returns(BlockedClaimsResponse)]
+    BlockedClaims {
+        start_after_key: Option<(Addr, String)>,
+        limit: Option<u32>,
+    },
+    // Returns the transfers from XRPL that are waiting for their token to be enabled or unfrozen to be delivered
+    #[returns(DeferredInboundTransfersResponse)]
+    DeferredInboundTransfers {
+        start_after_key: Option<(String, String)>,
+        limit: Option<u32>,
+    },
+    // Returns the pending operations created at least min_age seconds ago or that are missing signatures for a quorum
+    // for at least missing_quorum_for seconds, all of them if none is provided
+    #[returns(StaleOperationsResponse)]
+    StaleOperations {
+        min_age: Option<u64>,
+        missing_quorum_for: Option<u64>,
+        start_after_key: Option<u64>,
+        limit: Option<u32>,
+    },
+    // Returns the inbound transfers that couldn't be delivered and are waiting to be claimed or returned to XRPL
+    #[returns(UndeliveredTransfersResponse)]
+    UndeliveredTransfers {
+        start_after_key: Option<(Addr, String)>,
+        limit: Option<u32>,
+    },
+    // Returns the activity of the bridge during an epoch, the current one if not provided
+    #[returns(BridgeActivitySnapshotResponse)]
+    BridgeActivitySnapshot { epoch: Option<u64> },
+    // Returns the most recent fee claims of a relayer, from oldest to newest
+    #[returns(RelayerFeeClaimsResponse)]
+    RelayerFeeClaims {
+        relayer_address: Addr,
+        start_after_key: Option<u64>,
+        limit: Option<u32>,
+    },
+    // Returns the conflicting evidences a relayer was reported for, sorted by transaction hash
+    #[returns(RelayerInfractionsResponse)]
+    RelayerInfractions {
+        relayer_address: Addr,
+        start_after_key: Option<String>,
+        limit: Option<u32>,
+    },
+    // Returns the most recent transfers from XRPL delivered to a recipient, from oldest to newest
+    #[returns(InboundTransfersResponse)]
+    InboundTransfers {
+        recipient: Addr,
+        start_after_key: Option<u64>,
+        limit: Option<u32>,
+    },
+    // Returns the most recently resolved operations with their results, from oldest to newest
+    #[returns(ArchivedOperationsResponse)]
+    ArchivedOperations {
+        start_after_key: Option<u64>,
+        limit: Option<u32>,
+    },
     #[returns(BridgeStateResponse)]
     BridgeState {},
     #[returns(TransactionEvidence)]
@@ -198,6 +812,12 @@ pub enum QueryMsg {
         start_after_key: Option<String>,
         limit: Option<u32>,
     },
+    // Returns the evidences that didn't reach the threshold within the evidence TTL and can be pruned
+    #[returns(StaleEvidencesResponse)]
+    StaleEvidences {
+        start_after_key: Option<String>,
+        limit: Option<u32>,
+    },
     #[returns(bool)]
     ProcessedTx { hash: String },
     #[returns(ProcessedTxsResponse)]
@@ -207,7 +827,123 @@ pub enum QueryMsg {
     },
     #[returns(ProhibitedXRPLAddressesResponse)]
     #[serde(rename = "prohibited_xrpl_addresses")]
-    ProhibitedXRPLAddresses {},
+    ProhibitedXRPLAddresses {
+        start_after_key: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(XRPLRecipientRulesResponse)]
+    #[serde(rename = "xrpl_recipient_rules")]
+    XRPLRecipientRules {
+        start_after_key: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(ProvenXRPLTransactionResponse)]
+    #[serde(rename = "proven_xrpl_transaction")]
+    ProvenXRPLTransaction { tx_hash: String },
+    #[returns(EvidenceSigningKeysResponse)]
+    EvidenceSigningKeys {},
+    #[returns(EvidenceDelegatesResponse)]
+    EvidenceDelegates {},
+    #[returns(EvidenceSourcesResponse)]
+    EvidenceSources {},
+    // Returns the configuration attestations published by the relayers
+    #[returns(RelayerAttestationsResponse)]
+    RelayerAttestations {},
+    // Returns the emergency withdrawal scheduled by governance, if any
+    #[returns(EmergencyWithdrawalResponse)]
+    EmergencyWithdrawal {},
+    #[returns(RecurringTransfersResponse)]
+    RecurringTransfers {
+        start_after_key: Option<u64>,
+        limit: Option<u32>,
+    },
+    // Returns the minimum amount of a token from which transfers to XRPL must be committed before being revealed
+    #[returns(CommitRevealThresholdResponse)]
+    CommitRevealThreshold { denom: String },
+    // Returns the transfers to XRPL committed by a sender that were not revealed or cancelled yet
+    #[returns(TransferCommitmentsResponse)]
+    TransferCommitments {
+        sender: Addr,
+        start_after_key: Option<String>,
+        limit: Option<u32>,
+    },
+    // Validates an XRPL address (classic or X-address) and returns its decoded components
+    #[returns(XRPLAddressResponse)]
+    #[serde(rename = "xrpl_address")]
+    XRPLAddress { address: String },
+    // Returns the relayers that requested the bridge to be halted and the amount of requests needed to halt it
+    #[returns(HaltRequestsResponse)]
+    HaltRequests {},
+    // Returns the relayers that acknowledged the current bridge state and the ones that didn't yet
+    #[returns(BridgeStateAcknowledgementsResponse)]
+    BridgeStateAcknowledgements {},
+    // Returns the freeze of a token (if any) and the relayers that requested it to be frozen
+    #[returns(TokenFreezeResponse)]
+    TokenFreeze { denom: String },
+    // Returns the account settings of an issuer of XRPL tokens, as last reported by relayers
+    #[returns(XRPLIssuerConfigResponse)]
+    #[serde(rename = "xrpl_issuer_config")]
+    XRPLIssuerConfig { issuer: String },
+    // Returns the USD bridging fee of a token, its last price and the bridging fee that would be charged right now
+    #[returns(USDBridgingFeeResponse)]
+    #[serde(rename = "usd_bridging_fee")]
+    USDBridgingFee { denom: String },
+    // Returns the median of the XRPL DEX/AMM quotes of a token that relayers published within the DEX quote TTL
+    #[returns(XRPLDexQuoteResponse)]
+    #[serde(rename = "xrpl_dex_quote")]
+    XRPLDexQuote { denom: String },
+    // Returns the account settings of an XRPL recipient that make transfers to it fail, as last reported by relayers
+    #[returns(XRPLRecipientFlagsResponse)]
+    #[serde(rename = "xrpl_recipient_flags")]
+    XRPLRecipientFlags { account: String },
+    // Calculates the amount that will be sent to XRPL when bridging a Coreum amount of a registered token
+    #[returns(AmountConversionResponse)]
+    #[serde(rename = "coreum_to_xrpl_amount")]
+    CoreumToXRPLAmount { denom: String, amount: Uint128 },
+    // Calculates the amount that will be received on Coreum when bridging an XRPL amount of a registered token
+    #[returns(AmountConversionResponse)]
+    #[serde(rename = "xrpl_to_coreum_amount")]
+    XRPLToCoreumAmount {
+        issuer: String,
+        currency: String,
+        amount: Uint128,
+    },
+    // Compares, for every registered token, the amounts the contract owes with the amounts it is actually holding
+    // Tokens of both origins are returned together in the order of their denoms
+    #[returns(SolvencyResponse)]
+    Solvency {
+        start_after_key: Option<String>,
+        limit: Option<u32>,
+    },
+    // Returns the XRPL currency that will be generated for a Coreum token when it's registered without a custom currency
+    #[returns(XRPLCurrencyForDenomResponse)]
+    #[serde(rename = "xrpl_currency_for_denom")]
+    XRPLCurrencyForDenom { denom: String },
+    // Returns the metadata of the Coreum FT issued for an XRPL originated token
+    #[returns(TokenMetadata)]
+    TokenMetadata { denom: String },
+    // Returns the most recent state transitions of a token, which can be found by its denom or, for XRPL originated tokens, by its issuer and currency
+    #[returns(TokenStateHistoryResponse)]
+    TokenStateHistory {
+        denom: Option<String>,
+        issuer: Option<String>,
+        currency: Option<String>,
+    },
+    // Returns the amount of XRPL originated tokens registered and the maximum amount that can be registered
+    #[returns(RegisteredXRPLTokensResponse)]
+    #[serde(rename = "registered_xrpl_tokens")]
+    RegisteredXRPLTokens {},
+    #[returns(RelayerReimbursementResponse)]
+    RelayerReimbursement {},
+    // Returns the lifecycle of a transfer, found by its receipt id (operation id for transfers to XRPL) or its XRPL transaction hash
+    #[returns(TransferReceipt)]
+    TransferReceipt { id_or_hash: String },
+    // Returns the operation ids of the chunks a transfer to XRPL was split into, empty if it wasn't split
+    #[returns(TransferChunksResponse)]
+    TransferChunks { parent_transfer_id: String },
+    // Returns how the contract state is stored, so that indexers reading it with raw queries can detect layout changes
+    #[returns(StorageLayoutResponse)]
+    StorageLayout {},
 }
 
 #[cw_serde]
@@ -216,6 +952,29 @@ pub struct XRPLTokensResponse {
     pub tokens: Vec<XRPLToken>,
 }
 
+#[cw_serde]
+pub struct XRPLSupplyDriftsResponse {
+    pub last_key: Option<String>,
+    pub drifts: Vec<XRPLSupplyDrift>,
+}
+
+#[cw_serde]
+pub struct XRPLTokenSuppliesResponse {
+    pub last_key: Option<String>,
+    pub supplies: Vec<XRPLTokenSupply>,
+}
+
+#[cw_serde]
+pub struct XRPLTokenSupply {
+    pub issuer: String,
+    pub currency: String,
+    pub coreum_denom: String,
+    // Amount minted by the bridge minus the amount burned when sending back to XRPL
+    pub minted: Uint128,
+    // Current supply of the Coreum denom
+    pub total_supply: Uint128,
+}
+
 #[cw_serde]
 pub struct CoreumTokensResponse {
     pub last_key: Option<String>,
@@ -228,6 +987,35 @@ pub struct PendingOperationsResponse {
     pub operations: Vec<Operation>,
 }
 
+#[cw_serde]
+pub struct PrioritizedPendingOperationsResponse {
+    pub last_key: Option<u64>,
+    pub operations: Vec<PrioritizedOperation>,
+}
+
+#[cw_serde]
+pub struct PrioritizedOperation {
+    pub priority: bool,
+    pub operation: Operation,
+}
+
+#[cw_serde]
+pub struct OperationSigningPayloadResponse {
+    pub operation_id: u64,
+    pub operation_version: u64,
+    // Hex encoded XRPL transaction built for the operation, with only the fields that are signed
+    pub tx_blob: String,
+    pub payloads: Vec<RelayerSigningPayload>,
+}
+
+#[cw_serde]
+pub struct RelayerSigningPayload {
+    pub relayer_coreum_address: Addr,
+    pub xrpl_address: String,
+    // Hex encoded bytes to sign: the multisigning prefix, the transaction and the account of the relayer
+    pub payload: String,
+}
+
 #[cw_serde]
 pub struct AvailableTicketsResponse {
     pub tickets: Vec<u64>,
@@ -236,6 +1024,8 @@ pub struct AvailableTicketsResponse {
 #[cw_serde]
 pub struct FeesCollectedResponse {
     pub fees_collected: Vec<Coin>,
+    // Denom the relayer elected to receive its claimed fees in, if any
+    pub consolidation_denom: Option<String>,
 }
 
 #[cw_serde]
@@ -244,6 +1034,13 @@ pub struct PendingRefundsResponse {
     pub pending_refunds: Vec<PendingRefund>,
 }
 
+#[cw_serde]
+pub struct PendingRefundTotalsResponse {
+    pub address: Option<Addr>,
+    // Amount earmarked for pending refunds, one coin per denom
+    pub totals: Vec<Coin>,
+}
+
 #[cw_serde]
 pub struct PendingRefund {
     pub id: String,
@@ -251,9 +1048,222 @@ pub struct PendingRefund {
     pub coin: Coin,
 }
 
+#[cw_serde]
+pub struct InvalidDepositsResponse {
+    pub last_key: Option<String>,
+    pub invalid_deposits: Vec<InvalidDeposit>,
+}
+
+#[cw_serde]
+pub struct RelayersActivityResponse {
+    pub relayers: Vec<RelayerActivityInfo>,
+    pub relayer_inactivity_period: u64,
+}
+
+#[cw_serde]
+pub struct OperationEvidenceDeadlineResponse {
+    pub operation_id: u64,
+    // Operations created before evidence deadlines existed don't have one and can only be cancelled by the owner
+    pub evidence_deadline: Option<u64>,
+    pub expired: bool,
+}
+
+#[cw_serde]
+pub struct OperationSubmissionResponse {
+    pub operation_id: u64,
+    pub submission: Option<OperationSubmission>,
+}
+
+#[cw_serde]
+pub struct PendingHighRiskActionsResponse {
+    pub actions: Vec<PendingHighRiskActionInfo>,
+}
+
+#[cw_serde]
+pub struct PendingHighRiskActionInfo {
+    pub action: String,
+    pub msg_hash: String,
+    pub proposed_by: Addr,
+    pub proposed_at_height: u64,
+    pub expires_at: u64,
+}
+
+#[cw_serde]
+pub struct StaleOperationsResponse {
+    pub last_key: Option<u64>,
+    pub operations: Vec<StaleOperation>,
+}
+
+#[cw_serde]
+pub struct StaleOperation {
+    pub operation: Operation,
+    // Operations created before their creation block was recorded don't have a creation height
+    pub created_at_height: Option<u64>,
+    pub created_at: u64,
+    pub age: u64,
+    pub signatures: u32,
+    pub evidence_threshold: u32,
+}
+
+#[cw_serde]
+pub struct RelayerActivityInfo {
+    pub relayer_coreum_address: Addr,
+    pub last_active_at: Option<u64>,
+    pub inactive_since: Option<u64>,
+    pub signatures_saved: u64,
+    // Transfers from XRPL delivered because the evidence of the relayer reached the threshold, which pays the gas of the mints
+    pub inbound_deliveries: u64,
+}
+
+#[cw_serde]
+pub struct XRPLReserveResponse {
+    // Trust lines, tickets and signer list owned by the bridge XRPL account
+    pub owner_count: u32,
+    pub required_reserve: Uint128,
+    // Amount of relayers whose attestations didn't expire
+    pub attestations: u32,
+    // None if less than evidence threshold relayers attested the balance
+    pub attested_balance: Option<Uint128>,
+    // XRP held by the bridge XRPL account that was bridged to Coreum
+    pub bridged_xrp: Uint128,
+    // XRP that must be sent to the bridge XRPL account to cover its reserve. None if the balance wasn't attested
+    pub top_up_amount: Option<Uint128>,
+}
+
+#[cw_serde]
+pub struct UndeliveredTransfersResponse {
+    pub last_key: Option<(Addr, String)>,
+    pub undelivered_transfers: Vec<UndeliveredTransfer>,
+}
+
+#[cw_serde]
+pub struct StorageLayoutResponse {
+    pub contract_version: String,
+    // Encoding used for all stored values
+    pub value_encoding: String,
+    pub entries: Vec<StorageLayoutEntry>,
+}
+
+#[cw_serde]
+pub struct StorageLayoutEntry {
+    pub name: String,
+    pub namespace: String,
+    pub kind: String,
+    pub key_encoding: String,
+    pub value_type: String,
+    pub version: u32,
+}
+
+#[cw_serde]
+pub struct BridgeActivitySnapshotResponse {
+    pub epoch: u64,
+    pub epoch_start: u64,
+    pub epoch_end: u64,
+    pub activity: BridgeActivity,
+    // Share of the operations resolved in the epoch that failed
+    pub operation_failure_rate: Decimal,
+}
+
+#[cw_serde]
+pub struct RelayerFeeClaimsResponse {
+    pub last_key: Option<u64>,
+    pub claims: Vec<RelayerFeeClaim>,
+}
+
+#[cw_serde]
+pub struct RelayerInfractionsResponse {
+    pub last_key: Option<String>,
+    pub infractions: Vec<RelayerInfraction>,
+}
+
+#[cw_serde]
+pub struct InboundTransfersResponse {
+    pub last_key: Option<u64>,
+    pub transfers: Vec<InboundTransfer>,
+}
+
+#[cw_serde]
+pub struct DustAccountingResponse {
+    pub denom: String,
+    pub truncation_dust: Uint128,
+    pub fee_remainder: Uint128,
+    pub swept: Uint128,
+}
+
+#[cw_serde]
+pub struct FeeAccountingResponse {
+    pub denom: String,
+    pub bridging_fees: Uint128,
+    pub priority_fees: Uint128,
+    // Amounts truncated because of the sending precision of the token and collected as fees
+    pub truncation_fees: Uint128,
+}
+
+#[cw_serde]
+pub struct FeeRemaindersResponse {
+    pub last_key: Option<String>,
+    pub fee_remainders: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct ArchivedOperationsResponse {
+    pub last_key: Option<u64>,
+    pub operations: Vec<ArchivedOperation>,
+}
+
+#[cw_serde]
+pub struct BlockedClaimsResponse {
+    pub last_key: Option<(Addr, String)>,
+    pub blocked_claims: Vec<BlockedClaimInfo>,
+}
+
+#[cw_serde]
+pub struct BlockedClaimInfo {
+    pub owner: Addr,
+    pub pending_refund_id: String,
+    pub coin: Coin,
+    pub first_blocked_at_height: u64,
+    pub last_attempt_height: u64,
+    pub attempts: u32,
+    // The token is no longer frozen so the refund can be claimed again
+    pub claimable: bool,
+}
+
+#[cw_serde]
+pub struct DeferredInboundTransfersResponse {
+    pub last_key: Option<(String, String)>,
+    pub deferred_transfers: Vec<DeferredInboundTransferInfo>,
+}
+
+#[cw_serde]
+pub struct DeferredInboundTransferInfo {
+    pub transfer_id: String,
+    pub issuer: String,
+    pub currency: String,
+    pub coreum_denom: String,
+    pub amount: Uint128,
+    pub recipient: Addr,
+    pub deferred_at: u64,
+    // Seconds elapsed since the transfer was deferred
+    pub age: u64,
+    // The transfer is delivered once the token is Enabled and not frozen
+    pub token_state: TokenState,
+    pub frozen: bool,
+}
+
+#[cw_serde]
+pub struct UndeliveredTransfer {
+    pub recipient: Addr,
+    // Id of the pending refund the recipient can claim
+    pub id: String,
+    pub xrpl_tx_hash: Option<String>,
+    pub coin: Coin,
+}
+
 #[cw_serde]
 pub struct BridgeStateResponse {
     pub state: BridgeState,
+    pub halt_info: Option<HaltInfo>,
 }
 
 #[cw_serde]
@@ -268,6 +1278,20 @@ pub struct TransactionEvidencesResponse {
     pub transaction_evidences: Vec<TransactionEvidence>,
 }
 
+#[cw_serde]
+pub struct StaleEvidence {
+    pub hash: String,
+    pub relayer_addresses: Vec<Addr>,
+    pub created_at: u64,
+}
+
+#[cw_serde]
+pub struct StaleEvidencesResponse {
+    pub last_key: Option<String>,
+    pub evidence_ttl: u64,
+    pub evidences: Vec<StaleEvidence>,
+}
+
 #[cw_serde]
 pub struct ProcessedTxsResponse {
     pub last_key: Option<String>,
@@ -276,5 +1300,256 @@ pub struct ProcessedTxsResponse {
 
 #[cw_serde]
 pub struct ProhibitedXRPLAddressesResponse {
+    pub last_key: Option<String>,
     pub prohibited_xrpl_addresses: Vec<String>,
 }
+
+#[cw_serde]
+pub struct ProvenXRPLTransactionResponse {
+    pub proven_transaction: Option<ProvenXRPLTransaction>,
+}
+
+#[cw_serde]
+pub struct XRPLAddressResponse {
+    pub valid: bool,
+    // If the address is not valid, this will contain the reason
+    pub error: Option<String>,
+    // Code of the error if the address is not valid
+    pub error_code: Option<u32>,
+    pub is_x_address: bool,
+    pub classic_address: Option<String>,
+    pub destination_tag: Option<u32>,
+    pub prohibited: bool,
+}
+
+#[cw_serde]
+pub struct TransferChunksResponse {
+    pub parent_transfer_id: String,
+    pub operation_ids: Vec<String>,
+}
+
+#[cw_serde]
+pub struct HaltRequestsResponse {
+    pub relayer_addresses: Vec<Addr>,
+    pub halt_request_threshold: u32,
+}
+
+#[cw_serde]
+pub struct BridgeStateAcknowledgementsResponse {
+    pub bridge_state: BridgeState,
+    pub acknowledgements: Vec<BridgeStateAcknowledgement>,
+    pub pending_relayers: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct BridgeStateAcknowledgement {
+    pub relayer_coreum_address: Addr,
+    pub acknowledged_at: u64,
+}
+
+#[cw_serde]
+pub struct XRPLIssuerConfigResponse {
+    // None if no change was reported for this issuer
+    pub config: Option<XRPLIssuerConfig>,
+}
+
+#[cw_serde]
+pub struct TokenPriceUpdate {
+    pub denom: String,
+    pub usd_price: Decimal,
+}
+
+#[cw_serde]
+pub struct USDBridgingFeeResponse {
+    pub denom: String,
+    pub usd_bridging_fee: Option<USDBridgingFee>,
+    pub price: Option<TokenPrice>,
+    pub bridging_fee: Uint128,
+}
+
+#[cw_serde]
+pub struct XRPLDexQuoteResponse {
+    pub denom: String,
+    // None if less than evidence threshold relayers published a quote that didn't expire
+    pub price: Option<Decimal>,
+    // Amount of relayers whose quotes didn't expire
+    pub quotes: u32,
+    // Time of the oldest quote used to calculate the price
+    pub oldest_quote_at: Option<u64>,
+}
+
+#[cw_serde]
+pub struct XRPLRecipientRulesResponse {
+    pub last_key: Option<String>,
+    pub rules: Vec<XRPLRecipientRuleInfo>,
+}
+
+#[cw_serde]
+pub struct XRPLRecipientRuleInfo {
+    pub name: String,
+    pub rule: XRPLRecipientRule,
+}
+
+#[cw_serde]
+pub struct XRPLRecipientFlagsResponse {
+    // None if the recipient has no settings that make transfers to it fail
+    pub flags: Option<XRPLRecipientFlags>,
+}
+
+#[cw_serde]
+pub struct TokenFreezeResponse {
+    // Whether transfers of the token are currently rejected
+    pub frozen: bool,
+    pub freeze: Option<TokenFreeze>,
+    pub freeze_requests: Vec<Addr>,
+    pub token_freeze_request_threshold: u32,
+}
+
+#[cw_serde]
+pub struct AmountConversionResponse {
+    // Amount that will be received on the destination chain, using the decimals of the token on that chain
+    pub amount: Uint128,
+    // Bridging fee and truncated remainder that will be collected as fees, in the Coreum denom of the token
+    pub bridging_fee: Uint128,
+    pub remainder: Uint128,
+    // Truncated amount that is not collected as fees because of the rounding mode of the token
+    // It's returned to the sender (directly or as a pending refund) for transfers to XRPL and it's already included in the amount for transfers from XRPL
+    pub dust: Uint128,
+}
+
+#[cw_serde]
+pub struct EvidenceSigningKey {
+    pub relayer_coreum_address: Addr,
+    pub pub_key: String,
+}
+
+#[cw_serde]
+pub struct EvidenceSigningKeysResponse {
+    pub evidence_signing_keys: Vec<EvidenceSigningKey>,
+}
+
+#[cw_serde]
+pub struct EvidenceDelegate {
+    pub relayer_coreum_address: Addr,
+    pub delegate: Addr,
+}
+
+#[cw_serde]
+pub struct EvidenceDelegatesResponse {
+    pub evidence_delegates: Vec<EvidenceDelegate>,
+}
+
+#[cw_serde]
+pub struct EvidenceSource {
+    pub relayer_coreum_address: Addr,
+    pub source: String,
+}
+
+#[cw_serde]
+pub struct EvidenceSourcesResponse {
+    pub evidence_sources: Vec<EvidenceSource>,
+    pub min_distinct_evidence_sources: u32,
+}
+
+#[cw_serde]
+pub struct RelayerAttestationInfo {
+    pub relayer_coreum_address: Addr,
+    pub attestation: RelayerAttestation,
+}
+
+#[cw_serde]
+pub struct RelayerAttestationsResponse {
+    pub attestations: Vec<RelayerAttestationInfo>,
+}
+
+#[cw_serde]
+pub struct EmergencyWithdrawalResponse {
+    pub emergency_withdrawal: Option<EmergencyWithdrawal>,
+}
+
+// Details of a transfer to XRPL that are hashed to build its commitment
+#[cw_serde]
+pub struct TransferCommitmentPreimage {
+    pub sender: Addr,
+    pub recipient: String,
+    pub deliver_amount: Option<Uint128>,
+    pub exact_amount: bool,
+    pub salt: String,
+}
+
+#[cw_serde]
+pub struct CommitRevealThresholdResponse {
+    pub denom: String,
+    pub threshold: Option<Uint128>,
+}
+
+#[cw_serde]
+pub struct TransferCommitmentInfo {
+    pub commitment: String,
+    pub coin: Coin,
+    pub committed_at_height: u64,
+    // First block in which the transfer can be revealed
+    pub revealable_at_height: u64,
+    // From this block on the transfer can't be revealed anymore and anyone can cancel it
+    pub expires_at_height: u64,
+}
+
+#[cw_serde]
+pub struct TransferCommitmentsResponse {
+    pub last_key: Option<String>,
+    pub transfer_commitments: Vec<TransferCommitmentInfo>,
+}
+
+#[cw_serde]
+pub struct RecurringTransfersResponse {
+    pub last_key: Option<u64>,
+    pub recurring_transfers: Vec<RecurringTransfer>,
+}
+
+#[cw_serde]
+pub struct SolvencyResponse {
+    pub last_key: Option<String>,
+    // False if the contract is holding less than what it owes for any of the tokens of the page
+    pub solvent: bool,
+    pub tokens: Vec<TokenSolvency>,
+}
+
+#[cw_serde]
+pub struct TokenSolvency {
+    pub denom: String,
+    pub xrpl_originated: bool,
+    // Amount minted by the bridge that wasn't burned yet. Only used for XRPL originated tokens
+    pub minted: Uint128,
+    pub locked: Uint128,
+    pub refund_reserved: Uint128,
+    pub fee_reserved: Uint128,
+    // Amount the contract must be holding to cover the locked and reserved amounts
+    pub liabilities: Uint128,
+    // Amount the contract is actually holding
+    pub balance: Uint128,
+    pub surplus: Uint128,
+    pub shortfall: Uint128,
+}
+
+#[cw_serde]
+pub struct XRPLCurrencyForDenomResponse {
+    pub xrpl_currency: String,
+}
+
+#[cw_serde]
+pub struct TokenStateHistoryResponse {
+    pub denom: String,
+    pub transitions: Vec<TokenStateTransition>,
+}
+
+#[cw_serde]
+pub struct RegisteredXRPLTokensResponse {
+    pub registered_xrpl_tokens: u32,
+    pub max_registered_xrpl_tokens: u32,
+}
+
+#[cw_serde]
+pub struct RelayerReimbursementResponse {
+    pub amount_per_operation: Option<Coin>,
+    pub pool: Uint128,
+}
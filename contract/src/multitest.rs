@@ -0,0 +1,370 @@
+// In-process test harness built on cw-multi-test, where the assetft module of Coreum is mocked so that the contract logic can be tested
+// without running a Coreum chain. Frozen balances are only recorded, they don't restrict bank transfers like in Coreum
+use anyhow::{bail, Result as AnyResult};
+use coreum_wasm_sdk::{
+    assetft::{self, Params, ParamsResponse, BURNING, FREEZING, MINTING},
+    core::{CoreumMsg, CoreumQueries},
+};
+use cosmwasm_schema::{cw_serde, serde::de::DeserializeOwned};
+use cosmwasm_std::{
+    coin,
+    testing::{MockApi, MockStorage},
+    to_json_binary, Addr, Api, BankMsg, Binary, BlockInfo, Coin, CustomMsg, CustomQuery, Deps,
+    DepsMut, Empty, Env, Event, Querier, Response, StdResult, Storage, Uint128,
+};
+use cw_multi_test::{
+    App, AppBuilder, AppResponse, BankKeeper, BankSudo, Contract, ContractWrapper, CosmosRouter,
+    Executor, Module, WasmKeeper,
+};
+use cw_storage_plus::Map;
+
+use crate::{
+    contract::{execute, instantiate, query, reply, sudo},
+    error::ContractError,
+    migration::migrate,
+    msg::{InstantiateMsg, MigrateMsg, QueryMsg},
+};
+
+pub const ISSUE_FEE_AMOUNT: u128 = 10_000_000;
+pub const ISSUE_FEE_DENOM: &str = "ucore";
+
+pub type CoreumApp =
+    App<BankKeeper, MockApi, MockStorage, AssetFTModule, WasmKeeper<CoreumMsg, CoreumQueries>>;
+
+// Tokens issued through the mocked assetft module
+// Key is the denom of the token
+const ISSUED_TOKENS: Map<String, IssuedToken> = Map::new("multitest_assetft_tokens");
+// Key is the tuple (account, denom)
+const FROZEN_BALANCES: Map<(String, String), Uint128> =
+    Map::new("multitest_assetft_frozen_balances");
+
+#[cw_serde]
+pub struct IssuedToken {
+    pub issuer: Addr,
+    pub precision: u32,
+    pub features: Vec<u32>,
+    pub globally_frozen: bool,
+}
+
+pub struct AssetFTModule {
+    pub issue_fee: Coin,
+}
+
+impl Default for AssetFTModule {
+    fn default() -> Self {
+        Self {
+            issue_fee: coin(ISSUE_FEE_AMOUNT, ISSUE_FEE_DENOM),
+        }
+    }
+}
+
+impl AssetFTModule {
+    fn load_token(storage: &dyn Storage, denom: &str) -> AnyResult<IssuedToken> {
+        match ISSUED_TOKENS.may_load(storage, denom.to_owned())? {
+            Some(token) => Ok(token),
+            None => bail!("token {} not found", denom),
+        }
+    }
+
+    // Only the issuer can use the features of a token, and only if the token was issued with them
+    fn check_feature(token: &IssuedToken, sender: &Addr, feature: u32) -> AnyResult<()> {
+        if token.issuer != sender {
+            bail!("only the issuer can do this");
+        }
+        if !token.features.contains(&feature) {
+            bail!("feature {} is disabled", feature);
+        }
+        Ok(())
+    }
+
+    fn set_globally_frozen(
+        storage: &mut dyn Storage,
+        sender: &Addr,
+        denom: String,
+        globally_frozen: bool,
+    ) -> AnyResult<AppResponse> {
+        let mut token = Self::load_token(storage, &denom)?;
+        Self::check_feature(&token, sender, FREEZING)?;
+
+        token.globally_frozen = globally_frozen;
+        ISSUED_TOKENS.save(storage, denom, &token)?;
+
+        Ok(AppResponse::default())
+    }
+}
+
+impl Module for AssetFTModule {
+    type ExecT = CoreumMsg;
+    type QueryT = CoreumQueries;
+    type SudoT = Empty;
+
+    fn execute<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        msg: Self::ExecT,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: CustomMsg + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        let CoreumMsg::AssetFT(msg) = msg else {
+            bail!("only assetft messages are supported");
+        };
+
+        match msg {
+            assetft::Msg::Issue {
+                subunit,
+                precision,
+                initial_amount,
+                features,
+                ..
+            } => {
+                let denom = format!("{}-{}", subunit, sender).to_lowercase();
+                if ISSUED_TOKENS.has(storage, denom.clone()) {
+                    bail!("token {} already exists", denom);
+                }
+
+                // The issue fee is burnt from the balance of the issuer
+                if !self.issue_fee.amount.is_zero() {
+                    router.execute(
+                        api,
+                        storage,
+                        block,
+                        sender.clone(),
+                        BankMsg::Burn {
+                            amount: vec![self.issue_fee.clone()],
+                        }
+                        .into(),
+                    )?;
+                }
+                if !initial_amount.is_zero() {
+                    router.sudo(
+                        api,
+                        storage,
+                        block,
+                        BankSudo::Mint {
+                            to_address: sender.to_string(),
+                            amount: vec![coin(initial_amount.u128(), denom.clone())],
+                        }
+                        .into(),
+                    )?;
+                }
+
+                ISSUED_TOKENS.save(
+                    storage,
+                    denom.clone(),
+                    &IssuedToken {
+                        issuer: sender.clone(),
+                        precision,
+                        features: features.unwrap_or_default(),
+                        globally_frozen: false,
+                    },
+                )?;
+
+                Ok(AppResponse {
+                    events: vec![Event::new("assetft_issue")
+                        .add_attribute("denom", denom)
+                        .add_attribute("issuer", sender)],
+                    data: None,
+                })
+            }
+            assetft::Msg::Mint { coin, recipient } => {
+                let token = Self::load_token(storage, &coin.denom)?;
+                Self::check_feature(&token, &sender, MINTING)?;
+
+                // Minting nothing is allowed, but the bank module doesn't accept empty amounts
+                let recipient = recipient.unwrap_or_else(|| sender.to_string());
+                if !coin.amount.is_zero() {
+                    router.sudo(
+                        api,
+                        storage,
+                        block,
+                        BankSudo::Mint {
+                            to_address: recipient.clone(),
+                            amount: vec![coin.clone()],
+                        }
+                        .into(),
+                    )?;
+                }
+
+                Ok(AppResponse {
+                    events: vec![Event::new("assetft_mint")
+                        .add_attribute("recipient", recipient)
+                        .add_attribute("amount", coin.to_string())],
+                    data: None,
+                })
+            }
+            assetft::Msg::Burn { coin } => {
+                // The issuer can always burn its tokens, other holders only if the burning feature is enabled
+                let token = Self::load_token(storage, &coin.denom)?;
+                if token.issuer != sender && !token.features.contains(&BURNING) {
+                    bail!("feature {} is disabled", BURNING);
+                }
+
+                router.execute(
+                    api,
+                    storage,
+                    block,
+                    sender.clone(),
+                    BankMsg::Burn {
+                        amount: vec![coin.clone()],
+                    }
+                    .into(),
+                )?;
+
+                Ok(AppResponse {
+                    events: vec![Event::new("assetft_burn")
+                        .add_attribute("sender", sender)
+                        .add_attribute("amount", coin.to_string())],
+                    data: None,
+                })
+            }
+            assetft::Msg::Freeze { account, coin } => {
+                let token = Self::load_token(storage, &coin.denom)?;
+                Self::check_feature(&token, &sender, FREEZING)?;
+
+                FROZEN_BALANCES.update(
+                    storage,
+                    (account, coin.denom),
+                    |frozen| -> AnyResult<Uint128> {
+                        Ok(frozen.unwrap_or_default().checked_add(coin.amount)?)
+                    },
+                )?;
+
+                Ok(AppResponse::default())
+            }
+            assetft::Msg::Unfreeze { account, coin } => {
+                let token = Self::load_token(storage, &coin.denom)?;
+                Self::check_feature(&token, &sender, FREEZING)?;
+
+                FROZEN_BALANCES.update(
+                    storage,
+                    (account, coin.denom),
+                    |frozen| -> AnyResult<Uint128> {
+                        Ok(frozen.unwrap_or_default().checked_sub(coin.amount)?)
+                    },
+                )?;
+
+                Ok(AppResponse::default())
+            }
+            assetft::Msg::GloballyFreeze { denom } => {
+                Self::set_globally_frozen(storage, &sender, denom, true)
+            }
+            assetft::Msg::GloballyUnfreeze { denom } => {
+                Self::set_globally_frozen(storage, &sender, denom, false)
+            }
+            msg => bail!("unsupported assetft message: {:?}", msg),
+        }
+    }
+
+    fn query(
+        &self,
+        _api: &dyn Api,
+        _storage: &dyn Storage,
+        _querier: &dyn Querier,
+        _block: &BlockInfo,
+        request: Self::QueryT,
+    ) -> AnyResult<Binary> {
+        match request {
+            CoreumQueries::AssetFT(assetft::Query::Params {}) => {
+                Ok(to_json_binary(&ParamsResponse {
+                    params: Params {
+                        issue_fee: self.issue_fee.clone(),
+                        token_upgrade_decimal_fee: coin(0, ISSUE_FEE_DENOM),
+                        token_upgrade_grace_period: 0,
+                    },
+                })?)
+            }
+            request => bail!("unsupported coreum query: {:?}", request),
+        }
+    }
+
+    fn sudo<ExecC, QueryC>(
+        &self,
+        _api: &dyn Api,
+        _storage: &mut dyn Storage,
+        _router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        _block: &BlockInfo,
+        _msg: Self::SudoT,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: CustomMsg + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        bail!("sudo is not supported by the assetft module")
+    }
+}
+
+// Queries and migrations of the contract don't use Coreum queries, so they are adapted to the interface of the other entry points
+fn query_with_coreum_queries(
+    deps: Deps<CoreumQueries>,
+    env: Env,
+    msg: QueryMsg,
+) -> StdResult<Binary> {
+    query(deps.into_empty(), env, msg)
+}
+
+fn migrate_with_coreum_queries(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    msg: MigrateMsg,
+) -> Result<Response<CoreumMsg>, ContractError> {
+    let response = migrate(deps.into_empty(), env, msg)?;
+    Ok(Response::new()
+        .add_attributes(response.attributes)
+        .add_events(response.events))
+}
+
+pub fn bridge_contract() -> Box<dyn Contract<CoreumMsg, CoreumQueries>> {
+    Box::new(
+        ContractWrapper::new(execute, instantiate, query_with_coreum_queries)
+            .with_reply(reply)
+            .with_sudo(sudo)
+            .with_migrate(migrate_with_coreum_queries),
+    )
+}
+
+// App with the mocked assetft module, where the accounts start with the provided balances
+pub fn mock_app(balances: &[(Addr, Vec<Coin>)]) -> CoreumApp {
+    AppBuilder::new_custom()
+        .with_custom(AssetFTModule::default())
+        .build(|router, _, storage| {
+            for (account, amount) in balances {
+                router
+                    .bank
+                    .init_balance(storage, account, amount.to_owned())
+                    .unwrap();
+            }
+        })
+}
+
+// Stores the code of the contract and instantiates it, paying the issue fee from the sender, who can migrate the contract
+pub fn instantiate_bridge(app: &mut CoreumApp, sender: &Addr, msg: &InstantiateMsg) -> Addr {
+    let code_id = app.store_code(bridge_contract());
+    app.instantiate_contract(
+        code_id,
+        sender.to_owned(),
+        msg,
+        &[coin(ISSUE_FEE_AMOUNT, ISSUE_FEE_DENOM)],
+        "coreumbridge-xrpl",
+        Some(sender.to_string()),
+    )
+    .unwrap()
+}
+
+pub fn issued_token(app: &CoreumApp, denom: &str) -> Option<IssuedToken> {
+    app.read_module(|_, _, storage| ISSUED_TOKENS.may_load(storage, denom.to_owned()).unwrap())
+}
+
+pub fn frozen_balance(app: &CoreumApp, account: &str, denom: &str) -> Uint128 {
+    app.read_module(|_, _, storage| {
+        FROZEN_BALANCES
+            .may_load(storage, (account.to_owned(), denom.to_owned()))
+            .unwrap()
+            .unwrap_or_default()
+    })
+}
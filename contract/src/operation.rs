@@ -1,26 +1,38 @@
 use coreum_wasm_sdk::{assetft, core::CoreumMsg};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{coin, Addr, Coin, CosmosMsg, Response, Storage, Uint128};
+use cosmwasm_std::{
+    coin, Addr, BlockInfo, Coin, CosmosMsg, Event, Order, Response, StdResult, Storage, Uint128,
+};
 
 use crate::{
-    contract::{convert_amount_decimals, XRPL_TOKENS_DECIMALS},
+    accounting::{burn_amount, release_refund, reserve_refund, unlock_amount},
+    activity::{record_operation_activity, record_refund_activity},
+    contract::XRPL_TOKENS_DECIMALS,
     error::ContractError,
     evidence::{OperationResult, TransactionResult},
-    relayer::{handle_rotate_keys_confirmation, Relayer},
+    receipt::{record_coreum_to_xrpl_transfer_result, record_transfer_refunded},
+    relayer::{current_relayer_set_epoch, handle_rotate_keys_confirmation, Relayer},
     signatures::Signature,
     state::{
-        BridgeState, Config, PendingRefund, TokenState, CONFIG, COREUM_TOKENS, PENDING_OPERATIONS,
-        PENDING_REFUNDS, PENDING_ROTATE_KEYS, XRPL_TOKENS,
+        ArchivedOperation, BlockedClaim, BridgeState, OperationCreation, PendingRefund, TokenState,
+        TokenStateTransitionReason, BLOCKED_CLAIMS, BRIDGE_STATE_CONFIG, COREUM_TOKENS,
+        FEES_CONFIG, OPERATIONS_CONFIG, OPERATION_ARCHIVE, OPERATION_ARCHIVE_COUNTER,
+        OPERATION_CREATIONS, OPERATION_EVIDENCE_DEADLINES, OPERATION_SUBMISSIONS,
+        PENDING_OPERATIONS, PENDING_REFUNDS, PENDING_ROTATE_KEYS, PRIORITY_OPERATIONS,
+        QUEUED_XRPL_TOKEN_REGISTRATIONS, UNDELIVERED_TRANSFERS, XRPL_CONFIG, XRPL_TOKENS,
+    },
+    tickets::{allocate_ticket, handle_ticket_allocation_confirmation, return_ticket},
+    token::{
+        build_xrpl_token_key, convert_amount_decimals, record_token_state_transition,
+        track_token_transfer_result,
     },
-    tickets::{handle_ticket_allocation_confirmation, return_ticket},
-    token::build_xrpl_token_key,
 };
 
 #[cw_serde]
 pub struct Operation {
     pub id: String,
     // version will be used to handle changes in xrpl_base_fee.
-    // If xrpl_base_fee changes, the version of operation will be increased by 1 (it's always created with an initial version = 1)
+    // If xrpl_base_fee changes (globally or only for this operation), the version of operation will be increased by 1 (it's always created with an initial version = 1)
     // This way, relayers can know if they need to provide the signature again, for this version
     pub version: u64,
     pub ticket_sequence: Option<u64>,
@@ -53,6 +65,8 @@ pub enum OperationType {
         max_amount: Option<Uint128>,
         sender: Addr,
         recipient: String,
+        // Only present if the recipient was provided as an X-address that contains a destination tag
+        destination_tag: Option<u32>,
     },
 }
 
@@ -81,15 +95,16 @@ pub fn check_operation_exists(
 
 pub fn create_pending_operation(
     storage: &mut dyn Storage,
-    timestamp: u64,
+    block: &BlockInfo,
     ticket_sequence: Option<u64>,
     account_sequence: Option<u64>,
     operation_type: OperationType,
-) -> Result<(), ContractError> {
-    let config = CONFIG.load(storage)?;
+) -> Result<String, ContractError> {
+    let timestamp = block.time.seconds();
 
     // If bridge is halted we prohibit all operation creations except allowed ones
-    check_valid_operation_if_halt(storage, &config, &operation_type)?;
+    let bridge_state = BRIDGE_STATE_CONFIG.load(storage)?.bridge_state;
+    check_valid_operation_if_halt(storage, &bridge_state, &operation_type)?;
 
     let operation_id = ticket_sequence.unwrap_or_else(|| account_sequence.unwrap());
     // We use a unique ID for operations that will also be used for refunding failed operations
@@ -102,15 +117,29 @@ pub fn create_pending_operation(
         account_sequence,
         signatures: vec![],
         operation_type,
-        xrpl_base_fee: config.xrpl_base_fee,
+        xrpl_base_fee: FEES_CONFIG.load(storage)?.xrpl_base_fee,
     };
 
     if PENDING_OPERATIONS.has(storage, operation_id) {
         return Err(ContractError::PendingOperationAlreadyExists {});
     }
     PENDING_OPERATIONS.save(storage, operation_id, &operation)?;
+    OPERATION_EVIDENCE_DEADLINES.save(
+        storage,
+        operation_id,
+        &timestamp.saturating_add(OPERATIONS_CONFIG.load(storage)?.operation_evidence_period),
+    )?;
+    OPERATION_CREATIONS.save(
+        storage,
+        operation_id,
+        &OperationCreation {
+            height: block.height,
+            timestamp,
+            relayer_set_epoch: current_relayer_set_epoch(storage)?,
+        },
+    )?;
 
-    Ok(())
+    Ok(operation.id)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -122,8 +151,11 @@ pub fn handle_operation(
     tx_hash: &Option<String>,
     operation_id: u64,
     ticket_sequence: Option<u64>,
+    block: &BlockInfo,
+    evidence_relayers: &[Addr],
     response: &mut Response<CoreumMsg>,
 ) -> Result<(), ContractError> {
+    let timestamp = block.time.seconds();
     match &operation.operation_type {
         // We check that if the operation was a ticket allocation, the result is also for a ticket allocation
         OperationType::AllocateTickets { .. } => match operation_result {
@@ -133,13 +165,25 @@ pub fn handle_operation(
                     tickets.clone(),
                     transaction_result,
                 )?;
+
+                // New tickets can be used for the token registrations that were waiting for them
+                if transaction_result.eq(&TransactionResult::Accepted) {
+                    process_queued_xrpl_token_registrations(storage, block, response)?;
+                }
             }
             None => return Err(ContractError::InvalidOperationResult {}),
         },
         OperationType::TrustSet {
             issuer, currency, ..
         } => {
-            handle_trust_set_confirmation(storage, issuer, currency, transaction_result)?;
+            handle_trust_set_confirmation(
+                storage,
+                issuer,
+                currency,
+                transaction_result,
+                timestamp,
+                response,
+            )?;
         }
         OperationType::RotateKeys {
             new_relayers,
@@ -150,6 +194,7 @@ pub fn handle_operation(
                 new_relayers.to_owned(),
                 new_evidence_threshold.to_owned(),
                 transaction_result,
+                timestamp,
             )?;
         }
         OperationType::CoreumToXRPLTransfer { .. } => {
@@ -158,12 +203,27 @@ pub fn handle_operation(
                 transaction_result,
                 tx_hash.clone(),
                 operation_id,
+                timestamp,
+                evidence_relayers,
                 response,
             )?;
         }
     }
-    // Operation is removed because it was confirmed
+    record_operation_activity(storage, timestamp, transaction_result)?;
+    // Operation is removed because it was confirmed, we keep it in the archive with its result
+    archive_operation(
+        storage,
+        operation,
+        operation_result,
+        transaction_result,
+        tx_hash,
+        timestamp,
+    )?;
     PENDING_OPERATIONS.remove(storage, operation_id);
+    OPERATION_EVIDENCE_DEADLINES.remove(storage, operation_id);
+    OPERATION_CREATIONS.remove(storage, operation_id);
+    OPERATION_SUBMISSIONS.remove(storage, operation_id);
+    PRIORITY_OPERATIONS.remove(storage, operation_id);
 
     // If an operation was invalid, the ticket was never consumed, so we must return it to the ticket array.
     if transaction_result.eq(&TransactionResult::Invalid) && ticket_sequence.is_some() {
@@ -173,11 +233,127 @@ pub fn handle_operation(
     Ok(())
 }
 
+pub fn archive_operation(
+    storage: &mut dyn Storage,
+    operation: &Operation,
+    operation_result: &Option<OperationResult>,
+    transaction_result: &TransactionResult,
+    tx_hash: &Option<String>,
+    timestamp: u64,
+) -> Result<(), ContractError> {
+    let operation_archive_size = OPERATIONS_CONFIG.load(storage)?.operation_archive_size;
+    if operation_archive_size != 0 {
+        let counter = OPERATION_ARCHIVE_COUNTER
+            .may_load(storage)?
+            .unwrap_or_default();
+        OPERATION_ARCHIVE_COUNTER.save(storage, &(counter + 1))?;
+
+        OPERATION_ARCHIVE.save(
+            storage,
+            counter,
+            &ArchivedOperation {
+                operation: operation.to_owned(),
+                transaction_result: transaction_result.to_owned(),
+                operation_result: operation_result.to_owned(),
+                tx_hash: tx_hash.to_owned(),
+                // Operation ids start with the timestamp in which they were created
+                created_at: operation
+                    .id
+                    .split_once('-')
+                    .and_then(|(created_at, _)| created_at.parse().ok())
+                    .unwrap_or_default(),
+                resolved_at: timestamp,
+            },
+        )?;
+    }
+
+    // We remove up to 2 entries each time, so that the archive slowly shrinks to its size if it was reduced
+    prune_operation_archive(storage, operation_archive_size, 2)?;
+
+    Ok(())
+}
+
+// Removes the oldest archived operations that exceed the archive size, returning how many were removed
+pub fn prune_operation_archive(
+    storage: &mut dyn Storage,
+    operation_archive_size: u32,
+    limit: u32,
+) -> Result<u32, ContractError> {
+    let counter = OPERATION_ARCHIVE_COUNTER
+        .may_load(storage)?
+        .unwrap_or_default();
+
+    // Entries are always removed from the oldest one, so all keys between the first one and the counter are present
+    let keys: Vec<u64> = OPERATION_ARCHIVE
+        .keys(storage, None, None, Order::Ascending)
+        .take(limit as usize)
+        .collect::<StdResult<_>>()?;
+
+    let mut pruned = 0;
+    for key in keys {
+        if counter - key <= u64::from(operation_archive_size) {
+            break;
+        }
+        OPERATION_ARCHIVE.remove(storage, key);
+        pruned += 1;
+    }
+
+    Ok(pruned)
+}
+
+pub fn process_queued_xrpl_token_registrations(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    response: &mut Response<CoreumMsg>,
+) -> Result<(), ContractError> {
+    let mut queued_registrations = QUEUED_XRPL_TOKEN_REGISTRATIONS.load(storage)?;
+    let trust_set_limit_amount = XRPL_CONFIG.load(storage)?.trust_set_limit_amount;
+
+    while !queued_registrations.is_empty() {
+        let ticket = match allocate_ticket(storage) {
+            Ok(ticket) => ticket,
+            // The remaining registrations will be processed on the next ticket allocation
+            Err(ContractError::NoAvailableTickets {} | ContractError::LastTicketReserved {}) => {
+                break
+            }
+            Err(e) => return Err(e),
+        };
+
+        let key = queued_registrations.pop_front().unwrap();
+        let token = XRPL_TOKENS.load(storage, key)?;
+
+        create_pending_operation(
+            storage,
+            block,
+            Some(ticket),
+            None,
+            OperationType::TrustSet {
+                issuer: token.issuer.clone(),
+                currency: token.currency.clone(),
+                trust_set_limit_amount,
+            },
+        )?;
+
+        *response = response.to_owned().add_event(
+            Event::new("xrpl_token_registration_activated")
+                .add_attribute("issuer", token.issuer)
+                .add_attribute("currency", token.currency)
+                .add_attribute("denom", token.coreum_denom),
+        );
+    }
+
+    QUEUED_XRPL_TOKEN_REGISTRATIONS.save(storage, &queued_registrations)?;
+
+    Ok(())
+}
+
 pub fn handle_trust_set_confirmation(
     storage: &mut dyn Storage,
     issuer: &str,
     currency: &str,
     transaction_result: &TransactionResult,
+    timestamp: u64,
+    response: &mut Response<CoreumMsg>,
 ) -> Result<(), ContractError> {
     let key = build_xrpl_token_key(issuer, currency);
 
@@ -185,28 +361,69 @@ pub fn handle_trust_set_confirmation(
         .load(storage, key.clone())
         .map_err(|_| ContractError::TokenNotRegistered {})?;
 
+    let previous_state = token.state.clone();
+
     // Set token to active if TrustSet operation was successful
-    if transaction_result.eq(&TransactionResult::Accepted) {
+    let reason = if transaction_result.eq(&TransactionResult::Accepted) {
         token.state = TokenState::Enabled;
+        TokenStateTransitionReason::TrustSetAccepted
     } else {
         token.state = TokenState::Inactive;
-    }
+        TokenStateTransitionReason::TrustSetFailed
+    };
 
     XRPL_TOKENS.save(storage, key, &token)?;
+
+    let event = record_token_state_transition(
+        storage,
+        &token.coreum_denom,
+        Some(previous_state),
+        token.state,
+        reason,
+        timestamp,
+    )?;
+    *response = response.to_owned().add_event(event);
+
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handle_coreum_to_xrpl_transfer_confirmation(
     storage: &mut dyn Storage,
     transaction_result: &TransactionResult,
     tx_hash: Option<String>,
     operation_id: u64,
+    timestamp: u64,
+    evidence_relayers: &[Addr],
     response: &mut Response<CoreumMsg>,
 ) -> Result<(), ContractError> {
     let pending_operation = PENDING_OPERATIONS
         .load(storage, operation_id)
         .map_err(|_| ContractError::PendingOperationNotFound {})?;
 
+    record_coreum_to_xrpl_transfer_result(
+        storage,
+        &pending_operation,
+        transaction_result,
+        &tx_hash,
+        evidence_relayers,
+        timestamp,
+    )?;
+
+    if let OperationType::CoreumToXRPLTransfer {
+        issuer, currency, ..
+    } = &pending_operation.operation_type
+    {
+        track_token_transfer_result(
+            storage,
+            issuer,
+            currency,
+            transaction_result,
+            timestamp,
+            response,
+        )?;
+    }
+
     match pending_operation.operation_type {
         OperationType::CoreumToXRPLTransfer {
             issuer,
@@ -222,8 +439,11 @@ pub fn handle_coreum_to_xrpl_transfer_confirmation(
                 Some(xrpl_token) => {
                     // if operation was with XRP, max amount might be empty so we will use amount.
                     let amount_sent = max_amount.unwrap_or(amount);
+                    // The amount is not held for the transfer anymore, it's either burned or refunded
+                    unlock_amount(storage, &xrpl_token.coreum_denom, amount_sent)?;
                     // If transaction was accepted and the token that was sent back was an XRPL originated token, we must burn the token amount
                     if transaction_result.eq(&TransactionResult::Accepted) {
+                        burn_amount(storage, &xrpl_token.coreum_denom, amount_sent)?;
                         let burn_msg = CosmosMsg::from(CoreumMsg::AssetFT(assetft::Msg::Burn {
                             coin: coin(amount_sent.u128(), xrpl_token.coreum_denom),
                         }));
@@ -231,12 +451,14 @@ pub fn handle_coreum_to_xrpl_transfer_confirmation(
                         *response = response.to_owned().add_message(burn_msg);
                     } else {
                         // If transaction was rejected, we must store the amount so that sender can claim it back
+                        let refund = coin(amount_sent.u128(), xrpl_token.coreum_denom);
+                        record_refund_activity(storage, timestamp, &refund)?;
                         store_pending_refund(
                             storage,
                             pending_operation.id,
                             tx_hash,
                             sender,
-                            coin(amount_sent.u128(), xrpl_token.coreum_denom),
+                            refund,
                         )?;
                     }
                 }
@@ -256,13 +478,16 @@ pub fn handle_coreum_to_xrpl_transfer_confirmation(
                                     token.decimals,
                                     max_amount.unwrap(),
                                 )?;
+                                unlock_amount(storage, &token.denom, amount_to_send_back)?;
                                 // If transaction was rejected, we must store the amount so that sender can claim it back.
+                                let refund = coin(amount_to_send_back.u128(), token.denom);
+                                record_refund_activity(storage, timestamp, &refund)?;
                                 store_pending_refund(
                                     storage,
                                     pending_operation.id,
                                     tx_hash,
                                     sender,
-                                    coin(amount_to_send_back.u128(), token.denom),
+                                    refund,
                                 )?;
                             }
                             // In practice this will never happen because any token issued from the multisig address is a token that was bridged from Coreum so it will be registered.
@@ -296,6 +521,11 @@ pub fn store_pending_refund(
         coin,
     };
 
+    reserve_refund(
+        storage,
+        &pending_refund.coin.denom,
+        pending_refund.coin.amount,
+    )?;
     PENDING_REFUNDS.save(storage, (receiver, pending_operation_id), &pending_refund)?;
 
     Ok(())
@@ -305,23 +535,57 @@ pub fn remove_pending_refund(
     storage: &mut dyn Storage,
     sender: &Addr,
     pending_refund_id: String,
+    timestamp: u64,
 ) -> Result<Coin, ContractError> {
     // If pending refund is not found we return the error
     let pending_refund = PENDING_REFUNDS
         .load(storage, (sender.clone(), pending_refund_id.clone()))
         .map_err(|_| ContractError::PendingRefundNotFound {})?;
 
-    PENDING_REFUNDS.remove(storage, (sender.clone(), pending_refund_id))?;
+    PENDING_REFUNDS.remove(storage, (sender.clone(), pending_refund_id.clone()))?;
+    UNDELIVERED_TRANSFERS.remove(storage, (sender.clone(), pending_refund_id.clone()));
+    BLOCKED_CLAIMS.remove(storage, (sender.clone(), pending_refund_id.clone()));
+    release_refund(
+        storage,
+        &pending_refund.coin.denom,
+        pending_refund.coin.amount,
+    )?;
+    record_transfer_refunded(storage, &pending_refund_id, timestamp)?;
 
     Ok(pending_refund.coin)
 }
 
+// Records an attempt to claim a pending refund that failed because its token is frozen
+pub fn record_blocked_claim(
+    storage: &mut dyn Storage,
+    owner: &Addr,
+    pending_refund_id: String,
+    height: u64,
+) -> Result<BlockedClaim, ContractError> {
+    let key = (owner.clone(), pending_refund_id);
+    let blocked_claim = match BLOCKED_CLAIMS.may_load(storage, key.clone())? {
+        Some(blocked_claim) => BlockedClaim {
+            last_attempt_height: height,
+            attempts: blocked_claim.attempts.saturating_add(1),
+            ..blocked_claim
+        },
+        None => BlockedClaim {
+            first_blocked_at_height: height,
+            last_attempt_height: height,
+            attempts: 1,
+        },
+    };
+    BLOCKED_CLAIMS.save(storage, key, &blocked_claim)?;
+
+    Ok(blocked_claim)
+}
+
 pub fn check_valid_operation_if_halt(
     storage: &mut dyn Storage,
-    config: &Config,
+    bridge_state: &BridgeState,
     operation_type: &OperationType,
 ) -> Result<(), ContractError> {
-    if config.bridge_state.eq(&BridgeState::Halted) {
+    if bridge_state.eq(&BridgeState::Halted) {
         match &operation_type {
             // Only RotateKeys operations (if there is a pending rotate keys ongoing) or ticket allocations are allowed during bridge halt
             OperationType::RotateKeys { .. } => {
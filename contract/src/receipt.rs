@@ -0,0 +1,215 @@
+use cosmwasm_std::{coin, Addr, Coin, Env, Order, StdResult, Storage, Uint128};
+
+use crate::{
+    error::ContractError,
+    evidence::TransactionResult,
+    operation::{Operation, OperationType},
+    state::{
+        InboundTransfer, TransferDirection, TransferReceipt, TransferStatus, INBOUND_TRANSFERS,
+        INBOUND_TRANSFERS_COUNTER, TRANSFER_RECEIPTS, TRANSFER_RECEIPT_IDS,
+    },
+};
+
+// Amount of delivered transfers from XRPL kept for each recipient, older ones are removed
+pub const MAX_INBOUND_TRANSFERS_PER_RECIPIENT: usize = 50;
+
+pub fn save_transfer_receipt(
+    storage: &mut dyn Storage,
+    receipt: &TransferReceipt,
+) -> Result<(), ContractError> {
+    TRANSFER_RECEIPTS.save(storage, receipt.id.clone(), receipt)?;
+    // XRPL transaction hashes are case insensitive so we index them in uppercase
+    if let Some(xrpl_tx_hash) = &receipt.xrpl_tx_hash {
+        TRANSFER_RECEIPT_IDS.save(storage, xrpl_tx_hash.to_uppercase(), &receipt.id)?;
+    }
+
+    Ok(())
+}
+
+pub fn load_transfer_receipt(
+    storage: &dyn Storage,
+    id_or_hash: String,
+) -> Result<TransferReceipt, ContractError> {
+    if let Some(receipt) = TRANSFER_RECEIPTS.may_load(storage, id_or_hash.clone())? {
+        return Ok(receipt);
+    }
+
+    let id = TRANSFER_RECEIPT_IDS
+        .load(storage, id_or_hash.to_uppercase())
+        .map_err(|_| ContractError::TransferReceiptNotFound {})?;
+
+    Ok(TRANSFER_RECEIPTS.load(storage, id)?)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_coreum_to_xrpl_transfer_receipt(
+    storage: &mut dyn Storage,
+    env: &Env,
+    operation_id: String,
+    ticket_sequence: u64,
+    sender: Addr,
+    recipient: String,
+    coin: Coin,
+    fee_charged: Uint128,
+) -> Result<(), ContractError> {
+    let receipt = TransferReceipt {
+        id: operation_id,
+        direction: TransferDirection::CoreumToXRPL,
+        status: TransferStatus::Pending,
+        sender: Some(sender),
+        recipient,
+        coin,
+        fee_charged,
+        delivered_amount: None,
+        coreum_height: env.block.height,
+        coreum_tx_index: env.transaction.as_ref().map(|tx| tx.index),
+        xrpl_tx_hash: None,
+        ticket_sequence: Some(ticket_sequence),
+        signers: vec![],
+        evidence_relayers: vec![],
+        refund_id: None,
+        created_at: env.block.time.seconds(),
+        updated_at: env.block.time.seconds(),
+    };
+
+    save_transfer_receipt(storage, &receipt)
+}
+
+pub fn create_xrpl_to_coreum_transfer_receipt(
+    storage: &mut dyn Storage,
+    env: &Env,
+    xrpl_tx_hash: String,
+    recipient: &Addr,
+    delivered: Coin,
+    fee_charged: Uint128,
+    evidence_relayers: Vec<Addr>,
+) -> Result<(), ContractError> {
+    let receipt = TransferReceipt {
+        id: xrpl_tx_hash.clone(),
+        direction: TransferDirection::XRPLToCoreum,
+        status: TransferStatus::Completed,
+        sender: None,
+        recipient: recipient.to_string(),
+        coin: coin(
+            delivered.amount.checked_add(fee_charged)?.u128(),
+            delivered.denom,
+        ),
+        fee_charged,
+        delivered_amount: Some(delivered.amount),
+        coreum_height: env.block.height,
+        coreum_tx_index: env.transaction.as_ref().map(|tx| tx.index),
+        xrpl_tx_hash: Some(xrpl_tx_hash),
+        ticket_sequence: None,
+        signers: vec![],
+        evidence_relayers,
+        refund_id: None,
+        created_at: env.block.time.seconds(),
+        updated_at: env.block.time.seconds(),
+    };
+
+    save_transfer_receipt(storage, &receipt)
+}
+
+pub fn record_coreum_to_xrpl_transfer_result(
+    storage: &mut dyn Storage,
+    operation: &Operation,
+    transaction_result: &TransactionResult,
+    tx_hash: &Option<String>,
+    evidence_relayers: &[Addr],
+    timestamp: u64,
+) -> Result<(), ContractError> {
+    // Transfers that were sent before receipts were kept don't have one
+    let mut receipt = match TRANSFER_RECEIPTS.may_load(storage, operation.id.clone())? {
+        Some(receipt) => receipt,
+        None => return Ok(()),
+    };
+
+    receipt.signers = operation
+        .signatures
+        .iter()
+        .map(|signature| signature.relayer_coreum_address.clone())
+        .collect();
+    receipt.evidence_relayers = evidence_relayers.to_vec();
+    receipt.xrpl_tx_hash = tx_hash.clone();
+    receipt.updated_at = timestamp;
+
+    if transaction_result.eq(&TransactionResult::Accepted) {
+        if let OperationType::CoreumToXRPLTransfer { amount, .. } = &operation.operation_type {
+            receipt.delivered_amount = Some(*amount);
+        }
+        receipt.status = TransferStatus::Completed;
+    } else {
+        // Failed transfers are refunded with the operation id
+        receipt.refund_id = Some(operation.id.clone());
+        receipt.status = TransferStatus::Refundable;
+    }
+
+    save_transfer_receipt(storage, &receipt)
+}
+
+pub fn record_undelivered_transfer(
+    storage: &mut dyn Storage,
+    xrpl_tx_hash: &str,
+    timestamp: u64,
+) -> Result<(), ContractError> {
+    if let Some(mut receipt) = TRANSFER_RECEIPTS.may_load(storage, xrpl_tx_hash.to_owned())? {
+        // Holdbacks are claimed with the XRPL transaction hash
+        receipt.delivered_amount = None;
+        receipt.refund_id = Some(xrpl_tx_hash.to_owned());
+        receipt.status = TransferStatus::Refundable;
+        receipt.updated_at = timestamp;
+        TRANSFER_RECEIPTS.save(storage, receipt.id.clone(), &receipt)?;
+    }
+
+    Ok(())
+}
+
+pub fn record_transfer_refunded(
+    storage: &mut dyn Storage,
+    refund_id: &str,
+    timestamp: u64,
+) -> Result<(), ContractError> {
+    // Refund ids are the ids of the receipts of the transfers they come from
+    if let Some(mut receipt) = TRANSFER_RECEIPTS.may_load(storage, refund_id.to_owned())? {
+        receipt.status = TransferStatus::Refunded;
+        receipt.updated_at = timestamp;
+        TRANSFER_RECEIPTS.save(storage, receipt.id.clone(), &receipt)?;
+    }
+
+    Ok(())
+}
+
+pub fn record_inbound_transfer(
+    storage: &mut dyn Storage,
+    recipient: &Addr,
+    xrpl_tx_hash: String,
+    coin: Coin,
+    timestamp: u64,
+) -> Result<(), ContractError> {
+    let counter = INBOUND_TRANSFERS_COUNTER
+        .may_load(storage)?
+        .unwrap_or_default();
+    INBOUND_TRANSFERS_COUNTER.save(storage, &(counter + 1))?;
+
+    INBOUND_TRANSFERS.save(
+        storage,
+        (recipient.clone(), counter),
+        &InboundTransfer {
+            xrpl_tx_hash,
+            coin,
+            timestamp,
+        },
+    )?;
+
+    // Only one transfer is added each time so removing the oldest one is enough to stay within the limit
+    let keys: Vec<u64> = INBOUND_TRANSFERS
+        .prefix(recipient.clone())
+        .keys(storage, None, None, Order::Ascending)
+        .take(MAX_INBOUND_TRANSFERS_PER_RECIPIENT + 1)
+        .collect::<StdResult<_>>()?;
+    if keys.len() > MAX_INBOUND_TRANSFERS_PER_RECIPIENT {
+        INBOUND_TRANSFERS.remove(storage, (recipient.clone(), keys[0]));
+    }
+
+    Ok(())
+}
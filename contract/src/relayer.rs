@@ -1,14 +1,18 @@
 use std::collections::HashSet;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Deps, Storage};
+use cosmwasm_std::{Addr, Deps, StdResult, Storage};
 
 use crate::{
     address::validate_xrpl_address,
-    contract::MAX_RELAYERS,
     error::ContractError,
     evidence::TransactionResult,
-    state::{CONFIG, PENDING_ROTATE_KEYS, TX_EVIDENCES},
+    state::{
+        RelayerActivity, RelayerSetSnapshot, RelayerWorkload, RelayersConfig, EVIDENCE_DELEGATES,
+        EVIDENCE_SIGNING_KEYS, EVIDENCE_SOURCES, OPERATION_CREATIONS, PENDING_ROTATE_KEYS,
+        RELAYERS_CONFIG, RELAYER_ACTIVITY, RELAYER_ATTESTATIONS, RELAYER_SET_EPOCH,
+        RELAYER_SET_SNAPSHOTS, RELAYER_WORKLOADS, TOKEN_FREEZE_REQUESTS, TX_EVIDENCES,
+    },
 };
 
 #[cw_serde]
@@ -22,6 +26,7 @@ pub fn validate_relayers(
     deps: Deps,
     relayers: &Vec<Relayer>,
     evidence_threshold: u32,
+    max_relayers: u32,
 ) -> Result<(), ContractError> {
     let mut set_xrpl_addresses = HashSet::new();
     let mut set_xrpl_pubkeys = HashSet::new();
@@ -32,8 +37,8 @@ pub fn validate_relayers(
         return Err(ContractError::InvalidThreshold {});
     }
 
-    if relayers.len() > MAX_RELAYERS {
-        return Err(ContractError::TooManyRelayers {});
+    if relayers.len() > max_relayers as usize {
+        return Err(ContractError::TooManyRelayers { max_relayers });
     }
 
     for relayer in relayers {
@@ -56,9 +61,44 @@ pub fn validate_relayers(
 }
 
 pub fn is_relayer(storage: &dyn Storage, sender: &Addr) -> Result<bool, ContractError> {
-    let config = CONFIG.load(storage)?;
+    let relayers_config = RELAYERS_CONFIG.load(storage)?;
 
-    Ok(config.relayers.iter().any(|r| r.coreum_address == sender))
+    Ok(relayers_config
+        .relayers
+        .iter()
+        .any(|r| r.coreum_address == sender))
+}
+
+// Returns the relayer that an address acts for when saving evidences and signatures, which is either itself or the relayer that delegated to it
+pub fn evidence_relayer(storage: &dyn Storage, sender: &Addr) -> Result<Addr, ContractError> {
+    let relayers_config = RELAYERS_CONFIG.load(storage)?;
+
+    for relayer in relayers_config.relayers {
+        if relayer.coreum_address.eq(sender)
+            || EVIDENCE_DELEGATES
+                .may_load(storage, relayer.coreum_address.clone())?
+                .is_some_and(|delegate| delegate.eq(sender))
+        {
+            return Ok(relayer.coreum_address);
+        }
+    }
+
+    Err(ContractError::UnauthorizedSender {})
+}
+
+pub fn is_evidence_delegate(storage: &dyn Storage, address: &Addr) -> Result<bool, ContractError> {
+    let relayers_config = RELAYERS_CONFIG.load(storage)?;
+
+    for relayer in relayers_config.relayers {
+        if EVIDENCE_DELEGATES
+            .may_load(storage, relayer.coreum_address)?
+            .is_some_and(|delegate| delegate.eq(address))
+        {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
 }
 
 pub fn handle_rotate_keys_confirmation(
@@ -66,19 +106,207 @@ pub fn handle_rotate_keys_confirmation(
     relayers: Vec<Relayer>,
     new_evidence_threshold: u32,
     transaction_result: &TransactionResult,
+    timestamp: u64,
 ) -> Result<(), ContractError> {
     // If transaction was accepted, update the relayers and evidence threshold and clear all current evidences
     // Bridge will stay halted until owner resumes it.
     // If it failed, the bridge will remain halted and relayers are not updated, waiting for another recovery by owner
     if transaction_result.eq(&TransactionResult::Accepted) {
-        let mut config = CONFIG.load(storage)?;
-        config.relayers = relayers;
-        config.evidence_threshold = new_evidence_threshold;
-        CONFIG.save(storage, &config)?;
+        let mut relayers_config = RELAYERS_CONFIG.load(storage)?;
+
+        // Evidence signing keys and delegates of relayers that are no longer part of the bridge are removed
+        let removed_relayers: Vec<Addr> = relayers_config
+            .relayers
+            .iter()
+            .filter(|r| {
+                !relayers
+                    .iter()
+                    .any(|n| n.coreum_address == r.coreum_address)
+            })
+            .map(|r| r.coreum_address.clone())
+            .collect();
+        for relayer in removed_relayers {
+            EVIDENCE_SIGNING_KEYS.remove(storage, relayer.clone());
+            EVIDENCE_DELEGATES.remove(storage, relayer.clone());
+            RELAYER_ACTIVITY.remove(storage, relayer.clone());
+            RELAYER_WORKLOADS.remove(storage, relayer.clone());
+            EVIDENCE_SOURCES.remove(storage, relayer.clone());
+            RELAYER_ATTESTATIONS.remove(storage, relayer);
+        }
+        init_relayers_activity(storage, &relayers, timestamp)?;
+
+        // The halt and token freeze request thresholds and the distinct evidence sources required can't be higher than the amount of relayers
+        relayers_config.halt_request_threshold = relayers_config
+            .halt_request_threshold
+            .min(relayers.len() as u32);
+        relayers_config.token_freeze_request_threshold = relayers_config
+            .token_freeze_request_threshold
+            .min(relayers.len() as u32);
+        relayers_config.min_distinct_evidence_sources = relayers_config
+            .min_distinct_evidence_sources
+            .min(relayers.len() as u32);
+        // The replaced relayer set is kept for the operations that were created for it
+        let relayer_set_epoch = current_relayer_set_epoch(storage)?;
+        RELAYER_SET_SNAPSHOTS.save(
+            storage,
+            relayer_set_epoch,
+            &RelayerSetSnapshot {
+                relayers: relayers_config.relayers.clone(),
+                evidence_threshold: relayers_config.evidence_threshold,
+            },
+        )?;
+        RELAYER_SET_EPOCH.save(storage, &(relayer_set_epoch + 1))?;
+
+        relayers_config.relayers = relayers;
+        relayers_config.evidence_threshold = new_evidence_threshold;
+        RELAYERS_CONFIG.save(storage, &relayers_config)?;
         TX_EVIDENCES.clear(storage);
+        TOKEN_FREEZE_REQUESTS.clear(storage);
     }
 
     PENDING_ROTATE_KEYS.save(storage, &false)?;
 
     Ok(())
 }
+
+pub fn current_relayer_set_epoch(storage: &dyn Storage) -> StdResult<u64> {
+    Ok(RELAYER_SET_EPOCH.may_load(storage)?.unwrap_or_default())
+}
+
+// Epoch of the relayer set a pending operation must be signed by
+// Operations created before relayer sets were tracked are signed by the current one
+pub fn operation_relayer_set_epoch(storage: &dyn Storage, operation_id: u64) -> StdResult<u64> {
+    match OPERATION_CREATIONS.may_load(storage, operation_id)? {
+        Some(creation) => Ok(creation.relayer_set_epoch),
+        None => current_relayer_set_epoch(storage),
+    }
+}
+
+// Returns the relayers config with the relayer set a pending operation was signed by, which is the signer list its XRPL transaction was built for
+pub fn operation_signers_config(
+    storage: &dyn Storage,
+    relayers_config: &RelayersConfig,
+    operation_id: u64,
+) -> Result<RelayersConfig, ContractError> {
+    let mut signers_config = relayers_config.clone();
+    let relayer_set_epoch = operation_relayer_set_epoch(storage, operation_id)?;
+    if relayer_set_epoch != current_relayer_set_epoch(storage)? {
+        let snapshot = RELAYER_SET_SNAPSHOTS.load(storage, relayer_set_epoch)?;
+        signers_config.relayers = snapshot.relayers;
+        signers_config.evidence_threshold = snapshot.evidence_threshold;
+    }
+
+    Ok(signers_config)
+}
+
+// Makes a pending operation be signed by the current relayer set, used when its signatures are deleted to sign it again
+pub fn reset_operation_relayer_set(
+    storage: &mut dyn Storage,
+    operation_id: u64,
+) -> Result<(), ContractError> {
+    if let Some(mut creation) = OPERATION_CREATIONS.may_load(storage, operation_id)? {
+        creation.relayer_set_epoch = current_relayer_set_epoch(storage)?;
+        OPERATION_CREATIONS.save(storage, operation_id, &creation)?;
+    }
+
+    Ok(())
+}
+
+// New relayers are considered active from the moment they join the bridge
+pub fn init_relayers_activity(
+    storage: &mut dyn Storage,
+    relayers: &[Relayer],
+    timestamp: u64,
+) -> Result<(), ContractError> {
+    for relayer in relayers {
+        if !RELAYER_ACTIVITY.has(storage, relayer.coreum_address.clone()) {
+            RELAYER_ACTIVITY.save(
+                storage,
+                relayer.coreum_address.clone(),
+                &RelayerActivity {
+                    last_active_at: timestamp,
+                    inactive_since: None,
+                },
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+// Records that a relayer saved an evidence or a signature and reactivates it if it was marked as inactive.
+// Returns true if the relayer was reactivated
+pub fn record_relayer_activity(
+    storage: &mut dyn Storage,
+    relayer: &Addr,
+    timestamp: u64,
+) -> Result<bool, ContractError> {
+    let reactivated = RELAYER_ACTIVITY
+        .may_load(storage, relayer.clone())?
+        .is_some_and(|activity| activity.inactive_since.is_some());
+
+    RELAYER_ACTIVITY.save(
+        storage,
+        relayer.clone(),
+        &RelayerActivity {
+            last_active_at: timestamp,
+            inactive_since: None,
+        },
+    )?;
+
+    Ok(reactivated)
+}
+
+// Adds the signatures saved and the inbound transfers delivered by a relayer to its workload
+pub fn record_relayer_workload(
+    storage: &mut dyn Storage,
+    relayer: &Addr,
+    signatures_saved: u64,
+    inbound_deliveries: u64,
+) -> Result<(), ContractError> {
+    let mut workload = RELAYER_WORKLOADS
+        .may_load(storage, relayer.clone())?
+        .unwrap_or(RelayerWorkload {
+            signatures_saved: 0,
+            inbound_deliveries: 0,
+        });
+    workload.signatures_saved = workload.signatures_saved.saturating_add(signatures_saved);
+    workload.inbound_deliveries = workload
+        .inbound_deliveries
+        .saturating_add(inbound_deliveries);
+    RELAYER_WORKLOADS.save(storage, relayer.clone(), &workload)?;
+
+    Ok(())
+}
+
+// Relayers that aren't marked as inactive. If all of them are, they are all taken into account so that fees are still distributed
+pub fn active_relayers(
+    storage: &dyn Storage,
+    relayers: &[Relayer],
+) -> Result<Vec<Relayer>, ContractError> {
+    let mut active_relayers = vec![];
+    for relayer in relayers {
+        if !RELAYER_ACTIVITY
+            .may_load(storage, relayer.coreum_address.clone())?
+            .is_some_and(|activity| activity.inactive_since.is_some())
+        {
+            active_relayers.push(relayer.clone());
+        }
+    }
+
+    if active_relayers.is_empty() {
+        return Ok(relayers.to_vec());
+    }
+
+    Ok(active_relayers)
+}
+
+// Evidences can't require more relayers than the ones that are active, otherwise a relayer that stopped working would block the bridge
+pub fn effective_evidence_threshold(
+    storage: &dyn Storage,
+    relayers_config: &RelayersConfig,
+) -> Result<u32, ContractError> {
+    Ok(relayers_config
+        .evidence_threshold
+        .min(active_relayers(storage, &relayers_config.relayers)?.len() as u32))
+}
@@ -0,0 +1,151 @@
+use cosmwasm_std::{Order, StdResult, Storage, Uint128};
+
+use crate::{
+    accounting::load_token_accounting,
+    contract::{XRPL_MAX_SIGNERS, XRP_CURRENCY, XRP_ISSUER},
+    error::ContractError,
+    state::{
+        RelayersConfig, TokenState, XRPLConfig, AVAILABLE_TICKETS, FEES_CONFIG, PENDING_OPERATIONS,
+        RELAYERS_CONFIG, XRPL_BALANCE_ATTESTATIONS, XRPL_CONFIG, XRPL_TOKENS,
+    },
+    token::build_xrpl_token_key,
+};
+
+// Seconds during which a balance attested by a relayer is taken into account
+pub const XRPL_BALANCE_ATTESTATION_TTL: u64 = 3600;
+
+// Relayers submit every multisigned transaction paying the fee of the maximum amount of signers, which is xrpl_base_fee * (1 + signers)
+pub fn xrpl_multisigning_fee(xrpl_base_fee: u64) -> Result<Uint128, ContractError> {
+    Ok(Uint128::from(xrpl_base_fee).checked_mul(Uint128::from(1 + XRPL_MAX_SIGNERS as u64))?)
+}
+
+// Relayers compute the multisigning fee with 64 bits integers, so it must not overflow for the base fee to be usable
+pub fn validate_xrpl_base_fee(xrpl_base_fee: u64) -> Result<(), ContractError> {
+    if xrpl_base_fee == 0
+        || xrpl_base_fee
+            .checked_mul(1 + XRPL_MAX_SIGNERS as u64)
+            .is_none()
+    {
+        return Err(ContractError::InvalidXRPLBaseFee {});
+    }
+
+    Ok(())
+}
+
+// Objects owned by the bridge XRPL account, each of them increasing the reserve it must hold:
+// a trust line for each registered token whose TrustSet didn't fail, each ticket not consumed yet and the signer list
+pub fn bridge_xrpl_owner_count(storage: &dyn Storage) -> Result<u32, ContractError> {
+    let xrp_key = build_xrpl_token_key(XRP_ISSUER, XRP_CURRENCY);
+    let mut owner_count = 1;
+
+    for state in [
+        TokenState::Enabled,
+        TokenState::Disabled,
+        TokenState::Processing,
+    ] {
+        for key in XRPL_TOKENS
+            .idx
+            .state
+            .prefix(state.as_str().to_string())
+            .keys(storage, None, None, Order::Ascending)
+        {
+            if key? != xrp_key {
+                owner_count += 1;
+            }
+        }
+    }
+
+    owner_count += AVAILABLE_TICKETS.load(storage)?.len() as u32;
+
+    // Tickets of pending operations are still owned by the account until the operation is executed on XRPL
+    for item in PENDING_OPERATIONS.range(storage, None, None, Order::Ascending) {
+        let (_, operation) = item?;
+        if operation.ticket_sequence.is_some() {
+            owner_count += 1;
+        }
+    }
+
+    Ok(owner_count)
+}
+
+pub fn required_xrpl_reserve(
+    xrpl_config: &XRPLConfig,
+    owner_count: u32,
+) -> Result<Uint128, ContractError> {
+    Ok(xrpl_config
+        .xrpl_owner_reserve
+        .checked_mul(Uint128::from(owner_count))?
+        .checked_add(xrpl_config.xrpl_base_reserve)?)
+}
+
+// XRP bridged to Coreum is held by the bridge XRPL account but belongs to its holders, so it can't be used for the reserve
+pub fn bridged_xrp(storage: &dyn Storage) -> Result<Uint128, ContractError> {
+    let xrp_token = XRPL_TOKENS.load(storage, build_xrpl_token_key(XRP_ISSUER, XRP_CURRENCY))?;
+    Ok(load_token_accounting(storage, &xrp_token.coreum_denom)?.minted)
+}
+
+// Returns the amount of balances that were taken into account and, if at least evidence threshold relayers attested it, the median balance
+pub fn attested_bridge_xrpl_balance(
+    storage: &dyn Storage,
+    relayers_config: &RelayersConfig,
+    timestamp: u64,
+) -> StdResult<(u32, Option<Uint128>)> {
+    // Attestations of relayers that were removed or that weren't refreshed within the TTL are ignored
+    let mut balances = XRPL_BALANCE_ATTESTATIONS
+        .range(storage, None, None, Order::Ascending)
+        .filter(|item| match item {
+            Ok((relayer, attestation)) => {
+                relayers_config
+                    .relayers
+                    .iter()
+                    .any(|r| r.coreum_address.eq(relayer))
+                    && attestation
+                        .attested_at
+                        .saturating_add(XRPL_BALANCE_ATTESTATION_TTL)
+                        > timestamp
+            }
+            Err(_) => true,
+        })
+        .map(|item| item.map(|(_, attestation)| attestation.balance))
+        .collect::<StdResult<Vec<Uint128>>>()?;
+
+    let attestations = balances.len() as u32;
+    if attestations == 0 || attestations < relayers_config.evidence_threshold {
+        return Ok((attestations, None));
+    }
+
+    // Taking the lower median prevents a single relayer from inflating the balance
+    balances.sort();
+    Ok((attestations, Some(balances[(balances.len() - 1) / 2])))
+}
+
+// Rejects operations if the XRP the bridge XRPL account holds for itself can't cover the reserve of the objects they add
+// plus the fees of the transactions they need. Nothing is checked while relayers haven't attested the balance of the account
+pub fn check_xrpl_reserve(
+    storage: &dyn Storage,
+    timestamp: u64,
+    additional_objects: u32,
+    transactions: u32,
+) -> Result<(), ContractError> {
+    let relayers_config = RELAYERS_CONFIG.load(storage)?;
+    let balance = match attested_bridge_xrpl_balance(storage, &relayers_config, timestamp)?.1 {
+        Some(balance) => balance,
+        None => return Ok(()),
+    };
+
+    let owner_count = bridge_xrpl_owner_count(storage)?.saturating_add(additional_objects);
+    let xrpl_base_fee = FEES_CONFIG.load(storage)?.xrpl_base_fee;
+    let required = required_xrpl_reserve(&XRPL_CONFIG.load(storage)?, owner_count)?.checked_add(
+        xrpl_multisigning_fee(xrpl_base_fee)?.checked_mul(Uint128::from(transactions))?,
+    )?;
+    let available = balance.saturating_sub(bridged_xrp(storage)?);
+
+    if available < required {
+        return Err(ContractError::InsufficientXRPLReserve {
+            required,
+            available,
+        });
+    }
+
+    Ok(())
+}
@@ -4,7 +4,8 @@ use cosmwasm_std::{Addr, DepsMut};
 use crate::{
     error::ContractError,
     operation::check_valid_operation_if_halt,
-    state::{CONFIG, PENDING_OPERATIONS},
+    relayer::{current_relayer_set_epoch, operation_relayer_set_epoch},
+    state::{BRIDGE_STATE_CONFIG, PENDING_OPERATIONS, RELAYERS_CONFIG},
 };
 
 const MAX_SIGNATURE_LENGTH: usize = 200;
@@ -15,13 +16,19 @@ pub struct Signature {
     pub signature: String,
 }
 
+// Signatures collected for the current version of an operation and the amount the XRPL signer list needs
+pub struct SigningProgress {
+    pub signatures: usize,
+    pub quorum: u32,
+}
+
 pub fn add_signature(
     deps: DepsMut,
     operation_id: u64,
     operation_version: u64,
     sender: Addr,
     signature: String,
-) -> Result<(), ContractError> {
+) -> Result<SigningProgress, ContractError> {
     validate_signature(&signature)?;
 
     // We get the current signatures for this specific operation
@@ -33,10 +40,21 @@ pub fn add_signature(
         return Err(ContractError::OperationVersionMismatch {});
     }
 
-    let config = CONFIG.load(deps.storage)?;
+    // Signatures of an operation must all be for the same signer list, so operations created before a key rotation
+    // must be rebased before the new relayer set can sign them
+    if operation_relayer_set_epoch(deps.storage, operation_id)?
+        != current_relayer_set_epoch(deps.storage)?
+    {
+        return Err(ContractError::OperationRelayerSetOutdated {});
+    }
 
     // If bridge is halted we prohibit all signatures except for allowed operations
-    check_valid_operation_if_halt(deps.storage, &config, &pending_operation.operation_type)?;
+    let bridge_state = BRIDGE_STATE_CONFIG.load(deps.storage)?.bridge_state;
+    check_valid_operation_if_halt(
+        deps.storage,
+        &bridge_state,
+        &pending_operation.operation_type,
+    )?;
 
     let mut signatures = pending_operation.signatures;
 
@@ -59,7 +77,11 @@ pub fn add_signature(
     pending_operation.signatures = signatures;
     PENDING_OPERATIONS.save(deps.storage, operation_id, &pending_operation)?;
 
-    Ok(())
+    // The signer list on XRPL is always set with the evidence threshold as quorum
+    Ok(SigningProgress {
+        signatures: pending_operation.signatures.len(),
+        quorum: RELAYERS_CONFIG.load(deps.storage)?.evidence_threshold,
+    })
 }
 
 fn validate_signature(signature: &str) -> Result<(), ContractError> {
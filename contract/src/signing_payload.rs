@@ -0,0 +1,432 @@
+use cosmwasm_std::Uint128;
+use sha2::{Digest, Sha512};
+
+use crate::{
+    address::decode_xrpl_address,
+    contract::XRPL_TOKENS_DECIMALS,
+    error::ContractError,
+    operation::{Operation, OperationType},
+    reserve::xrpl_multisigning_fee,
+    state::RelayersConfig,
+    token::is_token_xrp,
+};
+
+// Prefix XRPL adds to the serialized transaction before it's signed by a member of a signer list
+const MULTISIGNING_PREFIX: [u8; 4] = *b"SMT\0";
+// Prefix XRPL adds to the serialized signed transaction to compute its hash
+const TRANSACTION_ID_PREFIX: [u8; 4] = *b"TXN\0";
+
+// Maximum combinations of collected signatures tried when recomputing the hash of the transaction of an operation
+const MAX_TRANSACTION_HASH_CANDIDATES: u64 = 256;
+
+// Field codes (type code, field code) of the fields included in the transactions built for operations
+const TRANSACTION_TYPE_FIELD: (u8, u8) = (1, 2);
+const SIGNER_WEIGHT_FIELD: (u8, u8) = (1, 3);
+const FLAGS_FIELD: (u8, u8) = (2, 2);
+const SEQUENCE_FIELD: (u8, u8) = (2, 4);
+const DESTINATION_TAG_FIELD: (u8, u8) = (2, 14);
+const SIGNER_QUORUM_FIELD: (u8, u8) = (2, 35);
+const TICKET_COUNT_FIELD: (u8, u8) = (2, 40);
+const TICKET_SEQUENCE_FIELD: (u8, u8) = (2, 41);
+const AMOUNT_FIELD: (u8, u8) = (6, 1);
+const LIMIT_AMOUNT_FIELD: (u8, u8) = (6, 3);
+const FEE_FIELD: (u8, u8) = (6, 8);
+const SEND_MAX_FIELD: (u8, u8) = (6, 9);
+const SIGNING_PUB_KEY_FIELD: (u8, u8) = (7, 3);
+const TXN_SIGNATURE_FIELD: (u8, u8) = (7, 4);
+const ACCOUNT_FIELD: (u8, u8) = (8, 1);
+const DESTINATION_FIELD: (u8, u8) = (8, 3);
+const SIGNER_ENTRY_FIELD: (u8, u8) = (14, 11);
+const SIGNER_FIELD: (u8, u8) = (14, 16);
+const SIGNERS_FIELD: (u8, u8) = (15, 3);
+const SIGNER_ENTRIES_FIELD: (u8, u8) = (15, 4);
+const OBJECT_END_MARKER: u8 = 0xE1;
+const ARRAY_END_MARKER: u8 = 0xF1;
+
+const PAYMENT_TRANSACTION_TYPE: u16 = 0;
+const TICKET_CREATE_TRANSACTION_TYPE: u16 = 10;
+const SIGNER_LIST_SET_TRANSACTION_TYPE: u16 = 12;
+const TRUST_SET_TRANSACTION_TYPE: u16 = 20;
+
+// Relayers always request fully canonical signatures, and TrustSet operations don't allow rippling on the trust line
+const TF_FULLY_CANONICAL_SIG: u32 = 0x80000000;
+const TF_SET_NO_RIPPLE: u32 = 0x00020000;
+
+const ISSUED_AMOUNT_EXPONENT_OFFSET: i32 = 97;
+const MIN_ISSUED_AMOUNT_MANTISSA: u64 = 1_000_000_000_000_000;
+const MAX_ISSUED_AMOUNT_MANTISSA: u64 = 9_999_999_999_999_999;
+
+#[derive(Clone)]
+enum FieldValue {
+    UInt16(u16),
+    UInt32(u32),
+    Amount(Vec<u8>),
+    Blob(Vec<u8>),
+    AccountID(Vec<u8>),
+    SignerEntries(Vec<(Vec<u8>, u16)>),
+    // Account, public key and transaction signature of each signer
+    Signers(Vec<(Vec<u8>, Vec<u8>, Vec<u8>)>),
+}
+
+// Member of the XRPL signer list whose signature is included in the transaction submitted for an operation
+struct TransactionSigner {
+    account: Vec<u8>,
+    pub_key: Vec<u8>,
+    signature: Vec<u8>,
+}
+
+// Serializes the XRPL transaction that relayers build for the operation, in the canonical binary format of XRPL.
+// Only signing fields are included, with an empty SigningPubKey as required for multisigning
+pub fn serialize_operation_transaction(
+    bridge_xrpl_address: &str,
+    operation: &Operation,
+) -> Result<Vec<u8>, ContractError> {
+    Ok(encode_fields(operation_transaction_fields(
+        bridge_xrpl_address,
+        operation,
+    )?))
+}
+
+// Checks that the hash reported in the result of an operation is the hash of its transaction signed with the signatures
+// collected for it. Relayers submit the transaction with the first valid signatures that reach the quorum, so each combination
+// of quorum signatures is a candidate. If the combinations can't be tried (not enough signatures or too many of them), the hash is not checked
+pub fn verify_operation_transaction_hash(
+    signers_config: &RelayersConfig,
+    bridge_xrpl_address: &str,
+    operation: &Operation,
+    tx_hash: &str,
+) -> Result<(), ContractError> {
+    // Signatures of accounts that are not in the signer list or that can't be decoded are skipped by relayers too
+    let signers: Vec<TransactionSigner> = operation
+        .signatures
+        .iter()
+        .filter_map(|signature| {
+            let relayer = signers_config
+                .relayers
+                .iter()
+                .find(|relayer| relayer.coreum_address == signature.relayer_coreum_address)?;
+            Some(TransactionSigner {
+                account: decode_xrpl_address(&relayer.xrpl_address).ok()?,
+                pub_key: hex::decode(&relayer.xrpl_pub_key).ok()?,
+                signature: hex::decode(&signature.signature).ok()?,
+            })
+        })
+        .collect();
+
+    let quorum = signers_config.evidence_threshold as usize;
+    if quorum == 0
+        || signers.len() < quorum
+        || combinations_count(signers.len(), quorum) > MAX_TRANSACTION_HASH_CANDIDATES
+    {
+        return Ok(());
+    }
+
+    let fields = operation_transaction_fields(bridge_xrpl_address, operation)?;
+    let mut combination: Vec<usize> = (0..quorum).collect();
+    loop {
+        let candidate_signers = combination.iter().map(|index| &signers[*index]).collect();
+        if operation_transaction_hash(&fields, candidate_signers).eq_ignore_ascii_case(tx_hash) {
+            return Ok(());
+        }
+        if !next_combination(&mut combination, signers.len()) {
+            return Err(ContractError::TransactionHashMismatch {});
+        }
+    }
+}
+
+// Fields of the XRPL transaction that relayers build for the operation, without the signers
+fn operation_transaction_fields(
+    bridge_xrpl_address: &str,
+    operation: &Operation,
+) -> Result<Vec<((u8, u8), FieldValue)>, ContractError> {
+    // Relayers sign with the base fee of the operation, which can differ from the current one
+    let mut fields = vec![
+        (
+            FEE_FIELD,
+            FieldValue::Amount(encode_xrp_amount(xrpl_multisigning_fee(
+                operation.xrpl_base_fee,
+            )?)?),
+        ),
+        (SIGNING_PUB_KEY_FIELD, FieldValue::Blob(vec![])),
+        (
+            ACCOUNT_FIELD,
+            FieldValue::AccountID(decode_xrpl_address(bridge_xrpl_address)?),
+        ),
+    ];
+
+    // Operations executed with a ticket have a sequence of 0. Sequences are 32 bits on XRPL
+    match operation.ticket_sequence {
+        Some(ticket_sequence) => {
+            fields.push((SEQUENCE_FIELD, FieldValue::UInt32(0)));
+            fields.push((
+                TICKET_SEQUENCE_FIELD,
+                FieldValue::UInt32(ticket_sequence as u32),
+            ));
+        }
+        None => fields.push((
+            SEQUENCE_FIELD,
+            FieldValue::UInt32(operation.account_sequence.unwrap_or_default() as u32),
+        )),
+    }
+
+    let mut flags = TF_FULLY_CANONICAL_SIG;
+    match &operation.operation_type {
+        OperationType::AllocateTickets { number } => {
+            fields.push((
+                TRANSACTION_TYPE_FIELD,
+                FieldValue::UInt16(TICKET_CREATE_TRANSACTION_TYPE),
+            ));
+            fields.push((TICKET_COUNT_FIELD, FieldValue::UInt32(*number)));
+        }
+        OperationType::TrustSet {
+            issuer,
+            currency,
+            trust_set_limit_amount,
+        } => {
+            flags |= TF_SET_NO_RIPPLE;
+            fields.push((
+                TRANSACTION_TYPE_FIELD,
+                FieldValue::UInt16(TRUST_SET_TRANSACTION_TYPE),
+            ));
+            fields.push((
+                LIMIT_AMOUNT_FIELD,
+                FieldValue::Amount(encode_amount(issuer, currency, *trust_set_limit_amount)?),
+            ));
+        }
+        OperationType::RotateKeys {
+            new_relayers,
+            new_evidence_threshold,
+        } => {
+            fields.push((
+                TRANSACTION_TYPE_FIELD,
+                FieldValue::UInt16(SIGNER_LIST_SET_TRANSACTION_TYPE),
+            ));
+            fields.push((
+                SIGNER_QUORUM_FIELD,
+                FieldValue::UInt32(*new_evidence_threshold),
+            ));
+            // Every relayer has the same weight in the signer list
+            let signer_entries = new_relayers
+                .iter()
+                .map(|relayer| Ok((decode_xrpl_address(&relayer.xrpl_address)?, 1)))
+                .collect::<Result<Vec<(Vec<u8>, u16)>, ContractError>>()?;
+            fields.push((
+                SIGNER_ENTRIES_FIELD,
+                FieldValue::SignerEntries(signer_entries),
+            ));
+        }
+        OperationType::CoreumToXRPLTransfer {
+            issuer,
+            currency,
+            amount,
+            max_amount,
+            recipient,
+            destination_tag,
+            ..
+        } => {
+            fields.push((
+                TRANSACTION_TYPE_FIELD,
+                FieldValue::UInt16(PAYMENT_TRANSACTION_TYPE),
+            ));
+            fields.push((
+                AMOUNT_FIELD,
+                FieldValue::Amount(encode_amount(issuer, currency, *amount)?),
+            ));
+            if let Some(max_amount) = max_amount {
+                fields.push((
+                    SEND_MAX_FIELD,
+                    FieldValue::Amount(encode_amount(issuer, currency, *max_amount)?),
+                ));
+            }
+            fields.push((
+                DESTINATION_FIELD,
+                FieldValue::AccountID(decode_xrpl_address(recipient)?),
+            ));
+            if let Some(destination_tag) = destination_tag {
+                fields.push((DESTINATION_TAG_FIELD, FieldValue::UInt32(*destination_tag)));
+            }
+        }
+    }
+    fields.push((FLAGS_FIELD, FieldValue::UInt32(flags)));
+
+    Ok(fields)
+}
+
+// Hash of the multisigned transaction: the first half of the SHA512 of the prefixed transaction, as XRPL shows it
+fn operation_transaction_hash(
+    fields: &[((u8, u8), FieldValue)],
+    mut signers: Vec<&TransactionSigner>,
+) -> String {
+    // XRPL requires signers to be sorted by account
+    signers.sort_by(|a, b| a.account.cmp(&b.account));
+
+    let mut fields = fields.to_vec();
+    fields.push((
+        SIGNERS_FIELD,
+        FieldValue::Signers(
+            signers
+                .into_iter()
+                .map(|signer| {
+                    (
+                        signer.account.clone(),
+                        signer.pub_key.clone(),
+                        signer.signature.clone(),
+                    )
+                })
+                .collect(),
+        ),
+    ));
+
+    let mut data = TRANSACTION_ID_PREFIX.to_vec();
+    data.extend_from_slice(&encode_fields(fields));
+    hex::encode_upper(&Sha512::digest(data)[..32])
+}
+
+fn encode_fields(mut fields: Vec<((u8, u8), FieldValue)>) -> Vec<u8> {
+    // XRPL requires fields to be sorted by type code and then by field code
+    fields.sort_by_key(|(field, _)| *field);
+
+    let mut data = vec![];
+    for (field, value) in fields {
+        encode_field(&mut data, field, value);
+    }
+
+    data
+}
+
+fn combinations_count(n: usize, k: usize) -> u64 {
+    let mut count: u64 = 1;
+    for i in 0..k.min(n - k) as u64 {
+        count = count * (n as u64 - i) / (i + 1);
+    }
+    count
+}
+
+// Advances to the next combination of indexes in lexicographic order, returns false if it was the last one
+fn next_combination(combination: &mut [usize], n: usize) -> bool {
+    let k = combination.len();
+    for i in (0..k).rev() {
+        if combination[i] < n - k + i {
+            combination[i] += 1;
+            let start = combination[i];
+            for (offset, index) in combination[i + 1..].iter_mut().enumerate() {
+                *index = start + offset + 1;
+            }
+            return true;
+        }
+    }
+    false
+}
+
+// Builds the exact bytes a relayer signs for the operation: the multisigning prefix, the serialized transaction and the account of the signer
+pub fn operation_signing_payload(
+    transaction: &[u8],
+    signer_xrpl_address: &str,
+) -> Result<Vec<u8>, ContractError> {
+    let mut payload = MULTISIGNING_PREFIX.to_vec();
+    payload.extend_from_slice(transaction);
+    payload.extend_from_slice(&decode_xrpl_address(signer_xrpl_address)?);
+    Ok(payload)
+}
+
+fn encode_field(data: &mut Vec<u8>, field: (u8, u8), value: FieldValue) {
+    encode_field_header(data, field);
+    match value {
+        FieldValue::UInt16(value) => data.extend_from_slice(&value.to_be_bytes()),
+        FieldValue::UInt32(value) => data.extend_from_slice(&value.to_be_bytes()),
+        FieldValue::Amount(value) => data.extend_from_slice(&value),
+        // Account IDs are 20 bytes and blobs are public keys and signatures (at most 100 bytes), so their length always fits in one byte
+        FieldValue::Blob(value) | FieldValue::AccountID(value) => {
+            data.push(value.len() as u8);
+            data.extend_from_slice(&value);
+        }
+        FieldValue::SignerEntries(entries) => {
+            for (account, weight) in entries {
+                encode_field_header(data, SIGNER_ENTRY_FIELD);
+                encode_field(data, SIGNER_WEIGHT_FIELD, FieldValue::UInt16(weight));
+                encode_field(data, ACCOUNT_FIELD, FieldValue::AccountID(account));
+                data.push(OBJECT_END_MARKER);
+            }
+            data.push(ARRAY_END_MARKER);
+        }
+        FieldValue::Signers(signers) => {
+            for (account, pub_key, signature) in signers {
+                encode_field_header(data, SIGNER_FIELD);
+                encode_field(data, SIGNING_PUB_KEY_FIELD, FieldValue::Blob(pub_key));
+                encode_field(data, TXN_SIGNATURE_FIELD, FieldValue::Blob(signature));
+                encode_field(data, ACCOUNT_FIELD, FieldValue::AccountID(account));
+                data.push(OBJECT_END_MARKER);
+            }
+            data.push(ARRAY_END_MARKER);
+        }
+    }
+}
+
+// Type and field codes lower than 16 are packed in the same byte, otherwise they use a byte of their own
+fn encode_field_header(data: &mut Vec<u8>, (type_code, field_code): (u8, u8)) {
+    match (type_code < 16, field_code < 16) {
+        (true, true) => data.push((type_code << 4) | field_code),
+        (true, false) => data.extend_from_slice(&[type_code << 4, field_code]),
+        (false, true) => data.extend_from_slice(&[field_code, type_code]),
+        (false, false) => data.extend_from_slice(&[0, type_code, field_code]),
+    }
+}
+
+// Encodes an amount with the decimals the bridge uses for the token into an XRPL amount
+fn encode_amount(issuer: &str, currency: &str, amount: Uint128) -> Result<Vec<u8>, ContractError> {
+    if is_token_xrp(issuer, currency) {
+        return encode_xrp_amount(amount);
+    }
+
+    let mut data = encode_issued_value(amount)?.to_be_bytes().to_vec();
+    data.extend_from_slice(&encode_currency(currency)?);
+    data.extend_from_slice(&decode_xrpl_address(issuer)?);
+    Ok(data)
+}
+
+// XRP amounts are the amount of drops with the second bit set to indicate that it's positive
+fn encode_xrp_amount(drops: Uint128) -> Result<Vec<u8>, ContractError> {
+    if drops.u128() >= 1 << 62 {
+        return Err(ContractError::InvalidXRPLAmount {});
+    }
+    Ok(((1u64 << 62) | drops.u128() as u64).to_be_bytes().to_vec())
+}
+
+// Issued amounts are represented as a normalized mantissa of 16 digits * 10^exponent. Zero is represented with only the first bit set
+fn encode_issued_value(amount: Uint128) -> Result<u64, ContractError> {
+    if amount.is_zero() {
+        return Ok(1 << 63);
+    }
+
+    let mut mantissa = amount.u128();
+    let mut exponent = -(XRPL_TOKENS_DECIMALS as i32);
+    while mantissa > MAX_ISSUED_AMOUNT_MANTISSA as u128 {
+        // Digits can't be dropped without changing the amount
+        if mantissa % 10 != 0 {
+            return Err(ContractError::InvalidXRPLAmount {});
+        }
+        mantissa /= 10;
+        exponent += 1;
+    }
+    while mantissa < MIN_ISSUED_AMOUNT_MANTISSA as u128 {
+        mantissa *= 10;
+        exponent -= 1;
+    }
+
+    Ok((1 << 63)
+        | (1 << 62)
+        | (((exponent + ISSUED_AMOUNT_EXPONENT_OFFSET) as u64) << 54)
+        | mantissa as u64)
+}
+
+// Converts a currency into its 160-bit representation
+fn encode_currency(currency: &str) -> Result<Vec<u8>, ContractError> {
+    // Standard currency codes are 3 ASCII characters in bytes 12 to 14, with all the other bytes set to 0
+    if currency.len() == 3 {
+        let mut data = vec![0u8; 20];
+        data[12..15].copy_from_slice(currency.as_bytes());
+        return Ok(data);
+    }
+
+    hex::decode(currency).map_err(|_| ContractError::InvalidXRPLCurrency {})
+}
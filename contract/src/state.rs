@@ -1,16 +1,20 @@
 use std::collections::VecDeque;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Coin, Empty, Uint128};
+use cosmwasm_std::{Addr, Coin, Decimal, Empty, StdResult, Storage, Uint128};
 use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex, UniqueIndex};
 
-use crate::{evidence::Evidences, operation::Operation, relayer::Relayer};
+use crate::{
+    evidence::{Evidences, OperationResult, TransactionResult},
+    light_client::{LightClientConfig, ProvenXRPLTransaction},
+    operation::Operation,
+    relayer::Relayer,
+};
 
 /// Top level storage key. Values must not conflict.
 /// Each key is only one byte long to ensure we use the smallest possible storage keys.
 #[repr(u8)]
 pub enum TopKey {
-    Config = b'1',
     TxEvidences = b'2',
     ProcessedTxs = b'3',
     CoreumTokens = b'4',
@@ -25,6 +29,54 @@ pub enum TopKey {
     FeeRemainders = b'd',
     PendingRotateKeys = b'e',
     ProhibitedXRPLAddresses = b'f',
+    ProvenXRPLTransactions = b'g',
+    EvidenceSigningKeys = b'h',
+    HaltRequests = b'i',
+    TokenAccounting = b'j',
+    PendingDelivery = b'k',
+    UndeliveredTransfers = b'l',
+    TokenMetadata = b'm',
+    TokenStateHistory = b'n',
+    QueuedXRPLTokenRegistrations = b'o',
+    RelayerReimbursement = b'p',
+    TransferReceipts = b'q',
+    TransferReceiptIds = b'r',
+    InboundTransfers = b's',
+    InboundTransfersCounter = b't',
+    TokenFreezeRequests = b'u',
+    TokenFreezes = b'v',
+    XRPLIssuerConfigs = b'w',
+    OperationArchive = b'x',
+    OperationArchiveCounter = b'y',
+    DustAccounting = b'z',
+    EvidenceDelegates = b'A',
+    CommitRevealThresholds = b'B',
+    TransferCommitments = b'C',
+    RecurringTransfers = b'D',
+    RecurringTransfersCounter = b'E',
+    XRPLRecipientFlags = b'F',
+    XRPLDexQuotes = b'G',
+    USDBridgingFees = b'H',
+    TokenPrices = b'I',
+    XRPLSupplyDrifts = b'J',
+    InvalidDeposits = b'K',
+    XRPLBalanceAttestations = b'L',
+    RelayerActivity = b'M',
+    OperationEvidenceDeadlines = b'N',
+    RelayersConfig = b'O',
+    FeesConfig = b'P',
+    BridgeStateConfig = b'Q',
+    XRPLConfig = b'R',
+    OperationsConfig = b'S',
+    BridgeStateAcknowledgements = b'T',
+    TransferChunks = b'U',
+    XRPLRecipientRules = b'V',
+    RelayerFeeClaims = b'W',
+    RelayerFeeClaimsCounter = b'X',
+    BridgeActivity = b'Y',
+    TokenFailures = b'Z',
+    RelayerInfractions = b'0',
+    // b'1' was used by the Config item before it was split in components, it's removed when migrating and must not be reused
 }
 
 impl TopKey {
@@ -37,6 +89,48 @@ impl TopKey {
     }
 }
 
+/// Top level storage keys used once all the one byte keys of TopKey were taken. Values must not conflict.
+/// Each key is two bytes long, so they can't conflict with the keys of TopKey either.
+pub enum ExtendedTopKey {
+    SendToXRPLIdempotencyKeys,
+    OperationCreations,
+    RelayerFeeConsolidationDenoms,
+    RelayerSetEpoch,
+    RelayerSetSnapshots,
+    PendingHighRiskActions,
+    RelayerWorkloads,
+    BlockedClaims,
+    DeferredInboundTransfers,
+    OperationSubmissions,
+    EvidenceSources,
+    RelayerAttestations,
+    EmergencyWithdrawal,
+    FeeAccounting,
+    PriorityOperations,
+}
+
+impl ExtendedTopKey {
+    const fn as_str(&self) -> &str {
+        match self {
+            Self::SendToXRPLIdempotencyKeys => "0a",
+            Self::OperationCreations => "0b",
+            Self::RelayerFeeConsolidationDenoms => "0c",
+            Self::RelayerSetEpoch => "0d",
+            Self::RelayerSetSnapshots => "0e",
+            Self::PendingHighRiskActions => "0f",
+            Self::RelayerWorkloads => "0g",
+            Self::BlockedClaims => "0h",
+            Self::DeferredInboundTransfers => "0i",
+            Self::OperationSubmissions => "0j",
+            Self::EvidenceSources => "0k",
+            Self::RelayerAttestations => "0l",
+            Self::EmergencyWithdrawal => "0m",
+            Self::FeeAccounting => "0n",
+            Self::PriorityOperations => "0o",
+        }
+    }
+}
+
 #[cw_serde]
 pub struct Config {
     pub relayers: Vec<Relayer>,
@@ -46,6 +140,226 @@ pub struct Config {
     pub bridge_xrpl_address: String,
     pub bridge_state: BridgeState,
     pub xrpl_base_fee: u64,
+    // If set, transfers from XRPL are only accepted if their transaction was proven to be in a validated ledger
+    pub light_client: Option<LightClientConfig>,
+    // Information about why and by whom the bridge was halted. Only present while the bridge is halted
+    pub halt_info: Option<HaltInfo>,
+    // Amount of relayers that need to request a halt for the bridge to be halted
+    pub halt_request_threshold: u32,
+    // Maximum amount of XRPL originated tokens (XRP excluded) that can be registered, each one of them uses a trust line on XRPL
+    pub max_registered_xrpl_tokens: u32,
+    // Amount of relayers that need to request a token freeze for the token to be frozen
+    pub token_freeze_request_threshold: u32,
+    // Seconds a token frozen by relayers stays frozen if the owner doesn't confirm the freeze
+    pub token_freeze_duration: u64,
+    // If set, the tokens of an issuer are disabled when relayers report that it enabled a global freeze or clawback
+    pub auto_disable_on_issuer_change: bool,
+    // Maximum amount of resolved operations kept in the operation archive, 0 disables the archive
+    pub operation_archive_size: u32,
+    // Seconds after which evidences that didn't reach the threshold are considered stale and can be pruned by anyone
+    pub evidence_ttl: u64,
+    // If set, this contract is queried before accepting transfers to XRPL and before delivering transfers from XRPL
+    pub compliance_contract: Option<Addr>,
+    // Blocks that must pass after a transfer to XRPL is committed before it can be revealed
+    pub commit_reveal_delay: u64,
+    // Blocks after which a transfer to XRPL that was committed but not revealed is abandoned and can be refunded by anyone
+    pub commit_reveal_expiration: u64,
+    // Amount (in the denom of the relayer reimbursement pool) paid from the pool to whoever triggers housekeeping that had work to do
+    pub crank_reward: Uint128,
+    // Seconds during which a DEX quote published by a relayer is taken into account
+    pub xrpl_dex_quote_ttl: u64,
+    // If set, this address pushes the USD prices used to convert USD bridging fees to token amounts
+    pub price_oracle: Option<Addr>,
+    // XRP (in drops) that the bridge XRPL account must always hold
+    pub xrpl_base_reserve: Uint128,
+    // XRP (in drops) that the bridge XRPL account must hold for each object it owns (trust lines, tickets, signer list)
+    pub xrpl_owner_reserve: Uint128,
+    // Seconds without saving evidences or signatures after which the owner can mark a relayer as inactive
+    pub relayer_inactivity_period: u64,
+    // Seconds a pending operation waits for relayers to save its evidence before anyone can expire it
+    pub operation_evidence_period: u64,
+    // Consecutive transfers of a token to XRPL rejected on XRPL after which the token is disabled, 0 never disables it
+    pub token_failure_threshold: u32,
+    // Extra fee paid to place a transfer to XRPL in the priority lane. If not set, transfers can't be prioritized
+    pub priority_fee: Option<Coin>,
+    // If set, fees claimed by relayers that elected a consolidation denom are sent to this contract to be converted to that denom
+    pub fee_converter_contract: Option<Addr>,
+    // Distinct sources that the relayers confirming the result of an operation must have declared, 0 disables the rule
+    pub min_distinct_evidence_sources: u32,
+    // Maximum amount of relayers, which can't be higher than the amount of signers XRPL allows in a signer list
+    pub max_relayers: u32,
+    // XRPL network the bridge is deployed for, X-addresses of other networks are rejected
+    pub xrpl_network: XRPLNetwork,
+}
+
+// The config is stored split in the following components, so that reading or updating one of them
+// doesn't deserialize or rewrite the others. CONFIG loads and saves all of them as a single Config
+#[cw_serde]
+pub struct RelayersConfig {
+    pub relayers: Vec<Relayer>,
+    pub evidence_threshold: u32,
+    pub halt_request_threshold: u32,
+    pub token_freeze_request_threshold: u32,
+    pub relayer_inactivity_period: u64,
+    pub min_distinct_evidence_sources: u32,
+    pub max_relayers: u32,
+}
+
+#[cw_serde]
+pub struct FeesConfig {
+    pub xrpl_base_fee: u64,
+    pub crank_reward: Uint128,
+    pub xrpl_dex_quote_ttl: u64,
+    pub price_oracle: Option<Addr>,
+    pub priority_fee: Option<Coin>,
+    pub fee_converter_contract: Option<Addr>,
+}
+
+#[cw_serde]
+pub struct BridgeStateConfig {
+    pub bridge_state: BridgeState,
+    pub halt_info: Option<HaltInfo>,
+}
+
+#[cw_serde]
+pub struct XRPLConfig {
+    pub bridge_xrpl_address: String,
+    pub used_ticket_sequence_threshold: u32,
+    pub trust_set_limit_amount: Uint128,
+    pub light_client: Option<LightClientConfig>,
+    pub max_registered_xrpl_tokens: u32,
+    pub xrpl_base_reserve: Uint128,
+    pub xrpl_owner_reserve: Uint128,
+    pub xrpl_network: XRPLNetwork,
+}
+
+#[cw_serde]
+pub struct OperationsConfig {
+    pub token_freeze_duration: u64,
+    pub auto_disable_on_issuer_change: bool,
+    pub operation_archive_size: u32,
+    pub evidence_ttl: u64,
+    pub compliance_contract: Option<Addr>,
+    pub commit_reveal_delay: u64,
+    pub commit_reveal_expiration: u64,
+    pub operation_evidence_period: u64,
+    pub token_failure_threshold: u32,
+}
+
+pub struct ConfigStore {}
+
+impl ConfigStore {
+    pub fn load(&self, storage: &dyn Storage) -> StdResult<Config> {
+        let relayers = RELAYERS_CONFIG.load(storage)?;
+        let fees = FEES_CONFIG.load(storage)?;
+        let bridge_state = BRIDGE_STATE_CONFIG.load(storage)?;
+        let xrpl = XRPL_CONFIG.load(storage)?;
+        let operations = OPERATIONS_CONFIG.load(storage)?;
+
+        Ok(Config {
+            relayers: relayers.relayers,
+            evidence_threshold: relayers.evidence_threshold,
+            used_ticket_sequence_threshold: xrpl.used_ticket_sequence_threshold,
+            trust_set_limit_amount: xrpl.trust_set_limit_amount,
+            bridge_xrpl_address: xrpl.bridge_xrpl_address,
+            bridge_state: bridge_state.bridge_state,
+            xrpl_base_fee: fees.xrpl_base_fee,
+            light_client: xrpl.light_client,
+            halt_info: bridge_state.halt_info,
+            halt_request_threshold: relayers.halt_request_threshold,
+            max_registered_xrpl_tokens: xrpl.max_registered_xrpl_tokens,
+            token_freeze_request_threshold: relayers.token_freeze_request_threshold,
+            token_freeze_duration: operations.token_freeze_duration,
+            auto_disable_on_issuer_change: operations.auto_disable_on_issuer_change,
+            operation_archive_size: operations.operation_archive_size,
+            evidence_ttl: operations.evidence_ttl,
+            compliance_contract: operations.compliance_contract,
+            commit_reveal_delay: operations.commit_reveal_delay,
+            commit_reveal_expiration: operations.commit_reveal_expiration,
+            crank_reward: fees.crank_reward,
+            xrpl_dex_quote_ttl: fees.xrpl_dex_quote_ttl,
+            price_oracle: fees.price_oracle,
+            xrpl_base_reserve: xrpl.xrpl_base_reserve,
+            xrpl_owner_reserve: xrpl.xrpl_owner_reserve,
+            xrpl_network: xrpl.xrpl_network,
+            relayer_inactivity_period: relayers.relayer_inactivity_period,
+            operation_evidence_period: operations.operation_evidence_period,
+            token_failure_threshold: operations.token_failure_threshold,
+            priority_fee: fees.priority_fee,
+            fee_converter_contract: fees.fee_converter_contract,
+            min_distinct_evidence_sources: relayers.min_distinct_evidence_sources,
+            max_relayers: relayers.max_relayers,
+        })
+    }
+
+    // Writes every component, only used when instantiating. Execution paths save the component they modify
+    pub fn save(&self, storage: &mut dyn Storage, config: &Config) -> StdResult<()> {
+        RELAYERS_CONFIG.save(
+            storage,
+            &RelayersConfig {
+                relayers: config.relayers.clone(),
+                evidence_threshold: config.evidence_threshold,
+                halt_request_threshold: config.halt_request_threshold,
+                token_freeze_request_threshold: config.token_freeze_request_threshold,
+                relayer_inactivity_period: config.relayer_inactivity_period,
+                min_distinct_evidence_sources: config.min_distinct_evidence_sources,
+                max_relayers: config.max_relayers,
+            },
+        )?;
+        FEES_CONFIG.save(
+            storage,
+            &FeesConfig {
+                xrpl_base_fee: config.xrpl_base_fee,
+                crank_reward: config.crank_reward,
+                xrpl_dex_quote_ttl: config.xrpl_dex_quote_ttl,
+                price_oracle: config.price_oracle.clone(),
+                priority_fee: config.priority_fee.clone(),
+                fee_converter_contract: config.fee_converter_contract.clone(),
+            },
+        )?;
+        BRIDGE_STATE_CONFIG.save(
+            storage,
+            &BridgeStateConfig {
+                bridge_state: config.bridge_state.clone(),
+                halt_info: config.halt_info.clone(),
+            },
+        )?;
+        XRPL_CONFIG.save(
+            storage,
+            &XRPLConfig {
+                bridge_xrpl_address: config.bridge_xrpl_address.clone(),
+                used_ticket_sequence_threshold: config.used_ticket_sequence_threshold,
+                trust_set_limit_amount: config.trust_set_limit_amount,
+                light_client: config.light_client.clone(),
+                max_registered_xrpl_tokens: config.max_registered_xrpl_tokens,
+                xrpl_base_reserve: config.xrpl_base_reserve,
+                xrpl_owner_reserve: config.xrpl_owner_reserve,
+                xrpl_network: config.xrpl_network.clone(),
+            },
+        )?;
+        OPERATIONS_CONFIG.save(
+            storage,
+            &OperationsConfig {
+                token_freeze_duration: config.token_freeze_duration,
+                auto_disable_on_issuer_change: config.auto_disable_on_issuer_change,
+                operation_archive_size: config.operation_archive_size,
+                evidence_ttl: config.evidence_ttl,
+                compliance_contract: config.compliance_contract.clone(),
+                commit_reveal_delay: config.commit_reveal_delay,
+                commit_reveal_expiration: config.commit_reveal_expiration,
+                operation_evidence_period: config.operation_evidence_period,
+                token_failure_threshold: config.token_failure_threshold,
+            },
+        )
+    }
+}
+
+#[cw_serde]
+pub struct RelayerReimbursement {
+    // Amount the relayer whose evidence reaches the threshold (and pays for executing it) receives
+    pub amount_per_operation: Coin,
+    // Amount available in the contract to pay reimbursements, in the same denom as amount_per_operation
+    pub pool: Uint128,
 }
 
 #[cw_serde]
@@ -54,6 +368,411 @@ pub enum BridgeState {
     Active,
     // Bridge is halted and no operations can be executed until it's reactivated by owner (if there are no pending rotate keys operation on going)
     Halted,
+    // Bridge is in maintenance, users can't send new transfers to XRPL but relayers can keep providing evidences and signatures
+    // so that all operations that are in flight can be completed
+    Maintenance,
+}
+
+impl BridgeState {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Halted => "halted",
+            Self::Maintenance => "maintenance",
+        }
+    }
+}
+
+// X-addresses encode the network they belong to, so that funds aren't sent to an address of another network
+#[cw_serde]
+pub enum XRPLNetwork {
+    Mainnet,
+    Testnet,
+}
+
+#[cw_serde]
+pub enum HaltReasonCode {
+    Unspecified,
+    // Scheduled halt, e.g. for an upgrade
+    Maintenance,
+    // Halt triggered because something is not working as expected
+    Incident,
+    // The bridge is automatically halted when a rotate keys operation is triggered
+    RotateKeys,
+}
+
+// For convenience in the responses
+impl HaltReasonCode {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "unspecified",
+            Self::Maintenance => "maintenance",
+            Self::Incident => "incident",
+            Self::RotateKeys => "rotate_keys",
+        }
+    }
+}
+
+#[cw_serde]
+pub struct HaltReason {
+    pub code: HaltReasonCode,
+    pub message: Option<String>,
+}
+
+#[cw_serde]
+pub struct HaltInfo {
+    pub reason: HaltReason,
+    pub halted_by: Addr,
+    // Block time (in seconds) when the bridge was halted
+    pub halted_at: u64,
+}
+
+#[cw_serde]
+pub struct PendingDelivery {
+    pub recipient: Addr,
+    pub xrpl_tx_hash: String,
+    pub coin: Coin,
+}
+
+// Account settings of an issuer of XRPL tokens, as last reported by relayers
+#[cw_serde]
+pub struct XRPLIssuerConfig {
+    pub global_freeze: bool,
+    pub transfer_rate: u32,
+    pub clawback_enabled: bool,
+    pub tx_hash: String,
+    pub updated_at: u64,
+}
+
+// Account settings of an XRPL recipient that make transfers from the bridge fail or that it doesn't want, as last reported by relayers
+#[cw_serde]
+pub struct XRPLRecipientFlags {
+    pub deposit_auth: bool,
+    pub disallow_xrp: bool,
+    pub tx_hash: String,
+    pub updated_at: u64,
+}
+
+// Rule that prohibits the XRPL recipients matching it, so that they don't have to be added one by one to the prohibited addresses
+#[cw_serde]
+pub enum XRPLRecipientRule {
+    // Transfers to any of these addresses must have a destination tag, e.g. for the deposit addresses of exchanges
+    RequireDestinationTag { xrpl_addresses: Vec<String> },
+    // Transfers to the issuer of any registered XRPL token are prohibited
+    ProhibitTokenIssuers {},
+}
+
+// Bridging fee of a token set in USD, which is converted to the token with the price pushed by the price oracle
+#[cw_serde]
+pub struct USDBridgingFee {
+    pub usd_amount: Decimal,
+    // Bounds of the converted fee, so that a wrong or extreme price can't make bridging free or unaffordable
+    pub min_fee: Uint128,
+    pub max_fee: Uint128,
+}
+
+// USD price of one whole token (not of its smallest unit), as pushed by the price oracle
+#[cw_serde]
+pub struct TokenPrice {
+    pub usd_price: Decimal,
+    pub updated_at: u64,
+}
+
+// Change in the balance of the bridge account for an XRPL token made outside of the bridge, as reported by relayers
+// It's kept until the owner reconciles the bridged amount of the token with it
+#[cw_serde]
+pub struct XRPLSupplyDrift {
+    pub tx_hash: String,
+    pub issuer: String,
+    pub currency: String,
+    pub amount: Uint128,
+    pub decreased: bool,
+    pub reported_at: u64,
+}
+
+// Transfer to the bridge account without a valid Coreum recipient, waiting to be resolved
+#[cw_serde]
+pub struct InvalidDeposit {
+    pub tx_hash: String,
+    pub issuer: String,
+    pub currency: String,
+    pub amount: Uint128,
+    // XRPL account that sent the deposit
+    pub sender: String,
+    pub recorded_at: u64,
+}
+
+// Block in which a pending operation was created
+#[cw_serde]
+pub struct OperationCreation {
+    pub height: u64,
+    pub timestamp: u64,
+    // Relayer set the operation must be signed by, which is the one that was active when it was created or last rebased
+    pub relayer_set_epoch: u64,
+}
+
+// High risk action proposed by the owner, which is executed when the same message is executed again before it expires
+#[cw_serde]
+pub struct PendingHighRiskAction {
+    // Hex encoded SHA256 hash of the JSON encoded execute message
+    pub msg_hash: String,
+    pub proposed_by: Addr,
+    pub proposed_at_height: u64,
+    pub expires_at: u64,
+}
+
+// Relayer set that was replaced by a key rotation, kept so that operations created for it can still be verified
+#[cw_serde]
+pub struct RelayerSetSnapshot {
+    pub relayers: Vec<Relayer>,
+    pub evidence_threshold: u32,
+}
+
+// Last time a relayer saved an evidence or a signature, and since when it's marked as inactive if it is
+#[cw_serde]
+pub struct RelayerActivity {
+    pub last_active_at: u64,
+    pub inactive_since: Option<u64>,
+}
+
+// Work performed by a relayer, the gas spent delivering inbound transfers is tracked separately from signatures
+#[cw_serde]
+pub struct RelayerWorkload {
+    pub signatures_saved: u64,
+    // Transfers from XRPL minted or released because the evidence of this relayer reached the threshold
+    pub inbound_deliveries: u64,
+}
+
+// XRP balance of the bridge XRPL account, as attested by a relayer
+#[cw_serde]
+pub struct XRPLBalanceAttestation {
+    pub balance: Uint128,
+    pub attested_at: u64,
+}
+
+// Price of a registered token on the XRPL DEX/AMM, as published by a relayer
+#[cw_serde]
+pub struct XRPLDexQuote {
+    // Amount of XRP received on XRPL for one unit of the token
+    pub price: Decimal,
+    pub published_at: u64,
+}
+
+// Operation that was resolved (confirmed or cancelled) together with how it was resolved
+#[cw_serde]
+pub struct ArchivedOperation {
+    pub operation: Operation,
+    pub transaction_result: TransactionResult,
+    pub operation_result: Option<OperationResult>,
+    // Hash of the XRPL transaction that resolved the operation. Not present if it was cancelled by the owner
+    pub tx_hash: Option<String>,
+    pub created_at: u64,
+    pub resolved_at: u64,
+}
+
+// Funds of a transfer to XRPL whose details are only revealed after the commit reveal delay
+#[cw_serde]
+pub struct TransferCommitment {
+    pub coin: Coin,
+    pub committed_at_height: u64,
+}
+
+// Transfer to XRPL that is sent every interval from funds escrowed in the contract
+#[cw_serde]
+pub struct RecurringTransfer {
+    pub id: u64,
+    pub sender: Addr,
+    pub recipient: String,
+    pub amount: Uint128,
+    pub denom: String,
+    // Seconds between executions
+    pub interval: u64,
+    // Block time (in seconds) from which the next execution can be triggered
+    pub next_execution_at: u64,
+    // Amount escrowed in the contract to pay for the executions
+    pub balance: Uint128,
+}
+
+// Amounts of a token that the bridge kept because they couldn't be sent or distributed exactly
+#[cw_serde]
+#[derive(Default)]
+pub struct DustAccounting {
+    // Total amount truncated because of the sending precision of the token and collected as fees
+    pub truncation_dust: Uint128,
+    // Total amount of fee division remainders that was swept by the owner
+    pub swept: Uint128,
+}
+
+// Fees of a token that were charged explicitly, the amounts truncated because of the sending precision are kept in DustAccounting
+#[cw_serde]
+#[derive(Default)]
+pub struct FeeAccounting {
+    // Total bridging fees collected
+    pub bridging_fees: Uint128,
+    // Total fees paid to place transfers in the priority lane
+    pub priority_fees: Uint128,
+}
+
+#[cw_serde]
+pub struct TokenFreeze {
+    pub requested_by: Vec<Addr>,
+    pub frozen_at: u64,
+    // The freeze is lifted at this time unless the owner confirms it. Confirmed freezes don't expire
+    pub expires_at: Option<u64>,
+}
+
+// Attempts to claim a pending refund of a token that was frozen by the bridge
+#[cw_serde]
+pub struct BlockedClaim {
+    pub first_blocked_at_height: u64,
+    pub last_attempt_height: u64,
+    pub attempts: u32,
+}
+
+// Withdrawal of contract balances scheduled by chain governance, last resort recovery path if the owner and the relayers are compromised
+#[cw_serde]
+pub struct EmergencyWithdrawal {
+    pub recipient: Addr,
+    pub amounts: Vec<Coin>,
+    pub scheduled_at: u64,
+    // Timestamp (in seconds) after which anyone can execute the withdrawal
+    pub executable_at: u64,
+}
+
+// Configuration a relayer publishes about itself for operational transparency, it's not verified by the contract
+#[cw_serde]
+pub struct RelayerAttestation {
+    // Fingerprint of the endpoint of the XRPL node the relayer is connected to
+    pub xrpl_node_fingerprint: Option<String>,
+    pub software_version: Option<String>,
+    // Public contact of the operator of the relayer
+    pub contact: Option<String>,
+    pub updated_at: u64,
+}
+
+// XRPL transaction submitted by a relayer for a pending operation that reached the quorum of signatures
+#[cw_serde]
+pub struct OperationSubmission {
+    pub relayer: Addr,
+    // Version of the operation the transaction was built for, transactions of previous versions are no longer valid
+    pub operation_version: u64,
+    pub tx_hash: String,
+    pub tx_blob: Option<String>,
+    pub submitted_at: u64,
+}
+
+// Transfer from XRPL of a token whose TrustSet wasn't confirmed yet, delivered once the token is enabled
+#[cw_serde]
+pub struct DeferredInboundTransfer {
+    pub issuer: String,
+    pub currency: String,
+    pub amount: Uint128,
+    pub recipient: Addr,
+    // Relayers whose evidences reached the threshold, kept for the receipt of the transfer
+    pub evidence_relayers: Vec<Addr>,
+    pub deferred_at: u64,
+}
+
+#[cw_serde]
+pub struct InboundTransfer {
+    pub xrpl_tx_hash: String,
+    // Amount delivered to the recipient
+    pub coin: Coin,
+    pub timestamp: u64,
+}
+
+#[cw_serde]
+pub struct RelayerFeeClaim {
+    // Fees paid out to the relayer in this claim
+    pub amounts: Vec<Coin>,
+    pub height: u64,
+    pub timestamp: u64,
+}
+
+// Conflicting evidences that a relayer provided for the same XRPL transaction
+#[cw_serde]
+pub struct RelayerInfraction {
+    pub tx_hash: String,
+    // Hashes of the conflicting evidences
+    pub evidence_hashes: Vec<String>,
+    pub reporter: Addr,
+    pub reported_at: u64,
+}
+
+#[cw_serde]
+pub enum TransferDirection {
+    XRPLToCoreum,
+    CoreumToXRPL,
+}
+
+#[cw_serde]
+pub enum TransferStatus {
+    // The transfer to XRPL is waiting for relayers to confirm the result of its XRPL transaction
+    Pending,
+    Completed,
+    // The transfer failed or couldn't be delivered and its funds can be claimed with the refund id
+    Refundable,
+    // The funds of a failed or undelivered transfer were claimed back
+    Refunded,
+}
+
+#[cw_serde]
+pub struct TransferReceipt {
+    // Operation id for transfers to XRPL, XRPL transaction hash for transfers from XRPL
+    pub id: String,
+    pub direction: TransferDirection,
+    pub status: TransferStatus,
+    // Only known for transfers to XRPL
+    pub sender: Option<Addr>,
+    pub recipient: String,
+    // Full amount of the transfer in its Coreum denom, including fees
+    pub coin: Coin,
+    pub fee_charged: Uint128,
+    // Amount received by the recipient, in the decimals of the chain it was delivered on
+    pub delivered_amount: Option<Uint128>,
+    // Coreum block and transaction index where the transfer was sent (to XRPL) or delivered (from XRPL)
+    pub coreum_height: u64,
+    pub coreum_tx_index: Option<u32>,
+    pub xrpl_tx_hash: Option<String>,
+    pub ticket_sequence: Option<u64>,
+    // Relayers that signed the XRPL transaction
+    pub signers: Vec<Addr>,
+    // Relayers whose evidences confirmed the transfer or the result of its XRPL transaction
+    pub evidence_relayers: Vec<Addr>,
+    pub refund_id: Option<String>,
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+// Amounts of a token the bridge is accountable for, updated on every operation that moves funds
+#[cw_serde]
+#[derive(Default)]
+pub struct TokenAccounting {
+    // Amount held for transfers to XRPL. For Coreum originated tokens it also includes the amount that is currently on XRPL
+    pub locked: Uint128,
+    // Amount minted by the bridge that wasn't burned yet. Only used for XRPL originated tokens
+    pub minted: Uint128,
+    // Amount held for failed transfers that can be claimed back by their senders
+    pub refund_reserved: Uint128,
+    // Amount of fees collected that were not claimed by the relayers yet, including undistributed remainders
+    pub fee_reserved: Uint128,
+}
+
+// Transfers, fees, refunds and operation results of the bridge during an epoch, used to report its activity
+#[cw_serde]
+#[derive(Default)]
+pub struct BridgeActivity {
+    pub coreum_to_xrpl_transfers: u64,
+    pub xrpl_to_coreum_transfers: u64,
+    // Amounts bridged in each direction before fees, one coin per denom
+    pub coreum_to_xrpl_volume: Vec<Coin>,
+    pub xrpl_to_coreum_volume: Vec<Coin>,
+    pub fees_collected: Vec<Coin>,
+    // Amounts of failed transfers to XRPL that became claimable by their senders
+    pub refunds: Vec<Coin>,
+    pub operations_accepted: u64,
+    // Operations that were rejected on XRPL or that were invalid
+    pub operations_failed: u64,
 }
 
 #[cw_serde]
@@ -65,6 +784,16 @@ pub struct XRPLToken {
     pub max_holding_amount: Uint128,
     pub state: TokenState,
     pub bridging_fee: Uint128,
+    // Tokens registered before rounding modes were introduced don't have one stored
+    #[serde(default)]
+    pub rounding_mode: RoundingMode,
+}
+
+#[cw_serde]
+pub struct TokenMetadata {
+    pub symbol: String,
+    pub description: Option<String>,
+    pub uri: Option<String>,
 }
 
 #[cw_serde]
@@ -79,6 +808,87 @@ pub enum TokenState {
     Inactive,
 }
 
+impl TokenState {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Enabled => "enabled",
+            Self::Disabled => "disabled",
+            Self::Processing => "processing",
+            Self::Inactive => "inactive",
+        }
+    }
+}
+
+// How the amount truncated because of the sending precision of a token is handled
+#[cw_serde]
+#[derive(Default)]
+pub enum RoundingMode {
+    // The truncated amount is collected as fees
+    #[default]
+    TruncateToFee,
+    // The amount is rounded down and the truncated dust is returned to the sender (or delivered to the recipient for transfers from XRPL)
+    RefundDust,
+    // Transfers to XRPL are rejected if their amount would be truncated
+    // Transfers from XRPL can't be rejected, so their dust is delivered to the recipient
+    RejectIfImprecise,
+    // The amount is rounded down and the truncated dust is kept as a pending refund that the sender can claim
+    // Transfers from XRPL have their dust delivered to the recipient
+    DustToPendingRefund,
+}
+
+// For responses
+impl RoundingMode {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::TruncateToFee => "truncate_to_fee",
+            Self::RefundDust => "refund_dust",
+            Self::RejectIfImprecise => "reject_if_imprecise",
+            Self::DustToPendingRefund => "dust_to_pending_refund",
+        }
+    }
+}
+
+#[cw_serde]
+pub enum TokenStateTransitionReason {
+    // The token was registered (or issued during instantiation in the case of XRP)
+    Registration,
+    // The TrustSet operation for the token was accepted on XRPL
+    TrustSetAccepted,
+    // The TrustSet operation for the token was rejected on XRPL or cancelled by the owner
+    TrustSetFailed,
+    // The owner triggered the TrustSet operation again for an inactive token
+    RegistrationRecovery,
+    // The owner updated the state of the token
+    OwnerUpdate,
+    // The issuer of the token enabled a global freeze or clawback on XRPL
+    IssuerConfigChange,
+    // Too many consecutive transfers of the token to XRPL were rejected
+    RepeatedOperationFailures,
+}
+
+impl TokenStateTransitionReason {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Registration => "registration",
+            Self::TrustSetAccepted => "trust_set_accepted",
+            Self::TrustSetFailed => "trust_set_failed",
+            Self::RegistrationRecovery => "registration_recovery",
+            Self::OwnerUpdate => "owner_update",
+            Self::IssuerConfigChange => "issuer_config_change",
+            Self::RepeatedOperationFailures => "repeated_operation_failures",
+        }
+    }
+}
+
+#[cw_serde]
+pub struct TokenStateTransition {
+    // Tokens that were just registered don't have a previous state
+    pub from: Option<TokenState>,
+    pub to: TokenState,
+    pub reason: TokenStateTransitionReason,
+    pub timestamp: u64,
+}
+
 #[cw_serde]
 pub struct CoreumToken {
     pub denom: String,
@@ -88,6 +898,9 @@ pub struct CoreumToken {
     pub max_holding_amount: Uint128,
     pub state: TokenState,
     pub bridging_fee: Uint128,
+    // Tokens registered before rounding modes were introduced don't have one stored
+    #[serde(default)]
+    pub rounding_mode: RoundingMode,
 }
 
 #[cw_serde]
@@ -101,16 +914,26 @@ pub struct PendingRefund {
     pub coin: Coin,
 }
 
-pub const CONFIG: Item<Config> = Item::new(TopKey::Config.as_str());
+pub const CONFIG: ConfigStore = ConfigStore {};
+pub const RELAYERS_CONFIG: Item<RelayersConfig> = Item::new(TopKey::RelayersConfig.as_str());
+pub const FEES_CONFIG: Item<FeesConfig> = Item::new(TopKey::FeesConfig.as_str());
+// Read on its own by the checks of the bridge state done by most executions
+pub const BRIDGE_STATE_CONFIG: Item<BridgeStateConfig> =
+    Item::new(TopKey::BridgeStateConfig.as_str());
+pub const XRPL_CONFIG: Item<XRPLConfig> = Item::new(TopKey::XRPLConfig.as_str());
+pub const OPERATIONS_CONFIG: Item<OperationsConfig> = Item::new(TopKey::OperationsConfig.as_str());
 // Tokens registered from XRPL side. These tokens are XRPL originated tokens - primary key is issuer+currency on XRPL
 // XRPLTokens will have coreum_denom as a secondary index so that we can get the XRPLToken corresponding to a coreum_denom
+// They are also indexed by issuer and state so that we can get all the tokens with one of them without going through all the tokens
 pub struct XRPLTokensIndexes<'a> {
     pub coreum_denom: UniqueIndex<'a, String, XRPLToken, String>,
+    pub issuer: MultiIndex<'a, String, XRPLToken, String>,
+    pub state: MultiIndex<'a, String, XRPLToken, String>,
 }
 
 impl<'a> IndexList<XRPLToken> for XRPLTokensIndexes<'a> {
     fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<XRPLToken>> + '_> {
-        let v: Vec<&dyn Index<XRPLToken>> = vec![&self.coreum_denom];
+        let v: Vec<&dyn Index<XRPLToken>> = vec![&self.coreum_denom, &self.issuer, &self.state];
         Box::new(v.into_iter())
     }
 }
@@ -122,17 +945,29 @@ pub const XRPL_TOKENS: IndexedMap<String, XRPLToken, XRPLTokensIndexes> = Indexe
             |xrpl_token| xrpl_token.coreum_denom.clone(),
             "xrpl_token__coreum_denom",
         ),
+        issuer: MultiIndex::new(
+            |_pk, xrpl_token: &XRPLToken| xrpl_token.issuer.clone(),
+            TopKey::XRPLTokens.as_str(),
+            "xrpl_token__issuer",
+        ),
+        state: MultiIndex::new(
+            |_pk, xrpl_token: &XRPLToken| xrpl_token.state.as_str().to_string(),
+            TopKey::XRPLTokens.as_str(),
+            "xrpl_token__state",
+        ),
     },
 );
 // Tokens registered from Coreum side. These tokens are coreum originated tokens that are registered to be bridged - key is denom on Coreum chain
 // CoreumTokens will have xrpl_currency as a secondary index so that we can get the CoreumToken corresponding to a xrpl_currency
+// They are also indexed by state so that we can get all the tokens in a state without going through all the tokens
 pub struct CoreumTokensIndexes<'a> {
     pub xrpl_currency: UniqueIndex<'a, String, CoreumToken, String>,
+    pub state: MultiIndex<'a, String, CoreumToken, String>,
 }
 
 impl<'a> IndexList<CoreumToken> for CoreumTokensIndexes<'a> {
     fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<CoreumToken>> + '_> {
-        let v: Vec<&dyn Index<CoreumToken>> = vec![&self.xrpl_currency];
+        let v: Vec<&dyn Index<CoreumToken>> = vec![&self.xrpl_currency, &self.state];
         Box::new(v.into_iter())
     }
 }
@@ -144,6 +979,11 @@ pub const COREUM_TOKENS: IndexedMap<String, CoreumToken, CoreumTokensIndexes> =
             |coreum_token| coreum_token.xrpl_currency.clone(),
             "coreum_token__xrpl_currency",
         ),
+        state: MultiIndex::new(
+            |_pk, coreum_token: &CoreumToken| coreum_token.state.as_str().to_string(),
+            TopKey::CoreumTokens.as_str(),
+            "coreum_token__state",
+        ),
     },
 );
 
@@ -153,6 +993,12 @@ pub const TX_EVIDENCES: Map<String, Evidences> = Map::new(TopKey::TxEvidences.as
 pub const PROCESSED_TXS: Map<String, Empty> = Map::new(TopKey::ProcessedTxs.as_str());
 // Current tickets available
 pub const AVAILABLE_TICKETS: Item<VecDeque<u64>> = Item::new(TopKey::AvailableTickets.as_str());
+// XRPL tokens (their keys) that were registered when there were no tickets available, waiting for their TrustSet operation to be created
+pub const QUEUED_XRPL_TOKEN_REGISTRATIONS: Item<VecDeque<String>> =
+    Item::new(TopKey::QueuedXRPLTokenRegistrations.as_str());
+// Reimbursement relayers get for triggering state transitions and the pool it's paid from. Not present until the owner configures it
+pub const RELAYER_REIMBURSEMENT: Item<RelayerReimbursement> =
+    Item::new(TopKey::RelayerReimbursement.as_str());
 // Counter we use to control the used tickets threshold.
 // If we surpass this counter, we will trigger a new allocation operation.
 // Every time we allocate new tickets (operation is accepted), we will substract the amount of new tickets allocated from this amount
@@ -198,6 +1044,897 @@ pub const FEE_REMAINDERS: Map<String, Uint128> = Map::new(TopKey::FeeRemainders.
 // XRPL addresses that have been marked as prohibited and can't be used for receiving funds, issuing tokens, or multisigning transactions
 pub const PROHIBITED_XRPL_ADDRESSES: Map<String, Empty> =
     Map::new(TopKey::ProhibitedXRPLAddresses.as_str());
+// XRPL transactions that were proven to be included in a validated ledger, waiting for their evidences to be processed
+// Key is the XRPL transaction hash
+pub const PROVEN_XRPL_TRANSACTIONS: Map<String, ProvenXRPLTransaction> =
+    Map::new(TopKey::ProvenXRPLTransactions.as_str());
+// Public keys that relayers must use to sign the content of their evidences, kept separately from their Coreum account keys
+// Key is the relayer Coreum address
+pub const EVIDENCE_SIGNING_KEYS: Map<Addr, String> = Map::new(TopKey::EvidenceSigningKeys.as_str());
+// Addresses that can save evidences and signatures on behalf of a relayer, so that the relayer address can be kept in cold storage
+// Key is the relayer Coreum address
+pub const EVIDENCE_DELEGATES: Map<Addr, Addr> = Map::new(TopKey::EvidenceDelegates.as_str());
+// Denom in which each relayer that opted in wants to receive all the fees it claims
+// Key is the relayer Coreum address
+pub const RELAYER_FEE_CONSOLIDATION_DENOMS: Map<Addr, String> =
+    Map::new(ExtendedTopKey::RelayerFeeConsolidationDenoms.as_str());
+// Minimum amount of a token from which transfers to XRPL must be committed before being revealed
+// Key is the Coreum denom
+pub const COMMIT_REVEAL_THRESHOLDS: Map<String, Uint128> =
+    Map::new(TopKey::CommitRevealThresholds.as_str());
+// Transfers to XRPL committed but not revealed yet
+// Key is the sender and the commitment
+pub const TRANSFER_COMMITMENTS: Map<(Addr, String), TransferCommitment> =
+    Map::new(TopKey::TransferCommitments.as_str());
+// Recurring transfers to XRPL that anyone can execute when they are due
+// Key is the recurring transfer id
+pub const RECURRING_TRANSFERS: Map<u64, RecurringTransfer> =
+    Map::new(TopKey::RecurringTransfers.as_str());
+// Used to generate the ids of recurring transfers
+pub const RECURRING_TRANSFERS_COUNTER: Item<u64> =
+    Item::new(TopKey::RecurringTransfersCounter.as_str());
+// XRPL recipients with settings that make transfers to them fail. Recipients that cleared them are removed
+// Key is the XRPL address of the recipient
+pub const XRPL_RECIPIENT_FLAGS: Map<String, XRPLRecipientFlags> =
+    Map::new(TopKey::XRPLRecipientFlags.as_str());
+// Last XRPL DEX/AMM quote published by each relayer
+// Key is the Coreum denom of the token and the relayer Coreum address
+pub const XRPL_DEX_QUOTES: Map<(String, Addr), XRPLDexQuote> =
+    Map::new(TopKey::XRPLDexQuotes.as_str());
+// Last XRP balance of the bridge XRPL account attested by each relayer
+pub const XRPL_BALANCE_ATTESTATIONS: Map<Addr, XRPLBalanceAttestation> =
+    Map::new(TopKey::XRPLBalanceAttestations.as_str());
+// Activity of each current relayer, used to exclude the ones that stopped working from fee splits and the evidence threshold
+pub const RELAYER_ACTIVITY: Map<Addr, RelayerActivity> = Map::new(TopKey::RelayerActivity.as_str());
+// Block time after which each pending operation can be expired by anyone if its evidence wasn't saved
+// Key is the operation id (ticket sequence or account sequence)
+pub const OPERATION_EVIDENCE_DEADLINES: Map<u64, u64> =
+    Map::new(TopKey::OperationEvidenceDeadlines.as_str());
+// Tokens whose bridging fee is set in USD
+// Key is the Coreum denom of the token
+pub const USD_BRIDGING_FEES: Map<String, USDBridgingFee> =
+    Map::new(TopKey::USDBridgingFees.as_str());
+// Last USD price of each token pushed by the price oracle
+// Key is the Coreum denom of the token
+pub const TOKEN_PRICES: Map<String, TokenPrice> = Map::new(TopKey::TokenPrices.as_str());
+// Supply drifts reported by relayers that weren't reconciled yet
+// Key is the XRPL transaction hash that caused the drift
+pub const XRPL_SUPPLY_DRIFTS: Map<String, XRPLSupplyDrift> =
+    Map::new(TopKey::XRPLSupplyDrifts.as_str());
+// Deposits from XRPL that couldn't be delivered because they didn't have a valid Coreum recipient
+// Key is the XRPL transaction hash of the deposit
+pub const INVALID_DEPOSITS: Map<String, InvalidDeposit> =
+    Map::new(TopKey::InvalidDeposits.as_str());
+// Relayers that requested the bridge to be halted. They are cleared every time the bridge state changes
+pub const HALT_REQUESTS: Item<Vec<Addr>> = Item::new(TopKey::HaltRequests.as_str());
+// Time at which each relayer acknowledged the current bridge state. They are cleared every time the bridge state changes
+pub const BRIDGE_STATE_ACKNOWLEDGEMENTS: Map<Addr, u64> =
+    Map::new(TopKey::BridgeStateAcknowledgements.as_str());
+// Accounting of the amounts the bridge is responsible for, per token
+// Key is the Coreum denom of the token
+pub const TOKEN_ACCOUNTING: Map<String, TokenAccounting> =
+    Map::new(TopKey::TokenAccounting.as_str());
+// Deliveries of Coreum originated tokens that are being executed as submessages, in the order they are executed
+// If a delivery fails (e.g. the token is frozen) it's kept as a pending refund (holdback) that the recipient can claim later
+pub const PENDING_DELIVERY: Item<Vec<PendingDelivery>> =
+    Item::new(TopKey::PendingDelivery.as_str());
+// Pending refunds that were created because an inbound transfer couldn't be delivered (holdbacks)
+// Key is the same as the one of the pending refund. These can also be returned to XRPL by their recipient
+pub const UNDELIVERED_TRANSFERS: Map<(Addr, String), Empty> =
+    Map::new(TopKey::UndeliveredTransfers.as_str());
+// Metadata of the Coreum FTs issued by the bridge for XRPL originated tokens
+// Key is the Coreum denom of the token
+pub const TOKEN_METADATA: Map<String, TokenMetadata> = Map::new(TopKey::TokenMetadata.as_str());
+// Most recent state transitions of each token, oldest first
+// Key is the Coreum denom of the token
+pub const TOKEN_STATE_HISTORY: Map<String, Vec<TokenStateTransition>> =
+    Map::new(TopKey::TokenStateHistory.as_str());
+// Lifecycle of every transfer, from the moment it's sent or confirmed until it's completed or refunded
+// Key is the id of the receipt
+pub const TRANSFER_RECEIPTS: Map<String, TransferReceipt> =
+    Map::new(TopKey::TransferReceipts.as_str());
+// Ids of the transfer receipts by the XRPL transaction hash (uppercase) of their transfer
+pub const TRANSFER_RECEIPT_IDS: Map<String, String> = Map::new(TopKey::TransferReceiptIds.as_str());
+// Operations of the transfers to XRPL that were split because their amount couldn't be represented on XRPL
+// Key is the id of the parent transfer, which is the operation id of the first chunk and the id of its receipt
+pub const TRANSFER_CHUNKS: Map<String, Vec<String>> = Map::new(TopKey::TransferChunks.as_str());
+// Rules that are checked for the recipient of every transfer to XRPL, in addition to the prohibited addresses
+// Key is the name of the rule
+pub const XRPL_RECIPIENT_RULES: Map<String, XRPLRecipientRule> =
+    Map::new(TopKey::XRPLRecipientRules.as_str());
+// Most recent fee claims of each relayer, so that the fees paid out can be reconciled without an external indexer
+// Key is the tuple (relayer, counter), so that the claims of a relayer are sorted from oldest to newest
+pub const RELAYER_FEE_CLAIMS: Map<(Addr, u64), RelayerFeeClaim> =
+    Map::new(TopKey::RelayerFeeClaims.as_str());
+// Counter used to build the keys of RELAYER_FEE_CLAIMS
+pub const RELAYER_FEE_CLAIMS_COUNTER: Item<u64> =
+    Item::new(TopKey::RelayerFeeClaimsCounter.as_str());
+// Activity of the bridge in the most recent epochs
+// Key is the epoch number
+pub const BRIDGE_ACTIVITY: Map<u64, BridgeActivity> = Map::new(TopKey::BridgeActivity.as_str());
+// Transfers to XRPL of each token rejected on XRPL since the last one that was accepted
+// Key is the Coreum denom of the token
+pub const TOKEN_FAILURES: Map<String, u32> = Map::new(TopKey::TokenFailures.as_str());
+// Relayers that were reported for providing conflicting evidences, which can't provide evidences for the transaction anymore
+// Key is the tuple (relayer, tx_hash)
+pub const RELAYER_INFRACTIONS: Map<(Addr, String), RelayerInfraction> =
+    Map::new(TopKey::RelayerInfractions.as_str());
+// Pending transfers to XRPL that were placed in the priority lane, which relayers sign before the rest of the pending operations
+// Key is the ticket (or account sequence) of the operation, like in PENDING_OPERATIONS
+pub const PRIORITY_OPERATIONS: Map<u64, Empty> =
+    Map::new(ExtendedTopKey::PriorityOperations.as_str());
+// Operations created by transfers to XRPL sent with an idempotency key, so that retrying the transfer doesn't create it again
+// Key is the tuple (sender, idempotency_key) and the value is the id of the operation
+pub const SEND_TO_XRPL_IDEMPOTENCY_KEYS: Map<(Addr, String), String> =
+    Map::new(ExtendedTopKey::SendToXRPLIdempotencyKeys.as_str());
+// Block in which each pending operation was created, used to find operations that are stuck
+// Key is the ticket (or account sequence) of the operation, like in PENDING_OPERATIONS
+pub const OPERATION_CREATIONS: Map<u64, OperationCreation> =
+    Map::new(ExtendedTopKey::OperationCreations.as_str());
+// Number of key rotations confirmed since relayer sets are tracked, which identifies the current relayer set
+pub const RELAYER_SET_EPOCH: Item<u64> = Item::new(ExtendedTopKey::RelayerSetEpoch.as_str());
+// Relayer sets replaced by key rotations
+// Key is the epoch in which the relayer set was active
+pub const RELAYER_SET_SNAPSHOTS: Map<u64, RelayerSetSnapshot> =
+    Map::new(ExtendedTopKey::RelayerSetSnapshots.as_str());
+// High risk actions waiting to be confirmed, only the last proposal of each action is kept
+// Key is the name of the action
+pub const PENDING_HIGH_RISK_ACTIONS: Map<String, PendingHighRiskAction> =
+    Map::new(ExtendedTopKey::PendingHighRiskActions.as_str());
+// Signatures and inbound deliveries performed by each relayer
+pub const RELAYER_WORKLOADS: Map<Addr, RelayerWorkload> =
+    Map::new(ExtendedTopKey::RelayerWorkloads.as_str());
+// Pending refunds that couldn't be claimed because their token is frozen, removed once the refund is claimed
+// Key is the same as in PENDING_REFUNDS
+pub const BLOCKED_CLAIMS: Map<(Addr, String), BlockedClaim> =
+    Map::new(ExtendedTopKey::BlockedClaims.as_str());
+// Transfers from XRPL of tokens in Processing or Inactive state, removed once they are delivered
+// Key is the tuple (XRPL token key, transfer id), so that the transfers of a token are kept together
+pub const DEFERRED_INBOUND_TRANSFERS: Map<(String, String), DeferredInboundTransfer> =
+    Map::new(ExtendedTopKey::DeferredInboundTransfers.as_str());
+// Last XRPL transaction submitted for each pending operation, removed once the operation is confirmed
+// Key is the ticket (or account sequence) of the operation, like in PENDING_OPERATIONS
+pub const OPERATION_SUBMISSIONS: Map<u64, OperationSubmission> =
+    Map::new(ExtendedTopKey::OperationSubmissions.as_str());
+// Source of XRPL data (e.g. the XRPL node) declared by each relayer, used to require results of operations to be confirmed from different sources
+pub const EVIDENCE_SOURCES: Map<Addr, String> = Map::new(ExtendedTopKey::EvidenceSources.as_str());
+// Last configuration attestation published by each relayer
+pub const RELAYER_ATTESTATIONS: Map<Addr, RelayerAttestation> =
+    Map::new(ExtendedTopKey::RelayerAttestations.as_str());
+// Emergency withdrawal scheduled by chain governance, if any
+pub const EMERGENCY_WITHDRAWAL: Item<EmergencyWithdrawal> =
+    Item::new(ExtendedTopKey::EmergencyWithdrawal.as_str());
+// Fees charged explicitly for each token, key is the denom
+pub const FEE_ACCOUNTING: Map<String, FeeAccounting> =
+    Map::new(ExtendedTopKey::FeeAccounting.as_str());
+// Most recent transfers from XRPL delivered to each recipient, so that they can be listed without an external indexer
+// Key is the tuple (recipient, counter), so that the transfers of a recipient are sorted from oldest to newest
+pub const INBOUND_TRANSFERS: Map<(Addr, u64), InboundTransfer> =
+    Map::new(TopKey::InboundTransfers.as_str());
+// Counter used to build the keys of INBOUND_TRANSFERS
+pub const INBOUND_TRANSFERS_COUNTER: Item<u64> =
+    Item::new(TopKey::InboundTransfersCounter.as_str());
+// Relayers that requested a token to be frozen because they detected anomalous activity for it
+// Key is the Coreum denom of the token
+pub const TOKEN_FREEZE_REQUESTS: Map<String, Vec<Addr>> =
+    Map::new(TopKey::TokenFreezeRequests.as_str());
+// Tokens frozen by relayers, transfers of these tokens are rejected in both directions until the freeze expires or is lifted
+// Key is the Coreum denom of the token
+pub const TOKEN_FREEZES: Map<String, TokenFreeze> = Map::new(TopKey::TokenFreezes.as_str());
+// Account settings of the issuers of registered XRPL tokens, only present once a change was reported
+// Key is the XRPL address of the issuer
+pub const XRPL_ISSUER_CONFIGS: Map<String, XRPLIssuerConfig> =
+    Map::new(TopKey::XRPLIssuerConfigs.as_str());
+// Most recently resolved operations, removed from PENDING_OPERATIONS once confirmed or cancelled
+// Key is a counter so that operations are sorted from the oldest to the most recently resolved one
+pub const OPERATION_ARCHIVE: Map<u64, ArchivedOperation> =
+    Map::new(TopKey::OperationArchive.as_str());
+// Counter used to build the keys of OPERATION_ARCHIVE
+pub const OPERATION_ARCHIVE_COUNTER: Item<u64> =
+    Item::new(TopKey::OperationArchiveCounter.as_str());
+// Dust the bridge kept for each token. Fee division remainders are kept in FEE_REMAINDERS
+// Key is the Coreum denom of the token
+pub const DUST_ACCOUNTING: Map<String, DustAccounting> = Map::new(TopKey::DustAccounting.as_str());
+
+pub enum StorageKind {
+    Item,
+    Map,
+    IndexedMap,
+    UniqueIndex,
+    MultiIndex,
+}
+
+impl StorageKind {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Item => "item",
+            Self::Map => "map",
+            Self::IndexedMap => "indexed_map",
+            Self::UniqueIndex => "unique_index",
+            Self::MultiIndex => "multi_index",
+        }
+    }
+}
+
+pub struct StorageDescriptor {
+    pub name: &'static str,
+    pub namespace: &'static str,
+    pub kind: StorageKind,
+    // Types that compose the key, in order. Empty for items
+    pub key_encoding: &'static str,
+    pub value_type: &'static str,
+    // Must be increased every time the key or the value of an entry is stored differently
+    pub version: u32,
+}
+
+// Layout of the contract state, for indexers that read it with raw queries
+// Keys follow the cw-storage-plus encoding and values are stored as JSON
+pub const STORAGE_LAYOUT: &[StorageDescriptor] = &[
+    StorageDescriptor {
+        name: "contract_info",
+        namespace: "contract_info",
+        kind: StorageKind::Item,
+        key_encoding: "",
+        value_type: "ContractVersion",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "ownership",
+        namespace: "ownership",
+        kind: StorageKind::Item,
+        key_encoding: "",
+        value_type: "Ownership<Addr>",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "relayers_config",
+        namespace: TopKey::RelayersConfig.as_str(),
+        kind: StorageKind::Item,
+        key_encoding: "",
+        value_type: "RelayersConfig",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "fees_config",
+        namespace: TopKey::FeesConfig.as_str(),
+        kind: StorageKind::Item,
+        key_encoding: "",
+        value_type: "FeesConfig",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "bridge_state_config",
+        namespace: TopKey::BridgeStateConfig.as_str(),
+        kind: StorageKind::Item,
+        key_encoding: "",
+        value_type: "BridgeStateConfig",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "xrpl_config",
+        namespace: TopKey::XRPLConfig.as_str(),
+        kind: StorageKind::Item,
+        key_encoding: "",
+        value_type: "XRPLConfig",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "operations_config",
+        namespace: TopKey::OperationsConfig.as_str(),
+        kind: StorageKind::Item,
+        key_encoding: "",
+        value_type: "OperationsConfig",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "tx_evidences",
+        namespace: TopKey::TxEvidences.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "string",
+        value_type: "Evidences",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "processed_txs",
+        namespace: TopKey::ProcessedTxs.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "string",
+        value_type: "Empty",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "coreum_tokens",
+        namespace: TopKey::CoreumTokens.as_str(),
+        kind: StorageKind::IndexedMap,
+        key_encoding: "string",
+        value_type: "CoreumToken",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "coreum_token__xrpl_currency",
+        namespace: "coreum_token__xrpl_currency",
+        kind: StorageKind::UniqueIndex,
+        key_encoding: "string",
+        value_type: "CoreumToken",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "coreum_token__state",
+        namespace: "coreum_token__state",
+        kind: StorageKind::MultiIndex,
+        key_encoding: "(string, string)",
+        value_type: "CoreumToken",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "xrpl_tokens",
+        namespace: TopKey::XRPLTokens.as_str(),
+        kind: StorageKind::IndexedMap,
+        key_encoding: "string",
+        value_type: "XRPLToken",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "xrpl_token__coreum_denom",
+        namespace: "xrpl_token__coreum_denom",
+        kind: StorageKind::UniqueIndex,
+        key_encoding: "string",
+        value_type: "XRPLToken",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "xrpl_token__issuer",
+        namespace: "xrpl_token__issuer",
+        kind: StorageKind::MultiIndex,
+        key_encoding: "(string, string)",
+        value_type: "XRPLToken",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "xrpl_token__state",
+        namespace: "xrpl_token__state",
+        kind: StorageKind::MultiIndex,
+        key_encoding: "(string, string)",
+        value_type: "XRPLToken",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "available_tickets",
+        namespace: TopKey::AvailableTickets.as_str(),
+        kind: StorageKind::Item,
+        key_encoding: "",
+        value_type: "VecDeque<u64>",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "used_tickets",
+        namespace: TopKey::UsedTickets.as_str(),
+        kind: StorageKind::Item,
+        key_encoding: "",
+        value_type: "u32",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "pending_operations",
+        namespace: TopKey::PendingOperations.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "u64",
+        value_type: "Operation",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "pending_ticket_update",
+        namespace: TopKey::PendingTicketUpdate.as_str(),
+        kind: StorageKind::Item,
+        key_encoding: "",
+        value_type: "bool",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "pending_refunds",
+        namespace: TopKey::PendingRefunds.as_str(),
+        kind: StorageKind::IndexedMap,
+        key_encoding: "(addr, string)",
+        value_type: "PendingRefund",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "pending_refund__address",
+        namespace: "pending_refund__address",
+        kind: StorageKind::MultiIndex,
+        key_encoding: "(addr, (addr, string))",
+        value_type: "PendingRefund",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "fees_collected",
+        namespace: TopKey::FeesCollected.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "addr",
+        value_type: "Vec<Coin>",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "fee_remainders",
+        namespace: TopKey::FeeRemainders.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "string",
+        value_type: "Uint128",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "pending_rotate_keys",
+        namespace: TopKey::PendingRotateKeys.as_str(),
+        kind: StorageKind::Item,
+        key_encoding: "",
+        value_type: "bool",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "prohibited_xrpl_addresses",
+        namespace: TopKey::ProhibitedXRPLAddresses.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "string",
+        value_type: "Empty",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "proven_xrpl_transactions",
+        namespace: TopKey::ProvenXRPLTransactions.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "string",
+        value_type: "ProvenXRPLTransaction",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "evidence_signing_keys",
+        namespace: TopKey::EvidenceSigningKeys.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "addr",
+        value_type: "String",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "halt_requests",
+        namespace: TopKey::HaltRequests.as_str(),
+        kind: StorageKind::Item,
+        key_encoding: "",
+        value_type: "Vec<Addr>",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "bridge_state_acknowledgements",
+        namespace: TopKey::BridgeStateAcknowledgements.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "addr",
+        value_type: "u64",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "token_accounting",
+        namespace: TopKey::TokenAccounting.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "string",
+        value_type: "TokenAccounting",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "pending_delivery",
+        namespace: TopKey::PendingDelivery.as_str(),
+        kind: StorageKind::Item,
+        key_encoding: "",
+        value_type: "Vec<PendingDelivery>",
+        version: 2,
+    },
+    StorageDescriptor {
+        name: "undelivered_transfers",
+        namespace: TopKey::UndeliveredTransfers.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "(addr, string)",
+        value_type: "Empty",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "token_metadata",
+        namespace: TopKey::TokenMetadata.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "string",
+        value_type: "TokenMetadata",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "token_state_history",
+        namespace: TopKey::TokenStateHistory.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "string",
+        value_type: "Vec<TokenStateTransition>",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "queued_xrpl_token_registrations",
+        namespace: TopKey::QueuedXRPLTokenRegistrations.as_str(),
+        kind: StorageKind::Item,
+        key_encoding: "",
+        value_type: "VecDeque<String>",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "relayer_reimbursement",
+        namespace: TopKey::RelayerReimbursement.as_str(),
+        kind: StorageKind::Item,
+        key_encoding: "",
+        value_type: "RelayerReimbursement",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "transfer_receipts",
+        namespace: TopKey::TransferReceipts.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "string",
+        value_type: "TransferReceipt",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "transfer_receipt_ids",
+        namespace: TopKey::TransferReceiptIds.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "string",
+        value_type: "String",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "transfer_chunks",
+        namespace: TopKey::TransferChunks.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "string",
+        value_type: "Vec<String>",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "xrpl_recipient_rules",
+        namespace: TopKey::XRPLRecipientRules.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "string",
+        value_type: "XRPLRecipientRule",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "relayer_fee_claims",
+        namespace: TopKey::RelayerFeeClaims.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "(addr, u64)",
+        value_type: "RelayerFeeClaim",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "relayer_fee_claims_counter",
+        namespace: TopKey::RelayerFeeClaimsCounter.as_str(),
+        kind: StorageKind::Item,
+        key_encoding: "",
+        value_type: "u64",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "bridge_activity",
+        namespace: TopKey::BridgeActivity.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "u64",
+        value_type: "BridgeActivity",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "token_failures",
+        namespace: TopKey::TokenFailures.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "string",
+        value_type: "u32",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "relayer_infractions",
+        namespace: TopKey::RelayerInfractions.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "(addr, string)",
+        value_type: "RelayerInfraction",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "priority_operations",
+        namespace: ExtendedTopKey::PriorityOperations.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "u64",
+        value_type: "Empty",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "send_to_xrpl_idempotency_keys",
+        namespace: ExtendedTopKey::SendToXRPLIdempotencyKeys.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "(addr, string)",
+        value_type: "String",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "operation_creations",
+        namespace: ExtendedTopKey::OperationCreations.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "u64",
+        value_type: "OperationCreation",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "relayer_set_epoch",
+        namespace: ExtendedTopKey::RelayerSetEpoch.as_str(),
+        kind: StorageKind::Item,
+        key_encoding: "",
+        value_type: "u64",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "relayer_set_snapshots",
+        namespace: ExtendedTopKey::RelayerSetSnapshots.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "u64",
+        value_type: "RelayerSetSnapshot",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "pending_high_risk_actions",
+        namespace: ExtendedTopKey::PendingHighRiskActions.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "string",
+        value_type: "PendingHighRiskAction",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "relayer_workloads",
+        namespace: ExtendedTopKey::RelayerWorkloads.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "addr",
+        value_type: "RelayerWorkload",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "blocked_claims",
+        namespace: ExtendedTopKey::BlockedClaims.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "(addr, string)",
+        value_type: "BlockedClaim",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "deferred_inbound_transfers",
+        namespace: ExtendedTopKey::DeferredInboundTransfers.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "(string, string)",
+        value_type: "DeferredInboundTransfer",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "operation_submissions",
+        namespace: ExtendedTopKey::OperationSubmissions.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "u64",
+        value_type: "OperationSubmission",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "evidence_sources",
+        namespace: ExtendedTopKey::EvidenceSources.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "addr",
+        value_type: "String",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "relayer_attestations",
+        namespace: ExtendedTopKey::RelayerAttestations.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "addr",
+        value_type: "RelayerAttestation",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "emergency_withdrawal",
+        namespace: ExtendedTopKey::EmergencyWithdrawal.as_str(),
+        kind: StorageKind::Item,
+        key_encoding: "",
+        value_type: "EmergencyWithdrawal",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "fee_accounting",
+        namespace: ExtendedTopKey::FeeAccounting.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "string",
+        value_type: "FeeAccounting",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "relayer_fee_consolidation_denoms",
+        namespace: ExtendedTopKey::RelayerFeeConsolidationDenoms.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "addr",
+        value_type: "String",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "inbound_transfers",
+        namespace: TopKey::InboundTransfers.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "(addr, u64)",
+        value_type: "InboundTransfer",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "inbound_transfers_counter",
+        namespace: TopKey::InboundTransfersCounter.as_str(),
+        kind: StorageKind::Item,
+        key_encoding: "",
+        value_type: "u64",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "token_freeze_requests",
+        namespace: TopKey::TokenFreezeRequests.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "string",
+        value_type: "Vec<Addr>",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "token_freezes",
+        namespace: TopKey::TokenFreezes.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "string",
+        value_type: "TokenFreeze",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "xrpl_issuer_configs",
+        namespace: TopKey::XRPLIssuerConfigs.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "string",
+        value_type: "XRPLIssuerConfig",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "operation_archive",
+        namespace: TopKey::OperationArchive.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "u64",
+        value_type: "ArchivedOperation",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "operation_archive_counter",
+        namespace: TopKey::OperationArchiveCounter.as_str(),
+        kind: StorageKind::Item,
+        key_encoding: "",
+        value_type: "u64",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "dust_accounting",
+        namespace: TopKey::DustAccounting.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "string",
+        value_type: "DustAccounting",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "evidence_delegates",
+        namespace: TopKey::EvidenceDelegates.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "addr",
+        value_type: "Addr",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "commit_reveal_thresholds",
+        namespace: TopKey::CommitRevealThresholds.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "string",
+        value_type: "Uint128",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "transfer_commitments",
+        namespace: TopKey::TransferCommitments.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "(addr, string)",
+        value_type: "TransferCommitment",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "recurring_transfers",
+        namespace: TopKey::RecurringTransfers.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "u64",
+        value_type: "RecurringTransfer",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "recurring_transfers_counter",
+        namespace: TopKey::RecurringTransfersCounter.as_str(),
+        kind: StorageKind::Item,
+        key_encoding: "",
+        value_type: "u64",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "xrpl_recipient_flags",
+        namespace: TopKey::XRPLRecipientFlags.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "string",
+        value_type: "XRPLRecipientFlags",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "xrpl_dex_quotes",
+        namespace: TopKey::XRPLDexQuotes.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "(string, addr)",
+        value_type: "XRPLDexQuote",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "xrpl_balance_attestations",
+        namespace: TopKey::XRPLBalanceAttestations.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "addr",
+        value_type: "XRPLBalanceAttestation",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "relayer_activity",
+        namespace: TopKey::RelayerActivity.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "addr",
+        value_type: "RelayerActivity",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "operation_evidence_deadlines",
+        namespace: TopKey::OperationEvidenceDeadlines.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "u64",
+        value_type: "u64",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "usd_bridging_fees",
+        namespace: TopKey::USDBridgingFees.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "string",
+        value_type: "USDBridgingFee",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "token_prices",
+        namespace: TopKey::TokenPrices.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "string",
+        value_type: "TokenPrice",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "xrpl_supply_drifts",
+        namespace: TopKey::XRPLSupplyDrifts.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "string",
+        value_type: "XRPLSupplyDrift",
+        version: 1,
+    },
+    StorageDescriptor {
+        name: "invalid_deposits",
+        namespace: TopKey::InvalidDeposits.as_str(),
+        kind: StorageKind::Map,
+        key_encoding: "string",
+        value_type: "InvalidDeposit",
+        version: 1,
+    },
+];
 
 pub enum ContractActions {
     Instantiation,
@@ -212,12 +1949,83 @@ pub enum ContractActions {
     UpdateXRPLToken,
     UpdateCoreumToken,
     UpdateXRPLBaseFee,
-    UpdateProhibitedXRPLAddresses,
+    AddProhibitedXRPLRecipient,
+    RemoveProhibitedXRPLRecipient,
+    SetXRPLRecipientRule,
+    RemoveXRPLRecipientRule,
     ClaimRefunds,
     HaltBridge,
     ResumeBridge,
     RotateKeys,
     CancelPendingOperation,
+    UpdateLightClientConfig,
+    SaveXRPLTransactionProof,
+    UpdateEvidenceSigningKey,
+    UpdateOperationXRPLBaseFee,
+    StartMaintenance,
+    RequestHalt,
+    UpdateHaltRequestThreshold,
+    RescueFunds,
+    ReturnUndeliveredTransfer,
+    UpdateXRPLTokenMetadata,
+    UpdateMaxRegisteredXRPLTokens,
+    UpdateRelayerReimbursement,
+    FundReimbursementPool,
+    RequestTokenFreeze,
+    ConfirmTokenFreeze,
+    UnfreezeToken,
+    UpdateTokenFreezeConfig,
+    UpdateAutoDisableOnIssuerChange,
+    UpdateTokenFailureThreshold,
+    UpdatePriorityFee,
+    UpdateOperationArchiveSize,
+    PruneOperationArchive,
+    UpdateEvidenceTTL,
+    PruneStaleEvidences,
+    SweepFeeRemainder,
+    UpdateEvidenceDelegate,
+    UpdateComplianceContract,
+    CommitSendToXRPL,
+    RevealSendToXRPL,
+    CancelSendToXRPLCommitment,
+    UpdateCommitRevealThreshold,
+    UpdateCommitRevealConfig,
+    CreateRecurringTransfer,
+    FundRecurringTransfer,
+    CancelRecurringTransfer,
+    ExecuteRecurringTransfer,
+    Crank,
+    UpdateCrankReward,
+    PublishXRPLDexQuote,
+    UpdateXRPLDexQuoteTTL,
+    UpdatePriceOracle,
+    UpdateUSDBridgingFee,
+    UpdateTokenPrices,
+    ReconcileXRPLTokenSupply,
+    AssignInvalidDeposit,
+    AttestBridgeXRPLBalance,
+    UpdateXRPLReserves,
+    MarkRelayerInactive,
+    UpdateRelayerInactivityPeriod,
+    ExpireOperation,
+    UpdateOperationEvidencePeriod,
+    RebasePendingOperations,
+    AcknowledgeBridgeState,
+    ReportConflictingEvidences,
+    CancelHaltedTransfer,
+    CancelPendingOperations,
+    UpdateFeeConverterContract,
+    UpdateFeeConsolidationDenom,
+    GloballyFreezeToken,
+    GloballyUnfreezeToken,
+    SaveTransactionSubmission,
+    UpdateEvidenceSource,
+    UpdateMinDistinctEvidenceSources,
+    UpdateRelayerAttestation,
+    ScheduleEmergencyWithdrawal,
+    CancelEmergencyWithdrawal,
+    ExecuteEmergencyWithdrawal,
+    UpdateMaxRelayers,
 }
 
 pub enum UserType {
@@ -240,12 +2048,87 @@ impl UserType {
             ContractActions::UpdateXRPLToken => matches!(self, Self::Owner),
             ContractActions::UpdateCoreumToken => matches!(self, Self::Owner),
             ContractActions::UpdateXRPLBaseFee => matches!(self, Self::Owner),
-            ContractActions::UpdateProhibitedXRPLAddresses => matches!(self, Self::Owner),
+            ContractActions::AddProhibitedXRPLRecipient => matches!(self, Self::Owner),
+            ContractActions::RemoveProhibitedXRPLRecipient => matches!(self, Self::Owner),
+            ContractActions::SetXRPLRecipientRule => matches!(self, Self::Owner),
+            ContractActions::RemoveXRPLRecipientRule => matches!(self, Self::Owner),
             ContractActions::ClaimRefunds => true,
             ContractActions::HaltBridge => matches!(self, Self::Owner | Self::Relayer),
             ContractActions::ResumeBridge => matches!(self, Self::Owner),
             ContractActions::RotateKeys => matches!(self, Self::Owner),
             ContractActions::CancelPendingOperation => matches!(self, Self::Owner),
+            ContractActions::UpdateLightClientConfig => matches!(self, Self::Owner),
+            ContractActions::SaveXRPLTransactionProof => matches!(self, Self::Relayer),
+            ContractActions::UpdateEvidenceSigningKey => matches!(self, Self::Owner),
+            ContractActions::UpdateOperationXRPLBaseFee => matches!(self, Self::Owner),
+            ContractActions::StartMaintenance => matches!(self, Self::Owner),
+            ContractActions::RequestHalt => matches!(self, Self::Relayer),
+            ContractActions::UpdateHaltRequestThreshold => matches!(self, Self::Owner),
+            ContractActions::RescueFunds => matches!(self, Self::Owner),
+            ContractActions::ReturnUndeliveredTransfer => true,
+            ContractActions::UpdateXRPLTokenMetadata => matches!(self, Self::Owner),
+            ContractActions::UpdateMaxRegisteredXRPLTokens => matches!(self, Self::Owner),
+            ContractActions::UpdateRelayerReimbursement => matches!(self, Self::Owner),
+            ContractActions::FundReimbursementPool => matches!(self, Self::Owner),
+            ContractActions::RequestTokenFreeze => matches!(self, Self::Relayer),
+            ContractActions::ConfirmTokenFreeze => matches!(self, Self::Owner),
+            ContractActions::UnfreezeToken => matches!(self, Self::Owner),
+            ContractActions::UpdateTokenFreezeConfig => matches!(self, Self::Owner),
+            ContractActions::UpdateAutoDisableOnIssuerChange => matches!(self, Self::Owner),
+            ContractActions::UpdateTokenFailureThreshold => matches!(self, Self::Owner),
+            ContractActions::UpdatePriorityFee => matches!(self, Self::Owner),
+            ContractActions::UpdateOperationArchiveSize => matches!(self, Self::Owner),
+            ContractActions::PruneOperationArchive => matches!(self, Self::Owner),
+            ContractActions::UpdateEvidenceTTL => matches!(self, Self::Owner),
+            ContractActions::PruneStaleEvidences => true,
+            ContractActions::SweepFeeRemainder => matches!(self, Self::Owner),
+            ContractActions::UpdateEvidenceDelegate => matches!(self, Self::Relayer),
+            ContractActions::UpdateComplianceContract => matches!(self, Self::Owner),
+            ContractActions::CommitSendToXRPL => true,
+            ContractActions::RevealSendToXRPL => true,
+            ContractActions::CancelSendToXRPLCommitment => true,
+            ContractActions::UpdateCommitRevealThreshold => matches!(self, Self::Owner),
+            ContractActions::UpdateCommitRevealConfig => matches!(self, Self::Owner),
+            ContractActions::CreateRecurringTransfer => true,
+            ContractActions::FundRecurringTransfer => true,
+            ContractActions::CancelRecurringTransfer => true,
+            ContractActions::ExecuteRecurringTransfer => true,
+            ContractActions::Crank => true,
+            ContractActions::UpdateCrankReward => matches!(self, Self::Owner),
+            ContractActions::PublishXRPLDexQuote => matches!(self, Self::Relayer),
+            ContractActions::UpdateXRPLDexQuoteTTL => matches!(self, Self::Owner),
+            ContractActions::UpdatePriceOracle => matches!(self, Self::Owner),
+            ContractActions::UpdateUSDBridgingFee => matches!(self, Self::Owner),
+            // Only the price oracle can push prices, which is checked when they are updated
+            ContractActions::UpdateTokenPrices => true,
+            ContractActions::ReconcileXRPLTokenSupply => matches!(self, Self::Owner),
+            ContractActions::AssignInvalidDeposit => matches!(self, Self::Owner),
+            ContractActions::AttestBridgeXRPLBalance => matches!(self, Self::Relayer),
+            ContractActions::UpdateXRPLReserves => matches!(self, Self::Owner),
+            ContractActions::MarkRelayerInactive => matches!(self, Self::Owner),
+            ContractActions::UpdateRelayerInactivityPeriod => matches!(self, Self::Owner),
+            ContractActions::ExpireOperation => true,
+            ContractActions::UpdateOperationEvidencePeriod => matches!(self, Self::Owner),
+            ContractActions::RebasePendingOperations => matches!(self, Self::Owner),
+            ContractActions::AcknowledgeBridgeState => matches!(self, Self::Relayer),
+            ContractActions::ReportConflictingEvidences => true,
+            // Only the sender of the transfer can cancel it, which is checked when it's cancelled
+            ContractActions::CancelHaltedTransfer => true,
+            ContractActions::CancelPendingOperations => matches!(self, Self::Owner),
+            ContractActions::UpdateFeeConverterContract => matches!(self, Self::Owner),
+            ContractActions::UpdateFeeConsolidationDenom => matches!(self, Self::Relayer),
+            ContractActions::GloballyFreezeToken => matches!(self, Self::Owner),
+            ContractActions::GloballyUnfreezeToken => matches!(self, Self::Owner),
+            ContractActions::SaveTransactionSubmission => matches!(self, Self::Relayer),
+            ContractActions::UpdateEvidenceSource => matches!(self, Self::Relayer),
+            ContractActions::UpdateMinDistinctEvidenceSources => matches!(self, Self::Owner),
+            ContractActions::UpdateRelayerAttestation => matches!(self, Self::Relayer),
+            // Only chain governance can do this through sudo
+            ContractActions::ScheduleEmergencyWithdrawal => false,
+            ContractActions::CancelEmergencyWithdrawal => false,
+            // Funds can only be sent to the recipient chosen by governance, once the delay passed
+            ContractActions::ExecuteEmergencyWithdrawal => true,
+            ContractActions::UpdateMaxRelayers => matches!(self, Self::Owner),
         }
     }
 }
@@ -266,11 +2149,82 @@ impl ContractActions {
             Self::UpdateXRPLToken => "update_xrpl_token",
             Self::UpdateCoreumToken => "update_coreum_token",
             Self::UpdateXRPLBaseFee => "update_xrpl_base_fee",
-            Self::UpdateProhibitedXRPLAddresses => "update_invalid_xrpl_addresses",
+            Self::AddProhibitedXRPLRecipient => "add_prohibited_xrpl_recipient",
+            Self::RemoveProhibitedXRPLRecipient => "remove_prohibited_xrpl_recipient",
+            Self::SetXRPLRecipientRule => "set_xrpl_recipient_rule",
+            Self::RemoveXRPLRecipientRule => "remove_xrpl_recipient_rule",
             Self::HaltBridge => "halt_bridge",
             Self::ResumeBridge => "resume_bridge",
             Self::RotateKeys => "rotate_keys",
             Self::CancelPendingOperation => "cancel_pending_operation",
+            Self::UpdateLightClientConfig => "update_light_client_config",
+            Self::SaveXRPLTransactionProof => "save_xrpl_transaction_proof",
+            Self::UpdateEvidenceSigningKey => "update_evidence_signing_key",
+            Self::UpdateOperationXRPLBaseFee => "update_operation_xrpl_base_fee",
+            Self::StartMaintenance => "start_maintenance",
+            Self::RequestHalt => "request_halt",
+            Self::UpdateHaltRequestThreshold => "update_halt_request_threshold",
+            Self::RescueFunds => "rescue_funds",
+            Self::ReturnUndeliveredTransfer => "return_undelivered_transfer",
+            Self::UpdateXRPLTokenMetadata => "update_xrpl_token_metadata",
+            Self::UpdateMaxRegisteredXRPLTokens => "update_max_registered_xrpl_tokens",
+            Self::UpdateRelayerReimbursement => "update_relayer_reimbursement",
+            Self::FundReimbursementPool => "fund_reimbursement_pool",
+            Self::RequestTokenFreeze => "request_token_freeze",
+            Self::ConfirmTokenFreeze => "confirm_token_freeze",
+            Self::UnfreezeToken => "unfreeze_token",
+            Self::UpdateTokenFreezeConfig => "update_token_freeze_config",
+            Self::UpdateAutoDisableOnIssuerChange => "update_auto_disable_on_issuer_change",
+            Self::UpdateTokenFailureThreshold => "update_token_failure_threshold",
+            Self::UpdatePriorityFee => "update_priority_fee",
+            Self::UpdateOperationArchiveSize => "update_operation_archive_size",
+            Self::PruneOperationArchive => "prune_operation_archive",
+            Self::UpdateEvidenceTTL => "update_evidence_ttl",
+            Self::PruneStaleEvidences => "prune_stale_evidences",
+            Self::SweepFeeRemainder => "sweep_fee_remainder",
+            Self::UpdateEvidenceDelegate => "update_evidence_delegate",
+            Self::UpdateComplianceContract => "update_compliance_contract",
+            Self::CommitSendToXRPL => "commit_send_to_xrpl",
+            Self::RevealSendToXRPL => "reveal_send_to_xrpl",
+            Self::CancelSendToXRPLCommitment => "cancel_send_to_xrpl_commitment",
+            Self::UpdateCommitRevealThreshold => "update_commit_reveal_threshold",
+            Self::UpdateCommitRevealConfig => "update_commit_reveal_config",
+            Self::CreateRecurringTransfer => "create_recurring_transfer",
+            Self::FundRecurringTransfer => "fund_recurring_transfer",
+            Self::CancelRecurringTransfer => "cancel_recurring_transfer",
+            Self::ExecuteRecurringTransfer => "execute_recurring_transfer",
+            Self::Crank => "crank",
+            Self::UpdateCrankReward => "update_crank_reward",
+            Self::PublishXRPLDexQuote => "publish_xrpl_dex_quote",
+            Self::UpdateXRPLDexQuoteTTL => "update_xrpl_dex_quote_ttl",
+            Self::UpdatePriceOracle => "update_price_oracle",
+            Self::UpdateUSDBridgingFee => "update_usd_bridging_fee",
+            Self::UpdateTokenPrices => "update_token_prices",
+            Self::ReconcileXRPLTokenSupply => "reconcile_xrpl_token_supply",
+            Self::AssignInvalidDeposit => "assign_invalid_deposit",
+            Self::AttestBridgeXRPLBalance => "attest_bridge_xrpl_balance",
+            Self::UpdateXRPLReserves => "update_xrpl_reserves",
+            Self::MarkRelayerInactive => "mark_relayer_inactive",
+            Self::UpdateRelayerInactivityPeriod => "update_relayer_inactivity_period",
+            Self::ExpireOperation => "expire_operation",
+            Self::UpdateOperationEvidencePeriod => "update_operation_evidence_period",
+            Self::RebasePendingOperations => "rebase_pending_operations",
+            Self::AcknowledgeBridgeState => "acknowledge_bridge_state",
+            Self::ReportConflictingEvidences => "report_conflicting_evidences",
+            Self::CancelHaltedTransfer => "cancel_halted_transfer",
+            Self::CancelPendingOperations => "cancel_pending_operations",
+            Self::UpdateFeeConverterContract => "update_fee_converter_contract",
+            Self::UpdateFeeConsolidationDenom => "update_fee_consolidation_denom",
+            Self::GloballyFreezeToken => "globally_freeze_token",
+            Self::GloballyUnfreezeToken => "globally_unfreeze_token",
+            Self::SaveTransactionSubmission => "save_transaction_submission",
+            Self::UpdateEvidenceSource => "update_evidence_source",
+            Self::UpdateMinDistinctEvidenceSources => "update_min_distinct_evidence_sources",
+            Self::UpdateRelayerAttestation => "update_relayer_attestation",
+            Self::ScheduleEmergencyWithdrawal => "schedule_emergency_withdrawal",
+            Self::CancelEmergencyWithdrawal => "cancel_emergency_withdrawal",
+            Self::ExecuteEmergencyWithdrawal => "execute_emergency_withdrawal",
+            Self::UpdateMaxRelayers => "update_max_relayers",
         }
     }
 }
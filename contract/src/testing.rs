@@ -0,0 +1,313 @@
+// Helpers to write scenario tests against the contract with test-tube, so that integrators don't have to copy the setup of the contract tests.
+// Helpers panic if any of the steps fails, like the tests that use them
+use coreum_test_tube::{Account, AssetFT, CoreumTestApp, Module, SigningAccount, Wasm};
+use coreum_wasm_sdk::types::coreum::asset::ft::v1::QueryParamsRequest;
+use cosmwasm_std::{coins, Addr, Coin, Uint128};
+use rand::{distributions::Alphanumeric, thread_rng, Rng};
+use ripple_keypairs::Seed;
+
+use crate::{
+    evidence::{Evidence, OperationResult, TransactionResult},
+    msg::{ExecuteMsg, InstantiateMsg, PendingOperationsResponse, QueryMsg},
+    operation::{Operation, OperationType},
+    relayer::Relayer,
+    state::XRPLNetwork,
+};
+
+pub const DEFAULT_TRUST_SET_LIMIT_AMOUNT: u128 = 1_000_000_000_000_000_000; // 1e18
+pub const DEFAULT_USED_TICKET_SEQUENCE_THRESHOLD: u32 = 50;
+pub const DEFAULT_XRPL_BASE_FEE: u64 = 10;
+
+pub fn generate_hash() -> String {
+    String::from_utf8(
+        thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(20)
+            .collect::<Vec<_>>(),
+    )
+    .unwrap()
+}
+
+pub fn generate_xrpl_address() -> String {
+    let seed = Seed::random();
+    let (_, public_key) = seed.derive_keypair().unwrap();
+    public_key.derive_address()
+}
+
+pub fn generate_xrpl_pub_key() -> String {
+    String::from_utf8(
+        thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(52)
+            .collect::<Vec<_>>(),
+    )
+    .unwrap()
+}
+
+// Relayer for the account with a random XRPL address and public key
+pub fn generate_relayer(account: &SigningAccount) -> Relayer {
+    Relayer {
+        coreum_address: Addr::unchecked(account.address()),
+        xrpl_address: generate_xrpl_address(),
+        xrpl_pub_key: generate_xrpl_pub_key(),
+    }
+}
+
+// Fee that must be sent when instantiating the contract and registering tokens, since they issue a Coreum FT
+pub fn query_issue_fee(asset_ft: &AssetFT<'_, CoreumTestApp>) -> Vec<Coin> {
+    let issue_fee = asset_ft
+        .query_params(&QueryParamsRequest {})
+        .unwrap()
+        .params
+        .unwrap()
+        .issue_fee
+        .unwrap();
+    coins(issue_fee.amount.parse().unwrap(), issue_fee.denom)
+}
+
+// Instantiate message owned by the owner, where the evidences of all relayers are needed to reach the threshold
+pub fn default_instantiate_msg(owner: &SigningAccount, relayers: Vec<Relayer>) -> InstantiateMsg {
+    InstantiateMsg {
+        owner: Addr::unchecked(owner.address()),
+        evidence_threshold: relayers.len() as u32,
+        relayers,
+        used_ticket_sequence_threshold: DEFAULT_USED_TICKET_SEQUENCE_THRESHOLD,
+        trust_set_limit_amount: Uint128::new(DEFAULT_TRUST_SET_LIMIT_AMOUNT),
+        bridge_xrpl_address: generate_xrpl_address(),
+        xrpl_base_fee: DEFAULT_XRPL_BASE_FEE,
+        xrpl_network: XRPLNetwork::Mainnet,
+    }
+}
+
+// Stores the code of the contract and instantiates it, returning the address of the contract
+pub fn store_and_instantiate(
+    wasm: &Wasm<'_, CoreumTestApp>,
+    signer: &SigningAccount,
+    wasm_byte_code: &[u8],
+    msg: &InstantiateMsg,
+    issue_fee: &[Coin],
+) -> String {
+    let code_id = wasm
+        .store_code(wasm_byte_code, None, signer)
+        .unwrap()
+        .data
+        .code_id;
+    wasm.instantiate(
+        code_id,
+        msg,
+        None,
+        Some("coreumbridge-xrpl"),
+        issue_fee,
+        signer,
+    )
+    .unwrap()
+    .data
+    .address
+}
+
+// Instantiates the contract with the default instantiate message, owned by the owner
+pub fn instantiate_with_defaults(
+    app: &CoreumTestApp,
+    owner: &SigningAccount,
+    wasm_byte_code: &[u8],
+    relayers: Vec<Relayer>,
+) -> String {
+    store_and_instantiate(
+        &Wasm::new(app),
+        owner,
+        wasm_byte_code,
+        &default_instantiate_msg(owner, relayers),
+        &query_issue_fee(&AssetFT::new(app)),
+    )
+}
+
+// Saves the evidence from each of the relayer accounts
+pub fn save_evidence(
+    wasm: &Wasm<'_, CoreumTestApp>,
+    contract_addr: &str,
+    relayer_accounts: &[&SigningAccount],
+    evidence: &Evidence,
+) {
+    for relayer_account in relayer_accounts {
+        wasm.execute::<ExecuteMsg>(
+            contract_addr,
+            &ExecuteMsg::SaveEvidence {
+                evidence: evidence.clone(),
+                signature: None,
+                close_time: None,
+            },
+            &[],
+            relayer_account,
+        )
+        .unwrap();
+    }
+}
+
+// Recovers tickets with the account sequence and confirms the allocation of the tickets that follow it
+pub fn allocate_tickets(
+    wasm: &Wasm<'_, CoreumTestApp>,
+    contract_addr: &str,
+    owner: &SigningAccount,
+    relayer_accounts: &[&SigningAccount],
+    account_sequence: u64,
+    number_of_tickets: u32,
+) {
+    wasm.execute::<ExecuteMsg>(
+        contract_addr,
+        &ExecuteMsg::RecoverTickets {
+            account_sequence,
+            number_of_tickets: Some(number_of_tickets),
+        },
+        &[],
+        owner,
+    )
+    .unwrap();
+
+    save_evidence(
+        wasm,
+        contract_addr,
+        relayer_accounts,
+        &Evidence::XRPLTransactionResult {
+            tx_hash: Some(generate_hash()),
+            account_sequence: Some(account_sequence),
+            ticket_sequence: None,
+            transaction_result: TransactionResult::Accepted,
+            operation_result: Some(OperationResult::TicketsAllocation {
+                tickets: Some(
+                    (account_sequence + 1..=account_sequence + number_of_tickets as u64).collect(),
+                ),
+            }),
+        },
+    );
+}
+
+pub fn pending_operations(wasm: &Wasm<'_, CoreumTestApp>, contract_addr: &str) -> Vec<Operation> {
+    wasm.query::<QueryMsg, PendingOperationsResponse>(
+        contract_addr,
+        &QueryMsg::PendingOperations {
+            start_after_key: None,
+            limit: None,
+        },
+    )
+    .unwrap()
+    .operations
+}
+
+// Confirms the result of a pending operation on XRPL from each of the relayer accounts.
+// Ticket allocations need the allocated tickets, so they are confirmed with allocate_tickets instead
+pub fn resolve_operation(
+    wasm: &Wasm<'_, CoreumTestApp>,
+    contract_addr: &str,
+    relayer_accounts: &[&SigningAccount],
+    operation: &Operation,
+    transaction_result: TransactionResult,
+) {
+    // Invalid transactions never reach XRPL so they don't have a transaction hash
+    let tx_hash = match transaction_result {
+        TransactionResult::Invalid => None,
+        _ => Some(generate_hash()),
+    };
+
+    save_evidence(
+        wasm,
+        contract_addr,
+        relayer_accounts,
+        &Evidence::XRPLTransactionResult {
+            tx_hash,
+            account_sequence: operation.account_sequence,
+            ticket_sequence: operation.ticket_sequence,
+            transaction_result,
+            operation_result: None,
+        },
+    );
+}
+
+pub fn accept_operation(
+    wasm: &Wasm<'_, CoreumTestApp>,
+    contract_addr: &str,
+    relayer_accounts: &[&SigningAccount],
+    operation: &Operation,
+) {
+    resolve_operation(
+        wasm,
+        contract_addr,
+        relayer_accounts,
+        operation,
+        TransactionResult::Accepted,
+    );
+}
+
+pub fn reject_operation(
+    wasm: &Wasm<'_, CoreumTestApp>,
+    contract_addr: &str,
+    relayer_accounts: &[&SigningAccount],
+    operation: &Operation,
+) {
+    resolve_operation(
+        wasm,
+        contract_addr,
+        relayer_accounts,
+        operation,
+        TransactionResult::Rejected,
+    );
+}
+
+// Registers an XRPL token and accepts its TrustSet operation so that it can be bridged, returning its denom on Coreum.
+// The bridge needs an available ticket for the TrustSet operation, see allocate_tickets
+#[allow(clippy::too_many_arguments)]
+pub fn register_and_activate_xrpl_token(
+    wasm: &Wasm<'_, CoreumTestApp>,
+    contract_addr: &str,
+    owner: &SigningAccount,
+    relayer_accounts: &[&SigningAccount],
+    issuer: String,
+    currency: String,
+    sending_precision: i32,
+    max_holding_amount: Uint128,
+    issue_fee: &[Coin],
+) -> String {
+    let response = wasm
+        .execute::<ExecuteMsg>(
+            contract_addr,
+            &ExecuteMsg::RegisterXRPLToken {
+                issuer: issuer.clone(),
+                currency: currency.clone(),
+                sending_precision,
+                max_holding_amount,
+                bridging_fee: Uint128::zero(),
+                symbol: None,
+                description: None,
+                uri: None,
+                queue_if_no_tickets: None,
+            },
+            issue_fee,
+            owner,
+        )
+        .unwrap();
+
+    let denom = response
+        .events
+        .iter()
+        .flat_map(|event| event.attributes.iter())
+        .find(|attribute| attribute.key == "denom")
+        .unwrap()
+        .value
+        .clone();
+
+    let trust_set_operation = pending_operations(wasm, contract_addr)
+        .into_iter()
+        .find(|operation| {
+            matches!(
+                &operation.operation_type,
+                OperationType::TrustSet {
+                    issuer: operation_issuer,
+                    currency: operation_currency,
+                    ..
+                } if *operation_issuer == issuer && *operation_currency == currency
+            )
+        })
+        .unwrap();
+    accept_operation(wasm, contract_addr, relayer_accounts, &trust_set_operation);
+
+    denom
+}
@@ -1,12 +1,12 @@
 use std::collections::VecDeque;
 
-use cosmwasm_std::{StdResult, Storage};
+use cosmwasm_std::{BlockInfo, StdResult, Storage};
 
 use crate::{
     error::ContractError,
     evidence::TransactionResult,
     operation::{create_pending_operation, OperationType},
-    state::{AVAILABLE_TICKETS, CONFIG, PENDING_TICKET_UPDATE, USED_TICKETS_COUNTER},
+    state::{AVAILABLE_TICKETS, PENDING_TICKET_UPDATE, USED_TICKETS_COUNTER, XRPL_CONFIG},
 };
 
 // This function will be used to provide a ticket for a pending operation
@@ -30,36 +30,45 @@ pub fn allocate_ticket(storage: &mut dyn Storage) -> Result<u64, ContractError>
 // Once we confirm/reject a transaction, we need to register a ticket as used
 pub fn register_used_ticket(
     storage: &mut dyn Storage,
-    timestamp: u64,
+    block: &BlockInfo,
 ) -> Result<bool, ContractError> {
     let used_tickets = USED_TICKETS_COUNTER.load(storage)?;
-    let config = CONFIG.load(storage)?;
-
     USED_TICKETS_COUNTER.save(storage, &(used_tickets + 1))?;
 
-    // If we reach the max allowed tickets to be used, we need to create an operation to allocate new ones
-    if used_tickets + 1 >= config.used_ticket_sequence_threshold
-        && !PENDING_TICKET_UPDATE.load(storage)?
+    // If our creation of a ticket allocation operation failed because we have no tickets left, we need to propagate
+    // this so that we are aware that we need to allocate new tickets because we've run out of them
+    match allocate_tickets_if_needed(storage, block) {
+        Ok(_) => Ok(true),
+        Err(ContractError::NoAvailableTickets {}) => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+// If we reach the max allowed tickets to be used, we need to create an operation to allocate new ones
+// Returns whether the ticket allocation operation was created
+pub fn allocate_tickets_if_needed(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+) -> Result<bool, ContractError> {
+    let used_ticket_sequence_threshold = XRPL_CONFIG.load(storage)?.used_ticket_sequence_threshold;
+    if USED_TICKETS_COUNTER.load(storage)? < used_ticket_sequence_threshold
+        || PENDING_TICKET_UPDATE.load(storage)?
     {
-        // If our creation of a ticket allocation operation failed because we have no tickets left, we need to propagate
-        // this so that we are aware that we need to allocate new tickets because we've run out of them
-        match reserve_ticket(storage) {
-            Ok(ticket_to_update) => {
-                create_pending_operation(
-                    storage,
-                    timestamp,
-                    Some(ticket_to_update),
-                    None,
-                    OperationType::AllocateTickets {
-                        number: config.used_ticket_sequence_threshold,
-                    },
-                )?;
-                PENDING_TICKET_UPDATE.save(storage, &true)?;
-            }
-            Err(ContractError::NoAvailableTickets {}) => return Ok(false),
-            Err(e) => return Err(e),
-        }
+        return Ok(false);
     }
+
+    let ticket_to_update = reserve_ticket(storage)?;
+    create_pending_operation(
+        storage,
+        block,
+        Some(ticket_to_update),
+        None,
+        OperationType::AllocateTickets {
+            number: used_ticket_sequence_threshold,
+        },
+    )?;
+    PENDING_TICKET_UPDATE.save(storage, &true)?;
+
     Ok(true)
 }
 
@@ -1,11 +1,22 @@
-use cosmwasm_std::Uint128;
+use coreum_wasm_sdk::core::CoreumMsg;
+use std::cmp::Ordering;
+
+use cosmwasm_std::{Event, Order, Response, StdResult, Storage, Uint128};
 
 use crate::{
     contract::{validate_sending_precision, XRP_CURRENCY, XRP_ISSUER},
     error::ContractError,
-    state::TokenState,
+    evidence::TransactionResult,
+    state::{
+        RoundingMode, TokenState, TokenStateTransition, TokenStateTransitionReason,
+        XRPLIssuerConfig, XRPLToken, COREUM_TOKENS, OPERATIONS_CONFIG, TOKEN_FAILURES,
+        TOKEN_FREEZES, TOKEN_STATE_HISTORY, XRPL_ISSUER_CONFIGS, XRPL_TOKENS,
+    },
 };
 
+// Amount of state transitions we keep in the history of each token
+pub const MAX_TOKEN_STATE_HISTORY: usize = 20;
+
 // Build the key to access the Tokens saved in state
 pub fn build_xrpl_token_key(issuer: &str, currency: &str) -> String {
     // Issuer+currency is the key we use to find an XRPL
@@ -17,6 +28,44 @@ pub fn is_token_xrp(issuer: &str, currency: &str) -> bool {
     issuer == XRP_ISSUER && currency == XRP_CURRENCY
 }
 
+// Returns 10^exponent, or None if it doesn't fit in a Uint128 (i.e. if the exponent is higher than 38)
+pub fn checked_decimals_factor(exponent: u32) -> Option<Uint128> {
+    Uint128::new(10).checked_pow(exponent).ok()
+}
+
+// Converts an amount between two amounts of decimals (e.g. the 15 decimals of XRPL and the decimals of a Coreum token)
+// Scaling down by a factor that doesn't fit in a Uint128 always results in 0, while scaling up fails if the result doesn't fit
+pub fn convert_amount_decimals(
+    from_decimals: u32,
+    to_decimals: u32,
+    amount: Uint128,
+) -> Result<Uint128, ContractError> {
+    let converted_amount = match from_decimals.cmp(&to_decimals) {
+        Ordering::Less => checked_decimals_factor(to_decimals - from_decimals)
+            .and_then(|factor| amount.checked_mul(factor).ok())
+            .ok_or(ContractError::DecimalsConversionOverflow {
+                from_decimals,
+                to_decimals,
+            })?,
+        Ordering::Greater => match checked_decimals_factor(from_decimals - to_decimals) {
+            Some(factor) => amount.checked_div(factor)?,
+            None => Uint128::zero(),
+        },
+        Ordering::Equal => amount,
+    };
+
+    Ok(converted_amount)
+}
+
+// Amount of XRPL originated tokens registered in the bridge, XRP is not included because it's not registered
+pub fn count_registered_xrpl_tokens(storage: &dyn Storage) -> u32 {
+    let tokens = XRPL_TOKENS
+        .keys(storage, None, None, Order::Ascending)
+        .count() as u32;
+
+    tokens.saturating_sub(1)
+}
+
 // Helper function to update the status of a token
 pub fn set_token_state(
     state: &mut TokenState,
@@ -36,6 +85,42 @@ pub fn set_token_state(
     Ok(())
 }
 
+// Helper function to store a state transition of a token in its history and build the event that informs about it
+pub fn record_token_state_transition(
+    storage: &mut dyn Storage,
+    denom: &str,
+    from: Option<TokenState>,
+    to: TokenState,
+    reason: TokenStateTransitionReason,
+    timestamp: u64,
+) -> Result<Event, ContractError> {
+    let event = Event::new("token_state_transition")
+        .add_attribute("denom", denom)
+        .add_attribute("from", from.as_ref().map_or("none", TokenState::as_str))
+        .add_attribute("to", to.as_str())
+        .add_attribute("reason", reason.as_str());
+
+    let mut history = TOKEN_STATE_HISTORY
+        .may_load(storage, denom.to_owned())?
+        .unwrap_or_default();
+
+    history.push(TokenStateTransition {
+        from,
+        to,
+        reason,
+        timestamp,
+    });
+
+    // Only the most recent transitions are kept
+    if history.len() > MAX_TOKEN_STATE_HISTORY {
+        history.drain(..history.len() - MAX_TOKEN_STATE_HISTORY);
+    }
+
+    TOKEN_STATE_HISTORY.save(storage, denom.to_owned(), &history)?;
+
+    Ok(event)
+}
+
 // Helper function to update the sending precision of a token
 pub fn set_token_sending_precision(
     sending_precision: &mut i32,
@@ -63,6 +148,16 @@ pub fn set_token_bridging_fee(
     Ok(())
 }
 
+// Helper function to update the rounding mode of a token
+pub fn set_token_rounding_mode(
+    rounding_mode: &mut RoundingMode,
+    target_rounding_mode: Option<RoundingMode>,
+) {
+    if let Some(target_rounding_mode) = target_rounding_mode {
+        *rounding_mode = target_rounding_mode;
+    }
+}
+
 // Helper function to update the max holding amount of a token
 pub fn set_token_max_holding_amount(
     current_bridged_amount: Uint128,
@@ -80,3 +175,158 @@ pub fn set_token_max_holding_amount(
 
     Ok(())
 }
+
+pub fn is_token_registered(storage: &dyn Storage, denom: &str) -> StdResult<bool> {
+    Ok(COREUM_TOKENS.has(storage, denom.to_owned())
+        || XRPL_TOKENS
+            .idx
+            .coreum_denom
+            .item(storage, denom.to_owned())?
+            .is_some())
+}
+
+// Freezes that were not confirmed by the owner stop applying once they expire
+pub fn is_token_frozen(storage: &dyn Storage, denom: &str, timestamp: u64) -> StdResult<bool> {
+    Ok(match TOKEN_FREEZES.may_load(storage, denom.to_owned())? {
+        Some(freeze) => match freeze.expires_at {
+            Some(expires_at) => timestamp < expires_at,
+            None => true,
+        },
+        None => false,
+    })
+}
+
+pub fn assert_token_not_frozen(
+    storage: &dyn Storage,
+    denom: &str,
+    timestamp: u64,
+) -> Result<(), ContractError> {
+    if is_token_frozen(storage, denom, timestamp)? {
+        return Err(ContractError::TokenFrozen {});
+    }
+    Ok(())
+}
+
+// Registered XRPL originated tokens (with their keys) of an issuer
+pub fn xrpl_tokens_of_issuer(
+    storage: &dyn Storage,
+    issuer: &str,
+) -> StdResult<Vec<(String, XRPLToken)>> {
+    XRPL_TOKENS
+        .idx
+        .issuer
+        .prefix(issuer.to_owned())
+        .range(storage, None, None, Order::Ascending)
+        .collect()
+}
+
+pub fn handle_issuer_config_change(
+    storage: &mut dyn Storage,
+    issuer: String,
+    issuer_config: XRPLIssuerConfig,
+    timestamp: u64,
+    response: &mut Response<CoreumMsg>,
+) -> Result<(), ContractError> {
+    let operations_config = OPERATIONS_CONFIG.load(storage)?;
+
+    // A global freeze or clawback makes deliveries of the tokens of the issuer unreliable
+    if operations_config.auto_disable_on_issuer_change
+        && (issuer_config.global_freeze || issuer_config.clawback_enabled)
+    {
+        for (key, mut token) in xrpl_tokens_of_issuer(storage, &issuer)? {
+            if token.state.ne(&TokenState::Enabled) {
+                continue;
+            }
+            token.state = TokenState::Disabled;
+            XRPL_TOKENS.save(storage, key, &token)?;
+
+            let event = record_token_state_transition(
+                storage,
+                &token.coreum_denom,
+                Some(TokenState::Enabled),
+                TokenState::Disabled,
+                TokenStateTransitionReason::IssuerConfigChange,
+                timestamp,
+            )?;
+            *response = response.to_owned().add_event(event);
+        }
+    }
+
+    XRPL_ISSUER_CONFIGS.save(storage, issuer, &issuer_config)?;
+
+    Ok(())
+}
+
+// Counts the consecutive transfers of a token to XRPL that were rejected, disabling the token when they reach the threshold
+// because it usually means that there is a problem with the issuer or the configuration of the token
+pub fn track_token_transfer_result(
+    storage: &mut dyn Storage,
+    issuer: &str,
+    currency: &str,
+    transaction_result: &TransactionResult,
+    timestamp: u64,
+    response: &mut Response<CoreumMsg>,
+) -> Result<(), ContractError> {
+    let key = build_xrpl_token_key(issuer, currency);
+    let xrpl_token = XRPL_TOKENS.may_load(storage, key.clone())?;
+    let coreum_token = match xrpl_token {
+        Some(_) => None,
+        None => COREUM_TOKENS
+            .idx
+            .xrpl_currency
+            .item(storage, currency.to_owned())?
+            .map(|(_, ct)| ct),
+    };
+    let denom = match (&xrpl_token, &coreum_token) {
+        (Some(xrpl_token), _) => xrpl_token.coreum_denom.clone(),
+        (None, Some(coreum_token)) => coreum_token.denom.clone(),
+        (None, None) => return Ok(()),
+    };
+
+    // Invalid transactions never reached XRPL so they don't say anything about the token
+    match transaction_result {
+        TransactionResult::Accepted => {
+            TOKEN_FAILURES.remove(storage, denom);
+            return Ok(());
+        }
+        TransactionResult::Invalid => return Ok(()),
+        TransactionResult::Rejected => (),
+    }
+
+    let failures = TOKEN_FAILURES
+        .may_load(storage, denom.clone())?
+        .unwrap_or_default()
+        + 1;
+    let threshold = OPERATIONS_CONFIG.load(storage)?.token_failure_threshold;
+    if threshold == 0 || failures < threshold {
+        TOKEN_FAILURES.save(storage, denom, &failures)?;
+        return Ok(());
+    }
+
+    // The count starts again if the owner enables the token
+    TOKEN_FAILURES.remove(storage, denom.clone());
+    match (xrpl_token, coreum_token) {
+        (Some(mut xrpl_token), _) if xrpl_token.state.eq(&TokenState::Enabled) => {
+            xrpl_token.state = TokenState::Disabled;
+            XRPL_TOKENS.save(storage, key, &xrpl_token)?;
+        }
+        (None, Some(mut coreum_token)) if coreum_token.state.eq(&TokenState::Enabled) => {
+            coreum_token.state = TokenState::Disabled;
+            COREUM_TOKENS.save(storage, denom.clone(), &coreum_token)?;
+        }
+        _ => return Ok(()),
+    }
+
+    let event = record_token_state_transition(
+        storage,
+        &denom,
+        Some(TokenState::Enabled),
+        TokenState::Disabled,
+        TokenStateTransitionReason::RepeatedOperationFailures,
+        timestamp,
+    )?
+    .add_attribute("consecutive_failures", failures.to_string());
+    *response = response.to_owned().add_event(event);
+
+    Ok(())
+}
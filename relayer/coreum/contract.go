@@ -63,7 +63,8 @@ const (
 	ExecHaltBridge                    ExecMethod = "halt_bridge"
 	ExecResumeBridge                  ExecMethod = "resume_bridge"
 	ExecUpdateXRPLBaseFee             ExecMethod = "update_xrpl_base_fee"
-	ExecUpdateProhibitedXRPLAddresses ExecMethod = "update_prohibited_xrpl_addresses"
+	ExecAddProhibitedXRPLRecipient    ExecMethod = "add_prohibited_xrpl_recipient"
+	ExecRemoveProhibitedXRPLRecipient ExecMethod = "remove_prohibited_xrpl_recipient"
 	ExecCancelPendingOperation        ExecMethod = "cancel_pending_operation"
 )
 
@@ -93,8 +94,9 @@ type BridgeState string
 
 // BridgeState values.
 const (
-	BridgeStateActive BridgeState = "active"
-	BridgeStateHalted BridgeState = "halted"
+	BridgeStateActive      BridgeState = "active"
+	BridgeStateHalted      BridgeState = "halted"
+	BridgeStateMaintenance BridgeState = "maintenance"
 )
 
 // QueryMethod is contract query method.
@@ -233,11 +235,12 @@ type OperationTypeTrustSet struct {
 
 // OperationTypeCoreumToXRPLTransfer is coreum to XRPL transfer operation type.
 type OperationTypeCoreumToXRPLTransfer struct {
-	Issuer    string       `json:"issuer"`
-	Currency  string       `json:"currency"`
-	Amount    sdkmath.Int  `json:"amount"`
-	MaxAmount *sdkmath.Int `json:"max_amount,omitempty"`
-	Recipient string       `json:"recipient"`
+	Issuer         string       `json:"issuer"`
+	Currency       string       `json:"currency"`
+	Amount         sdkmath.Int  `json:"amount"`
+	MaxAmount      *sdkmath.Int `json:"max_amount,omitempty"`
+	Recipient      string       `json:"recipient"`
+	DestinationTag *uint32      `json:"destination_tag,omitempty"`
 }
 
 // OperationTypeRotateKeys is XRPL multi-signing address keys rotation operation type.
@@ -416,8 +419,8 @@ type updateXRPLBaseFeeRequest struct {
 	XRPLBaseFee uint32 `json:"xrpl_base_fee"`
 }
 
-type updateProhibitedXRPLAddressesRequest struct {
-	ProhibitedXRPLAddresses []string `json:"prohibited_xrpl_addresses"`
+type prohibitedXRPLRecipientRequest struct {
+	Recipient string `json:"recipient"`
 }
 
 type cancelPendingOperationRequest struct {
@@ -482,6 +485,7 @@ type transactionEvidencesResponse struct {
 }
 
 type prohibitedXRPLAddressesResponse struct {
+	LastKey                 string   `json:"last_key"`
 	ProhibitedXRPLAddresses []string `json:"prohibited_xrpl_addresses"`
 }
 
@@ -1235,19 +1239,53 @@ func (c *ContractClient) CancelPendingOperation(
 	return txRes, nil
 }
 
-// UpdateProhibitedXRPLAddresses executes `update_prohibited_xrpl_addresses` method.
+// UpdateProhibitedXRPLAddresses replaces the list of the prohibited XRPL addresses, executing
+// `add_prohibited_xrpl_recipient` and `remove_prohibited_xrpl_recipient` methods in a single transaction.
+// The bridge XRPL address is always kept.
 func (c *ContractClient) UpdateProhibitedXRPLAddresses(
 	ctx context.Context,
 	sender sdk.AccAddress,
 	prohibitedXRPLAddresses []string,
 ) (*sdk.TxResponse, error) {
-	txRes, err := c.execute(ctx, sender, execRequest{
-		Body: map[ExecMethod]updateProhibitedXRPLAddressesRequest{
-			ExecUpdateProhibitedXRPLAddresses: {
-				ProhibitedXRPLAddresses: prohibitedXRPLAddresses,
+	cfg, err := c.GetContractConfig(ctx)
+	if err != nil {
+		return nil, err
+	}
+	currentProhibitedXRPLAddresses, err := c.GetProhibitedXRPLAddresses(ctx)
+	if err != nil {
+		return nil, err
+	}
+
+	requests := make([]execRequest, 0)
+	for _, address := range prohibitedXRPLAddresses {
+		if lo.Contains(currentProhibitedXRPLAddresses, address) {
+			continue
+		}
+		requests = append(requests, execRequest{
+			Body: map[ExecMethod]prohibitedXRPLRecipientRequest{
+				ExecAddProhibitedXRPLRecipient: {
+					Recipient: address,
+				},
 			},
-		},
-	})
+		})
+	}
+	for _, address := range currentProhibitedXRPLAddresses {
+		if address == cfg.BridgeXRPLAddress || lo.Contains(prohibitedXRPLAddresses, address) {
+			continue
+		}
+		requests = append(requests, execRequest{
+			Body: map[ExecMethod]prohibitedXRPLRecipientRequest{
+				ExecRemoveProhibitedXRPLRecipient: {
+					Recipient: address,
+				},
+			},
+		})
+	}
+	if len(requests) == 0 {
+		return nil, nil
+	}
+
+	txRes, err := c.execute(ctx, sender, requests...)
 	if err != nil {
 		return nil, err
 	}
@@ -1446,15 +1484,27 @@ func (c *ContractClient) GetTransactionEvidences(ctx context.Context) ([]Transac
 
 // GetProhibitedXRPLAddresses returns the list prohibited XRPL addresses.
 func (c *ContractClient) GetProhibitedXRPLAddresses(ctx context.Context) ([]string, error) {
-	var response prohibitedXRPLAddressesResponse
-	err := c.query(ctx, map[QueryMethod]interface{}{
-		QueryMethodProhibitedXRPLAddresses: struct{}{},
-	}, &response)
-	if err != nil {
-		return nil, err
+	prohibitedXRPLAddresses := make([]string, 0)
+	lastKey := ""
+	for {
+		var response prohibitedXRPLAddressesResponse
+		err := c.query(ctx, map[QueryMethod]pagingStringKeyRequest{
+			QueryMethodProhibitedXRPLAddresses: {
+				StartAfterKey: lastKey,
+				Limit:         &c.cfg.PageLimit,
+			},
+		}, &response)
+		if err != nil {
+			return nil, err
+		}
+		if len(response.ProhibitedXRPLAddresses) == 0 {
+			break
+		}
+		prohibitedXRPLAddresses = append(prohibitedXRPLAddresses, response.ProhibitedXRPLAddresses...)
+		lastKey = response.LastKey
 	}
 
-	return response.ProhibitedXRPLAddresses, nil
+	return prohibitedXRPLAddresses, nil
 }
 
 // GetXRPLToCoreumTracingInfo returns XRPL to Coreum tracing info.
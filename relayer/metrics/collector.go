@@ -408,6 +408,8 @@ func (c *PeriodicCollector) collectBridgeState(ctx context.Context) error {
 		c.registry.BridgeStateGauge.Set(0)
 	case coreum.BridgeStateActive:
 		c.registry.BridgeStateGauge.Set(1)
+	case coreum.BridgeStateMaintenance:
+		c.registry.BridgeStateGauge.Set(2)
 	default:
 		return errors.Wrapf(err, "received unexpected bridge state:%s", contractCfg.BridgeState)
 	}
@@ -173,7 +173,8 @@ func buildPaymentTx(
 		)
 	}
 	tx := rippledata.Payment{
-		Destination: *recipient,
+		Destination:    *recipient,
+		DestinationTag: operation.OperationType.CoreumToXRPLTransfer.DestinationTag,
 		TxBase: rippledata.TxBase{
 			Account:         bridgeXRPLAddress,
 			TransactionType: rippledata.PAYMENT,